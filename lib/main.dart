@@ -2,6 +2,7 @@ import 'package:flipedit/app.dart';
 import 'package:flipedit/di/service_locator.dart';
 import 'package:flipedit/viewmodels/project_viewmodel.dart';
 import 'package:flipedit/utils/texture_bridge_check.dart';
+import 'package:flipedit/utils/display_refresh_rate.dart';
 import 'package:window_manager/window_manager.dart';
 import 'package:flutter/material.dart';
 import 'package:flipedit/utils/logger.dart';
@@ -53,6 +54,7 @@ class _MyWindowListener implements WindowListener {
 Future<void> main() async {
   await RustLib.init();
   WidgetsFlutterBinding.ensureInitialized();
+  DisplayRefreshRateReporter.start();
 
   // Note: Irondash engine context is initialized automatically when needed
   logInfo('main', 'Irondash engine context ready');
@@ -13,20 +13,13 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 String greet({required String name}) =>
     RustLib.instance.api.crateApiSimpleGreet(name: name);
 
-/// Create a new video texture using irondash for zero-copy rendering
-PlatformInt64 createVideoTexture({
-  required int width,
-  required int height,
-  required PlatformInt64 engineHandle,
-}) => RustLib.instance.api.crateApiSimpleCreateVideoTexture(
-  width: width,
-  height: height,
-  engineHandle: engineHandle,
-);
+/// Pace texture updates to the display's refresh rate (Hz), as reported by Flutter; faster
+/// sources are coalesced
+void setDisplayRefreshRate({required double hz}) =>
+    RustLib.instance.api.crateApiSimpleSetDisplayRefreshRate(hz: hz);
 
-/// Update video frame data for all irondash textures
-bool updateVideoFrame({required FrameData frameData}) =>
-    RustLib.instance.api.crateApiSimpleUpdateVideoFrame(frameData: frameData);
+double getDisplayRefreshRate() =>
+    RustLib.instance.api.crateApiSimpleGetDisplayRefreshRate();
 
 /// Get the number of active irondash textures
 BigInt getTextureCount() =>
@@ -73,6 +66,8 @@ abstract class GesTimelinePlayer implements RustOpaqueInterface {
   @override
   Future<void> dispose();
 
+  bool getAutoTransition();
+
   int? getDurationMs();
 
   FrameData? getLatestFrame();
@@ -98,6 +93,10 @@ abstract class GesTimelinePlayer implements RustOpaqueInterface {
 
   Future<void> seekToPosition({required int positionMs});
 
+  /// Crossfade where an added or moved clip overlaps another clip on its track, for as long
+  /// as they overlap. Saved with the loaded timeline, so each timeline keeps its own setting.
+  Future<void> setAutoTransition({required bool enabled});
+
   Stream<FrameData> setupFrameStream();
 
   Stream<(double, BigInt)> setupPositionStream();
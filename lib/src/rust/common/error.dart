@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.7.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Serializable error returned across the FFI boundary
+class FlipEditError implements FrbException {
+  final FlipEditErrorCode code;
+  final String message;
+
+  /// Path of the GStreamer element that raised the error, if any
+  final String? element;
+
+  /// GStreamer debug details, useful for bug reports
+  final String? debug;
+
+  const FlipEditError({
+    required this.code,
+    required this.message,
+    this.element,
+    this.debug,
+  });
+
+  @override
+  int get hashCode =>
+      code.hashCode ^ message.hashCode ^ element.hashCode ^ debug.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FlipEditError &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          message == other.message &&
+          element == other.element &&
+          debug == other.debug;
+}
+
+/// Error categories Flutter can react to programmatically
+enum FlipEditErrorCode {
+  fileNotFound,
+  missingPlugin,
+  decodeError,
+  notLoaded,
+  stateChangeFailed,
+  seekFailed,
+  resourceError,
+  invalidArgument,
+
+  /// The Dart bindings were generated for a different version of the native library
+  incompatibleVersion,
+
+  /// The player was disposed, or the GStreamer worker that owns it has stopped
+  playerStopped,
+  internal,
+  ;
+}
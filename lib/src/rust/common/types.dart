@@ -6,6 +6,145 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+/// One subtitle on the caption track, shown from `start_ms` until `end_ms` on the timeline
+class CaptionCue {
+  final int id;
+  final int startMs;
+  final int endMs;
+
+  /// Plain text; lines are separated by `\n`
+  final String text;
+  final CaptionStyle style;
+
+  const CaptionCue({
+    required this.id,
+    required this.startMs,
+    required this.endMs,
+    required this.text,
+    required this.style,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      startMs.hashCode ^
+      endMs.hashCode ^
+      text.hashCode ^
+      style.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CaptionCue &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          text == other.text &&
+          style == other.style;
+}
+
+/// Per-cue formatting, kept to what both SRT and WebVTT can carry
+class CaptionStyle {
+  final bool bold;
+  final bool italic;
+
+  /// Top of the frame instead of the bottom, e.g. to keep clear of on-screen text
+  final bool top;
+
+  const CaptionStyle({
+    required this.bold,
+    required this.italic,
+    required this.top,
+  });
+
+  @override
+  int get hashCode => bold.hashCode ^ italic.hashCode ^ top.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CaptionStyle &&
+          runtimeType == other.runtimeType &&
+          bold == other.bold &&
+          italic == other.italic &&
+          top == other.top;
+}
+
+/// How a clip's picture fills its preview box when their aspect ratios differ
+enum ClipFitMode {
+  /// Scale to the box exactly, distorting the picture
+  stretch,
+
+  /// Scale to fit inside the box, leaving bars
+  fit,
+
+  /// Scale to cover the box, cropping the overflow
+  fill,
+
+  /// Show at 100%, centred and cropped to the box
+  original,
+  ;
+}
+
+/// How a clip's picture is mirrored and turned before it's fitted into its preview box
+class ClipOrientation {
+  /// Clockwise. Whole quarter turns turn the frame itself, so a quarter turn makes a
+  /// landscape clip portrait; the rest tilts the picture inside its frame.
+  final double rotationDegrees;
+
+  /// Mirrored before rotating
+  final bool flipHorizontal;
+  final bool flipVertical;
+
+  /// Rotation over time, interpolated linearly and held past the ends; replaces
+  /// `rotation_degrees` except for its quarter turns
+  final List<RotationKeyframe> rotationKeyframes;
+
+  const ClipOrientation({
+    required this.rotationDegrees,
+    required this.flipHorizontal,
+    required this.flipVertical,
+    required this.rotationKeyframes,
+  });
+
+  @override
+  int get hashCode =>
+      rotationDegrees.hashCode ^
+      flipHorizontal.hashCode ^
+      flipVertical.hashCode ^
+      rotationKeyframes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClipOrientation &&
+          runtimeType == other.runtimeType &&
+          rotationDegrees == other.rotationDegrees &&
+          flipHorizontal == other.flipHorizontal &&
+          flipVertical == other.flipVertical &&
+          rotationKeyframes == other.rotationKeyframes;
+}
+
+/// Part of a clip's source shown at a point on the timeline
+class CropKeyframe {
+  final BigInt timeMs;
+  final SourceRect rect;
+
+  const CropKeyframe({required this.timeMs, required this.rect});
+
+  @override
+  int get hashCode => timeMs.hashCode ^ rect.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CropKeyframe &&
+          runtimeType == other.runtimeType &&
+          timeMs == other.timeMs &&
+          rect == other.rect;
+}
+
 class FrameData {
   final Uint8List data;
   final int width;
@@ -34,6 +173,54 @@ class FrameData {
           textureId == other.textureId;
 }
 
+/// Clockwise rotation of a clip at a point on the timeline
+class RotationKeyframe {
+  final BigInt timeMs;
+  final double degrees;
+
+  const RotationKeyframe({required this.timeMs, required this.degrees});
+
+  @override
+  int get hashCode => timeMs.hashCode ^ degrees.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RotationKeyframe &&
+          runtimeType == other.runtimeType &&
+          timeMs == other.timeMs &&
+          degrees == other.degrees;
+}
+
+/// Part of a clip's source frame, as fractions of its width and height
+class SourceRect {
+  final double x;
+  final double y;
+  final double width;
+  final double height;
+
+  const SourceRect({
+    required this.x,
+    required this.y,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      x.hashCode ^ y.hashCode ^ width.hashCode ^ height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SourceRect &&
+          runtimeType == other.runtimeType &&
+          x == other.x &&
+          y == other.y &&
+          width == other.width &&
+          height == other.height;
+}
+
 class TextureFrame {
   final BigInt textureId;
   final int width;
@@ -78,6 +265,28 @@ class TimelineClip {
   final double previewWidth;
   final double previewHeight;
 
+  /// 0.0 (transparent) to 1.0 (opaque)
+  final double previewOpacity;
+
+  /// Streams of the source this clip uses, e.g. `Audio` to drop a clip's picture
+  final TrackType trackType;
+
+  /// How the picture fills the preview box
+  final ClipFitMode fitMode;
+  final ClipOrientation orientation;
+
+  /// Pan and zoom over the source; empty uses the fit mode's crop
+  final List<CropKeyframe> cropKeyframes;
+
+  /// The clip's own audio effects, applied before its track's
+  final List<TrackAudioEffect> audioEffects;
+
+  /// Tone mapping forced on or off for this clip; `None` follows the player setting
+  final bool? toneMapping;
+
+  /// Length of the crossfade from the clip this one overlaps on its track, 0 for a cut
+  final int transitionInMs;
+
   const TimelineClip({
     this.id,
     required this.trackId,
@@ -90,6 +299,14 @@ class TimelineClip {
     required this.previewPositionY,
     required this.previewWidth,
     required this.previewHeight,
+    required this.previewOpacity,
+    required this.trackType,
+    required this.fitMode,
+    required this.orientation,
+    required this.cropKeyframes,
+    required this.audioEffects,
+    this.toneMapping,
+    required this.transitionInMs,
   });
 
   @override
@@ -104,7 +321,15 @@ class TimelineClip {
       previewPositionX.hashCode ^
       previewPositionY.hashCode ^
       previewWidth.hashCode ^
-      previewHeight.hashCode;
+      previewHeight.hashCode ^
+      previewOpacity.hashCode ^
+      trackType.hashCode ^
+      fitMode.hashCode ^
+      orientation.hashCode ^
+      cropKeyframes.hashCode ^
+      audioEffects.hashCode ^
+      toneMapping.hashCode ^
+      transitionInMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -121,23 +346,69 @@ class TimelineClip {
           previewPositionX == other.previewPositionX &&
           previewPositionY == other.previewPositionY &&
           previewWidth == other.previewWidth &&
-          previewHeight == other.previewHeight;
+          previewHeight == other.previewHeight &&
+          previewOpacity == other.previewOpacity &&
+          trackType == other.trackType &&
+          fitMode == other.fitMode &&
+          orientation == other.orientation &&
+          cropKeyframes == other.cropKeyframes &&
+          audioEffects == other.audioEffects &&
+          toneMapping == other.toneMapping &&
+          transitionInMs == other.transitionInMs;
 }
 
 class TimelineData {
   final List<TimelineTrack> tracks;
 
-  const TimelineData({required this.tracks});
+  /// The caption track, sorted by start time
+  final List<CaptionCue> captions;
+  final List<TimelineMarker> markers;
+
+  /// Crossfade where an added or moved clip overlaps another clip on its track
+  final bool autoTransition;
+
+  const TimelineData({
+    required this.tracks,
+    required this.captions,
+    required this.markers,
+    required this.autoTransition,
+  });
 
   @override
-  int get hashCode => tracks.hashCode;
+  int get hashCode =>
+      tracks.hashCode ^
+      captions.hashCode ^
+      markers.hashCode ^
+      autoTransition.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is TimelineData &&
           runtimeType == other.runtimeType &&
-          tracks == other.tracks;
+          tracks == other.tracks &&
+          captions == other.captions &&
+          markers == other.markers &&
+          autoTransition == other.autoTransition;
+}
+
+/// A named point on the timeline, e.g. the start of a chapter
+class TimelineMarker {
+  final int positionMs;
+  final String name;
+
+  const TimelineMarker({required this.positionMs, required this.name});
+
+  @override
+  int get hashCode => positionMs.hashCode ^ name.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimelineMarker &&
+          runtimeType == other.runtimeType &&
+          positionMs == other.positionMs &&
+          name == other.name;
 }
 
 class TimelineTrack {
@@ -145,14 +416,35 @@ class TimelineTrack {
   final String name;
   final List<TimelineClip> clips;
 
+  /// Effect chain applied to the track's audio, in order
+  final List<TrackAudioEffect> audioEffects;
+
+  /// Volume automation, sorted by time; e.g. generated by ducking
+  final List<VolumeKeyframe> volumeKeyframes;
+  final TrackType trackType;
+
+  /// Compositing order: higher priorities draw on top, equal ones in list order (later on top)
+  final int priority;
+
   const TimelineTrack({
     required this.id,
     required this.name,
     required this.clips,
+    required this.audioEffects,
+    required this.volumeKeyframes,
+    required this.trackType,
+    required this.priority,
   });
 
   @override
-  int get hashCode => id.hashCode ^ name.hashCode ^ clips.hashCode;
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      clips.hashCode ^
+      audioEffects.hashCode ^
+      volumeKeyframes.hashCode ^
+      trackType.hashCode ^
+      priority.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -161,5 +453,67 @@ class TimelineTrack {
           runtimeType == other.runtimeType &&
           id == other.id &&
           name == other.name &&
-          clips == other.clips;
+          clips == other.clips &&
+          audioEffects == other.audioEffects &&
+          volumeKeyframes == other.volumeKeyframes &&
+          trackType == other.trackType &&
+          priority == other.priority;
+}
+
+/// An audio effect in a track's chain, applied to every clip on the track
+class TrackAudioEffect {
+  final int effectId;
+
+  /// GStreamer element, e.g. `equalizer-10bands` or `audiodynamic`
+  final String factoryName;
+
+  /// Parameter values by name, in the units described by `AudioEffectParameter`
+  final Map<String, double> parameters;
+
+  const TrackAudioEffect({
+    required this.effectId,
+    required this.factoryName,
+    required this.parameters,
+  });
+
+  @override
+  int get hashCode =>
+      effectId.hashCode ^ factoryName.hashCode ^ parameters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrackAudioEffect &&
+          runtimeType == other.runtimeType &&
+          effectId == other.effectId &&
+          factoryName == other.factoryName &&
+          parameters == other.parameters;
+}
+
+/// Which streams a track or clip plays. A clip plays a stream only if both it and its track
+/// allow it, so an audio track never shows video.
+enum TrackType {
+  both,
+  video,
+  audio,
+  ;
+}
+
+/// A point of a track's volume automation, in timeline time
+class VolumeKeyframe {
+  final BigInt timeMs;
+  final double gainDb;
+
+  const VolumeKeyframe({required this.timeMs, required this.gainDb});
+
+  @override
+  int get hashCode => timeMs.hashCode ^ gainDb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VolumeKeyframe &&
+          runtimeType == other.runtimeType &&
+          timeMs == other.timeMs &&
+          gainDb == other.gainDb;
 }
@@ -5,6 +5,7 @@
 
 import 'api/bridge.dart';
 import 'api/simple.dart';
+import 'common/error.dart';
 import 'common/types.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -66,7 +67,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.7.0';
 
   @override
-  int get rustContentHash => -62202027;
+  int get rustContentHash => 1273942107;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -86,6 +87,10 @@ abstract class RustLibApi extends BaseApi {
     required GesTimelinePlayer that,
   });
 
+  bool crateApiSimpleGesTimelinePlayerGetAutoTransition({
+    required GesTimelinePlayer that,
+  });
+
   int? crateApiSimpleGesTimelinePlayerGetDurationMs({
     required GesTimelinePlayer that,
   });
@@ -134,6 +139,11 @@ abstract class RustLibApi extends BaseApi {
     required int positionMs,
   });
 
+  Future<void> crateApiSimpleGesTimelinePlayerSetAutoTransition({
+    required GesTimelinePlayer that,
+    required bool enabled,
+  });
+
   Stream<FrameData> crateApiSimpleGesTimelinePlayerSetupFrameStream({
     required GesTimelinePlayer that,
   });
@@ -302,11 +312,7 @@ abstract class RustLibApi extends BaseApi {
     required PlatformInt64 engineHandle,
   });
 
-  PlatformInt64 crateApiSimpleCreateVideoTexture({
-    required int width,
-    required int height,
-    required PlatformInt64 engineHandle,
-  });
+  double crateApiSimpleGetDisplayRefreshRate();
 
   BigInt crateApiSimpleGetTextureCount();
 
@@ -318,6 +324,8 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiBridgeInitApp();
 
+  void crateApiSimpleSetDisplayRefreshRate({required double hz});
+
   PlatformInt64 crateApiSimplePlayBasicVideo({
     required String filePath,
     required PlatformInt64 engineHandle,
@@ -329,8 +337,6 @@ abstract class RustLibApi extends BaseApi {
     required PlatformInt64 engineHandle,
   });
 
-  bool crateApiSimpleUpdateVideoFrame({required FrameData frameData});
-
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_GesTimelinePlayer;
 
@@ -389,7 +395,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_64,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerCreateTextureConstMeta,
         argValues: [that, engineHandle],
@@ -425,7 +431,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerDisposeConstMeta,
         argValues: [that],
@@ -440,6 +446,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that"],
       );
 
+  @override
+  bool crateApiSimpleGesTimelinePlayerGetAutoTransition({
+    required GesTimelinePlayer that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer(
+            that,
+            serializer,
+          );
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_flip_edit_error,
+        ),
+        constMeta: kCrateApiSimpleGesTimelinePlayerGetAutoTransitionConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGesTimelinePlayerGetAutoTransitionConstMeta =>
+      const TaskConstMeta(
+        debugName: "GesTimelinePlayer_get_auto_transition",
+        argNames: ["that"],
+      );
+
   @override
   int? crateApiSimpleGesTimelinePlayerGetDurationMs({
     required GesTimelinePlayer that,
@@ -456,7 +493,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerGetDurationMsConstMeta,
         argValues: [that],
@@ -550,7 +587,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_32,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerGetPositionMsConstMeta,
         argValues: [that],
@@ -612,7 +649,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerIsPlayingConstMeta,
         argValues: [that],
@@ -681,7 +718,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerLoadTimelineConstMeta,
         argValues: [that, timelineData],
@@ -707,7 +744,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerNewConstMeta,
         argValues: [],
@@ -740,7 +777,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerPauseConstMeta,
         argValues: [that],
@@ -776,7 +813,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerPlayConstMeta,
         argValues: [that],
@@ -814,7 +851,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerSeekToPositionConstMeta,
         argValues: [that, positionMs],
@@ -829,6 +866,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "positionMs"],
       );
 
+  @override
+  Future<void> crateApiSimpleGesTimelinePlayerSetAutoTransition({
+    required GesTimelinePlayer that,
+    required bool enabled,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer(
+            that,
+            serializer,
+          );
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_flip_edit_error,
+        ),
+        constMeta: kCrateApiSimpleGesTimelinePlayerSetAutoTransitionConstMeta,
+        argValues: [that, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGesTimelinePlayerSetAutoTransitionConstMeta =>
+      const TaskConstMeta(
+        debugName: "GesTimelinePlayer_set_auto_transition",
+        argNames: ["that", "enabled"],
+      );
+
   @override
   Stream<FrameData> crateApiSimpleGesTimelinePlayerSetupFrameStream({
     required GesTimelinePlayer that,
@@ -977,7 +1052,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerStopConstMeta,
         argValues: [that],
@@ -1023,7 +1098,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGesTimelinePlayerUpdateClipTransformConstMeta,
         argValues: [
@@ -1105,7 +1180,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleTimelinePlayerDisposeConstMeta,
         argValues: [that],
@@ -1198,7 +1273,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_32,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleTimelinePlayerGetPositionMsConstMeta,
         argValues: [that],
@@ -1258,7 +1333,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleTimelinePlayerIsPlayingConstMeta,
         argValues: [that],
@@ -1296,7 +1371,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleTimelinePlayerLoadTimelineConstMeta,
         argValues: [that, timelineData],
@@ -1322,7 +1397,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerTimelinePlayer,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleTimelinePlayerNewConstMeta,
         argValues: [],
@@ -1355,7 +1430,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleTimelinePlayerPauseConstMeta,
         argValues: [that],
@@ -1391,7 +1466,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleTimelinePlayerPlayConstMeta,
         argValues: [that],
@@ -1462,7 +1537,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleTimelinePlayerStopConstMeta,
         argValues: [that],
@@ -1526,7 +1601,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerDisposeConstMeta,
         argValues: [that],
@@ -1561,7 +1636,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerExtractFrameAtPositionConstMeta,
         argValues: [that, seconds],
@@ -1963,7 +2038,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerLoadVideoConstMeta,
         argValues: [that, filePath],
@@ -2043,7 +2118,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerPauseConstMeta,
         argValues: [that],
@@ -2074,7 +2149,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerPlayConstMeta,
         argValues: [that],
@@ -2111,7 +2186,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_f_64,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerSeekAndPauseControlConstMeta,
         argValues: [that, seconds, wasPlayingBefore],
@@ -2149,7 +2224,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerSeekToFrameConstMeta,
         argValues: [that, frameNumber],
@@ -2265,7 +2340,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerStopConstMeta,
         argValues: [that],
@@ -2336,7 +2411,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleVideoPlayerTestPipelineConstMeta,
         argValues: [that, filePath],
@@ -2373,7 +2448,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         codec: SseCodec(
           decodeSuccessData:
               sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_ges_timeline_player_i_64,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleCreateGesTimelinePlayerConstMeta,
         argValues: [timelineData, engineHandle],
@@ -2389,36 +2464,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  PlatformInt64 crateApiSimpleCreateVideoTexture({
-    required int width,
-    required int height,
-    required PlatformInt64 engineHandle,
-  }) {
+  double crateApiSimpleGetDisplayRefreshRate() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(width, serializer);
-          sse_encode_u_32(height, serializer);
-          sse_encode_i_64(engineHandle, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_i_64,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleCreateVideoTextureConstMeta,
-        argValues: [width, height, engineHandle],
+        constMeta: kCrateApiSimpleGetDisplayRefreshRateConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleCreateVideoTextureConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_video_texture",
-        argNames: ["width", "height", "engineHandle"],
-      );
+  TaskConstMeta get kCrateApiSimpleGetDisplayRefreshRateConstMeta =>
+      const TaskConstMeta(debugName: "get_display_refresh_rate", argNames: []);
+
+  @override
+  void crateApiSimpleSetDisplayRefreshRate({required double hz}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_f_64(hz, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleSetDisplayRefreshRateConstMeta,
+        argValues: [hz],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSetDisplayRefreshRateConstMeta =>
+      const TaskConstMeta(debugName: "set_display_refresh_rate", argNames: ["hz"]);
 
   @override
   BigInt crateApiSimpleGetTextureCount() {
@@ -2453,7 +2541,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimpleGetVideoDurationMsConstMeta,
         argValues: [filePath],
@@ -2556,7 +2644,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_64,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimplePlayBasicVideoConstMeta,
         argValues: [filePath, engineHandle],
@@ -2588,7 +2676,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_64,
-          decodeErrorData: sse_decode_String,
+          decodeErrorData: sse_decode_flip_edit_error,
         ),
         constMeta: kCrateApiSimplePlayDualVideoConstMeta,
         argValues: [filePathLeft, filePathRight, engineHandle],
@@ -2603,32 +2691,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["filePathLeft", "filePathRight", "engineHandle"],
       );
 
-  @override
-  bool crateApiSimpleUpdateVideoFrame({required FrameData frameData}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_frame_data(frameData, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleUpdateVideoFrameConstMeta,
-        argValues: [frameData],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleUpdateVideoFrameConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_video_frame",
-        argNames: ["frameData"],
-      );
-
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_GesTimelinePlayer =>
       wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer;
@@ -2740,6 +2802,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return VideoPlayerImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  Map<String, double> dco_decode_Map_String_f_64_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_f_64(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
   @protected
   GesTimelinePlayer
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer(
@@ -2799,6 +2871,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as bool;
   }
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
   @protected
   FrameData dco_decode_box_autoadd_frame_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2829,12 +2907,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_u_64(raw);
   }
 
+  @protected
+  CaptionCue dco_decode_caption_cue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CaptionCue(
+      id: dco_decode_i_32(arr[0]),
+      startMs: dco_decode_i_32(arr[1]),
+      endMs: dco_decode_i_32(arr[2]),
+      text: dco_decode_String(arr[3]),
+      style: dco_decode_caption_style(arr[4]),
+    );
+  }
+
+  @protected
+  CaptionStyle dco_decode_caption_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CaptionStyle(
+      bold: dco_decode_bool(arr[0]),
+      italic: dco_decode_bool(arr[1]),
+      top: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  ClipFitMode dco_decode_clip_fit_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ClipFitMode.values[raw as int];
+  }
+
+  @protected
+  ClipOrientation dco_decode_clip_orientation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ClipOrientation(
+      rotationDegrees: dco_decode_f_64(arr[0]),
+      flipHorizontal: dco_decode_bool(arr[1]),
+      flipVertical: dco_decode_bool(arr[2]),
+      rotationKeyframes: dco_decode_list_rotation_keyframe(arr[3]),
+    );
+  }
+
+  @protected
+  CropKeyframe dco_decode_crop_keyframe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CropKeyframe(
+      timeMs: dco_decode_u_64(arr[0]),
+      rect: dco_decode_source_rect(arr[1]),
+    );
+  }
+
   @protected
   double dco_decode_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as double;
   }
 
+  @protected
+  FlipEditError dco_decode_flip_edit_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FlipEditError(
+      code: dco_decode_flip_edit_error_code(arr[0]),
+      message: dco_decode_String(arr[1]),
+      element: dco_decode_opt_String(arr[2]),
+      debug: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  FlipEditErrorCode dco_decode_flip_edit_error_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FlipEditErrorCode.values[raw as int];
+  }
+
   @protected
   FrameData dco_decode_frame_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2861,24 +3019,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  List<CaptionCue> dco_decode_list_caption_cue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_caption_cue).toList();
+  }
+
+  @protected
+  List<CropKeyframe> dco_decode_list_crop_keyframe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_crop_keyframe).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as Uint8List;
   }
 
+  @protected
+  List<(String, double)> dco_decode_list_record_string_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_f_64).toList();
+  }
+
+  @protected
+  List<RotationKeyframe> dco_decode_list_rotation_keyframe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rotation_keyframe).toList();
+  }
+
   @protected
   List<TimelineClip> dco_decode_list_timeline_clip(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_timeline_clip).toList();
   }
 
+  @protected
+  List<TimelineMarker> dco_decode_list_timeline_marker(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_timeline_marker).toList();
+  }
+
   @protected
   List<TimelineTrack> dco_decode_list_timeline_track(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_timeline_track).toList();
   }
 
+  @protected
+  List<TrackAudioEffect> dco_decode_list_track_audio_effect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_track_audio_effect).toList();
+  }
+
+  @protected
+  List<VolumeKeyframe> dco_decode_list_volume_keyframe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_volume_keyframe).toList();
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
   @protected
   FrameData? dco_decode_opt_box_autoadd_frame_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2941,6 +3153,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (dco_decode_i_32(arr[0]), dco_decode_i_32(arr[1]));
   }
 
+  @protected
+  (String, double) dco_decode_record_string_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_f_64(arr[1]));
+  }
+
+  @protected
+  RotationKeyframe dco_decode_rotation_keyframe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RotationKeyframe(
+      timeMs: dco_decode_u_64(arr[0]),
+      degrees: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  SourceRect dco_decode_source_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SourceRect(
+      x: dco_decode_f_64(arr[0]),
+      y: dco_decode_f_64(arr[1]),
+      width: dco_decode_f_64(arr[2]),
+      height: dco_decode_f_64(arr[3]),
+    );
+  }
+
   @protected
   TextureFrame dco_decode_texture_frame(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2959,8 +3207,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TimelineClip dco_decode_timeline_clip(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    if (arr.length != 19)
+      throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
     return TimelineClip(
       id: dco_decode_opt_box_autoadd_i_32(arr[0]),
       trackId: dco_decode_i_32(arr[1]),
@@ -2973,6 +3221,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       previewPositionY: dco_decode_f_64(arr[8]),
       previewWidth: dco_decode_f_64(arr[9]),
       previewHeight: dco_decode_f_64(arr[10]),
+      previewOpacity: dco_decode_f_64(arr[11]),
+      trackType: dco_decode_track_type(arr[12]),
+      fitMode: dco_decode_clip_fit_mode(arr[13]),
+      orientation: dco_decode_clip_orientation(arr[14]),
+      cropKeyframes: dco_decode_list_crop_keyframe(arr[15]),
+      audioEffects: dco_decode_list_track_audio_effect(arr[16]),
+      toneMapping: dco_decode_opt_box_autoadd_bool(arr[17]),
+      transitionInMs: dco_decode_i_32(arr[18]),
     );
   }
 
@@ -2980,24 +3236,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TimelineData dco_decode_timeline_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return TimelineData(tracks: dco_decode_list_timeline_track(arr[0]));
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TimelineData(
+      tracks: dco_decode_list_timeline_track(arr[0]),
+      captions: dco_decode_list_caption_cue(arr[1]),
+      markers: dco_decode_list_timeline_marker(arr[2]),
+      autoTransition: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  TimelineMarker dco_decode_timeline_marker(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TimelineMarker(
+      positionMs: dco_decode_i_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+    );
   }
 
   @protected
   TimelineTrack dco_decode_timeline_track(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
     return TimelineTrack(
       id: dco_decode_i_32(arr[0]),
       name: dco_decode_String(arr[1]),
       clips: dco_decode_list_timeline_clip(arr[2]),
+      audioEffects: dco_decode_list_track_audio_effect(arr[3]),
+      volumeKeyframes: dco_decode_list_volume_keyframe(arr[4]),
+      trackType: dco_decode_track_type(arr[5]),
+      priority: dco_decode_i_32(arr[6]),
     );
   }
 
+  @protected
+  TrackAudioEffect dco_decode_track_audio_effect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TrackAudioEffect(
+      effectId: dco_decode_u_32(arr[0]),
+      factoryName: dco_decode_String(arr[1]),
+      parameters: dco_decode_Map_String_f_64_None(arr[2]),
+    );
+  }
+
+  @protected
+  TrackType dco_decode_track_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TrackType.values[raw as int];
+  }
+
   @protected
   int dco_decode_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3029,10 +3325,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+  VolumeKeyframe dco_decode_volume_keyframe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return VolumeKeyframe(
+      timeMs: dco_decode_u_64(arr[0]),
+      gainDb: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
   }
 
   @protected
@@ -3143,6 +3451,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  Map<String, double> sse_decode_Map_String_f_64_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_f_64(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
   @protected
   GesTimelinePlayer
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer(
@@ -3216,6 +3533,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8() != 0;
   }
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
   @protected
   FrameData sse_decode_box_autoadd_frame_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3250,12 +3573,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_u_64(deserializer));
   }
 
+  @protected
+  CaptionCue sse_decode_caption_cue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_32(deserializer);
+    var var_startMs = sse_decode_i_32(deserializer);
+    var var_endMs = sse_decode_i_32(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_style = sse_decode_caption_style(deserializer);
+    return CaptionCue(
+      id: var_id,
+      startMs: var_startMs,
+      endMs: var_endMs,
+      text: var_text,
+      style: var_style,
+    );
+  }
+
+  @protected
+  CaptionStyle sse_decode_caption_style(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bold = sse_decode_bool(deserializer);
+    var var_italic = sse_decode_bool(deserializer);
+    var var_top = sse_decode_bool(deserializer);
+    return CaptionStyle(bold: var_bold, italic: var_italic, top: var_top);
+  }
+
+  @protected
+  ClipFitMode sse_decode_clip_fit_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ClipFitMode.values[inner];
+  }
+
+  @protected
+  ClipOrientation sse_decode_clip_orientation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rotationDegrees = sse_decode_f_64(deserializer);
+    var var_flipHorizontal = sse_decode_bool(deserializer);
+    var var_flipVertical = sse_decode_bool(deserializer);
+    var var_rotationKeyframes = sse_decode_list_rotation_keyframe(deserializer);
+    return ClipOrientation(
+      rotationDegrees: var_rotationDegrees,
+      flipHorizontal: var_flipHorizontal,
+      flipVertical: var_flipVertical,
+      rotationKeyframes: var_rotationKeyframes,
+    );
+  }
+
+  @protected
+  CropKeyframe sse_decode_crop_keyframe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeMs = sse_decode_u_64(deserializer);
+    var var_rect = sse_decode_source_rect(deserializer);
+    return CropKeyframe(timeMs: var_timeMs, rect: var_rect);
+  }
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  FlipEditError sse_decode_flip_edit_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_flip_edit_error_code(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_element = sse_decode_opt_String(deserializer);
+    var var_debug = sse_decode_opt_String(deserializer);
+    return FlipEditError(
+      code: var_code,
+      message: var_message,
+      element: var_element,
+      debug: var_debug,
+    );
+  }
+
+  @protected
+  FlipEditErrorCode sse_decode_flip_edit_error_code(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FlipEditErrorCode.values[inner];
+  }
+
   @protected
   FrameData sse_decode_frame_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3283,6 +3686,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  List<CaptionCue> sse_decode_list_caption_cue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CaptionCue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_caption_cue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CropKeyframe> sse_decode_list_crop_keyframe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CropKeyframe>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_crop_keyframe(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3290,6 +3719,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8List(len_);
   }
 
+  @protected
+  List<(String, double)> sse_decode_list_record_string_f_64(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, double)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_f_64(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RotationKeyframe> sse_decode_list_rotation_keyframe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RotationKeyframe>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rotation_keyframe(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<TimelineClip> sse_decode_list_timeline_clip(
     SseDeserializer deserializer,
@@ -3304,6 +3761,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<TimelineMarker> sse_decode_list_timeline_marker(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TimelineMarker>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_timeline_marker(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<TimelineTrack> sse_decode_list_timeline_track(
     SseDeserializer deserializer,
@@ -3318,6 +3789,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<TrackAudioEffect> sse_decode_list_track_audio_effect(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrackAudioEffect>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_track_audio_effect(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<VolumeKeyframe> sse_decode_list_volume_keyframe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <VolumeKeyframe>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_volume_keyframe(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   FrameData? sse_decode_opt_box_autoadd_frame_data(
     SseDeserializer deserializer,
@@ -3396,6 +3917,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  (String, double) sse_decode_record_string_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_f_64(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  RotationKeyframe sse_decode_rotation_keyframe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeMs = sse_decode_u_64(deserializer);
+    var var_degrees = sse_decode_f_64(deserializer);
+    return RotationKeyframe(timeMs: var_timeMs, degrees: var_degrees);
+  }
+
+  @protected
+  SourceRect sse_decode_source_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_x = sse_decode_f_64(deserializer);
+    var var_y = sse_decode_f_64(deserializer);
+    var var_width = sse_decode_f_64(deserializer);
+    var var_height = sse_decode_f_64(deserializer);
+    return SourceRect(x: var_x, y: var_y, width: var_width, height: var_height);
+  }
+
   @protected
   TextureFrame sse_decode_texture_frame(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3425,6 +3972,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_previewPositionY = sse_decode_f_64(deserializer);
     var var_previewWidth = sse_decode_f_64(deserializer);
     var var_previewHeight = sse_decode_f_64(deserializer);
+    var var_previewOpacity = sse_decode_f_64(deserializer);
+    var var_trackType = sse_decode_track_type(deserializer);
+    var var_fitMode = sse_decode_clip_fit_mode(deserializer);
+    var var_orientation = sse_decode_clip_orientation(deserializer);
+    var var_cropKeyframes = sse_decode_list_crop_keyframe(deserializer);
+    var var_audioEffects = sse_decode_list_track_audio_effect(deserializer);
+    var var_toneMapping = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_transitionInMs = sse_decode_i_32(deserializer);
     return TimelineClip(
       id: var_id,
       trackId: var_trackId,
@@ -3437,6 +3992,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       previewPositionY: var_previewPositionY,
       previewWidth: var_previewWidth,
       previewHeight: var_previewHeight,
+      previewOpacity: var_previewOpacity,
+      trackType: var_trackType,
+      fitMode: var_fitMode,
+      orientation: var_orientation,
+      cropKeyframes: var_cropKeyframes,
+      audioEffects: var_audioEffects,
+      toneMapping: var_toneMapping,
+      transitionInMs: var_transitionInMs,
     );
   }
 
@@ -3444,7 +4007,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TimelineData sse_decode_timeline_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_tracks = sse_decode_list_timeline_track(deserializer);
-    return TimelineData(tracks: var_tracks);
+    var var_captions = sse_decode_list_caption_cue(deserializer);
+    var var_markers = sse_decode_list_timeline_marker(deserializer);
+    var var_autoTransition = sse_decode_bool(deserializer);
+    return TimelineData(
+      tracks: var_tracks,
+      captions: var_captions,
+      markers: var_markers,
+      autoTransition: var_autoTransition,
+    );
+  }
+
+  @protected
+  TimelineMarker sse_decode_timeline_marker(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_positionMs = sse_decode_i_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    return TimelineMarker(positionMs: var_positionMs, name: var_name);
   }
 
   @protected
@@ -3453,7 +4032,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_id = sse_decode_i_32(deserializer);
     var var_name = sse_decode_String(deserializer);
     var var_clips = sse_decode_list_timeline_clip(deserializer);
-    return TimelineTrack(id: var_id, name: var_name, clips: var_clips);
+    var var_audioEffects = sse_decode_list_track_audio_effect(deserializer);
+    var var_volumeKeyframes = sse_decode_list_volume_keyframe(deserializer);
+    var var_trackType = sse_decode_track_type(deserializer);
+    var var_priority = sse_decode_i_32(deserializer);
+    return TimelineTrack(
+      id: var_id,
+      name: var_name,
+      clips: var_clips,
+      audioEffects: var_audioEffects,
+      volumeKeyframes: var_volumeKeyframes,
+      trackType: var_trackType,
+      priority: var_priority,
+    );
+  }
+
+  @protected
+  TrackAudioEffect sse_decode_track_audio_effect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_effectId = sse_decode_u_32(deserializer);
+    var var_factoryName = sse_decode_String(deserializer);
+    var var_parameters = sse_decode_Map_String_f_64_None(deserializer);
+    return TrackAudioEffect(
+      effectId: var_effectId,
+      factoryName: var_factoryName,
+      parameters: var_parameters,
+    );
+  }
+
+  @protected
+  TrackType sse_decode_track_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TrackType.values[inner];
   }
 
   @protected
@@ -3485,6 +4096,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  VolumeKeyframe sse_decode_volume_keyframe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timeMs = sse_decode_u_64(deserializer);
+    var var_gainDb = sse_decode_f_64(deserializer);
+    return VolumeKeyframe(timeMs: var_timeMs, gainDb: var_gainDb);
+  }
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -3611,6 +4230,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_Map_String_f_64_None(
+    Map<String,
+    double> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_f_64(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer(
@@ -3713,6 +4345,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self ? 1 : 0);
   }
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_frame_data(
     FrameData self,
@@ -3752,12 +4390,76 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_caption_cue(CaptionCue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.id, serializer);
+    sse_encode_i_32(self.startMs, serializer);
+    sse_encode_i_32(self.endMs, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_caption_style(self.style, serializer);
+  }
+
+  @protected
+  void sse_encode_caption_style(CaptionStyle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.bold, serializer);
+    sse_encode_bool(self.italic, serializer);
+    sse_encode_bool(self.top, serializer);
+  }
+
+  @protected
+  void sse_encode_clip_fit_mode(ClipFitMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_clip_orientation(
+    ClipOrientation self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.rotationDegrees, serializer);
+    sse_encode_bool(self.flipHorizontal, serializer);
+    sse_encode_bool(self.flipVertical, serializer);
+    sse_encode_list_rotation_keyframe(self.rotationKeyframes, serializer);
+  }
+
+  @protected
+  void sse_encode_crop_keyframe(CropKeyframe self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timeMs, serializer);
+    sse_encode_source_rect(self.rect, serializer);
+  }
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_flip_edit_error(
+    FlipEditError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_flip_edit_error_code(self.code, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_String(self.element, serializer);
+    sse_encode_opt_String(self.debug, serializer);
+  }
+
+  @protected
+  void sse_encode_flip_edit_error_code(
+    FlipEditErrorCode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_frame_data(FrameData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3779,6 +4481,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_list_caption_cue(
+    List<CaptionCue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_caption_cue(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_crop_keyframe(
+    List<CropKeyframe> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_crop_keyframe(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -3789,6 +4515,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8List(self);
   }
 
+  @protected
+  void sse_encode_list_record_string_f_64(
+    List<(String,
+    double)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_f_64(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_rotation_keyframe(
+    List<RotationKeyframe> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rotation_keyframe(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_timeline_clip(
     List<TimelineClip> self,
@@ -3801,6 +4552,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_timeline_marker(
+    List<TimelineMarker> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_timeline_marker(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_timeline_track(
     List<TimelineTrack> self,
@@ -3813,6 +4576,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_track_audio_effect(
+    List<TrackAudioEffect> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_track_audio_effect(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_volume_keyframe(
+    List<VolumeKeyframe> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_volume_keyframe(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_frame_data(
     FrameData? self,
@@ -3890,6 +4697,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_record_string_f_64(
+    (String,
+    double) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_f_64(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_rotation_keyframe(
+    RotationKeyframe self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timeMs, serializer);
+    sse_encode_f_64(self.degrees, serializer);
+  }
+
+  @protected
+  void sse_encode_source_rect(SourceRect self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.x, serializer);
+    sse_encode_f_64(self.y, serializer);
+    sse_encode_f_64(self.width, serializer);
+    sse_encode_f_64(self.height, serializer);
+  }
+
   @protected
   void sse_encode_texture_frame(TextureFrame self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3913,12 +4750,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_f_64(self.previewPositionY, serializer);
     sse_encode_f_64(self.previewWidth, serializer);
     sse_encode_f_64(self.previewHeight, serializer);
+    sse_encode_f_64(self.previewOpacity, serializer);
+    sse_encode_track_type(self.trackType, serializer);
+    sse_encode_clip_fit_mode(self.fitMode, serializer);
+    sse_encode_clip_orientation(self.orientation, serializer);
+    sse_encode_list_crop_keyframe(self.cropKeyframes, serializer);
+    sse_encode_list_track_audio_effect(self.audioEffects, serializer);
+    sse_encode_opt_box_autoadd_bool(self.toneMapping, serializer);
+    sse_encode_i_32(self.transitionInMs, serializer);
   }
 
   @protected
   void sse_encode_timeline_data(TimelineData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_timeline_track(self.tracks, serializer);
+    sse_encode_list_caption_cue(self.captions, serializer);
+    sse_encode_list_timeline_marker(self.markers, serializer);
+    sse_encode_bool(self.autoTransition, serializer);
+  }
+
+  @protected
+  void sse_encode_timeline_marker(
+    TimelineMarker self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.positionMs, serializer);
+    sse_encode_String(self.name, serializer);
   }
 
   @protected
@@ -3927,6 +4785,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.id, serializer);
     sse_encode_String(self.name, serializer);
     sse_encode_list_timeline_clip(self.clips, serializer);
+    sse_encode_list_track_audio_effect(self.audioEffects, serializer);
+    sse_encode_list_volume_keyframe(self.volumeKeyframes, serializer);
+    sse_encode_track_type(self.trackType, serializer);
+    sse_encode_i_32(self.priority, serializer);
+  }
+
+  @protected
+  void sse_encode_track_audio_effect(
+    TrackAudioEffect self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.effectId, serializer);
+    sse_encode_String(self.factoryName, serializer);
+    sse_encode_Map_String_f_64_None(self.parameters, serializer);
+  }
+
+  @protected
+  void sse_encode_track_type(TrackType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -3957,6 +4836,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
+
+  @protected
+  void sse_encode_volume_keyframe(
+    VolumeKeyframe self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timeMs, serializer);
+    sse_encode_f_64(self.gainDb, serializer);
+  }
 }
 
 @sealed
@@ -3993,6 +4882,9 @@ class GesTimelinePlayerImpl extends RustOpaque implements GesTimelinePlayer {
   Future<void> dispose() =>
       RustLib.instance.api.crateApiSimpleGesTimelinePlayerDispose(that: this);
 
+  bool getAutoTransition() => RustLib.instance.api
+      .crateApiSimpleGesTimelinePlayerGetAutoTransition(that: this);
+
   int? getDurationMs() => RustLib.instance.api
       .crateApiSimpleGesTimelinePlayerGetDurationMs(that: this);
 
@@ -4032,6 +4924,14 @@ class GesTimelinePlayerImpl extends RustOpaque implements GesTimelinePlayer {
         positionMs: positionMs,
       );
 
+  /// Crossfade where an added or moved clip overlaps another clip on its track, for as long
+  /// as they overlap. Saved with the loaded timeline, so each timeline keeps its own setting.
+  Future<void> setAutoTransition({required bool enabled}) =>
+      RustLib.instance.api.crateApiSimpleGesTimelinePlayerSetAutoTransition(
+        that: this,
+        enabled: enabled,
+      );
+
   Stream<FrameData> setupFrameStream() => RustLib.instance.api
       .crateApiSimpleGesTimelinePlayerSetupFrameStream(that: this);
 
@@ -5,6 +5,7 @@
 
 import 'api/bridge.dart';
 import 'api/simple.dart';
+import 'common/error.dart';
 import 'common/types.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -89,6 +90,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  Map<String, double> dco_decode_Map_String_f_64_None(dynamic raw);
+
   @protected
   GesTimelinePlayer
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer(
@@ -124,6 +128,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
   @protected
   FrameData dco_decode_box_autoadd_frame_data(dynamic raw);
 
@@ -139,9 +146,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  CaptionCue dco_decode_caption_cue(dynamic raw);
+
+  @protected
+  CaptionStyle dco_decode_caption_style(dynamic raw);
+
+  @protected
+  ClipFitMode dco_decode_clip_fit_mode(dynamic raw);
+
+  @protected
+  ClipOrientation dco_decode_clip_orientation(dynamic raw);
+
+  @protected
+  CropKeyframe dco_decode_crop_keyframe(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FlipEditError dco_decode_flip_edit_error(dynamic raw);
+
+  @protected
+  FlipEditErrorCode dco_decode_flip_edit_error_code(dynamic raw);
+
   @protected
   FrameData dco_decode_frame_data(dynamic raw);
 
@@ -151,15 +179,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  List<CaptionCue> dco_decode_list_caption_cue(dynamic raw);
+
+  @protected
+  List<CropKeyframe> dco_decode_list_crop_keyframe(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<(String, double)> dco_decode_list_record_string_f_64(dynamic raw);
+
+  @protected
+  List<RotationKeyframe> dco_decode_list_rotation_keyframe(dynamic raw);
+
   @protected
   List<TimelineClip> dco_decode_list_timeline_clip(dynamic raw);
 
+  @protected
+  List<TimelineMarker> dco_decode_list_timeline_marker(dynamic raw);
+
   @protected
   List<TimelineTrack> dco_decode_list_timeline_track(dynamic raw);
 
+  @protected
+  List<TrackAudioEffect> dco_decode_list_track_audio_effect(dynamic raw);
+
+  @protected
+  List<VolumeKeyframe> dco_decode_list_volume_keyframe(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
   @protected
   FrameData? dco_decode_opt_box_autoadd_frame_data(dynamic raw);
 
@@ -184,6 +239,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (int, int) dco_decode_record_i_32_i_32(dynamic raw);
 
+  @protected
+  (String, double) dco_decode_record_string_f_64(dynamic raw);
+
+  @protected
+  RotationKeyframe dco_decode_rotation_keyframe(dynamic raw);
+
+  @protected
+  SourceRect dco_decode_source_rect(dynamic raw);
+
   @protected
   TextureFrame dco_decode_texture_frame(dynamic raw);
 
@@ -193,9 +257,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   TimelineData dco_decode_timeline_data(dynamic raw);
 
+  @protected
+  TimelineMarker dco_decode_timeline_marker(dynamic raw);
+
   @protected
   TimelineTrack dco_decode_timeline_track(dynamic raw);
 
+  @protected
+  TrackAudioEffect dco_decode_track_audio_effect(dynamic raw);
+
+  @protected
+  TrackType dco_decode_track_type(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -211,6 +284,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  VolumeKeyframe dco_decode_volume_keyframe(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
@@ -268,6 +344,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  Map<String, double> sse_decode_Map_String_f_64_None(
+    SseDeserializer deserializer,
+  );
+
   @protected
   GesTimelinePlayer
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer(
@@ -307,6 +388,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
   @protected
   FrameData sse_decode_box_autoadd_frame_data(SseDeserializer deserializer);
 
@@ -326,9 +410,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  CaptionCue sse_decode_caption_cue(SseDeserializer deserializer);
+
+  @protected
+  CaptionStyle sse_decode_caption_style(SseDeserializer deserializer);
+
+  @protected
+  ClipFitMode sse_decode_clip_fit_mode(SseDeserializer deserializer);
+
+  @protected
+  ClipOrientation sse_decode_clip_orientation(SseDeserializer deserializer);
+
+  @protected
+  CropKeyframe sse_decode_crop_keyframe(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  FlipEditError sse_decode_flip_edit_error(SseDeserializer deserializer);
+
+  @protected
+  FlipEditErrorCode sse_decode_flip_edit_error_code(
+    SseDeserializer deserializer,
+  );
+
   @protected
   FrameData sse_decode_frame_data(SseDeserializer deserializer);
 
@@ -338,19 +445,58 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  List<CaptionCue> sse_decode_list_caption_cue(SseDeserializer deserializer);
+
+  @protected
+  List<CropKeyframe> sse_decode_list_crop_keyframe(
+    SseDeserializer deserializer,
+  );
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<(String, double)> sse_decode_list_record_string_f_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RotationKeyframe> sse_decode_list_rotation_keyframe(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<TimelineClip> sse_decode_list_timeline_clip(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<TimelineMarker> sse_decode_list_timeline_marker(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<TimelineTrack> sse_decode_list_timeline_track(
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<TrackAudioEffect> sse_decode_list_track_audio_effect(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<VolumeKeyframe> sse_decode_list_volume_keyframe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
   @protected
   FrameData? sse_decode_opt_box_autoadd_frame_data(
     SseDeserializer deserializer,
@@ -379,6 +525,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (int, int) sse_decode_record_i_32_i_32(SseDeserializer deserializer);
 
+  @protected
+  (String, double) sse_decode_record_string_f_64(SseDeserializer deserializer);
+
+  @protected
+  RotationKeyframe sse_decode_rotation_keyframe(SseDeserializer deserializer);
+
+  @protected
+  SourceRect sse_decode_source_rect(SseDeserializer deserializer);
+
   @protected
   TextureFrame sse_decode_texture_frame(SseDeserializer deserializer);
 
@@ -388,9 +543,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   TimelineData sse_decode_timeline_data(SseDeserializer deserializer);
 
+  @protected
+  TimelineMarker sse_decode_timeline_marker(SseDeserializer deserializer);
+
   @protected
   TimelineTrack sse_decode_timeline_track(SseDeserializer deserializer);
 
+  @protected
+  TrackAudioEffect sse_decode_track_audio_effect(SseDeserializer deserializer);
+
+  @protected
+  TrackType sse_decode_track_type(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -406,6 +570,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  VolumeKeyframe sse_decode_volume_keyframe(SseDeserializer deserializer);
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -475,6 +642,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_Map_String_f_64_None(
+    Map<String,
+    double> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerGESTimelinePlayer(
@@ -520,6 +694,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_frame_data(
     FrameData self,
@@ -544,9 +721,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_caption_cue(CaptionCue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_caption_style(CaptionStyle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clip_fit_mode(ClipFitMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clip_orientation(
+    ClipOrientation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_crop_keyframe(CropKeyframe self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_flip_edit_error(
+    FlipEditError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_flip_edit_error_code(
+    FlipEditErrorCode self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_frame_data(FrameData self, SseSerializer serializer);
 
@@ -556,24 +763,73 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_caption_cue(
+    List<CaptionCue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_crop_keyframe(
+    List<CropKeyframe> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_string_f_64(
+    List<(String,
+    double)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_rotation_keyframe(
+    List<RotationKeyframe> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_timeline_clip(
     List<TimelineClip> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_timeline_marker(
+    List<TimelineMarker> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_timeline_track(
     List<TimelineTrack> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_track_audio_effect(
+    List<TrackAudioEffect> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_volume_keyframe(
+    List<VolumeKeyframe> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_frame_data(
     FrameData? self,
@@ -608,6 +864,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_record_i_32_i_32((int, int) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_record_string_f_64(
+    (String,
+    double) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rotation_keyframe(
+    RotationKeyframe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_source_rect(SourceRect self, SseSerializer serializer);
+
   @protected
   void sse_encode_texture_frame(TextureFrame self, SseSerializer serializer);
 
@@ -617,9 +889,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_timeline_data(TimelineData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_timeline_marker(
+    TimelineMarker self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_timeline_track(TimelineTrack self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_track_audio_effect(
+    TrackAudioEffect self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_track_type(TrackType self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -634,6 +921,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_volume_keyframe(
+    VolumeKeyframe self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
@@ -0,0 +1,36 @@
+import 'package:flutter/widgets.dart';
+import 'package:flipedit/src/rust/api/simple.dart';
+import 'package:flipedit/utils/logger.dart';
+
+/// Keeps the Rust frame pacer at the refresh rate of the display the window is on,
+/// so video frames are handed to Flutter once per display frame
+class DisplayRefreshRateReporter with WidgetsBindingObserver {
+  static const String _logTag = 'DisplayRefreshRateReporter';
+  static final DisplayRefreshRateReporter _instance =
+      DisplayRefreshRateReporter._();
+
+  double? _reportedHz;
+
+  DisplayRefreshRateReporter._();
+
+  /// Report the current display's rate, and again whenever the window's metrics change,
+  /// e.g. when it moves to another display
+  static void start() {
+    WidgetsBinding.instance.addObserver(_instance);
+    _instance._report();
+  }
+
+  @override
+  void didChangeMetrics() => _report();
+
+  void _report() {
+    final view = WidgetsBinding.instance.platformDispatcher.implicitView;
+    final hz = view?.display.refreshRate;
+    if (hz == null || hz <= 0 || hz == _reportedHz) {
+      return;
+    }
+    _reportedHz = hz;
+    setDisplayRefreshRate(hz: hz);
+    logInfo(_logTag, 'Pacing video frames to the display at $hz Hz');
+  }
+}
@@ -529,6 +529,18 @@ class TimelineViewModel extends ChangeNotifier {
                 previewPositionY: clipRow.previewPositionY,
                 previewWidth: clipRow.previewWidth,
                 previewHeight: clipRow.previewHeight,
+                previewOpacity: 1.0,
+                trackType: TrackType.both,
+                fitMode: ClipFitMode.stretch,
+                orientation: const ClipOrientation(
+                  rotationDegrees: 0.0,
+                  flipHorizontal: false,
+                  flipVertical: false,
+                  rotationKeyframes: [],
+                ),
+                cropKeyframes: const [],
+                audioEffects: const [],
+                transitionInMs: 0,
               ))
           .toList();
 
@@ -536,11 +548,18 @@ class TimelineViewModel extends ChangeNotifier {
         id: track.id,
         name: track.name,
         clips: clips,
+        audioEffects: const [],
+        volumeKeyframes: const [],
+        trackType: TrackType.both,
+        priority: 0,
       ));
     }
 
     return TimelineData(
       tracks: timelineTracks,
+      captions: const [],
+      markers: const [],
+      autoTransition: false,
     );
   }
 
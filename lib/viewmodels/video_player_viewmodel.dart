@@ -77,6 +77,18 @@ class VideoPlayerViewModel {
         previewPositionY: clipRow.previewPositionY,
         previewWidth: clipRow.previewWidth,
         previewHeight: clipRow.previewHeight,
+        previewOpacity: 1.0,
+        trackType: TrackType.both,
+        fitMode: ClipFitMode.stretch,
+        orientation: const ClipOrientation(
+          rotationDegrees: 0.0,
+          flipHorizontal: false,
+          flipVertical: false,
+          rotationKeyframes: [],
+        ),
+        cropKeyframes: const [],
+        audioEffects: const [],
+        transitionInMs: 0,
       )).toList();
       
       // DEBUG: Log transform values being passed to Rust
@@ -89,10 +101,19 @@ class VideoPlayerViewModel {
         id: track.id,
         name: track.name,
         clips: clips,
+        audioEffects: const [],
+        volumeKeyframes: const [],
+        trackType: TrackType.both,
+        priority: 0,
       ));
     }
     
-    return TimelineData(tracks: timelineTracks);
+    return TimelineData(
+      tracks: timelineTracks,
+      captions: const [],
+      markers: const [],
+      autoTransition: false,
+    );
   }
 
   Future<void> togglePlayPause() async {
@@ -1,13 +1,42 @@
 use flutter_rust_bridge::frb;
 use gstreamer as gst;
 use log::{info, error};
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
 use crate::common::logging::setup_logger;
 
+/// Version of the bridge API: the codegen's hash of every bridged function and type, so it
+/// changes with their shape without anyone remembering to bump it, and bindings generated for
+/// another version are turned away by `check_native_api_version`. The Dart side passes the
+/// `rustContentHash` of its generated bindings, as an unsigned 32-bit value.
+pub const NATIVE_API_VERSION: u32 = crate::frb_generated::FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH as u32;
+
 #[frb(sync)]
 pub fn greet(name: String) -> String {
     format!("Hello, {name}!")
 }
 
+/// Version of the bridge API this library was built with
+#[frb(sync)]
+pub fn get_native_api_version() -> u32 {
+    NATIVE_API_VERSION
+}
+
+/// Handshake for app startup: pass the version the Dart bindings were generated against, and
+/// get a clear error instead of undecodable calls later if the library doesn't match
+#[frb(sync)]
+pub fn check_native_api_version(expected: u32) -> Result<(), FlipEditError> {
+    if expected != NATIVE_API_VERSION {
+        return Err(FlipEditError::new(
+            FlipEditErrorCode::IncompatibleVersion,
+            format!(
+                "The app expects native API version {} but the library provides version {}; rebuild both from the same sources",
+                expected, NATIVE_API_VERSION,
+            ),
+        ));
+    }
+    Ok(())
+}
+
 #[frb(init)]
 pub fn init_app() {
     flutter_rust_bridge::setup_default_user_utils();
@@ -24,4 +53,5 @@ pub fn init_app() {
     // Print GStreamer version
     let (major, minor, micro, nano) = gst::version();
     info!("GStreamer version: {}.{}.{}.{}", major, minor, micro, nano);
+    info!("Native API version: {}", NATIVE_API_VERSION);
 } 
\ No newline at end of file
@@ -1,24 +1,30 @@
 use flutter_rust_bridge::frb;
 pub use crate::api::bridge::*;
 use crate::video::player::VideoPlayer as InternalVideoPlayer;
-use crate::video::direct_pipeline_player::DirectPipelinePlayer as InternalDirectPipelinePlayer;
-pub use crate::common::types::{FrameData, TimelineData, TimelineClip, TimelineTrack, TextureFrame};
+use crate::video::gst_worker::PlayerHandle;
+pub use crate::common::error::{FlipEditError, FlipEditErrorCode};
+pub use crate::common::types::{FrameData, TimelineData, TimelineClip, TimelineTrack, TextureFrame, ScrubMode, PlaybackEvent, PlaybackState, EndBehavior, MonitorRole, RenderSegment, RenderStatus, TimelineChange, RenderPath, PlayerTexture, RenderPathInfo, AudioDeviceEvent, AudioLevels, ResamplerQuality, AudioEngineSettings, AudioSampleRate, AudioChannelLayout, DownmixPolicy, TrackAudioEffect, AudioEffectParameter, VolumeKeyframe, VoiceoverPlayback, AudioInputDevice, ClipSyncResult, AudioClockMode, AudioRestoration, AudioOutputMode, ScreenCaptureSettings, ScreenRecordingEvent, LiveStreamSettings, TimelineLoadEvent, ActiveResource, ResourceKind, LogLine, CodecInfo, SystemCapabilities, DecoderPolicy, PerformanceStats, MemoryUsage, RecoveryStage, FrameRate, ColorSpace, PixelAspectRatio, ClipFitMode, ClipOrientation, RotationKeyframe, SourceRect, CropKeyframe, CaptionCue, CaptionStyle, CaptionFormat, CaptionAppearance, TimelineMarker, ClipInfo, TimelineEvent, EditMode, ScalingMethod, ConversionSettings, Thumbnail, ThumbnailFormat};
 use gstreamer as gst;
 use gstreamer::prelude::*;
 use crate::utils::testing;
 use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
 use anyhow::Result;
 use crate::frb_generated::StreamSink;
 use lazy_static::lazy_static;
 use std::sync::Mutex as StdMutex;
 use crate::video::pipeline::VideoPipeline;
 use crate::video::frame_handler::FrameHandler;
+use crate::video::player_registry;
+use crate::common::types::DuckingSettings;
 use log::info;
 
 lazy_static! {
     static ref ACTIVE_VIDEOS: StdMutex<Vec<VideoPipeline>> = StdMutex::new(Vec::new());
 }
 
+static NEXT_TIMELINE_LOAD_JOB: AtomicU64 = AtomicU64::new(1);
+
 // Position update callback type
 pub type PositionUpdateCallback = Box<dyn Fn(f64, u64) + Send + Sync>;
 
@@ -44,21 +50,35 @@ impl VideoPlayer {
         Self::new()
     }
 
+    /// Create a player that plays audio through cpal or through the GStreamer pipeline
+    #[frb(sync)]
+    pub fn new_with_audio_output(mode: AudioOutputMode) -> Self {
+        Self {
+            inner: InternalVideoPlayer::with_audio_output(mode),
+        }
+    }
+
+
+    pub fn load_video(&mut self, file_path: String) -> Result<(), FlipEditError> {
+        FlipEditError::check_file_exists(&file_path)?;
+        self.inner.load_video(file_path).map_err(FlipEditError::from)
+    }
 
-    pub fn load_video(&mut self, file_path: String) -> Result<(), String> {
-        self.inner.load_video(file_path)
+    pub fn play(&mut self) -> Result<(), FlipEditError> {
+        self.inner.play().map_err(FlipEditError::from)
     }
 
-    pub fn play(&mut self) -> Result<(), String> {
-        self.inner.play()
+    pub fn pause(&mut self) -> Result<(), FlipEditError> {
+        self.inner.pause().map_err(FlipEditError::from)
     }
 
-    pub fn pause(&mut self) -> Result<(), String> {
-        self.inner.pause()
+    pub fn stop(&mut self) -> Result<(), FlipEditError> {
+        self.inner.stop().map_err(FlipEditError::from)
     }
 
-    pub fn stop(&mut self) -> Result<(), String> {
-        self.inner.stop()
+    /// Create the texture this player renders into; call before `load_video`
+    pub fn create_texture(&mut self, engine_handle: i64) -> Result<i64, FlipEditError> {
+        self.inner.create_texture(engine_handle).map_err(FlipEditError::from)
     }
 
     pub fn setup_frame_stream(&mut self, sink: StreamSink<FrameData>) -> Result<()> {
@@ -84,6 +104,12 @@ impl VideoPlayer {
         Ok(())
     }
 
+    /// Set how often the position stream is updated while playing (default 16ms)
+    #[frb(sync)]
+    pub fn set_position_update_interval_ms(&mut self, interval_ms: u32) {
+        self.inner.set_position_update_interval_ms(interval_ms as u64);
+    }
+
     /// Get current position and frame - Flutter can call this periodically
     #[frb(sync)]
     pub fn get_current_position_and_frame(&self) -> (f64, u64) {
@@ -124,8 +150,36 @@ impl VideoPlayer {
         self.inner.has_audio()
     }
 
-    pub fn dispose(&mut self) -> Result<(), String> {
-        self.inner.dispose()
+    /// Measured cpal output latency that video is delayed by to stay in sync
+    #[frb(sync)]
+    pub fn get_audio_latency_ms(&self) -> f64 {
+        self.inner.get_audio_latency_ms()
+    }
+
+    /// This player's volume (1.0 = unity) on top of the master volume, e.g. to balance two players
+    #[frb(sync)]
+    pub fn set_volume(&mut self, gain: f64) {
+        self.inner.set_volume(gain);
+    }
+
+    #[frb(sync)]
+    pub fn get_volume(&self) -> f64 {
+        self.inner.get_volume()
+    }
+
+    /// Mute only this player
+    #[frb(sync)]
+    pub fn set_muted(&mut self, muted: bool) {
+        self.inner.set_muted(muted);
+    }
+
+    #[frb(sync)]
+    pub fn is_muted(&self) -> bool {
+        self.inner.is_muted()
+    }
+
+    pub fn dispose(&mut self) -> Result<(), FlipEditError> {
+        self.inner.dispose().map_err(FlipEditError::from)
     }
 
     #[frb(sync)]
@@ -154,13 +208,13 @@ impl VideoPlayer {
     }
 
     /// Extract frame at specific position for preview without seeking main pipeline
-    pub fn extract_frame_at_position(&mut self, seconds: f64) -> Result<(), String> {
-        self.inner.extract_frame_at_position(seconds)
+    pub fn extract_frame_at_position(&mut self, seconds: f64) -> Result<(), FlipEditError> {
+        self.inner.extract_frame_at_position(seconds).map_err(FlipEditError::from)
     }
 
     /// Seek to final position with pause/resume control - used when releasing slider  
-    pub fn seek_and_pause_control(&mut self, seconds: f64, was_playing_before: bool) -> Result<f64, String> {
-        self.inner.seek_and_pause_control(seconds, was_playing_before)
+    pub fn seek_and_pause_control(&mut self, seconds: f64, was_playing_before: bool) -> Result<f64, FlipEditError> {
+        self.inner.seek_and_pause_control(seconds, was_playing_before).map_err(FlipEditError::from)
     }
 
     /// Force synchronization between pipeline state and internal state
@@ -168,53 +222,70 @@ impl VideoPlayer {
         self.inner.sync_playing_state()
     }
 
-    pub fn seek_to_frame(&mut self, frame_number: u64) -> Result<(), String> {
-        self.inner.seek_to_frame(frame_number).map(|_| ())
+    pub fn seek_to_frame(&mut self, frame_number: u64) -> Result<(), FlipEditError> {
+        self.inner.seek_to_frame(frame_number).map(|_| ()).map_err(FlipEditError::from)
     }
 
-    pub fn test_pipeline(&self, file_path: String) -> Result<(), String> {
-        testing::test_pipeline(file_path)
+    pub fn test_pipeline(&self, file_path: String) -> Result<(), FlipEditError> {
+        testing::test_pipeline(file_path).map_err(FlipEditError::from)
     }
 }
 
 pub struct TimelinePlayer {
-    inner: InternalDirectPipelinePlayer,
+    inner: PlayerHandle,
 }
 
 impl TimelinePlayer {
     #[frb(sync)]
-    pub fn new() -> Self {
-        Self {
-            inner: InternalDirectPipelinePlayer::new().expect("Failed to create DirectPipelinePlayer"),
-        }
+    pub fn new() -> Result<Self, FlipEditError> {
+        Ok(Self {
+            inner: PlayerHandle::spawn()?,
+        })
+    }
+
+
+    pub fn load_timeline(&mut self, timeline_data: TimelineData) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.load_timeline(timeline_data)).map_err(FlipEditError::from)
     }
 
+    /// Create a small second texture, e.g. 320x180, for hover tooltips over the timeline.
+    /// `hover_preview` fills it without touching the program texture or playback.
+    pub fn create_hover_preview_texture(&mut self, engine_handle: i64, width: u32, height: u32) -> Result<i64, FlipEditError> {
+        self.inner.call(move |player| player.create_hover_preview_texture(engine_handle, width, height)).map_err(FlipEditError::from)
+    }
+
+    /// Show the frame at timeline `position_ms` in the hover preview texture. Cheap to call on
+    /// every pointer move: frames are decoded in the background and stale positions skipped.
+    pub fn hover_preview(&self, position_ms: i32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.hover_preview(position_ms)).map_err(FlipEditError::from)
+    }
 
-    pub fn load_timeline(&mut self, timeline_data: TimelineData) -> Result<(), String> {
-        self.inner.load_timeline(timeline_data).map_err(|e| e.to_string())
+    #[frb(sync)]
+    pub fn dispose_hover_preview(&mut self) {
+        self.inner.post(|player| player.dispose_hover_preview());
     }
 
     pub fn set_position_ms(&mut self, position_ms: i32) {
-        self.inner.seek(position_ms as u64).unwrap_or_else(|e| {
+        self.inner.call(move |player| player.seek(position_ms as u64)).unwrap_or_else(|e| {
             eprintln!("Failed to seek to position: {}", e);
         });
     }
 
     #[frb(sync)]
-    pub fn get_position_ms(&self) -> i32 {
-        self.inner.get_current_position_ms() as i32
+    pub fn get_position_ms(&self) -> Result<i32, FlipEditError> {
+        self.inner.run(|player| player.get_current_position_ms() as i32).map_err(FlipEditError::from)
     }
 
-    pub fn play(&mut self) -> Result<(), String> {
-        self.inner.play().map_err(|e| e.to_string())
+    pub fn play(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.play()).map_err(FlipEditError::from)
     }
 
-    pub fn pause(&mut self) -> Result<(), String> {
-        self.inner.pause().map_err(|e| e.to_string())
+    pub fn pause(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.pause()).map_err(FlipEditError::from)
     }
 
-    pub fn stop(&mut self) -> Result<(), String> {
-        self.inner.dispose().map_err(|e| e.to_string())
+    pub fn stop(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.dispose()).map_err(FlipEditError::from)
     }
 
     #[frb(sync)]
@@ -238,18 +309,18 @@ impl TimelinePlayer {
     }
 
     #[frb(sync)]
-    pub fn is_playing(&self) -> bool {
-        self.inner.is_playing()
+    pub fn is_playing(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.is_playing()).map_err(FlipEditError::from)
     }
 
-    pub fn dispose(&mut self) -> Result<(), String> {
-        self.inner.dispose().map_err(|e| e.to_string())
+    pub fn dispose(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.dispose()).map_err(FlipEditError::from)
     }
 
     /// Test method to verify timeline logic - set position and check if frame should be shown
     #[frb(sync)]
     pub fn test_timeline_logic(&mut self, position_ms: i32) -> bool {
-        self.inner.seek(position_ms as u64).unwrap_or_else(|e| {
+        self.inner.call(move |player| player.seek(position_ms as u64)).unwrap_or_else(|e| {
             eprintln!("Failed to seek to position for test: {}", e);
         });
         // TODO: Implement frame checking logic
@@ -259,56 +330,356 @@ impl TimelinePlayer {
 
 // GES timeline player implementation (now using DirectPipelinePlayer)
 pub struct GESTimelinePlayer {
-    inner: InternalDirectPipelinePlayer,
+    inner: PlayerHandle,
 }
 
 impl GESTimelinePlayer {
     #[frb(sync)]
-    pub fn new() -> Self {
-        Self {
-            inner: InternalDirectPipelinePlayer::new().expect("Failed to create DirectPipelinePlayer"),
-        }
+    pub fn new() -> Result<Self, FlipEditError> {
+        Ok(Self {
+            inner: PlayerHandle::spawn()?,
+        })
+    }
+
+
+    /// Prefer zero-copy GL or DMA-BUF textures (Linux) over CPU RGBA frames; call before `create_texture`
+    #[frb(sync)]
+    pub fn set_preferred_render_path(&mut self, path: RenderPath) {
+        self.inner.post(move |player| player.set_preferred_render_path(path));
+    }
+
+    /// Render path in use after texture creation, which may have fallen back to CPU frames
+    #[frb(sync)]
+    pub fn get_active_render_path(&self) -> Result<RenderPath, FlipEditError> {
+        self.inner.run(|player| player.get_active_render_path()).map_err(FlipEditError::from)
+    }
+
+    /// Active render path, GPU driver and fallback reason, for "video is slow" reports
+    #[frb(sync)]
+    pub fn get_render_path_info(&self) -> Result<RenderPathInfo, FlipEditError> {
+        self.inner.run(|player| player.get_render_path_info()).map_err(FlipEditError::from)
+    }
+
+    /// Rolling decode, convert and upload timings per frame
+    #[frb(sync)]
+    pub fn get_performance_stats(&self) -> Result<PerformanceStats, FlipEditError> {
+        self.inner.run(|player| player.get_performance_stats()).map_err(FlipEditError::from)
+    }
+
+    /// Tone map PQ and HLG HDR clips to SDR in the preview (GPU mixing only); applies on the next load
+    #[frb(sync)]
+    pub fn set_hdr_tone_mapping(&mut self, enabled: bool) {
+        self.inner.post(move |player| player.set_hdr_tone_mapping(enabled));
+    }
+
+    #[frb(sync)]
+    pub fn get_hdr_tone_mapping(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.get_hdr_tone_mapping()).map_err(FlipEditError::from)
+    }
+
+    /// Override tone mapping for one clip, e.g. to keep a deliberately flat HLG shot as is;
+    /// `None` follows `set_hdr_tone_mapping` again. Applies on the next load.
+    pub fn set_clip_tone_mapping(&mut self, clip_id: i32, enabled: Option<bool>) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_clip_tone_mapping(clip_id, enabled)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_clip_tone_mapping(&self, clip_id: i32) -> Result<Option<bool>, FlipEditError> {
+        self.inner.run(move |player| player.get_clip_tone_mapping(clip_id)).map_err(FlipEditError::from)
+    }
+
+    /// Project color space clips are converted to before mixing, so cameras recording in
+    /// different spaces match (CPU render paths); applies on the next load
+    #[frb(sync)]
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.inner.post(move |player| player.set_color_space(color_space));
+    }
+
+    #[frb(sync)]
+    pub fn get_color_space(&self) -> Result<ColorSpace, FlipEditError> {
+        self.inner.run(|player| player.get_color_space()).map_err(FlipEditError::from)
+    }
+
+    /// Threads and scaling filter for converting clip frames on CPU render paths, e.g. 0
+    /// threads (one per core) so 4K sources don't convert on a single core; applies on the
+    /// next load
+    #[frb(sync)]
+    pub fn set_conversion_settings(&mut self, settings: ConversionSettings) {
+        self.inner.post(move |player| player.set_conversion_settings(settings));
+    }
+
+    #[frb(sync)]
+    pub fn get_conversion_settings(&self) -> Result<ConversionSettings, FlipEditError> {
+        self.inner.run(|player| player.get_conversion_settings()).map_err(FlipEditError::from)
+    }
+
+    /// Pixel aspect ratio of the timeline canvas, e.g. 4/3 for an HDV project, so anamorphic
+    /// footage is edited at its stored size; `VideoSizeChanged` reports the width to display
+    /// frames at. Applies on the next load.
+    #[frb(sync)]
+    pub fn set_pixel_aspect_ratio(&mut self, par: PixelAspectRatio) {
+        self.inner.post(move |player| player.set_pixel_aspect_ratio(par));
     }
 
+    #[frb(sync)]
+    pub fn get_pixel_aspect_ratio(&self) -> Result<PixelAspectRatio, FlipEditError> {
+        self.inner.run(|player| player.get_pixel_aspect_ratio()).map_err(FlipEditError::from)
+    }
+
+    /// Color space a source file declares, or `None` if it doesn't say
+    pub fn get_source_color_space(&self, source_path: String) -> Result<Option<ColorSpace>, FlipEditError> {
+        self.inner.run(move |player| player.get_source_color_space(&source_path)).map_err(FlipEditError::from)
+    }
 
     /// Create texture for this player
-    pub fn create_texture(&mut self, engine_handle: i64) -> Result<i64, String> {
-        self.inner.create_texture(engine_handle).map_err(|e| e.to_string())
+    pub fn create_texture(&mut self, engine_handle: i64) -> Result<i64, FlipEditError> {
+        self.inner.call(move |player| player.create_texture(engine_handle)).map_err(FlipEditError::from)
+    }
+
+    pub fn load_timeline(&mut self, timeline_data: TimelineData) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.load_timeline(timeline_data)).map_err(FlipEditError::from)
+    }
+
+    /// The loaded timeline with the ids given to clips that had none; save this rather than
+    /// the data passed in so clip ids stay the same when the project is reopened
+    #[frb(sync)]
+    pub fn get_timeline_data(&self) -> Result<Option<TimelineData>, FlipEditError> {
+        self.inner.run(|player| player.get_timeline_data()).map_err(FlipEditError::from)
+    }
+
+    /// The loaded timeline as versioned, pretty-printed JSON, including assigned ids, captions,
+    /// markers, effects and automation, and each clip's fit, orientation, pan and zoom, audio
+    /// effects and tone mapping override; read it back with `deserialize_timeline`
+    pub fn serialize_timeline(&self) -> Result<String, FlipEditError> {
+        self.inner.call(|player| player.serialize_timeline()).map_err(FlipEditError::from)
+    }
+
+    /// Replace the caption track with the cues of an SRT or WebVTT file and show them in the
+    /// preview (CPU render paths). Returns the imported cues with their new ids.
+    pub fn import_captions(&mut self, path: String) -> Result<Vec<CaptionCue>, FlipEditError> {
+        self.inner.call(move |player| player.import_captions(&path)).map_err(FlipEditError::from)
+    }
+
+    /// Cues on the caption track, in start order
+    #[frb(sync)]
+    pub fn get_caption_cues(&self) -> Result<Vec<CaptionCue>, FlipEditError> {
+        self.inner.run(|player| player.get_caption_cues()).map_err(FlipEditError::from)
     }
 
-    pub fn load_timeline(&mut self, timeline_data: TimelineData) -> Result<(), String> {
-        self.inner.load_timeline(timeline_data).map_err(|e| e.to_string())
+    /// Add a cue to the caption track. Returns the cue with its new id.
+    pub fn add_caption_cue(&mut self, text: String, start_ms: i32, end_ms: i32, style: CaptionStyle) -> Result<CaptionCue, FlipEditError> {
+        self.inner.call(move |player| player.add_caption_cue(text, start_ms, end_ms, style)).map_err(FlipEditError::from)
     }
 
-    pub fn play(&mut self) -> Result<(), String> {
-        self.inner.play().map_err(|e| e.to_string())
+    /// Replace a cue's text, timing and style. The cue keeps its id; `get_caption_cues` returns
+    /// the track in its new order.
+    pub fn update_caption_cue(&mut self, id: i32, text: String, start_ms: i32, end_ms: i32, style: CaptionStyle) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.update_caption_cue(id, text, start_ms, end_ms, style)).map_err(FlipEditError::from)
     }
 
-    pub fn pause(&mut self) -> Result<(), String> {
-        self.inner.pause().map_err(|e| e.to_string())
+    pub fn remove_caption_cue(&mut self, id: i32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.remove_caption_cue(id)).map_err(FlipEditError::from)
     }
 
-    pub fn stop(&mut self) -> Result<(), String> {
-        self.inner.dispose().map_err(|e| e.to_string())
+    /// Font, size and outline of the captions drawn over the preview. Not saved with the
+    /// timeline; set it again after creating a player.
+    pub fn set_caption_appearance(&mut self, appearance: CaptionAppearance) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_caption_appearance(appearance)).map_err(FlipEditError::from)
     }
 
-    pub fn seek_to_position(&mut self, position_ms: i32) -> Result<(), String> {
-        self.inner.seek(position_ms as u64).map_err(|e| e.to_string())
+    #[frb(sync)]
+    pub fn get_caption_appearance(&self) -> Result<CaptionAppearance, FlipEditError> {
+        self.inner.run(|player| player.get_caption_appearance()).map_err(FlipEditError::from)
+    }
+
+    /// Burn the captions, in the current appearance, into the segments background rendering
+    /// writes (see `enable_background_render`). The preview stops drawing them over rendered
+    /// ranges so they aren't shown twice. Not saved with the timeline.
+    pub fn set_caption_burn_in(&mut self, enabled: bool) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_caption_burn_in(enabled)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_caption_burn_in(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.get_caption_burn_in()).map_err(FlipEditError::from)
+    }
+
+    /// Chapter list for a YouTube description, made from the timeline's markers and adjusted
+    /// to YouTube's rules (first chapter at 0:00, at least three chapters of 10s or more).
+    /// Fails when the markers can't make three chapters.
+    pub fn export_chapters(&self) -> Result<String, FlipEditError> {
+        self.inner.call(|player| player.export_chapters()).map_err(FlipEditError::from)
+    }
+
+    /// Save the caption track as a sidecar SRT or WebVTT file. Cue times are timeline times,
+    /// so send `TimelineChange::RippleCaptions` with ripple edits to keep them in step with
+    /// the clips.
+    pub fn export_captions(&self, format: CaptionFormat, path: String) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.export_captions(format, &path)).map_err(FlipEditError::from)
+    }
+
+    /// Load the timeline in the background, returning a job id at once. `sink` receives each
+    /// clip as its source is added, the preroll, then `Completed` or `Failed`. Loads queued
+    /// behind one another run in order; other player calls wait for the load to finish.
+    #[frb(sync)]
+    pub fn load_timeline_async(&mut self, timeline_data: TimelineData, sink: StreamSink<TimelineLoadEvent>) -> u64 {
+        let job_id = NEXT_TIMELINE_LOAD_JOB.fetch_add(1, Ordering::Relaxed);
+        self.inner.post(move |player| player.load_timeline_job(job_id, timeline_data, Box::new(move |event| {
+            if let Err(e) = sink.add(event) {
+                eprintln!("Failed to send timeline load progress to sink: {:?}", e);
+            }
+        })));
+        job_id
+    }
+
+    /// Apply add/move/resize/remove edits without tearing down the pipeline
+    pub fn apply_timeline_diff(&mut self, changes: Vec<TimelineChange>) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.apply_timeline_diff(changes)).map_err(FlipEditError::from)
+    }
+
+    /// Reorder a track in the compositing stack: higher priorities draw on top, and tracks
+    /// with equal priorities stack in list order
+    pub fn set_track_priority(&mut self, track_id: i32, priority: i32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_track_priority(track_id, priority)).map_err(FlipEditError::from)
+    }
+
+    /// Slip a clip to show its source from `in_point_ms`, keeping its place and length on the
+    /// track
+    pub fn set_clip_in_point(&mut self, clip_id: i32, in_point_ms: i32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_clip_in_point(clip_id, in_point_ms)).map_err(FlipEditError::from)
+    }
+
+    /// Left-edge trim: move a clip's start and in-point together, keeping its end
+    pub fn trim_clip_start(&mut self, clip_id: i32, start_time_on_track_ms: i32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.trim_clip_start(clip_id, start_time_on_track_ms)).map_err(FlipEditError::from)
+    }
+
+    /// How trims (`ResizeClip` changes and `trim_clip_start`) and moves affect neighbouring
+    /// clips: not at all, rippling the rest of the track, or rolling the edit point with the
+    /// adjacent clip (trims only)
+    #[frb(sync)]
+    pub fn set_edit_mode(&mut self, mode: EditMode) {
+        self.inner.post(move |player| player.set_edit_mode(mode));
     }
 
     #[frb(sync)]
-    pub fn get_position_ms(&self) -> i32 {
-        self.inner.get_current_position_ms() as i32
+    pub fn get_edit_mode(&self) -> Result<EditMode, FlipEditError> {
+        self.inner.run(|player| player.get_edit_mode()).map_err(FlipEditError::from)
+    }
+
+    /// Crossfade where an added or moved clip overlaps another clip on its track, for as long
+    /// as they overlap. Saved with the loaded timeline, so each timeline keeps its own setting.
+    pub fn set_auto_transition(&mut self, enabled: bool) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_auto_transition(enabled)).map_err(FlipEditError::from)
     }
 
     #[frb(sync)]
-    pub fn get_duration_ms(&self) -> Option<i32> {
-        self.inner.get_duration_ms().map(|d| d as i32)
+    pub fn get_auto_transition(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.get_auto_transition()).map_err(FlipEditError::from)
+    }
+
+    /// Turn off to queue `apply_timeline_diff` edits, e.g. for every pointer move of a trim
+    /// drag, and apply them together with `commit_timeline` on pointer-up. Queued edits are
+    /// checked at once but aren't shown, returned by `get_timeline_data` or reported as
+    /// timeline events until committed. Turning it back on commits them.
+    pub fn set_auto_commit(&mut self, enabled: bool) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_auto_commit(enabled)).map_err(FlipEditError::from)
     }
 
     #[frb(sync)]
-    pub fn is_playing(&self) -> bool {
-        self.inner.is_playing()
+    pub fn get_auto_commit(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.get_auto_commit()).map_err(FlipEditError::from)
+    }
+
+    pub fn commit_timeline(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.commit_timeline()).map_err(FlipEditError::from)
+    }
+
+    /// Add many clips in one call and one pipeline update; nothing is added if any clip is
+    /// invalid. Returns the clips with the ids they were given.
+    pub fn add_clips(&mut self, clips: Vec<TimelineClip>) -> Result<Vec<TimelineClip>, FlipEditError> {
+        self.inner.call(move |player| player.add_clips(clips)).map_err(FlipEditError::from)
+    }
+
+    pub fn play(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.play()).map_err(FlipEditError::from)
+    }
+
+    pub fn pause(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.pause()).map_err(FlipEditError::from)
+    }
+
+    pub fn stop(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.dispose()).map_err(FlipEditError::from)
+    }
+
+    pub fn seek_to_position(&mut self, position_ms: i32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.seek(position_ms as u64)).map_err(FlipEditError::from)
+    }
+
+    /// Seek with an explicit precision - use `Fast` while dragging the playhead and `Accurate` on release
+    pub fn seek_to_position_with_mode(&mut self, position_ms: i32, mode: ScrubMode) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.seek_with_mode(position_ms as u64, mode)).map_err(FlipEditError::from)
+    }
+
+    /// Seek to the start of a frame, counted at `get_frame_rate`
+    pub fn seek_to_frame(&mut self, frame: u64) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.seek_to_frame(frame)).map_err(FlipEditError::from)
+    }
+
+    /// Exact rate frame numbers are counted in: the override if set, otherwise the rate of the
+    /// timeline's first video clip (30/1 until one is loaded)
+    #[frb(sync)]
+    pub fn get_frame_rate(&self) -> Result<FrameRate, FlipEditError> {
+        self.inner.run(|player| player.get_frame_rate()).map_err(FlipEditError::from)
+    }
+
+    /// Count frames at a project rate instead of following the sources; `None` clears it
+    #[frb(sync)]
+    pub fn set_frame_rate(&mut self, frame_rate: Option<FrameRate>) {
+        self.inner.post(move |player| player.set_frame_rate(frame_rate));
+    }
+
+    #[frb(sync)]
+    pub fn get_current_frame_number(&self) -> Result<u64, FlipEditError> {
+        self.inner.run(|player| player.get_current_frame_number()).map_err(FlipEditError::from)
+    }
+
+    /// Set the default seek precision used by `seek_to_position`
+    #[frb(sync)]
+    pub fn set_scrub_mode(&mut self, mode: ScrubMode) {
+        self.inner.post(move |player| player.set_scrub_mode(mode));
+    }
+
+    #[frb(sync)]
+    pub fn get_scrub_mode(&self) -> Result<ScrubMode, FlipEditError> {
+        self.inner.run(|player| player.get_scrub_mode()).map_err(FlipEditError::from)
+    }
+
+    /// Shift timeline audio against video in milliseconds (negative plays audio earlier)
+    #[frb(sync)]
+    pub fn set_av_offset_ms(&mut self, offset_ms: i64) {
+        self.inner.post(move |player| player.set_av_offset_ms(offset_ms));
+    }
+
+    #[frb(sync)]
+    pub fn get_av_offset_ms(&self) -> Result<i64, FlipEditError> {
+        self.inner.run(|player| player.get_av_offset_ms()).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_position_ms(&self) -> Result<i32, FlipEditError> {
+        self.inner.run(|player| player.get_current_position_ms() as i32).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_duration_ms(&self) -> Result<Option<i32>, FlipEditError> {
+        self.inner.run(|player| player.get_duration_ms().map(|d| d as i32)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn is_playing(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.is_playing()).map_err(FlipEditError::from)
     }
 
     #[frb(sync)]
@@ -334,7 +705,7 @@ impl GESTimelinePlayer {
     /// Update position from GStreamer pipeline - call this regularly for smooth playhead updates
     #[frb(sync)]
     pub fn update_position(&self) {
-        self.inner.update_position();
+        self.inner.post(|player| player.update_position());
     }
 
     pub fn setup_frame_stream(&mut self, _sink: StreamSink<FrameData>) -> Result<()> {
@@ -343,25 +714,185 @@ impl GESTimelinePlayer {
     }
 
     pub fn setup_position_stream(&mut self, sink: StreamSink<(f64, u64)>) -> Result<()> {
-        self.inner.set_position_update_callback(Box::new(move |position, frame| {
+        self.inner.call(move |player| player.set_position_update_callback(Box::new(move |position, frame| {
             if let Err(e) = sink.add((position, frame)) {
                 eprintln!("Failed to send position update to sink: {:?}", e);
             }
             Ok(())
-        })).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        }))).map_err(|e| anyhow::anyhow!(e.to_string()))?;
         Ok(())
     }
 
+    /// Set how often the position stream is updated while playing (default 16ms).
+    /// While paused positions are only pushed after seeks.
+    #[frb(sync)]
+    pub fn set_position_update_interval_ms(&mut self, interval_ms: u32) {
+        self.inner.post(move |player| player.set_position_update_interval_ms(interval_ms as u64));
+    }
+
     pub fn setup_seek_completion_stream(&mut self, sink: StreamSink<i32>) -> Result<()> {
-        self.inner.set_seek_completion_callback(Box::new(move |position_ms| {
+        self.inner.call(move |player| player.set_seek_completion_callback(Box::new(move |position_ms| {
             if let Err(e) = sink.add(position_ms as i32) {
                 eprintln!("Failed to send seek completion to sink: {:?}", e);
             }
             Ok(())
-        })).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        }))).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Play only this clip full-frame without changing the timeline
+    pub fn preview_clip(&mut self, clip_id: i32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.preview_clip(clip_id)).map_err(FlipEditError::from)
+    }
+
+    /// Return from `preview_clip` to the timeline at the previous playhead
+    pub fn exit_clip_preview(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.exit_clip_preview()).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_previewed_clip_id(&self) -> Result<Option<i32>, FlipEditError> {
+        self.inner.run(|player| player.get_previewed_clip_id()).map_err(FlipEditError::from)
+    }
+
+    /// Render ranges with at least `min_layers` overlapping clips into `cache_dir` while idle.
+    /// Rendered ranges play from the cache after the next timeline load.
+    pub fn enable_background_render(&mut self, cache_dir: String, min_layers: u32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.enable_background_render(&cache_dir, min_layers as usize)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn disable_background_render(&mut self) {
+        self.inner.post(|player| player.disable_background_render());
+    }
+
+    #[frb(sync)]
+    pub fn get_render_segments(&self) -> Result<Vec<RenderSegment>, FlipEditError> {
+        self.inner.run(|player| player.get_render_segments()).map_err(FlipEditError::from)
+    }
+
+    /// Stream per-segment render status changes for painting the "rendered" bar
+    pub fn setup_render_status_stream(&mut self, sink: StreamSink<RenderSegment>) -> Result<()> {
+        self.inner.post(move |player| player.set_render_status_callback(Box::new(move |segment| {
+            if let Err(e) = sink.add(segment) {
+                eprintln!("Failed to send render status to sink: {:?}", e);
+            }
+            Ok(())
+        })));
+        Ok(())
+    }
+
+    /// Choose what happens when the playhead reaches the end of the timeline
+    #[frb(sync)]
+    pub fn set_end_behavior(&mut self, behavior: EndBehavior) {
+        self.inner.post(move |player| player.set_end_behavior(behavior));
+    }
+
+    #[frb(sync)]
+    pub fn get_end_behavior(&self) -> Result<EndBehavior, FlipEditError> {
+        self.inner.run(|player| player.get_end_behavior()).map_err(FlipEditError::from)
+    }
+
+    /// Retry failed or stalled playback automatically, escalating from a flush seek to a new
+    /// video sink to a new pipeline; progress is reported as recovery playback events
+    #[frb(sync)]
+    pub fn set_auto_recovery(&mut self, enabled: bool) {
+        self.inner.post(move |player| player.set_auto_recovery(enabled));
+    }
+
+    #[frb(sync)]
+    pub fn get_auto_recovery(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.get_auto_recovery()).map_err(FlipEditError::from)
+    }
+
+    /// Stream end-of-stream, error, state and duration notifications to Flutter
+    pub fn setup_playback_event_stream(&mut self, sink: StreamSink<PlaybackEvent>) -> Result<()> {
+        self.inner.call(move |player| player.set_playback_event_callback(Box::new(move |event| {
+            if let Err(e) = sink.add(event) {
+                eprintln!("Failed to send playback event to sink: {:?}", e);
+            }
+            Ok(())
+        }))).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Stream every change to the timeline model, including ones the player makes itself
+    /// (audio sync, voice-over clips, ducking), so the app's timeline can mirror the engine's
+    pub fn setup_timeline_events_stream(&mut self, sink: StreamSink<TimelineEvent>) -> Result<()> {
+        self.inner.run(move |player| player.set_timeline_event_callback(Box::new(move |event| {
+            if let Err(e) = sink.add(event) {
+                eprintln!("Failed to send timeline event to sink: {:?}", e);
+            }
+        }))).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Stream per-channel peak/RMS levels of the master output for the mixer panel's meters
+    pub fn setup_audio_meter_stream(&mut self, sink: StreamSink<AudioLevels>) -> Result<()> {
+        self.inner.call(move |player| player.set_audio_level_callback(Box::new(move |levels| {
+            if let Err(e) = sink.add(levels) {
+                eprintln!("Failed to send audio levels to sink: {:?}", e);
+            }
+            Ok(())
+        }))).map_err(|e| anyhow::anyhow!(e.to_string()))?;
         Ok(())
     }
 
+    /// Choose whether a clip whose shape doesn't match its box is stretched (the default),
+    /// letterboxed, cropped to fill, or shown at 100%. Cropping needs a CPU render path; GPU
+    /// mixing letterboxes instead.
+    pub fn set_clip_fit_mode(&mut self, clip_id: i32, mode: ClipFitMode) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_clip_fit_mode(clip_id, mode)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_clip_fit_mode(&self, clip_id: i32) -> Result<ClipFitMode, FlipEditError> {
+        self.inner.run(move |player| player.get_clip_fit_mode(clip_id)).map_err(FlipEditError::from)
+    }
+
+    /// Rotate a clip clockwise. Multiples of 90 degrees turn the whole frame, e.g. to fix
+    /// portrait phone footage; other angles tilt the picture, which needs a CPU render path.
+    pub fn set_clip_rotation(&mut self, clip_id: i32, degrees: f64) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_clip_rotation(clip_id, degrees)).map_err(FlipEditError::from)
+    }
+
+    pub fn set_clip_flip(&mut self, clip_id: i32, horizontal: bool, vertical: bool) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_clip_flip(clip_id, horizontal, vertical)).map_err(FlipEditError::from)
+    }
+
+    /// Animate a clip's tilt over the timeline; an empty list holds the clip's rotation again
+    pub fn set_clip_rotation_keyframes(&mut self, clip_id: i32, keyframes: Vec<RotationKeyframe>) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_clip_rotation_keyframes(clip_id, keyframes)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_clip_orientation(&self, clip_id: i32) -> Result<ClipOrientation, FlipEditError> {
+        self.inner.run(move |player| player.get_clip_orientation(clip_id)).map_err(FlipEditError::from)
+    }
+
+    /// Ken Burns move over the whole clip, from one part of its source to another, e.g. to
+    /// give a photo montage movement. Rects are fractions of the source frame.
+    pub fn set_pan_zoom(&mut self, clip_id: i32, start_rect: SourceRect, end_rect: SourceRect) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_pan_zoom(clip_id, start_rect, end_rect)).map_err(FlipEditError::from)
+    }
+
+    /// The keyframes behind `set_pan_zoom`, for editing them one by one; empty to stop animating
+    pub fn set_clip_crop_keyframes(&mut self, clip_id: i32, keyframes: Vec<CropKeyframe>) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_clip_crop_keyframes(clip_id, keyframes)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_clip_crop_keyframes(&self, clip_id: i32) -> Result<Vec<CropKeyframe>, FlipEditError> {
+        self.inner.run(move |player| player.get_clip_crop_keyframes(clip_id)).map_err(FlipEditError::from)
+    }
+
+    /// Everything about a clip in one call: its timing and in/out points, the rectangle it's
+    /// actually drawn in, fit mode, tone mapping, what probing its source found, and its
+    /// track's audio effects, volume automation and the markers within it
+    pub fn get_clip(&mut self, clip_id: i32) -> Result<ClipInfo, FlipEditError> {
+        self.inner.call(move |player| player.get_clip(clip_id)).map_err(FlipEditError::from)
+    }
+
     /// Update a specific clip's transform properties without reloading the entire timeline
     pub fn update_clip_transform(
         &mut self,
@@ -370,90 +901,271 @@ impl GESTimelinePlayer {
         preview_position_y: f64,
         preview_width: f64,
         preview_height: f64,
-    ) -> Result<(), String> {
-        self.inner.update_clip_transform(
+    ) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.update_clip_transform(
             clip_id,
             preview_position_x,
             preview_position_y,
             preview_width,
             preview_height,
-        ).map_err(|e| e.to_string())
+        )).map_err(FlipEditError::from)
     }
 
+    /// Update a clip's opacity (0.0-1.0) without reloading the timeline
+    pub fn update_clip_opacity(&mut self, clip_id: i32, opacity: f64) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.update_clip_opacity(clip_id, opacity)).map_err(FlipEditError::from)
+    }
 
-    pub fn dispose(&mut self) -> Result<(), String> {
-        self.inner.dispose().map_err(|e| e.to_string())
+    /// Append an audio effect (e.g. "equalizer-10bands", "audiodynamic") to a track; returns its id
+    pub fn add_track_audio_effect(&mut self, track_id: i32, factory_name: String) -> Result<u32, FlipEditError> {
+        self.inner.call(move |player| player.add_track_audio_effect(track_id, &factory_name)).map_err(FlipEditError::from)
     }
-}
 
-// =================== IRONDASH TEXTURE API ===================
+    /// Append denoise or de-hum effects to a track (de-hum notches the mains frequency and its
+    /// 2nd and 3rd harmonics); each is tuned through `set_track_audio_effect_parameter` like any
+    /// other effect. Returns their ids in chain order.
+    pub fn add_track_audio_restoration(&mut self, track_id: i32, restoration: AudioRestoration) -> Result<Vec<u32>, FlipEditError> {
+        self.inner.call(move |player| player.add_track_audio_restoration(track_id, restoration)).map_err(FlipEditError::from)
+    }
 
-/// Create a new video texture using irondash for zero-copy rendering
-#[frb(sync)]
-pub fn create_video_texture(width: u32, height: u32, engine_handle: i64) -> Result<i64, String> {
-    crate::video::irondash_texture::create_video_texture_on_main_thread(width, height, engine_handle)
-        .map_err(|e| e.to_string())
-}
+    pub fn remove_track_audio_effect(&mut self, track_id: i32, effect_id: u32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.remove_track_audio_effect(track_id, effect_id)).map_err(FlipEditError::from)
+    }
 
-/// Update video frame data for all irondash textures
-#[frb(sync)]
-pub fn update_video_frame(frame_data: FrameData) -> bool {
-    // Only call the real irondash texture update function
-    match crate::video::irondash_texture::update_video_frame(frame_data) {
-        Ok(_) => true,
-        Err(e) => {
-            log::error!("Failed to update video frame: {}", e);
-            false
-        }
+    pub fn set_track_audio_effect_parameter(&mut self, track_id: i32, effect_id: u32, name: String, value: f64) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_track_audio_effect_parameter(track_id, effect_id, &name, value)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_track_audio_effects(&self, track_id: i32) -> Result<Vec<TrackAudioEffect>, FlipEditError> {
+        self.inner.run(move |player| player.get_track_audio_effects(track_id)).map_err(FlipEditError::from)
+    }
+
+    /// Append an audio effect to a single clip, run before its track's effects; returns its id
+    pub fn add_clip_audio_effect(&mut self, clip_id: i32, factory_name: String) -> Result<u32, FlipEditError> {
+        self.inner.call(move |player| player.add_clip_audio_effect(clip_id, &factory_name)).map_err(FlipEditError::from)
+    }
+
+    /// Append denoise or de-hum effects to a single clip, run before its track's effects;
+    /// returns their ids in chain order
+    pub fn add_clip_audio_restoration(&mut self, clip_id: i32, restoration: AudioRestoration) -> Result<Vec<u32>, FlipEditError> {
+        self.inner.call(move |player| player.add_clip_audio_restoration(clip_id, restoration)).map_err(FlipEditError::from)
+    }
+
+    /// Shift a clip's pitch by `semitones` (-24 to 24) keeping its speed, with soundtouch's
+    /// `pitch` element. Adjust it through `set_clip_audio_effect_parameter` with "semitones".
+    /// Returns its id.
+    pub fn add_clip_pitch_shift(&mut self, clip_id: i32, semitones: f64) -> Result<u32, FlipEditError> {
+        self.inner.call(move |player| player.add_clip_pitch_shift(clip_id, semitones)).map_err(FlipEditError::from)
+    }
+
+    pub fn remove_clip_audio_effect(&mut self, clip_id: i32, effect_id: u32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.remove_clip_audio_effect(clip_id, effect_id)).map_err(FlipEditError::from)
+    }
+
+    pub fn set_clip_audio_effect_parameter(&mut self, clip_id: i32, effect_id: u32, name: String, value: f64) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.set_clip_audio_effect_parameter(clip_id, effect_id, &name, value)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_clip_audio_effects(&self, clip_id: i32) -> Result<Vec<TrackAudioEffect>, FlipEditError> {
+        self.inner.run(move |player| player.get_clip_audio_effects(clip_id)).map_err(FlipEditError::from)
+    }
+
+    /// Lower the music track by `reduction_db` wherever the dialogue track's level exceeds
+    /// `threshold_db`; returns the generated volume keyframes. The dialogue is analyzed off the
+    /// player's worker, which only reloads the music track's automation.
+    pub fn enable_ducking(
+        &mut self,
+        music_track_id: i32,
+        dialogue_track_id: i32,
+        threshold_db: f64,
+        reduction_db: f64,
+        attack_ms: u64,
+        release_ms: u64,
+    ) -> Result<Vec<VolumeKeyframe>, FlipEditError> {
+        let settings = DuckingSettings {
+            music_track_id,
+            dialogue_track_id,
+            threshold_db,
+            reduction_db,
+            attack_ms,
+            release_ms,
+        };
+        let timeline_data = self.inner.run(|player| player.get_timeline_data())
+            .map_err(FlipEditError::from)?
+            .ok_or_else(FlipEditError::not_loaded)?;
+        let keyframes = crate::audio_automation::timeline_ducking_keyframes(&timeline_data, &settings)
+            .map_err(FlipEditError::from)?;
+        let music_keyframes = keyframes.clone();
+        self.inner.call(move |player| player.set_volume_keyframes(music_track_id, music_keyframes))
+            .map_err(FlipEditError::from)?;
+        Ok(keyframes)
+    }
+
+    pub fn disable_ducking(&mut self, music_track_id: i32) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.clear_volume_keyframes(music_track_id)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn get_volume_keyframes(&self, track_id: i32) -> Result<Vec<VolumeKeyframe>, FlipEditError> {
+        self.inner.run(move |player| player.get_volume_keyframes(track_id)).map_err(FlipEditError::from)
+    }
+
+    /// Record the default input device to a WAV at `output_path` while the timeline plays
+    /// from `start_ms`; stopping adds the recording to the track
+    pub fn start_voiceover(
+        &mut self,
+        track_id: i32,
+        start_ms: u64,
+        output_path: String,
+        playback: VoiceoverPlayback,
+    ) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.start_voiceover(track_id, start_ms, &output_path, playback)).map_err(FlipEditError::from)
+    }
+
+    /// Returns the clip inserted for the recording, with the id the player gave it
+    pub fn stop_voiceover(&mut self) -> Result<TimelineClip, FlipEditError> {
+        self.inner.call(|player| player.stop_voiceover()).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn is_recording_voiceover(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.is_recording_voiceover()).map_err(FlipEditError::from)
+    }
+
+    /// PNG of the frame currently in the preview, optionally also saved to `output_path`
+    pub fn capture_preview_frame(&self, output_path: Option<String>) -> Result<Vec<u8>, FlipEditError> {
+        self.inner.call(move |player| player.capture_preview_frame(output_path.as_deref())).map_err(FlipEditError::from)
+    }
+
+    /// Write the pipeline topology as a Graphviz DOT file at `path`, e.g. for bug reports about
+    /// black frames or stuck prerolls
+    pub fn dump_pipeline_graph(&self, path: String) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.dump_pipeline_graph(&path)).map_err(FlipEditError::from)
+    }
+
+    /// Stream the program output to an RTMP or SRT ingest while the timeline plays
+    pub fn start_live_stream(&mut self, settings: LiveStreamSettings) -> Result<(), FlipEditError> {
+        self.inner.call(move |player| player.start_live_stream(settings)).map_err(FlipEditError::from)
+    }
+
+    #[frb(sync)]
+    pub fn stop_live_stream(&mut self) {
+        self.inner.post(|player| player.stop_live_stream());
+    }
+
+    #[frb(sync)]
+    pub fn is_live_streaming(&self) -> Result<bool, FlipEditError> {
+        self.inner.run(|player| player.is_live_streaming()).map_err(FlipEditError::from)
+    }
+
+    /// Align clips of the same event (e.g. multicam angles) by cross-correlating their audio;
+    /// the first clip stays the reference and the others move onto separate tracks. The audio
+    /// is analyzed off the player's worker, so playback carries on meanwhile.
+    pub fn sync_clips_by_audio(&mut self, clip_ids: Vec<i32>) -> Result<Vec<ClipSyncResult>, FlipEditError> {
+        let timeline_data = self.inner.run(|player| player.get_timeline_data())
+            .map_err(FlipEditError::from)?
+            .ok_or_else(FlipEditError::not_loaded)?;
+        let results = crate::audio_sync::sync_clips(&timeline_data, &clip_ids).map_err(FlipEditError::from)?;
+        let changes = results.iter().map(|r| TimelineChange::MoveClip {
+            clip_id: r.clip_id,
+            track_id: r.track_id,
+            start_time_on_track_ms: r.start_time_on_track_ms,
+        }).collect();
+        self.inner.call(move |player| player.apply_timeline_diff(changes)).map_err(FlipEditError::from)?;
+        Ok(results)
+    }
+
+    /// Peak levels (0.0 to 1.0) of the whole timeline's audio mix, `samples_per_second` values
+    /// per second of timeline, e.g. for an overview strip. Rendered offline from the loaded
+    /// timeline without holding up playback.
+    pub fn generate_timeline_waveform(&self, samples_per_second: u32) -> Result<Vec<f32>, FlipEditError> {
+        let timeline_data = self.inner.run(|player| player.get_timeline_data())
+            .map_err(FlipEditError::from)?
+            .ok_or_else(FlipEditError::not_loaded)?;
+        crate::audio_waveform::timeline_peaks(&timeline_data, samples_per_second).map_err(FlipEditError::from)
+    }
+
+
+    pub fn dispose(&mut self) -> Result<(), FlipEditError> {
+        self.inner.call(|player| player.dispose()).map_err(FlipEditError::from)
     }
 }
 
+// =================== IRONDASH TEXTURE API ===================
+
 /// Get the number of active irondash textures
 #[frb(sync)]
 pub fn get_texture_count() -> usize {
-    crate::video::texture_registry::get_texture_count()
+    crate::video::irondash_texture::get_texture_count()
 } 
 
+/// Pace texture updates to the display's refresh rate (Hz); faster sources are coalesced. The
+/// app reports the rate of the display its window is on, and again when that changes.
+#[frb(sync)]
+pub fn set_display_refresh_rate(hz: f64) {
+    crate::video::timer_pacer::set_display_refresh_rate(hz);
+}
+
+#[frb(sync)]
+pub fn get_display_refresh_rate() -> f64 {
+    crate::video::timer_pacer::get_display_refresh_rate()
+}
+
 /// Play a basic MP4 video and return irondash texture id
+///
+/// Deprecated: prerolls the pipeline on the calling thread; use `play_basic_video_async`
 #[frb(sync)]
-pub fn play_basic_video(file_path: String, engine_handle: i64) -> Result<i64, String> {
-    // Create texture placeholder (1x1)
-    let texture_id = crate::video::irondash_texture::create_video_texture_on_main_thread(1, 1, engine_handle)
-        .map_err(|e| e.to_string())?;
+pub fn play_basic_video(file_path: String, engine_handle: i64) -> Result<i64, FlipEditError> {
+    // Create texture placeholder (1x1); frames resize it
+    let handler = FrameHandler::new();
+    let (texture_id, update_fn) = crate::video::irondash_texture::create_player_texture(1, 1, engine_handle, handler.buffer_pool.clone())
+        .map_err(FlipEditError::from)?;
 
     // Build pipeline
-    let handler = FrameHandler::new();
-    let vp = VideoPipeline::new(&file_path, std::sync::Arc::new(std::sync::Mutex::new(handler)))
-        .map_err(|e| e.to_string())?;
-    vp.play().map_err(|e| e.to_string())?;
+    let vp = VideoPipeline::new(&file_path, std::sync::Arc::new(std::sync::Mutex::new(handler)), Some(update_fn), None)
+        .map_err(FlipEditError::from)?;
+    vp.play().map_err(FlipEditError::from)?;
 
     ACTIVE_VIDEOS.lock().unwrap().push(vp);
 
     Ok(texture_id)
 } 
 
-#[frb(sync)]
-pub fn play_dual_video(file_path_left: String, file_path_right: String, engine_handle: i64) -> Result<i64, String> {
-    let texture_id = crate::video::irondash_texture::create_video_texture_on_main_thread(1, 1, engine_handle)
-        .map_err(|e| e.to_string())?;
+/// `play_basic_video` run on a bridge worker thread
+pub fn play_basic_video_async(file_path: String, engine_handle: i64) -> Result<i64, FlipEditError> {
+    play_basic_video(file_path, engine_handle)
+}
 
+/// Deprecated: prerolls both pipelines on the calling thread; use `play_dual_video_async`
+#[frb(sync)]
+pub fn play_dual_video(file_path_left: String, file_path_right: String, engine_handle: i64) -> Result<i64, FlipEditError> {
     let handler = FrameHandler::new();
-    let vp = VideoPipeline::new_dual(&file_path_left, &file_path_right, Arc::new(Mutex::new(handler)))
-        .map_err(|e| e.to_string())?;
-    vp.play().map_err(|e| e.to_string())?;
+    let (texture_id, update_fn) = crate::video::irondash_texture::create_player_texture(1, 1, engine_handle, handler.buffer_pool.clone())
+        .map_err(FlipEditError::from)?;
+
+    let vp = VideoPipeline::new_dual(&file_path_left, &file_path_right, Arc::new(Mutex::new(handler)), Some(update_fn))
+        .map_err(FlipEditError::from)?;
+    vp.play().map_err(FlipEditError::from)?;
 
     ACTIVE_VIDEOS.lock().unwrap().push(vp);
 
     Ok(texture_id)
 }
 
+/// `play_dual_video` run on a bridge worker thread
+pub fn play_dual_video_async(file_path_left: String, file_path_right: String, engine_handle: i64) -> Result<i64, FlipEditError> {
+    play_dual_video(file_path_left, file_path_right, engine_handle)
+}
+
 /// Create and load a direct pipeline timeline player with timeline data (GStreamer-only implementation)
-pub fn create_ges_timeline_player(timeline_data: TimelineData, engine_handle: i64) -> Result<(GESTimelinePlayer, i64), String> {
+pub fn create_ges_timeline_player(timeline_data: TimelineData, engine_handle: i64) -> Result<(GESTimelinePlayer, i64), FlipEditError> {
     // Initialize GStreamer only (no more GES)
     gst::init().map_err(|e| format!("Failed to initialize GStreamer: {}", e))?;
     
     // Create direct pipeline player
-    let mut direct_player = GESTimelinePlayer::new();
+    let mut direct_player = GESTimelinePlayer::new()?;
     
     // Create texture for this specific player
     let texture_id = direct_player.create_texture(engine_handle)?;
@@ -466,48 +1178,463 @@ pub fn create_ges_timeline_player(timeline_data: TimelineData, engine_handle: i6
     Ok((direct_player, texture_id))
 }
 
+// =================== PREVIEW MONITORS ===================
+
+/// Create the source or program monitor player with its own texture, replacing any existing one
+pub fn create_preview_monitor(role: MonitorRole, engine_handle: i64) -> Result<i64, FlipEditError> {
+    gst::init().map_err(|e| format!("Failed to initialize GStreamer: {}", e))?;
+    player_registry::create_monitor(role, engine_handle).map_err(FlipEditError::from)
+}
+
+/// Load a raw source file into the source monitor, returning its duration in milliseconds
+pub fn load_source_monitor_clip(file_path: String) -> Result<u64, FlipEditError> {
+    let duration_ms = get_video_duration_ms(file_path.clone())?;
+    player_registry::with_monitor(MonitorRole::Source, move |player| {
+        player.load_source_clip(&file_path, duration_ms)
+    }).map_err(FlipEditError::from)?;
+    Ok(duration_ms)
+}
+
+/// Load the edited timeline into the program monitor
+pub fn load_program_monitor_timeline(timeline_data: TimelineData) -> Result<(), FlipEditError> {
+    player_registry::with_monitor(MonitorRole::Program, move |player| player.load_timeline(timeline_data))
+        .map_err(FlipEditError::from)
+}
+
+pub fn monitor_play(role: MonitorRole) -> Result<(), FlipEditError> {
+    player_registry::with_monitor(role, |player| player.play()).map_err(FlipEditError::from)
+}
+
+pub fn monitor_pause(role: MonitorRole) -> Result<(), FlipEditError> {
+    player_registry::with_monitor(role, |player| player.pause()).map_err(FlipEditError::from)
+}
+
+pub fn monitor_seek(role: MonitorRole, position_ms: i32) -> Result<(), FlipEditError> {
+    player_registry::with_monitor(role, move |player| player.seek(position_ms.max(0) as u64))
+        .map_err(FlipEditError::from)
+}
+
+#[frb(sync)]
+pub fn monitor_get_position_ms(role: MonitorRole) -> Result<i32, FlipEditError> {
+    player_registry::with_monitor(role, |player| Ok(player.get_current_position_ms() as i32))
+        .map_err(FlipEditError::from)
+}
+
+#[frb(sync)]
+pub fn monitor_is_playing(role: MonitorRole) -> bool {
+    player_registry::with_monitor(role, |player| Ok(player.is_playing())).unwrap_or(false)
+}
+
+pub fn setup_monitor_position_stream(role: MonitorRole, sink: StreamSink<(f64, u64)>) -> Result<(), FlipEditError> {
+    player_registry::with_monitor(role, move |player| {
+        player.set_position_update_callback(Box::new(move |position, frame| {
+            if let Err(e) = sink.add((position, frame)) {
+                eprintln!("Failed to send monitor position update to sink: {:?}", e);
+            }
+            Ok(())
+        }))
+    }).map_err(FlipEditError::from)
+}
+
+pub fn dispose_preview_monitor(role: MonitorRole) -> Result<(), FlipEditError> {
+    player_registry::dispose_monitor(role).map_err(FlipEditError::from)
+}
+
+// =================== PLAYERS ===================
+
+/// Create an independent player with its own texture on `engine_handle`, e.g. for another window
+/// or an export preview. Players never share frames, whichever engine they render into.
+pub fn create_player(engine_handle: i64) -> Result<PlayerTexture, FlipEditError> {
+    gst::init().map_err(|e| format!("Failed to initialize GStreamer: {}", e))?;
+    player_registry::create_player(engine_handle).map_err(FlipEditError::from)
+}
+
+fn player_key(engine_handle: i64, player_id: u32) -> player_registry::PlayerKey {
+    player_registry::PlayerKey { engine_handle, player_id }
+}
+
+pub fn player_load_timeline(engine_handle: i64, player_id: u32, timeline_data: TimelineData) -> Result<(), FlipEditError> {
+    player_registry::with_player(player_key(engine_handle, player_id), move |player| player.load_timeline(timeline_data))
+        .map_err(FlipEditError::from)
+}
+
+pub fn player_play(engine_handle: i64, player_id: u32) -> Result<(), FlipEditError> {
+    player_registry::with_player(player_key(engine_handle, player_id), |player| player.play())
+        .map_err(FlipEditError::from)
+}
+
+pub fn player_pause(engine_handle: i64, player_id: u32) -> Result<(), FlipEditError> {
+    player_registry::with_player(player_key(engine_handle, player_id), |player| player.pause())
+        .map_err(FlipEditError::from)
+}
+
+pub fn player_seek(engine_handle: i64, player_id: u32, position_ms: i32) -> Result<(), FlipEditError> {
+    player_registry::with_player(player_key(engine_handle, player_id), move |player| player.seek(position_ms.max(0) as u64))
+        .map_err(FlipEditError::from)
+}
+
+#[frb(sync)]
+pub fn player_get_position_ms(engine_handle: i64, player_id: u32) -> Result<i32, FlipEditError> {
+    player_registry::with_player(player_key(engine_handle, player_id), |player| Ok(player.get_current_position_ms() as i32))
+        .map_err(FlipEditError::from)
+}
+
+pub fn setup_player_position_stream(engine_handle: i64, player_id: u32, sink: StreamSink<(f64, u64)>) -> Result<(), FlipEditError> {
+    player_registry::with_player(player_key(engine_handle, player_id), move |player| {
+        player.set_position_update_callback(Box::new(move |position, frame| {
+            if let Err(e) = sink.add((position, frame)) {
+                eprintln!("Failed to send player position update to sink: {:?}", e);
+            }
+            Ok(())
+        }))
+    }).map_err(FlipEditError::from)
+}
+
+/// Write a registry player's pipeline topology as a Graphviz DOT file at `path`
+pub fn dump_pipeline_graph(engine_handle: i64, player_id: u32, path: String) -> Result<(), FlipEditError> {
+    player_registry::with_player(player_key(engine_handle, player_id), move |player| player.dump_pipeline_graph(&path))
+        .map_err(FlipEditError::from)
+}
+
+/// Rolling decode, convert and upload timings of a registry player
+#[frb(sync)]
+pub fn get_performance_stats(engine_handle: i64, player_id: u32) -> Result<PerformanceStats, FlipEditError> {
+    player_registry::with_player(player_key(engine_handle, player_id), |player| Ok(player.get_performance_stats()))
+        .map_err(FlipEditError::from)
+}
+
+/// Ids of the players rendering into `engine_handle`
+#[frb(sync)]
+pub fn get_engine_player_ids(engine_handle: i64) -> Vec<u32> {
+    player_registry::engine_player_ids(engine_handle)
+}
+
+pub fn dispose_player(engine_handle: i64, player_id: u32) -> Result<(), FlipEditError> {
+    player_registry::dispose_player(player_key(engine_handle, player_id)).map_err(FlipEditError::from)
+}
+
+/// Dispose every player of a window's engine, e.g. when the window closes
+pub fn dispose_engine_players(engine_handle: i64) -> Result<(), FlipEditError> {
+    player_registry::dispose_engine_players(engine_handle).map_err(FlipEditError::from)
+}
+
+/// Pipelines, textures, timers and bus watches still held by players. After a hot restart,
+/// entries on an engine handle other than the current one have leaked; pass that handle to
+/// `dispose_engine_players` to release registry players still holding them.
+#[frb(sync)]
+pub fn get_active_resources() -> Vec<ActiveResource> {
+    crate::common::resources::active_resources()
+}
+
+// =================== DIAGNOSTICS ===================
+
+/// Installed decoders and encoders (with hardware flags and size limits) and GL/Vulkan
+/// availability, e.g. to grey out export options this machine can't produce
+pub fn get_system_capabilities() -> Result<SystemCapabilities, FlipEditError> {
+    crate::common::plugins::system_capabilities().map_err(FlipEditError::from)
+}
+
+/// Choose hardware or software decoding. Takes effect for media loaded afterwards and is
+/// remembered across launches.
+#[frb(sync)]
+pub fn set_decoder_policy(policy: DecoderPolicy) -> Result<(), FlipEditError> {
+    crate::common::decoders::set_policy(policy).map_err(FlipEditError::from)
+}
+
+#[frb(sync)]
+pub fn get_decoder_policy() -> DecoderPolicy {
+    crate::common::decoders::policy()
+}
+
+/// Cap the memory frame caches and buffer pools may hold together (1 GB by
+/// default); least recently used entries are evicted to stay within it
+#[frb(sync)]
+pub fn set_memory_budget(bytes: u64) {
+    crate::common::memory::set_budget(bytes);
+}
+
+#[frb(sync)]
+pub fn get_memory_usage() -> MemoryUsage {
+    crate::common::memory::usage()
+}
+
+/// Change GStreamer debug levels at runtime with a `GST_DEBUG` style spec, e.g.
+/// "2,compositor:6,uridecodebin:5"; messages that pass are captured for `setup_gst_log_stream`
+#[frb(sync)]
+pub fn set_gst_debug_threshold(spec: String) -> Result<(), FlipEditError> {
+    crate::common::gst_log::set_debug_threshold(&spec).map_err(FlipEditError::from)
+}
+
+/// Stream captured GStreamer log lines, starting with the last ones kept in memory
+pub fn setup_gst_log_stream(sink: StreamSink<LogLine>) -> Result<()> {
+    crate::common::gst_log::start_log_stream(Arc::new(move |line| {
+        // Not reported: a closed sink would print an error for every captured line
+        let _ = sink.add(line);
+    }))
+}
+
+#[frb(sync)]
+pub fn stop_gst_log_stream() {
+    crate::common::gst_log::stop_log_stream();
+}
+
+/// Log lines kept in memory, oldest first, e.g. to attach to a bug report
+#[frb(sync)]
+pub fn get_recent_gst_log_lines() -> Vec<LogLine> {
+    crate::common::gst_log::recent_log_lines()
+}
+
+#[frb(sync)]
+pub fn clear_gst_log_lines() {
+    crate::common::gst_log::clear_log_lines();
+}
+
+// =================== SOURCES ===================
+
+/// Source path for an SRT contribution feed, to be used as a clip's `source_path`.
+/// SRT clips play live, so the timeline can't seek while one is loaded.
+#[frb(sync)]
+pub fn srt_source_uri(address: String, latency_ms: Option<u32>, passphrase: Option<String>) -> String {
+    crate::common::media_uri::srt_uri(&address, latency_ms, passphrase.as_deref(), None)
+}
+
+// =================== THUMBNAILS ===================
+
+/// Frame of a file at `position_ms`, scaled down to fit `max_width`x`max_height` (0 for no
+/// limit on that side) and returned as RGBA or JPEG, e.g. for timeline thumbnails
+pub fn extract_thumbnail(
+    file_path: String,
+    position_ms: u64,
+    max_width: u32,
+    max_height: u32,
+    format: ThumbnailFormat,
+) -> Result<Thumbnail, FlipEditError> {
+    crate::video::thumbnail::extract_thumbnail(&file_path, position_ms, max_width, max_height, format)
+        .map_err(FlipEditError::from)
+}
+
+/// Frames of a file at each of `times_ms` from one decoding pipeline, as RGBA scaled to at most
+/// `max_width` wide (0 for full size), in the order asked for. Much cheaper than a thumbnail
+/// call per frame when building filmstrips or scene indexes.
+pub fn extract_frames(file_path: String, times_ms: Vec<u64>, max_width: u32) -> Result<Vec<Thumbnail>, FlipEditError> {
+    crate::video::thumbnail::extract_frames(&file_path, &times_ms, max_width, 0, ThumbnailFormat::Rgba, |_| {})
+        .map_err(FlipEditError::from)
+}
+
+/// `extract_frames`, sending each frame to `sink` as soon as it's decoded, in time order
+pub fn extract_frames_stream(
+    file_path: String,
+    times_ms: Vec<u64>,
+    max_width: u32,
+    sink: StreamSink<Thumbnail>,
+) -> Result<(), FlipEditError> {
+    crate::video::thumbnail::extract_frames(&file_path, &times_ms, max_width, 0, ThumbnailFormat::Rgba, |frame| {
+        if let Err(e) = sink.add(frame.clone()) {
+            log::warn!("Failed to send extracted frame to sink: {:?}", e);
+        }
+    })
+    .map(|_| ())
+    .map_err(FlipEditError::from)
+}
+
+// =================== TIMELINE JSON ===================
+
+/// Timeline from `serialize_timeline` JSON, ready for `load_timeline`. Fields added since the
+/// file was written take their defaults.
+#[frb(sync)]
+pub fn deserialize_timeline(json: String) -> Result<TimelineData, FlipEditError> {
+    crate::common::timeline_json::from_json(&json).map_err(FlipEditError::from)
+}
+
+// =================== TIMECODE ===================
+
+/// SMPTE timecode of the frame shown at `ms`, e.g. "01:00:00:00", or "00:01:00;02" with
+/// `drop_frame` at 30000/1001. Drop-frame is ignored for rates other than 29.97 and 59.94.
+#[frb(sync)]
+pub fn format_timecode(ms: i64, fps_num: u32, fps_den: u32, drop_frame: bool) -> String {
+    crate::common::timecode::format_timecode(ms, fps_num, fps_den, drop_frame)
+}
+
+/// Start in milliseconds of the frame a timecode names; a `;` before the frames marks it as
+/// drop-frame
+#[frb(sync)]
+pub fn parse_timecode(timecode: String, fps_num: u32, fps_den: u32) -> Result<i64, FlipEditError> {
+    crate::common::timecode::parse_timecode(&timecode, fps_num, fps_den).map_err(FlipEditError::from)
+}
+
+#[frb(sync)]
+pub fn frame_to_ms(frame: i64, fps_num: u32, fps_den: u32) -> i64 {
+    crate::common::timecode::frame_to_ms(frame, fps_num, fps_den)
+}
+
+#[frb(sync)]
+pub fn ms_to_frame(ms: i64, fps_num: u32, fps_den: u32) -> i64 {
+    crate::common::timecode::ms_to_frame(ms, fps_num, fps_den)
+}
+
+#[frb(sync)]
+pub fn supports_drop_frame(fps_num: u32, fps_den: u32) -> bool {
+    crate::common::timecode::supports_drop_frame(fps_num, fps_den)
+}
+
+// =================== AUDIO ===================
+
+/// Stream output device changes (unplugged headphones, new default device) to Flutter
+pub fn setup_audio_device_stream(sink: StreamSink<AudioDeviceEvent>) -> Result<(), FlipEditError> {
+    crate::audio_handler::set_audio_device_callback(Box::new(move |event| {
+        if let Err(e) = sink.add(event) {
+            eprintln!("Failed to send audio device event to sink: {:?}", e);
+        }
+    }));
+    Ok(())
+}
+
+/// Set the master output gain for every player (1.0 = unity, up to 4.0)
+#[frb(sync)]
+pub fn set_master_volume(gain: f64) {
+    crate::audio_handler::set_master_volume(gain);
+    crate::video::direct_pipeline_player::apply_master_volume();
+}
+
+#[frb(sync)]
+pub fn get_master_volume() -> f64 {
+    crate::audio_handler::get_master_volume()
+}
+
+#[frb(sync)]
+pub fn set_muted(muted: bool) {
+    crate::audio_handler::set_muted(muted);
+    crate::video::direct_pipeline_player::apply_master_volume();
+}
+
+#[frb(sync)]
+pub fn is_muted() -> bool {
+    crate::audio_handler::is_muted()
+}
+
+/// Set the sample rate and channel layout audio is mixed and played in. Running outputs
+/// switch within a second; timeline players use it from their next load.
+#[frb(sync)]
+pub fn set_audio_engine_settings(settings: AudioEngineSettings) {
+    crate::audio_handler::set_audio_engine_settings(settings);
+}
+
+#[frb(sync)]
+pub fn get_audio_engine_settings() -> AudioEngineSettings {
+    crate::audio_handler::get_audio_engine_settings()
+}
+
+/// Parameters an audio effect exposes, for building its controls
+pub fn get_audio_effect_parameters(factory_name: String) -> Result<Vec<AudioEffectParameter>, FlipEditError> {
+    gst::init().map_err(|e| FlipEditError::new(FlipEditErrorCode::Internal, format!("Failed to initialize GStreamer: {}", e)))?;
+    crate::audio_effects::describe_parameters(&factory_name).map_err(FlipEditError::from)
+}
+
+/// Capture devices available for voice-over recording
+pub fn list_audio_input_devices() -> Result<Vec<AudioInputDevice>, FlipEditError> {
+    crate::audio_input::list_audio_input_devices().map_err(FlipEditError::from)
+}
+
+/// Select the input for metering and recording by `AudioInputDevice::id`; None uses the system default
+pub fn set_input_device(id: Option<String>) -> Result<(), FlipEditError> {
+    crate::audio_input::set_input_device(id).map_err(FlipEditError::from)
+}
+
+#[frb(sync)]
+pub fn get_input_device() -> Option<String> {
+    crate::audio_input::get_input_device()
+}
+
+/// Stream levels of the selected input until `stop_input_level_stream` is called
+pub fn setup_input_level_stream(sink: StreamSink<AudioLevels>) -> Result<(), FlipEditError> {
+    crate::audio_input::start_input_metering(Box::new(move |levels| {
+        if let Err(e) = sink.add(levels) {
+            eprintln!("Failed to send input levels to sink: {:?}", e);
+        }
+    })).map_err(FlipEditError::from)
+}
+
+#[frb(sync)]
+pub fn stop_input_level_stream() {
+    crate::audio_input::stop_input_metering();
+}
+
+/// Record the screen to `settings.output_path`, streaming progress until the file is finalized
+pub fn start_screen_recording(settings: ScreenCaptureSettings, sink: StreamSink<ScreenRecordingEvent>) -> Result<(), FlipEditError> {
+    crate::video::screen_recorder::start_screen_recording(settings, Box::new(move |event| {
+        if let Err(e) = sink.add(event) {
+            eprintln!("Failed to send screen recording event to sink: {:?}", e);
+        }
+    })).map_err(FlipEditError::from)
+}
+
+/// Stop the screen recording and finalize its file, returning the recorded length in milliseconds
+pub fn stop_screen_recording() -> Result<u64, FlipEditError> {
+    crate::video::screen_recorder::stop_screen_recording().map_err(FlipEditError::from)
+}
+
+#[frb(sync)]
+pub fn is_screen_recording() -> bool {
+    crate::video::screen_recorder::is_screen_recording()
+}
+
+/// Sample-rate conversion quality for audio resampled to the output device rate
+#[frb(sync)]
+pub fn set_resampler_quality(quality: ResamplerQuality) {
+    crate::audio_handler::set_resampler_quality(quality);
+}
+
+#[frb(sync)]
+pub fn get_resampler_quality() -> ResamplerQuality {
+    crate::audio_handler::get_resampler_quality()
+}
+
+/// How cpal-output players keep audio and video together over long playback
+#[frb(sync)]
+pub fn set_audio_clock_mode(mode: AudioClockMode) {
+    crate::audio_handler::set_audio_clock_mode(mode);
+}
+
+#[frb(sync)]
+pub fn get_audio_clock_mode() -> AudioClockMode {
+    crate::audio_handler::get_audio_clock_mode()
+}
+
 /// Get video duration in milliseconds using GStreamer
 /// This is a reliable way to get video duration without depending on fallback estimations
+///
+/// Deprecated: prerolls the file on the calling thread, which can take seconds; use
+/// `get_video_duration_ms_async`
 #[frb(sync)]
-pub fn get_video_duration_ms(file_path: String) -> Result<u64, String> {
+pub fn get_video_duration_ms(file_path: String) -> Result<u64, FlipEditError> {
     // Initialize GStreamer if not already done
     if let Err(e) = gst::init() {
-        return Err(format!("Failed to initialize GStreamer: {}", e));
+        return Err(format!("Failed to initialize GStreamer: {}", e).into());
     }
     
     // Check if file exists
-    if !std::path::Path::new(&file_path).exists() {
-        return Err(format!("Video file not found: {}", file_path));
-    }
+    FlipEditError::check_file_exists(&file_path)?;
     
     info!("Getting video duration for: {}", file_path);
     
     // Create a minimal pipeline for duration query
     let pipeline = gst::Pipeline::new();
     
-    // Create elements
-    let source = gst::ElementFactory::make("filesrc")
-        .property("location", &file_path)
-        .build()
-        .map_err(|e| format!("Failed to create filesrc: {}", e))?;
-    
-    let decodebin = gst::ElementFactory::make("decodebin")
+    // Create elements; uridecodebin also opens the network sources clips can come from
+    let decodebin = gst::ElementFactory::make("uridecodebin")
+        .property("uri", crate::common::media_uri::source_uri(&file_path))
         .build()
-        .map_err(|e| format!("Failed to create decodebin: {}", e))?;
+        .map_err(|e| FlipEditError::new(FlipEditErrorCode::MissingPlugin, format!("Failed to create uridecodebin: {}", e)))?;
     
     let fakesink = gst::ElementFactory::make("fakesink")
         .build()
-        .map_err(|e| format!("Failed to create fakesink: {}", e))?;
+        .map_err(|e| FlipEditError::new(FlipEditErrorCode::MissingPlugin, format!("Failed to create fakesink: {}", e)))?;
     
     // Add elements to pipeline
-    pipeline.add_many(&[&source, &decodebin, &fakesink])
+    pipeline.add_many(&[&decodebin, &fakesink])
         .map_err(|e| format!("Failed to add elements to pipeline: {}", e))?;
     
-    // Link source to decodebin
-    source.link(&decodebin)
-        .map_err(|e| format!("Failed to link source to decodebin: {}", e))?;
-    
-    // Set up decodebin pad-added callback to link to fakesink
+    // Set up uridecodebin pad-added callback to link to fakesink
     let fakesink_clone = fakesink.clone();
     decodebin.connect_pad_added(move |_src, src_pad| {
         // Just link the first pad to fakesink (we only need duration, not actual decoding)
@@ -520,7 +1647,7 @@ pub fn get_video_duration_ms(file_path: String) -> Result<u64, String> {
     
     // Set pipeline to PAUSED state to get duration
     pipeline.set_state(gst::State::Paused)
-        .map_err(|e| format!("Failed to set pipeline to PAUSED: {:?}", e))?;
+        .map_err(|e| FlipEditError::new(FlipEditErrorCode::StateChangeFailed, format!("Failed to set pipeline to PAUSED: {:?}", e)))?;
     
     // Wait for pipeline to reach PAUSED state
     let timeout = std::time::Duration::from_secs(5);
@@ -543,7 +1670,7 @@ pub fn get_video_duration_ms(file_path: String) -> Result<u64, String> {
     } else {
         // Clean up pipeline
         pipeline.set_state(gst::State::Null).ok();
-        return Err("Could not query video duration".to_string());
+        return Err(FlipEditError::new(FlipEditErrorCode::DecodeError, "Could not query video duration"));
     };
     
     // Clean up pipeline
@@ -551,4 +1678,9 @@ pub fn get_video_duration_ms(file_path: String) -> Result<u64, String> {
         .map_err(|e| format!("Failed to clean up pipeline: {:?}", e))?;
     
     Ok(duration_ms)
-} 
\ No newline at end of file
+}
+
+/// `get_video_duration_ms` run on a bridge worker thread, so probing doesn't stall the UI
+pub fn get_video_duration_ms_async(file_path: String) -> Result<u64, FlipEditError> {
+    get_video_duration_ms(file_path)
+}
@@ -0,0 +1,268 @@
+use std::sync::Mutex;
+use std::time::Duration;
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{debug, info, warn};
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::media_uri::source_uri;
+use crate::common::types::{DuckingSettings, TimelineClip, TimelineData, VolumeKeyframe};
+use crate::video::clip_timing::{ClipTiming, SharedClipTiming};
+use crate::video::transitions::ClipFades;
+
+// Resolution of the dialogue envelope used for ducking
+const ANALYSIS_INTERVAL_MS: u64 = 20;
+const ANALYSIS_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Gain in dB at `timeline_ms`, interpolated linearly between keyframes and held past the ends
+pub fn gain_db_at(keyframes: &[VolumeKeyframe], timeline_ms: f64) -> f64 {
+    match keyframes.iter().position(|k| k.time_ms as f64 > timeline_ms) {
+        None => keyframes.last().map_or(0.0, |k| k.gain_db),
+        Some(0) => keyframes[0].gain_db,
+        Some(i) => {
+            let (a, b) = (&keyframes[i - 1], &keyframes[i]);
+            let t = (timeline_ms - a.time_ms as f64) / (b.time_ms - a.time_ms) as f64;
+            a.gain_db + (b.gain_db - a.gain_db) * t
+        }
+    }
+}
+
+fn db_to_gain(db: f64) -> f32 {
+    10f64.powf(db / 20.0) as f32
+}
+
+#[derive(Default)]
+struct EnvelopeState {
+    segment: Option<gst::FormattedSegment<gst::ClockTime>>,
+    rate: u32,
+    channels: usize,
+}
+
+/// A capsfilter pinning F32 samples whose output follows `keyframes` and the clip's crossfades,
+/// for a clip branch. Gains are interpolated per sample, so ducking ramps don't zipper.
+pub fn build_envelope_filter(keyframes: &[VolumeKeyframe], fades: ClipFades, timing: SharedClipTiming) -> Result<Option<gst::Element>> {
+    if keyframes.is_empty() && fades.is_empty() {
+        return Ok(None);
+    }
+    let filter = gst::ElementFactory::make("capsfilter")
+        .property("caps", gst::Caps::builder("audio/x-raw")
+            .field("format", "F32LE")
+            .field("layout", "interleaved")
+            .build())
+        .build()
+        .map_err(|e| anyhow!("Failed to create volume envelope capsfilter: {}", e))?;
+    let src_pad = filter.static_pad("src").ok_or_else(|| anyhow!("capsfilter has no src pad"))?;
+
+    let keyframes = keyframes.to_vec();
+    let state = Mutex::new(EnvelopeState::default());
+    src_pad.add_probe(gst::PadProbeType::BUFFER | gst::PadProbeType::EVENT_DOWNSTREAM, move |_pad, info| {
+        let mut state = state.lock().unwrap();
+        match info.data {
+            Some(gst::PadProbeData::Event(ref event)) => match event.view() {
+                gst::EventView::Caps(caps) => {
+                    if let Ok(audio_info) = gstreamer_audio::AudioInfo::from_caps(caps.caps()) {
+                        state.rate = audio_info.rate();
+                        state.channels = audio_info.channels() as usize;
+                    }
+                }
+                gst::EventView::Segment(segment) => {
+                    state.segment = segment.segment().downcast_ref::<gst::ClockTime>().cloned();
+                }
+                _ => {}
+            },
+            Some(gst::PadProbeData::Buffer(ref mut buffer)) => {
+                if state.rate == 0 || state.channels == 0 {
+                    return gst::PadProbeReturn::Ok;
+                }
+                let stream_time = state.segment.as_ref()
+                    .zip(buffer.pts())
+                    .and_then(|(segment, pts)| segment.to_stream_time(pts));
+                let Some(stream_time) = stream_time else {
+                    return gst::PadProbeReturn::Ok;
+                };
+
+                // Stream time is the source position; map it onto the timeline
+                let timing = *timing.lock().unwrap();
+                let start_ms = timing.start_ms as f64 + stream_time.nseconds() as f64 / 1_000_000.0 - timing.in_point_ms as f64;
+                let frame_bytes = 4 * state.channels;
+                let frames = buffer.size() / frame_bytes;
+                let end_ms = start_ms + frames as f64 * 1000.0 / state.rate as f64;
+                let gain_at = |timeline_ms: f64| db_to_gain(gain_db_at(&keyframes, timeline_ms)) * fades.audio_gain(timeline_ms) as f32;
+                let (from, to) = (gain_at(start_ms), gain_at(end_ms));
+                if from == 1.0 && to == 1.0 {
+                    return gst::PadProbeReturn::Ok;
+                }
+
+                let Ok(mut map) = buffer.make_mut().map_writable() else {
+                    return gst::PadProbeReturn::Ok;
+                };
+                for (i, frame) in map.as_mut_slice().chunks_exact_mut(frame_bytes).enumerate() {
+                    let gain = from + (to - from) * i as f32 / frames as f32;
+                    for sample in frame.chunks_exact_mut(4) {
+                        let value = f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]) * gain;
+                        sample.copy_from_slice(&value.to_le_bytes());
+                    }
+                }
+            }
+            _ => {}
+        }
+        gst::PadProbeReturn::Ok
+    });
+    Ok(Some(filter))
+}
+
+/// Volume keyframes ducking the music track of `timeline_data` under its dialogue track. The
+/// dialogue clips' audio is decoded for the analysis, so run it off the player's worker.
+pub fn timeline_ducking_keyframes(timeline_data: &TimelineData, settings: &DuckingSettings) -> Result<Vec<VolumeKeyframe>> {
+    if settings.music_track_id == settings.dialogue_track_id {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            "Music and dialogue must be different tracks",
+        )));
+    }
+    let track = |track_id: i32| {
+        timeline_data.tracks.iter().find(|t| t.id == track_id).ok_or_else(|| anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Track {} not found in timeline", track_id),
+        )))
+    };
+    track(settings.music_track_id)?;
+    ducking_keyframes(&track(settings.dialogue_track_id)?.clips, settings)
+}
+
+/// Volume keyframes lowering the music track while dialogue is louder than the threshold
+pub fn ducking_keyframes(dialogue_clips: &[TimelineClip], settings: &DuckingSettings) -> Result<Vec<VolumeKeyframe>> {
+    let mut active: Vec<(u64, u64)> = Vec::new();
+    for clip in dialogue_clips {
+        active.extend(speech_ranges(clip, settings.threshold_db)?);
+    }
+    active.sort();
+
+    // Join ranges whose ramps would overlap so the music doesn't pump between words
+    let bridge_ms = settings.attack_ms + settings.release_ms;
+    let mut merged: Vec<(u64, u64)> = Vec::new();
+    for range in active {
+        match merged.last_mut() {
+            Some(last) if range.0 <= last.1 + bridge_ms => last.1 = last.1.max(range.1),
+            _ => merged.push(range),
+        }
+    }
+
+    let ducked_db = -settings.reduction_db.abs();
+    let mut keyframes = Vec::with_capacity(merged.len() * 4);
+    for (start_ms, end_ms) in merged {
+        keyframes.push(VolumeKeyframe { time_ms: start_ms.saturating_sub(settings.attack_ms), gain_db: 0.0 });
+        keyframes.push(VolumeKeyframe { time_ms: start_ms, gain_db: ducked_db });
+        keyframes.push(VolumeKeyframe { time_ms: end_ms, gain_db: ducked_db });
+        keyframes.push(VolumeKeyframe { time_ms: end_ms + settings.release_ms, gain_db: 0.0 });
+    }
+    // Speech from the timeline's start clamps its attack onto the ducked key; keep the later one
+    keyframes.dedup_by(|later, earlier| {
+        if later.time_ms == earlier.time_ms {
+            *earlier = *later;
+            true
+        } else {
+            false
+        }
+    });
+    info!("Ducking: {} keyframes from {} dialogue clips", keyframes.len(), dialogue_clips.len());
+    Ok(keyframes)
+}
+
+/// Timeline ranges where `clip`'s audio RMS exceeds `threshold_db`, decoded as fast as possible
+fn speech_ranges(clip: &TimelineClip, threshold_db: f64) -> Result<Vec<(u64, u64)>> {
+    FlipEditError::check_file_exists(&clip.source_path)?;
+    let timing = ClipTiming::from_clip(clip);
+
+    let pipeline = gst::Pipeline::new();
+    let decodebin = gst::ElementFactory::make("uridecodebin")
+        .property("uri", source_uri(&clip.source_path))
+        .build()
+        .map_err(|e| anyhow!("Failed to create uridecodebin: {}", e))?;
+    let audioconvert = gst::ElementFactory::make("audioconvert").build()
+        .map_err(|e| anyhow!("Failed to create audioconvert: {}", e))?;
+    let level = gst::ElementFactory::make("level")
+        .property("interval", ANALYSIS_INTERVAL_MS * 1_000_000)
+        .property("post-messages", true)
+        .build()
+        .map_err(|e| anyhow!("Failed to create level: {}", e))?;
+    let sink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .map_err(|e| anyhow!("Failed to create fakesink: {}", e))?;
+    pipeline.add_many([&decodebin, &audioconvert, &level, &sink])?;
+    gst::Element::link_many([&audioconvert, &level, &sink])?;
+
+    let audioconvert_weak = audioconvert.downgrade();
+    decodebin.connect_pad_added(move |_, src_pad| {
+        let is_audio = src_pad.current_caps()
+            .and_then(|caps| caps.structure(0).map(|s| s.name().starts_with("audio/")))
+            .unwrap_or(false);
+        let Some(audioconvert) = audioconvert_weak.upgrade() else {
+            return;
+        };
+        if let Some(sink_pad) = audioconvert.static_pad("sink") {
+            if is_audio && !sink_pad.is_linked() {
+                if let Err(e) = src_pad.link(&sink_pad) {
+                    warn!("Failed to link audio for analysis: {:?}", e);
+                }
+            }
+        }
+    });
+
+    let result = (|| -> Result<Vec<(u64, u64)>> {
+        pipeline.set_state(gst::State::Paused)?;
+        let (state_result, _, _) = pipeline.state(Some(gst::ClockTime::from_seconds(10)));
+        state_result.map_err(|e| anyhow!("Analysis pipeline failed to preroll: {}", e))?;
+        pipeline.seek(
+            1.0,
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::SeekType::Set,
+            gst::ClockTime::from_mseconds(timing.in_point_ms),
+            gst::SeekType::Set,
+            gst::ClockTime::from_mseconds(timing.out_point_ms()),
+        )?;
+        pipeline.set_state(gst::State::Playing)?;
+
+        let bus = pipeline.bus().ok_or_else(|| anyhow!("Analysis pipeline has no bus"))?;
+        let deadline = std::time::Instant::now() + ANALYSIS_TIMEOUT;
+        let mut ranges: Vec<(u64, u64)> = Vec::new();
+        while std::time::Instant::now() < deadline {
+            let Some(message) = bus.timed_pop(gst::ClockTime::from_mseconds(100)) else {
+                continue;
+            };
+            match message.view() {
+                gst::MessageView::Eos(..) => return Ok(ranges),
+                gst::MessageView::Error(err) => return Err(anyhow!(FlipEditError::from_error_message(err))),
+                gst::MessageView::Element(element) => {
+                    let Some(structure) = element.structure().filter(|s| s.name() == "level") else {
+                        continue;
+                    };
+                    let (Ok(stream_time), Ok(rms)) = (
+                        structure.get::<gst::ClockTime>("stream-time"),
+                        structure.get::<gst::glib::ValueArray>("rms"),
+                    ) else {
+                        continue;
+                    };
+                    let loudest = rms.iter().filter_map(|v| v.get::<f64>().ok()).fold(f64::NEG_INFINITY, f64::max);
+                    if loudest < threshold_db {
+                        continue;
+                    }
+                    let start_ms = (timing.start_ms + stream_time.mseconds()).saturating_sub(timing.in_point_ms);
+                    let end_ms = start_ms + ANALYSIS_INTERVAL_MS;
+                    match ranges.last_mut() {
+                        Some(last) if start_ms <= last.1 => last.1 = end_ms,
+                        _ => ranges.push((start_ms, end_ms)),
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err(anyhow!("Timed out analyzing {}", clip.source_path))
+    })();
+
+    let _ = pipeline.set_state(gst::State::Null);
+    debug!("{}: {} speech ranges", clip.source_path, result.as_ref().map_or(0, Vec::len));
+    result
+}
@@ -0,0 +1,57 @@
+use gstreamer as gst;
+use gstreamer_audio::{AudioChannelPosition, AudioInfo};
+use std::f32::consts::FRAC_1_SQRT_2;
+
+use crate::common::types::{AudioChannelLayout, DownmixPolicy};
+
+/// `audioconvert` `mix-matrix` folding a surround source into the engine's stereo output
+/// according to `policy`. `None` leaves audioconvert's default conversion in place, which
+/// is the case for passthrough, non-surround sources and surround output layouts.
+pub fn mix_matrix(policy: DownmixPolicy, caps: &gst::CapsRef, output: AudioChannelLayout) -> Option<gst::Array> {
+    if policy == DownmixPolicy::Passthrough || output != AudioChannelLayout::Stereo {
+        return None;
+    }
+    let info = AudioInfo::from_caps(caps).ok()?;
+    if info.channels() <= 2 {
+        return None;
+    }
+    let positions = info.positions()?;
+
+    let has_center = positions.contains(&AudioChannelPosition::FrontCenter);
+    let gains: Vec<(f32, f32)> = positions
+        .iter()
+        .map(|&position| match policy {
+            DownmixPolicy::DialogueCenter if has_center => match position {
+                AudioChannelPosition::FrontCenter => (1.0, 1.0),
+                _ => (0.0, 0.0),
+            },
+            _ => stereo_gains(position),
+        })
+        .collect();
+
+    // Scale so a full-scale signal on every channel can't clip the mix
+    let headroom = gains
+        .iter()
+        .fold((0.0f32, 0.0f32), |(l, r), (gl, gr)| (l + gl, r + gr));
+    let scale = 1.0 / headroom.0.max(headroom.1).max(1.0);
+
+    let row = |pick: fn(&(f32, f32)) -> f32| {
+        gst::Array::new(gains.iter().map(|gain| pick(gain) * scale))
+    };
+    Some(gst::Array::new([row(|gain| gain.0), row(|gain| gain.1)]))
+}
+
+/// ITU-R BS.775 downmix coefficients: centre and surrounds at -3 dB, LFE dropped
+fn stereo_gains(position: AudioChannelPosition) -> (f32, f32) {
+    use AudioChannelPosition::*;
+    match position {
+        FrontLeft | FrontLeftOfCenter | WideLeft | TopFrontLeft => (1.0, 0.0),
+        FrontRight | FrontRightOfCenter | WideRight | TopFrontRight => (0.0, 1.0),
+        FrontCenter | Mono | TopFrontCenter => (FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+        RearLeft | SideLeft | SurroundLeft | TopSideLeft | TopRearLeft => (FRAC_1_SQRT_2, 0.0),
+        RearRight | SideRight | SurroundRight | TopSideRight | TopRearRight => (0.0, FRAC_1_SQRT_2),
+        RearCenter | TopCenter | TopRearCenter => (0.5, 0.5),
+        Lfe1 | Lfe2 => (0.0, 0.0),
+        _ => (0.5, 0.5),
+    }
+}
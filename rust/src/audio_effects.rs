@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::glib;
+use gst::prelude::*;
+use log::warn;
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::types::{AudioEffectParameter, AudioRestoration, TrackAudioEffect};
+
+// Restoration filters that don't classify themselves as audio effects
+const RESTORATION_FACTORIES: [&str; 1] = ["webrtcdsp"];
+// Each de-hum notch spans its frequency +-5 Hz; the long kernel keeps it that narrow
+const DEHUM_NOTCH_WIDTH_HZ: f64 = 10.0;
+const DEHUM_KERNEL_LENGTH: f64 = 8001.0;
+// Notches at the mains frequency and its 2nd and 3rd harmonics, where most hum energy sits
+const DEHUM_HARMONICS: u32 = 3;
+
+/// soundtouch's pitch shifter, which keeps the tempo
+pub const PITCH_FACTORY: &str = "pitch";
+/// Parameter of `pitch` in semitones, in place of its frequency ratio
+pub const SEMITONES_PARAMETER: &str = "semitones";
+const MAX_SEMITONES: f64 = 24.0;
+
+/// Name of an effect's element inside a clip's effect chain, for live parameter changes
+pub fn effect_element_name(effect_id: u32) -> String {
+    format!("track_fx_{}", effect_id)
+}
+
+/// Fail unless `factory_name` is an installed audio filter, e.g. `equalizer-10bands` or `audiodynamic`
+pub fn check_effect_factory(factory_name: &str) -> Result<()> {
+    let factory = gst::ElementFactory::find(factory_name).ok_or_else(|| {
+        anyhow!(FlipEditError::new(
+            FlipEditErrorCode::MissingPlugin,
+            format!("Audio effect {} is not installed", factory_name),
+        ))
+    })?;
+    let klass = factory.klass();
+    if RESTORATION_FACTORIES.contains(&factory_name) {
+        return Ok(());
+    }
+    if !(klass.contains("Audio") && (klass.contains("Filter") || klass.contains("Effect"))) {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("{} is not an audio effect ({})", factory_name, klass),
+        )));
+    }
+    Ok(())
+}
+
+/// Adjustable parameters of an effect, for building its UI
+pub fn describe_parameters(factory_name: &str) -> Result<Vec<AudioEffectParameter>> {
+    check_effect_factory(factory_name)?;
+    let element = gst::ElementFactory::make(factory_name)
+        .build()
+        .map_err(|e| anyhow!("Failed to create {}: {}", factory_name, e))?;
+
+    let mut parameters: Vec<AudioEffectParameter> = element
+        .list_properties()
+        .iter()
+        .filter(|pspec| is_adjustable(pspec))
+        .filter_map(|pspec| {
+            let (min, max, default_value, options) = parameter_range(pspec)?;
+            Some(AudioEffectParameter {
+                name: pspec.name().to_string(),
+                nick: pspec.nick().to_string(),
+                description: pspec.blurb().unwrap_or_default().to_string(),
+                min,
+                max,
+                default_value,
+                options,
+            })
+        })
+        .collect();
+    if factory_name == PITCH_FACTORY {
+        parameters.insert(0, AudioEffectParameter {
+            name: SEMITONES_PARAMETER.to_string(),
+            nick: "Semitones".to_string(),
+            description: "Pitch change in semitones; sets the pitch ratio".to_string(),
+            min: -MAX_SEMITONES,
+            max: MAX_SEMITONES,
+            default_value: 0.0,
+            options: Vec::new(),
+        });
+    }
+    Ok(parameters)
+}
+
+/// Set a parameter from its numeric UI value; choices are indices into `options`,
+/// toggles are 0 or 1. Values are clamped to the parameter's range.
+pub fn set_parameter(element: &gst::Element, name: &str, value: f64) -> Result<()> {
+    if name == SEMITONES_PARAMETER && element.factory().is_some_and(|f| f.name() == PITCH_FACTORY) {
+        let ratio = 2f64.powf(value.clamp(-MAX_SEMITONES, MAX_SEMITONES) / 12.0);
+        return set_parameter(element, "pitch", ratio);
+    }
+    let pspec = element
+        .find_property(name)
+        .filter(is_adjustable)
+        .ok_or_else(|| anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("{} has no adjustable parameter {}", element.name(), name),
+        )))?;
+    let (min, max, _, options) = parameter_range(&pspec)
+        .ok_or_else(|| anyhow!("Parameter {} has an unsupported type", name))?;
+    let value = value.clamp(min, max);
+
+    if !options.is_empty() {
+        element.set_property_from_str(name, &options[value.round() as usize]);
+        return Ok(());
+    }
+
+    let gvalue = match pspec.value_type() {
+        glib::Type::F64 => value.to_value(),
+        glib::Type::F32 => (value as f32).to_value(),
+        glib::Type::I32 => (value.round() as i32).to_value(),
+        glib::Type::U32 => (value.round() as u32).to_value(),
+        glib::Type::I64 => (value.round() as i64).to_value(),
+        glib::Type::U64 => (value.round() as u64).to_value(),
+        glib::Type::BOOL => (value >= 0.5).to_value(),
+        other => return Err(anyhow!("Parameter {} has unsupported type {}", name, other)),
+    };
+    element.set_property_from_value(name, &gvalue);
+    Ok(())
+}
+
+/// Elements and starting parameters of a restoration, in chain order: one effect for denoise,
+/// a notch per hum harmonic for de-hum. Each is then tuned like any other effect, e.g.
+/// webrtcdsp's `noise-suppression-level` or a notch's frequencies.
+pub fn restoration_effects(restoration: AudioRestoration) -> Result<Vec<(String, HashMap<String, f64>)>> {
+    let effects = match restoration {
+        AudioRestoration::Denoise if gst::ElementFactory::find("audiornnoise").is_some() => {
+            vec![("audiornnoise".to_string(), HashMap::new())]
+        }
+        AudioRestoration::Denoise => {
+            check_effect_factory("webrtcdsp")?;
+            // Only the noise suppressor: echo cancelling needs a probe and gain control alters levels
+            let parameters = HashMap::from([
+                ("echo-cancel".to_string(), 0.0),
+                ("gain-control".to_string(), 0.0),
+                ("noise-suppression".to_string(), 1.0),
+                ("noise-suppression-level".to_string(), option_index("webrtcdsp", "noise-suppression-level", "high")?),
+            ]);
+            vec![("webrtcdsp".to_string(), parameters)]
+        }
+        AudioRestoration::Dehum50Hz | AudioRestoration::Dehum60Hz => {
+            check_effect_factory("audiowsincband")?;
+            let mains_hz = if restoration == AudioRestoration::Dehum50Hz { 50.0 } else { 60.0 };
+            let band_reject = option_index("audiowsincband", "mode", "band-reject")?;
+            (1..=DEHUM_HARMONICS)
+                .map(|harmonic| {
+                    let hum_hz = mains_hz * harmonic as f64;
+                    let parameters = HashMap::from([
+                        ("mode".to_string(), band_reject),
+                        ("lower-frequency".to_string(), hum_hz - DEHUM_NOTCH_WIDTH_HZ / 2.0),
+                        ("upper-frequency".to_string(), hum_hz + DEHUM_NOTCH_WIDTH_HZ / 2.0),
+                        ("length".to_string(), DEHUM_KERNEL_LENGTH),
+                    ]);
+                    ("audiowsincband".to_string(), parameters)
+                })
+                .collect()
+        }
+    };
+    Ok(effects)
+}
+
+/// Numeric value of an enum parameter's option, as taken by `set_parameter`
+fn option_index(factory_name: &str, name: &str, nick: &str) -> Result<f64> {
+    describe_parameters(factory_name)?
+        .into_iter()
+        .find(|p| p.name == name)
+        .and_then(|p| p.options.iter().position(|option| option == nick))
+        .map(|index| index as f64)
+        .ok_or_else(|| anyhow!("{} has no {} option {}", factory_name, name, nick))
+}
+
+/// `audioconvert ! audioresample ! effect ! ...` for a track's effects, as one element with
+/// ghost pads. Converters between effects let each negotiate the format and rate it needs,
+/// e.g. the 48 kHz of the noise suppressors.
+pub fn build_effect_chain(effects: &[TrackAudioEffect]) -> Result<Option<gst::Element>> {
+    if effects.is_empty() {
+        return Ok(None);
+    }
+
+    let bin = gst::Bin::new();
+    let mut chain = Vec::with_capacity(effects.len() * 3 + 1);
+    for effect in effects {
+        let element = gst::ElementFactory::make(&effect.factory_name)
+            .name(effect_element_name(effect.effect_id))
+            .build()
+            .map_err(|e| anyhow!("Failed to create audio effect {}: {}", effect.factory_name, e))?;
+        for (name, value) in &effect.parameters {
+            if let Err(e) = set_parameter(&element, name, *value) {
+                warn!("Skipping {} parameter {}: {}", effect.factory_name, name, e);
+            }
+        }
+        chain.push(make_converter("audioconvert")?);
+        chain.push(make_converter("audioresample")?);
+        chain.push(element);
+    }
+    chain.push(make_converter("audioconvert")?);
+
+    bin.add_many(&chain)?;
+    gst::Element::link_many(&chain)?;
+
+    let sink_pad = chain[0].static_pad("sink").ok_or_else(|| anyhow!("Effect chain has no sink pad"))?;
+    let src_pad = chain[chain.len() - 1].static_pad("src").ok_or_else(|| anyhow!("Effect chain has no src pad"))?;
+    bin.add_pad(&gst::GhostPad::with_target(&sink_pad)?)?;
+    bin.add_pad(&gst::GhostPad::with_target(&src_pad)?)?;
+    Ok(Some(bin.upcast()))
+}
+
+fn make_converter(factory_name: &str) -> Result<gst::Element> {
+    gst::ElementFactory::make(factory_name)
+        .build()
+        .map_err(|e| anyhow!("Failed to create {}: {}", factory_name, e))
+}
+
+/// Writable at runtime and declared by the effect itself rather than a GStreamer base class
+fn is_adjustable(pspec: &glib::ParamSpec) -> bool {
+    let flags = pspec.flags();
+    flags.contains(glib::ParamFlags::READWRITE)
+        && !flags.contains(glib::ParamFlags::CONSTRUCT_ONLY)
+        && !matches!(
+            pspec.owner_type().name(),
+            "GstObject" | "GstElement" | "GstBaseTransform" | "GstAudioFilter"
+        )
+}
+
+/// (min, max, default, choice names) of a numeric, boolean or enum parameter
+fn parameter_range(pspec: &glib::ParamSpec) -> Option<(f64, f64, f64, Vec<String>)> {
+    if let Some(p) = pspec.downcast_ref::<glib::ParamSpecDouble>() {
+        return Some((p.minimum(), p.maximum(), p.default_value(), Vec::new()));
+    }
+    if let Some(p) = pspec.downcast_ref::<glib::ParamSpecFloat>() {
+        return Some((p.minimum() as f64, p.maximum() as f64, p.default_value() as f64, Vec::new()));
+    }
+    if let Some(p) = pspec.downcast_ref::<glib::ParamSpecInt>() {
+        return Some((p.minimum() as f64, p.maximum() as f64, p.default_value() as f64, Vec::new()));
+    }
+    if let Some(p) = pspec.downcast_ref::<glib::ParamSpecUInt>() {
+        return Some((p.minimum() as f64, p.maximum() as f64, p.default_value() as f64, Vec::new()));
+    }
+    if let Some(p) = pspec.downcast_ref::<glib::ParamSpecInt64>() {
+        return Some((p.minimum() as f64, p.maximum() as f64, p.default_value() as f64, Vec::new()));
+    }
+    if let Some(p) = pspec.downcast_ref::<glib::ParamSpecUInt64>() {
+        return Some((p.minimum() as f64, p.maximum() as f64, p.default_value() as f64, Vec::new()));
+    }
+    if let Some(p) = pspec.downcast_ref::<glib::ParamSpecBoolean>() {
+        return Some((0.0, 1.0, if p.default_value() { 1.0 } else { 0.0 }, Vec::new()));
+    }
+    if let Some(p) = pspec.downcast_ref::<glib::ParamSpecEnum>() {
+        let values = p.enum_class().values();
+        let default_index = values
+            .iter()
+            .position(|v| v.value() == p.default_value().value())
+            .unwrap_or(0);
+        let options: Vec<String> = values.iter().map(|v| v.nick().to_string()).collect();
+        return Some((0.0, options.len().saturating_sub(1) as f64, default_index as f64, options));
+    }
+    None
+}
@@ -1,9 +1,195 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use cpal::{Device, Host, Stream, StreamConfig, SampleFormat, SampleRate, ChannelCount};
+use cpal::{Device, Host, Stream, StreamConfig, StreamError, SampleFormat, SampleRate, ChannelCount};
 use std::sync::{Arc, Mutex, mpsc};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicU64, AtomicU8, Ordering};
 use std::thread;
-use log::{info, error, debug};
+use std::time::{Duration, Instant};
+use log::{info, error, debug, warn};
+use rubato::{
+    FastFixedIn, PolynomialDegree, Resampler, SincFixedIn, SincInterpolationParameters,
+    SincInterpolationType, WindowFunction,
+};
+
+use crate::common::types::{AudioClockMode, AudioDeviceEvent, AudioEngineSettings, ResamplerQuality};
+
+// How often the audio thread checks for a lost stream or a new default device
+const DEVICE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+// Upper bound for the master gain (about +12 dB)
+pub const MAX_MASTER_VOLUME: f64 = 4.0;
+
+// Master gain as f32 bits, shared by the cpal output and GStreamer volume elements
+static MASTER_VOLUME_BITS: AtomicU32 = AtomicU32::new(0x3f80_0000); // 1.0
+static MASTER_MUTED: AtomicBool = AtomicBool::new(false);
+
+// Source frames fed to the resampler per call
+const RESAMPLER_CHUNK_FRAMES: usize = 1024;
+
+static RESAMPLER_QUALITY: AtomicU8 = AtomicU8::new(ResamplerQuality::Balanced as u8);
+static AUDIO_CLOCK_MODE: AtomicU8 = AtomicU8::new(AudioClockMode::ResampleToClock as u8);
+
+// Weight of each new output buffer level in its moving average
+const DRIFT_SMOOTHING: f64 = 0.02;
+// Buffer pushes after (re)starting playback before the level is taken as the reference
+const DRIFT_SETTLE_PUSHES: u32 = 100;
+// Ratio correction per second of drift, and its bounds (+-0.5%)
+const DRIFT_CORRECTION_GAIN: f64 = 0.05;
+const MAX_DRIFT_CORRECTION: f64 = 0.005;
+const MAX_RELATIVE_RATIO: f64 = 1.0 + 2.0 * MAX_DRIFT_CORRECTION;
+
+pub type AudioDeviceCallback = Box<dyn Fn(AudioDeviceEvent) + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref AUDIO_DEVICE_CALLBACK: Mutex<Option<AudioDeviceCallback>> = Mutex::new(None);
+    static ref ENGINE_SETTINGS: Mutex<AudioEngineSettings> = Mutex::new(AudioEngineSettings::default());
+}
+
+/// Change the engine's output format. Running cpal outputs pick it up on their next device
+/// check; timeline pipelines on their next load.
+pub fn set_audio_engine_settings(settings: AudioEngineSettings) {
+    info!("Audio engine settings: {:?}", settings);
+    *ENGINE_SETTINGS.lock().unwrap() = settings;
+}
+
+pub fn get_audio_engine_settings() -> AudioEngineSettings {
+    *ENGINE_SETTINGS.lock().unwrap()
+}
+
+/// Receive output device changes from every audio handler
+pub fn set_audio_device_callback(callback: AudioDeviceCallback) {
+    *AUDIO_DEVICE_CALLBACK.lock().unwrap() = Some(callback);
+}
+
+/// Set the master output gain (1.0 = unity), clamped to 0..=MAX_MASTER_VOLUME
+pub fn set_master_volume(gain: f64) {
+    let gain = gain.clamp(0.0, MAX_MASTER_VOLUME) as f32;
+    MASTER_VOLUME_BITS.store(gain.to_bits(), Ordering::Relaxed);
+    debug!("Master volume set to {}", gain);
+}
+
+pub fn get_master_volume() -> f64 {
+    f32::from_bits(MASTER_VOLUME_BITS.load(Ordering::Relaxed)) as f64
+}
+
+pub fn set_muted(muted: bool) {
+    MASTER_MUTED.store(muted, Ordering::Relaxed);
+    debug!("Master output {}", if muted { "muted" } else { "unmuted" });
+}
+
+pub fn is_muted() -> bool {
+    MASTER_MUTED.load(Ordering::Relaxed)
+}
+
+/// Gain applied to output samples, taking mute into account
+fn effective_master_gain() -> f32 {
+    if is_muted() {
+        0.0
+    } else {
+        f32::from_bits(MASTER_VOLUME_BITS.load(Ordering::Relaxed))
+    }
+}
+
+/// Quality used by resamplers created from now on (i.e. on the next format change)
+pub fn set_resampler_quality(quality: ResamplerQuality) {
+    RESAMPLER_QUALITY.store(quality as u8, Ordering::Relaxed);
+    debug!("Resampler quality set to {:?}", quality);
+}
+
+pub fn get_resampler_quality() -> ResamplerQuality {
+    match RESAMPLER_QUALITY.load(Ordering::Relaxed) {
+        q if q == ResamplerQuality::Fast as u8 => ResamplerQuality::Fast,
+        q if q == ResamplerQuality::High as u8 => ResamplerQuality::High,
+        _ => ResamplerQuality::Balanced,
+    }
+}
+
+/// Choose how the cpal output is kept in sync with the pipeline clock; applied by running
+/// outputs on their next device check
+pub fn set_audio_clock_mode(mode: AudioClockMode) {
+    AUDIO_CLOCK_MODE.store(mode as u8, Ordering::Relaxed);
+    debug!("Audio clock mode set to {:?}", mode);
+}
+
+pub fn get_audio_clock_mode() -> AudioClockMode {
+    match AUDIO_CLOCK_MODE.load(Ordering::Relaxed) {
+        m if m == AudioClockMode::AudioMaster as u8 => AudioClockMode::AudioMaster,
+        _ => AudioClockMode::ResampleToClock,
+    }
+}
+
+/// Timing of the cpal output against the pipeline clock, shared with the video sink
+#[derive(Debug, Default)]
+pub struct AudioOutputTiming {
+    // How far the buffer level moved from its level when playback started; positive when
+    // audio is heard later than the pipeline clock delivered it
+    drift_ns: AtomicI64,
+    // Callback-to-speaker delay reported by the device
+    device_latency_ns: AtomicU64,
+    // Total delay from the audio appsink to the speaker, which video must match
+    latency_ns: AtomicI64,
+}
+
+impl AudioOutputTiming {
+    pub fn drift_ns(&self) -> i64 {
+        self.drift_ns.load(Ordering::Relaxed)
+    }
+
+    pub fn latency_ns(&self) -> i64 {
+        self.latency_ns.load(Ordering::Relaxed)
+    }
+}
+
+/// One player's volume and mute, applied on top of the master volume
+#[derive(Debug)]
+pub struct PlayerGain {
+    volume_bits: AtomicU32,
+    muted: AtomicBool,
+}
+
+impl Default for PlayerGain {
+    fn default() -> Self {
+        Self {
+            volume_bits: AtomicU32::new(1.0f32.to_bits()),
+            muted: AtomicBool::new(false),
+        }
+    }
+}
+
+impl PlayerGain {
+    /// Set the player's gain (1.0 = unity), clamped to 0..=MAX_MASTER_VOLUME
+    pub fn set_volume(&self, gain: f64) {
+        let gain = gain.clamp(0.0, MAX_MASTER_VOLUME) as f32;
+        self.volume_bits.store(gain.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn volume(&self) -> f64 {
+        f32::from_bits(self.volume_bits.load(Ordering::Relaxed)) as f64
+    }
+
+    pub fn set_muted(&self, muted: bool) {
+        self.muted.store(muted, Ordering::Relaxed);
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted.load(Ordering::Relaxed)
+    }
+
+    /// Gain applied to this player's samples, master volume included
+    fn output_gain(&self) -> f32 {
+        if self.is_muted() {
+            0.0
+        } else {
+            effective_master_gain() * f32::from_bits(self.volume_bits.load(Ordering::Relaxed))
+        }
+    }
+}
+
+fn emit_device_event(event: AudioDeviceEvent) {
+    info!("Audio device event: {:?}", event);
+    if let Some(callback) = AUDIO_DEVICE_CALLBACK.lock().unwrap().as_ref() {
+        callback(event);
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct AudioFormat {
@@ -15,40 +201,129 @@ pub struct AudioFormat {
 #[derive(Debug)]
 pub enum MediaData {
     AudioFormat(AudioFormat),
+    /// Interleaved f32 samples in the last announced format
+    AudioSamples(Vec<f32>),
     Stop,
     Pause,
-    Resume
+    Resume,
+    /// Close the output stream and end the audio thread
+    Shutdown,
 }
 
 pub type MediaSender = mpsc::Sender<MediaData>;
 
+/// Converts source audio to the output rate. An enum rather than a trait object since
+/// rubato's `Resampler::process` is generic.
+enum StreamResampler {
+    Fast(FastFixedIn<f32>),
+    Sinc(SincFixedIn<f32>),
+}
+
+impl StreamResampler {
+    fn new(
+        quality: ResamplerQuality,
+        from_rate: u32,
+        to_rate: u32,
+        channels: usize,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let ratio = to_rate as f64 / from_rate as f64;
+        let sinc = |sinc_len: usize, oversampling_factor: usize, window: WindowFunction| SincInterpolationParameters {
+            sinc_len,
+            f_cutoff: rubato::calculate_cutoff(sinc_len, window),
+            interpolation: SincInterpolationType::Cubic,
+            oversampling_factor,
+            window,
+        };
+        let resampler = match quality {
+            ResamplerQuality::Fast => Self::Fast(FastFixedIn::new(
+                ratio, MAX_RELATIVE_RATIO, PolynomialDegree::Cubic, RESAMPLER_CHUNK_FRAMES, channels,
+            )?),
+            ResamplerQuality::Balanced => Self::Sinc(SincFixedIn::new(
+                ratio, MAX_RELATIVE_RATIO, sinc(64, 128, WindowFunction::Blackman2), RESAMPLER_CHUNK_FRAMES, channels,
+            )?),
+            ResamplerQuality::High => Self::Sinc(SincFixedIn::new(
+                ratio, MAX_RELATIVE_RATIO, sinc(256, 256, WindowFunction::BlackmanHarris2), RESAMPLER_CHUNK_FRAMES, channels,
+            )?),
+        };
+        Ok(resampler)
+    }
+
+    fn input_frames_next(&self) -> usize {
+        match self {
+            Self::Fast(resampler) => resampler.input_frames_next(),
+            Self::Sinc(resampler) => resampler.input_frames_next(),
+        }
+    }
+
+    fn process(&mut self, input: &[Vec<f32>]) -> Result<Vec<Vec<f32>>, rubato::ResampleError> {
+        match self {
+            Self::Fast(resampler) => resampler.process(input, None),
+            Self::Sinc(resampler) => resampler.process(input, None),
+        }
+    }
+
+    /// Scale the conversion ratio for drift correction, ramped to avoid audible steps
+    fn set_ratio_relative(&mut self, relative: f64) -> Result<(), rubato::ResampleError> {
+        match self {
+            Self::Fast(resampler) => resampler.set_resample_ratio_relative(relative, true),
+            Self::Sinc(resampler) => resampler.set_resample_ratio_relative(relative, true),
+        }
+    }
+}
+
 pub struct AudioHandler {
     host: Host,
     device: Option<Device>,
+    device_name: Option<String>,
     stream: Option<Stream>,
+    // Set by the stream error callback when the device disappears
+    stream_lost: Arc<AtomicBool>,
     config: Option<StreamConfig>,
     is_playing: Arc<AtomicBool>,
     audio_buffer: Arc<Mutex<Vec<f32>>>,
     target_sample_rate: u32,
     target_channels: u16,
     devices_enumerated: bool, // Track if we've already enumerated devices
+    source_format: Option<AudioFormat>,
+    resampler: Option<StreamResampler>,
+    // Deinterleaved source frames waiting for a full resampler chunk
+    resampler_input: Vec<Vec<f32>>,
+    clock_mode: AudioClockMode,
+    timing: Arc<AudioOutputTiming>,
+    gain: Arc<PlayerGain>,
+    // Moving average of the output buffer level and its reference, in frames
+    buffer_level: Option<f64>,
+    reference_level: Option<f64>,
+    level_pushes: u32,
 }
 
 impl Default for AudioHandler {
     fn default() -> Self {
         let host = cpal::default_host();
+        let settings = get_audio_engine_settings();
         info!("Using audio host: {}", host.id().name());
         
         Self {
             host,
             device: None,
+            device_name: None,
             stream: None,
+            stream_lost: Arc::new(AtomicBool::new(false)),
             config: None,
             is_playing: Arc::new(AtomicBool::new(false)),
             audio_buffer: Arc::new(Mutex::new(Vec::new())),
-            target_sample_rate: 44100, // Standard sample rate
-            target_channels: 2, // Stereo
+            target_sample_rate: settings.sample_rate.hz(),
+            target_channels: settings.channel_layout.channels() as u16,
             devices_enumerated: false,
+            source_format: None,
+            resampler: None,
+            resampler_input: Vec::new(),
+            clock_mode: get_audio_clock_mode(),
+            timing: Arc::new(AudioOutputTiming::default()),
+            gain: Arc::new(PlayerGain::default()),
+            buffer_level: None,
+            reference_level: None,
+            level_pushes: 0,
         }
     }
 }
@@ -68,14 +343,168 @@ impl AudioHandler {
                 error!("Failed to initialize audio output: {}", e);
             }
         }
+
+        self.source_format = Some(format);
+        self.rebuild_resampler();
+        self.reset_drift();
+    }
+
+    fn output_sample_rate(&self) -> u32 {
+        self.config.as_ref().map_or(self.target_sample_rate, |config| config.sample_rate.0)
+    }
+
+    /// Set up conversion from the source rate to the output rate. Matching rates pass through
+    /// unless the resampler is needed to follow the pipeline clock.
+    fn rebuild_resampler(&mut self) {
+        self.resampler = None;
+        self.resampler_input = vec![Vec::new(); self.target_channels as usize];
+
+        let Some(source_rate) = self.source_format.as_ref().map(|format| format.sample_rate) else {
+            return;
+        };
+        let output_rate = self.output_sample_rate();
+        if source_rate == output_rate && self.clock_mode == AudioClockMode::AudioMaster {
+            return;
+        }
+
+        let quality = get_resampler_quality();
+        match StreamResampler::new(quality, source_rate, output_rate, self.target_channels as usize) {
+            Ok(resampler) => {
+                info!("Resampling {}Hz -> {}Hz ({:?} quality)", source_rate, output_rate, quality);
+                self.resampler = Some(resampler);
+            }
+            Err(e) => error!("Failed to create resampler: {}", e),
+        }
+    }
+
+    /// Queue interleaved source samples for output, converting channels and sample rate
+    pub fn push_samples(&mut self, samples: Vec<f32>) {
+        let Some(source_channels) = self.source_format.as_ref().map(|format| format.channels.max(1) as usize) else {
+            return;
+        };
+        let output_channels = self.target_channels as usize;
+
+        for frame in samples.chunks_exact(source_channels) {
+            for (channel, input) in self.resampler_input.iter_mut().enumerate() {
+                // Mono is duplicated to every output; extra source channels are dropped
+                let sample = if source_channels == 1 { frame[0] } else { frame.get(channel).copied().unwrap_or(0.0) };
+                input.push(sample);
+            }
+        }
+
+        let Some(resampler) = self.resampler.as_mut() else {
+            let frames = self.resampler_input[0].len();
+            let mut buffer = self.audio_buffer.lock().unwrap();
+            buffer.reserve(frames * output_channels);
+            for i in 0..frames {
+                buffer.extend(self.resampler_input.iter().map(|input| input[i]));
+            }
+            drop(buffer);
+            self.resampler_input.iter_mut().for_each(Vec::clear);
+            self.update_drift();
+            return;
+        };
+
+        loop {
+            let needed = resampler.input_frames_next();
+            if self.resampler_input[0].len() < needed {
+                break;
+            }
+            let chunk: Vec<Vec<f32>> = self.resampler_input
+                .iter_mut()
+                .map(|input| input.drain(..needed).collect())
+                .collect();
+            match resampler.process(&chunk) {
+                Ok(output) => {
+                    let frames = output[0].len();
+                    let mut buffer = self.audio_buffer.lock().unwrap();
+                    buffer.reserve(frames * output_channels);
+                    for i in 0..frames {
+                        buffer.extend(output.iter().map(|channel| channel[i]));
+                    }
+                }
+                Err(e) => {
+                    error!("Resampling failed: {}", e);
+                    break;
+                }
+            }
+        }
+        self.update_drift();
+    }
+
+    /// Track the output buffer level against its reference and, when following the pipeline
+    /// clock, nudge the resampler so the level (and with it the A/V offset) stays put.
+    /// Also updates the output latency that video is delayed by.
+    fn update_drift(&mut self) {
+        if !self.is_playing.load(Ordering::Relaxed) {
+            return;
+        }
+        let channels = self.target_channels.max(1) as usize;
+        let buffered_frames = self.audio_buffer.lock().unwrap().len() as f64 / channels as f64;
+        let level = match self.buffer_level {
+            Some(level) => level + (buffered_frames - level) * DRIFT_SMOOTHING,
+            None => buffered_frames,
+        };
+        self.buffer_level = Some(level);
+        let rate = self.output_sample_rate() as f64;
+
+        self.level_pushes = self.level_pushes.saturating_add(1);
+        if self.level_pushes < DRIFT_SETTLE_PUSHES {
+            self.store_latency(level / rate);
+            return;
+        }
+        let reference = *self.reference_level.get_or_insert(level);
+        let drift_secs = (level - reference) / rate;
+        self.timing.drift_ns.store((drift_secs * 1e9) as i64, Ordering::Relaxed);
+        // Following the clock holds the level at its reference; as master, audio keeps its drift
+        let queued_frames = match self.clock_mode {
+            AudioClockMode::ResampleToClock => reference,
+            AudioClockMode::AudioMaster => level,
+        };
+        self.store_latency(queued_frames / rate);
+
+        if self.clock_mode == AudioClockMode::ResampleToClock {
+            if let Some(resampler) = self.resampler.as_mut() {
+                // A growing buffer means the device is slower than the clock: produce fewer frames
+                let correction = (drift_secs * DRIFT_CORRECTION_GAIN).clamp(-MAX_DRIFT_CORRECTION, MAX_DRIFT_CORRECTION);
+                if let Err(e) = resampler.set_ratio_relative(1.0 - correction) {
+                    debug!("Failed to adjust resampler ratio: {}", e);
+                }
+            }
+        }
+    }
+
+    fn store_latency(&self, queued_secs: f64) {
+        let device_ns = self.timing.device_latency_ns.load(Ordering::Relaxed) as i64;
+        self.timing.latency_ns.store(device_ns + (queued_secs * 1e9) as i64, Ordering::Relaxed);
+    }
+
+    fn reset_drift(&mut self) {
+        self.buffer_level = None;
+        self.reference_level = None;
+        self.level_pushes = 0;
+        self.timing.drift_ns.store(0, Ordering::Relaxed);
+    }
+
+    /// Switch to the current clock mode if it changed since the resampler was set up
+    pub fn apply_clock_mode(&mut self) {
+        let mode = get_audio_clock_mode();
+        if mode == self.clock_mode {
+            return;
+        }
+        info!("Audio clock mode changed to {:?}", mode);
+        self.clock_mode = mode;
+        self.rebuild_resampler();
+        self.reset_drift();
     }
 
     fn init_audio_output(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         // Get default output device
         let device = self.host.default_output_device()
             .ok_or("No output device available")?;
+        let device_name = device.name().unwrap_or_else(|_| "Unknown".to_string());
         
-        info!("Using audio device: {}", device.name().unwrap_or_else(|_| "Unknown".to_string()));
+        info!("Using audio device: {}", device_name);
         
         // Only enumerate devices and configs once for performance
         if !self.devices_enumerated {
@@ -113,11 +542,20 @@ impl AudioHandler {
         }
         
         // Get a fresh iterator for finding the right config
+        let target_rate = SampleRate(self.target_sample_rate);
         let mut supported_configs_range = device.supported_output_configs()?;
         let _supported_config = supported_configs_range
-            .find(|c| c.channels() == self.target_channels && c.sample_format() == SampleFormat::F32)
-            .ok_or("No suitable audio config found")?
-            .with_sample_rate(SampleRate(self.target_sample_rate));
+            .find(|c| {
+                c.channels() == self.target_channels
+                    && c.sample_format() == SampleFormat::F32
+                    && c.min_sample_rate() <= target_rate
+                    && target_rate <= c.max_sample_rate()
+            })
+            .ok_or_else(|| format!(
+                "Device doesn't support {} channels at {}Hz",
+                self.target_channels, self.target_sample_rate
+            ))?
+            .with_sample_rate(target_rate);
 
         let config = StreamConfig {
             channels: self.target_channels as ChannelCount,
@@ -129,11 +567,21 @@ impl AudioHandler {
 
         let audio_buffer = self.audio_buffer.clone();
         let is_playing = self.is_playing.clone();
+        let stream_lost = self.stream_lost.clone();
+        stream_lost.store(false, Ordering::Relaxed);
+        let timing = self.timing.clone();
+        let gain = self.gain.clone();
 
         // Create audio stream with enhanced error reporting
         let stream = device.build_output_stream(
             &config,
-            move |data: &mut [f32], _info: &cpal::OutputCallbackInfo| {
+            move |data: &mut [f32], info: &cpal::OutputCallbackInfo| {
+                // Hosts that can't tell when the buffer reaches the speaker keep the last value
+                let timestamp = info.timestamp();
+                if let Some(latency) = timestamp.playback.duration_since(&timestamp.callback) {
+                    timing.device_latency_ns.store(latency.as_nanos() as u64, Ordering::Relaxed);
+                }
+
                 if !is_playing.load(Ordering::Relaxed) {
                     // Fill with silence when not playing
                     for sample in data.iter_mut() {
@@ -150,6 +598,7 @@ impl AudioHandler {
                         // Copy samples from buffer to output
                         data.copy_from_slice(&buffer[..samples_needed]);
                         buffer.drain(..samples_needed);
+                        apply_gain(data, gain.output_gain());
                     } else if samples_available > 0 {
                         // Not enough samples, copy what we have and fill rest with silence
                         data[..samples_available].copy_from_slice(&buffer[..]);
@@ -157,6 +606,7 @@ impl AudioHandler {
                             *sample = 0.0;
                         }
                         buffer.clear();
+                        apply_gain(&mut data[..samples_available], gain.output_gain());
                     } else {
                         // No samples available, fill with silence
                         for sample in data.iter_mut() {
@@ -170,7 +620,12 @@ impl AudioHandler {
                     }
                 }
             },
-            |err| error!("Audio stream error: {}", err),
+            move |err| {
+                error!("Audio stream error: {}", err);
+                if matches!(err, StreamError::DeviceNotAvailable) {
+                    stream_lost.store(true, Ordering::Relaxed);
+                }
+            },
             None,
         )?;
 
@@ -180,12 +635,80 @@ impl AudioHandler {
         info!("Audio stream started successfully");
         
         self.device = Some(device);
+        self.device_name = Some(device_name);
         self.stream = Some(stream);
         self.config = Some(config);
         
         Ok(())
     }
 
+    /// Reopen the output in the engine's format if the settings changed since it was opened
+    pub fn apply_engine_settings(&mut self) {
+        let settings = get_audio_engine_settings();
+        let sample_rate = settings.sample_rate.hz();
+        let channels = settings.channel_layout.channels() as u16;
+        if sample_rate == self.target_sample_rate && channels == self.target_channels {
+            return;
+        }
+
+        info!("Switching audio output to {}Hz, {} channels", sample_rate, channels);
+        self.target_sample_rate = sample_rate;
+        self.target_channels = channels;
+        if let Ok(mut buffer) = self.audio_buffer.lock() {
+            buffer.clear();
+        }
+
+        if self.stream.take().is_some() {
+            self.device = None;
+            if let Err(e) = self.init_audio_output() {
+                error!("Failed to reopen audio output: {}", e);
+            }
+        }
+        self.rebuild_resampler();
+    }
+
+    /// Rebuild the output stream if its device was lost or the system default output changed,
+    /// so unplugging headphones moves playback to the speakers instead of going silent
+    pub fn check_output_device(&mut self) {
+        // Nothing to follow until playback has set up an output
+        if self.stream.is_none() && self.device_name.is_none() {
+            return;
+        }
+
+        let lost = self.stream_lost.load(Ordering::Relaxed);
+        let default_name = self.host.default_output_device().and_then(|device| device.name().ok());
+        if !lost && self.stream.is_some() && default_name == self.device_name {
+            return;
+        }
+
+        if lost {
+            warn!("Audio output device lost: {:?}", self.device_name);
+            if let Some(device_name) = self.device_name.clone() {
+                emit_device_event(AudioDeviceEvent::DeviceLost { device_name });
+            }
+        }
+
+        if default_name.is_none() {
+            // Keep the old name so the switch is reported once a device comes back
+            if self.stream.take().is_some() {
+                self.device = None;
+                emit_device_event(AudioDeviceEvent::NoDevice);
+            }
+            return;
+        }
+
+        self.stream = None;
+        self.device = None;
+        match self.init_audio_output() {
+            Ok(()) => {
+                self.rebuild_resampler();
+                let device_name = self.device_name.clone().unwrap_or_default();
+                emit_device_event(AudioDeviceEvent::DeviceSwitched { device_name });
+            }
+            Err(e) => error!("Failed to rebuild audio output: {}", e),
+        }
+    }
+
     pub fn start_playback(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         if self.stream.is_none() {
             self.init_audio_output()?;
@@ -203,6 +726,8 @@ impl AudioHandler {
         if let Ok(mut buffer) = self.audio_buffer.lock() {
             buffer.clear();
         }
+        self.resampler_input.iter_mut().for_each(Vec::clear);
+        self.reset_drift();
         
         info!("Audio playback stopped");
     }
@@ -231,11 +756,21 @@ impl AudioHandler {
             }
         }
         
+        self.reset_drift();
         self.is_playing.store(true, Ordering::Relaxed);
         info!("Audio playback resumed");
     }
 }
 
+fn apply_gain(samples: &mut [f32], gain: f32) {
+    if gain == 1.0 {
+        return;
+    }
+    for sample in samples.iter_mut() {
+        *sample *= gain;
+    }
+}
+
 impl Drop for AudioHandler {
     fn drop(&mut self) {
         self.stop_playback();
@@ -246,43 +781,105 @@ impl Drop for AudioHandler {
     }
 }
 
-/// Start the audio thread that handles direct system audio playback
-pub fn start_audio_thread() -> MediaSender {
-    let (audio_sender, audio_receiver) = mpsc::channel::<MediaData>();
-    
-    thread::spawn(move || {
-        let mut audio_handler = AudioHandler::default();
-        info!("Audio thread started");
-        
-        loop {
-            match audio_receiver.recv() {
-                Ok(data) => {
-                    match data {
-                        MediaData::AudioFormat(f) => {
-                            audio_handler.handle_format(f);
-                        }
-                        MediaData::Stop => {
-                            info!("Audio thread received stop signal");
-                            audio_handler.stop_playback();
-                            break;
-                        }
-                        MediaData::Pause => {
-                            audio_handler.pause_playback();
-                        }
-                        MediaData::Resume => {
-                            audio_handler.resume_playback();
+/// A player's own cpal output: a dedicated audio thread and stream with the player's gain.
+/// Players never share a session, so each can play or be muted independently. Dropping the
+/// session closes its stream and joins the thread.
+pub struct AudioSession {
+    sender: MediaSender,
+    timing: Arc<AudioOutputTiming>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl AudioSession {
+    /// Start the audio thread playing at `gain`; the output stream opens with the first audio format
+    pub fn start(gain: Arc<PlayerGain>) -> Self {
+        let (audio_sender, audio_receiver) = mpsc::channel::<MediaData>();
+        let timing = Arc::new(AudioOutputTiming::default());
+        let handler_timing = Arc::clone(&timing);
+
+        let thread = thread::spawn(move || {
+            let mut audio_handler = AudioHandler::default();
+            audio_handler.timing = handler_timing;
+            audio_handler.gain = gain;
+            info!("Audio thread started");
+
+            let mut last_check = Instant::now();
+            loop {
+                match audio_receiver.recv_timeout(DEVICE_CHECK_INTERVAL.saturating_sub(last_check.elapsed())) {
+                    Ok(data) => {
+                        match data {
+                            MediaData::AudioFormat(f) => {
+                                audio_handler.handle_format(f);
+                            }
+                            MediaData::AudioSamples(samples) => {
+                                audio_handler.push_samples(samples);
+                            }
+                            // Stopping keeps the thread so the player can load and play again
+                            MediaData::Stop => {
+                                audio_handler.stop_playback();
+                            }
+                            MediaData::Pause => {
+                                audio_handler.pause_playback();
+                            }
+                            MediaData::Resume => {
+                                audio_handler.resume_playback();
+                            }
+                            MediaData::Shutdown => {
+                                info!("Audio thread received shutdown signal");
+                                break;
+                            }
                         }
                     }
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    Err(e) => {
+                        error!("Audio thread receiver error: {}", e);
+                        break;
+                    }
                 }
-                Err(e) => {
-                    error!("Audio thread receiver error: {}", e);
-                    break;
+
+                // Samples arrive far more often than the interval while playing, so the checks
+                // run on elapsed time rather than waiting for the channel to go quiet
+                if last_check.elapsed() >= DEVICE_CHECK_INTERVAL {
+                    audio_handler.apply_engine_settings();
+                    audio_handler.apply_clock_mode();
+                    audio_handler.check_output_device();
+                    last_check = Instant::now();
                 }
             }
+
+            // Dropping the handler stops and closes the stream
+            drop(audio_handler);
+            info!("Audio thread finished");
+        });
+
+        Self { sender: audio_sender, timing, thread: Some(thread) }
+    }
+
+    /// Sender for the pipeline's audio samples
+    pub fn sender(&self) -> MediaSender {
+        self.sender.clone()
+    }
+
+    /// Timing of the output against the pipeline clock
+    pub fn timing(&self) -> Arc<AudioOutputTiming> {
+        Arc::clone(&self.timing)
+    }
+
+    pub fn send(&self, data: MediaData) {
+        if let Err(e) = self.sender.send(data) {
+            warn!("Failed to send to the audio thread: {}", e);
+        }
+    }
+}
+
+impl Drop for AudioSession {
+    fn drop(&mut self) {
+        // The pipeline may still hold senders, so the thread is told to stop explicitly
+        let _ = self.sender.send(MediaData::Shutdown);
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                warn!("Audio thread panicked");
+            }
         }
-        
-        info!("Audio thread finished");
-    });
-    
-    audio_sender
-} 
\ No newline at end of file
+    }
+}
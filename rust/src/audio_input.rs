@@ -0,0 +1,217 @@
+use std::sync::{Arc, Mutex};
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{info, warn};
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::types::{AudioInputDevice, AudioLevels};
+use crate::video::direct_pipeline_player::DirectPipelinePlayer;
+
+pub type InputLevelCallback = Box<dyn Fn(AudioLevels) + Send + Sync>;
+
+// Input meter update rate, matching the output meters
+const INPUT_LEVEL_INTERVAL_MS: u64 = 50;
+// Device properties that identify a device across runs, by provider
+const DEVICE_ID_PROPERTIES: [&str; 4] = ["node.name", "device.string", "device.strid", "unique-id"];
+/// Input id that captures the system's default output through the platform loopback
+pub const SYSTEM_AUDIO_DEVICE_ID: &str = "system-audio";
+
+lazy_static::lazy_static! {
+    // None follows the system default input
+    static ref INPUT_DEVICE_ID: Mutex<Option<String>> = Mutex::new(None);
+    static ref INPUT_METER: Mutex<Option<gst::Pipeline>> = Mutex::new(None);
+    static ref INPUT_LEVEL_CALLBACK: Arc<Mutex<Option<InputLevelCallback>>> = Arc::new(Mutex::new(None));
+}
+
+fn device_id(device: &gst::Device) -> String {
+    device.properties()
+        .and_then(|props| DEVICE_ID_PROPERTIES.iter().find_map(|key| props.get::<String>(*key).ok()))
+        .unwrap_or_else(|| device.display_name().to_string())
+}
+
+/// PulseAudio and PipeWire list the monitors of their outputs as sources
+fn is_monitor_device(device: &gst::Device) -> bool {
+    device.properties()
+        .and_then(|props| props.get::<String>("device.class").ok())
+        .is_some_and(|class| class == "monitor")
+}
+
+fn input_devices() -> Result<Vec<gst::Device>> {
+    gst::init().map_err(|e| anyhow!("Failed to initialize GStreamer: {}", e))?;
+    let monitor = gst::DeviceMonitor::new();
+    monitor.add_filter(Some("Audio/Source"), None);
+    monitor.start().map_err(|e| anyhow!("Failed to start device monitor: {}", e))?;
+    let devices = monitor.devices().into_iter().collect();
+    monitor.stop();
+    Ok(devices)
+}
+
+pub fn list_audio_input_devices() -> Result<Vec<AudioInputDevice>> {
+    let selected = INPUT_DEVICE_ID.lock().unwrap().clone();
+    let system_audio = system_audio_supported().then(|| AudioInputDevice {
+        id: SYSTEM_AUDIO_DEVICE_ID.to_string(),
+        name: "System audio".to_string(),
+        is_default: false,
+        is_selected: selected.as_deref() == Some(SYSTEM_AUDIO_DEVICE_ID),
+        is_loopback: true,
+    });
+    let devices = input_devices()?;
+    Ok(system_audio.into_iter().chain(devices.iter().map(|device| {
+        let id = device_id(device);
+        AudioInputDevice {
+            is_selected: selected.as_deref() == Some(id.as_str()),
+            is_default: device.properties()
+                .and_then(|props| props.get::<bool>("is-default").ok())
+                .unwrap_or(false),
+            name: device.display_name().to_string(),
+            is_loopback: is_monitor_device(device),
+            id,
+        }
+    })).collect())
+}
+
+/// Select the input used for metering and recording, or `SYSTEM_AUDIO_DEVICE_ID` to capture
+/// what the system plays; None follows the system default
+pub fn set_input_device(id: Option<String>) -> Result<()> {
+    if let Some(ref id) = id {
+        let is_system_audio = id == SYSTEM_AUDIO_DEVICE_ID && system_audio_supported();
+        if !is_system_audio && !input_devices()?.iter().any(|device| &device_id(device) == id) {
+            return Err(anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Unknown audio input device: {}", id),
+            )));
+        }
+    }
+    info!("Audio input device set to {}", id.as_deref().unwrap_or("system default"));
+    *INPUT_DEVICE_ID.lock().unwrap() = id;
+
+    // Move a running meter over to the new device
+    let mut meter = INPUT_METER.lock().unwrap();
+    if let Some(old) = meter.take() {
+        let _ = old.set_state(gst::State::Null);
+        *meter = Some(start_meter_pipeline()?);
+    }
+    Ok(())
+}
+
+pub fn get_input_device() -> Option<String> {
+    INPUT_DEVICE_ID.lock().unwrap().clone()
+}
+
+fn system_audio_supported() -> bool {
+    cfg!(any(target_os = "linux", target_os = "windows"))
+}
+
+/// Capture of what the system is playing, through the platform's loopback
+pub fn create_system_audio_source() -> Result<gst::Element> {
+    #[cfg(target_os = "linux")]
+    {
+        // The default output's monitor, on PulseAudio and on PipeWire's Pulse server alike
+        gst::ElementFactory::make("pulsesrc")
+            .property("device", "@DEFAULT_MONITOR@")
+            .build()
+            .map_err(|e| anyhow!(FlipEditError::new(
+                FlipEditErrorCode::MissingPlugin,
+                format!("Failed to create pulsesrc for system audio: {}", e),
+            )))
+    }
+    #[cfg(target_os = "windows")]
+    {
+        gst::ElementFactory::make("wasapi2src")
+            .property("loopback", true)
+            .property("low-latency", true)
+            .build()
+            .map_err(|e| anyhow!(FlipEditError::new(
+                FlipEditErrorCode::MissingPlugin,
+                format!("Failed to create wasapi2src for system audio: {}", e),
+            )))
+    }
+    // macOS only exposes system audio through ScreenCaptureKit, which GStreamer has no source for yet
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::MissingPlugin,
+            "System audio capture isn't supported on this platform",
+        )))
+    }
+}
+
+/// Source element for the selected input, falling back to autoaudiosrc when it's gone
+pub fn create_input_source() -> Result<gst::Element> {
+    let selected = INPUT_DEVICE_ID.lock().unwrap().clone();
+    if selected.as_deref() == Some(SYSTEM_AUDIO_DEVICE_ID) {
+        return create_system_audio_source();
+    }
+    if let Some(id) = selected {
+        match input_devices()?.into_iter().find(|device| device_id(device) == id) {
+            Some(device) => {
+                return device.create_element(None)
+                    .map_err(|e| anyhow!("Failed to open audio input {}: {}", id, e));
+            }
+            None => warn!("Audio input {} is no longer available, using the default input", id),
+        }
+    }
+    gst::ElementFactory::make("autoaudiosrc")
+        .build()
+        .map_err(|e| anyhow!("Failed to create audio input: {}", e))
+}
+
+fn start_meter_pipeline() -> Result<gst::Pipeline> {
+    let pipeline = gst::Pipeline::with_name("input_meter");
+    let source = create_input_source()?;
+    let level = gst::ElementFactory::make("level")
+        .property("interval", INPUT_LEVEL_INTERVAL_MS * 1_000_000)
+        .property("post-messages", true)
+        .build()
+        .map_err(|e| anyhow!("Failed to create input level meter: {}", e))?;
+    let sink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .map_err(|e| anyhow!("Failed to create fakesink: {}", e))?;
+    let audioconvert = gst::ElementFactory::make("audioconvert").build()
+        .map_err(|e| anyhow!("Failed to create audioconvert: {}", e))?;
+    pipeline.add_many([&source, &audioconvert, &level, &sink])?;
+    gst::Element::link_many([&source, &audioconvert, &level, &sink])?;
+
+    // Levels are forwarded from the posting thread, so no main loop is needed
+    let bus = pipeline.bus().ok_or_else(|| anyhow!("Input meter pipeline has no bus"))?;
+    let callback = Arc::clone(&INPUT_LEVEL_CALLBACK);
+    bus.set_sync_handler(move |_, message| {
+        match message.view() {
+            gst::MessageView::Element(element) => {
+                if let Some(levels) = element.structure().and_then(DirectPipelinePlayer::parse_audio_levels) {
+                    if let Some(ref callback) = *callback.lock().unwrap() {
+                        callback(levels);
+                    }
+                }
+            }
+            gst::MessageView::Error(err) => warn!("Input meter error: {}", err.error()),
+            _ => {}
+        }
+        gst::BusSyncReply::Drop
+    });
+
+    pipeline.set_state(gst::State::Playing)
+        .map_err(|_| anyhow!(FlipEditError::new(FlipEditErrorCode::StateChangeFailed, "Failed to start the audio input meter")))?;
+    Ok(pipeline)
+}
+
+/// Meter the selected input, e.g. to check microphone levels before recording
+pub fn start_input_metering(callback: InputLevelCallback) -> Result<()> {
+    *INPUT_LEVEL_CALLBACK.lock().unwrap() = Some(callback);
+    let mut meter = INPUT_METER.lock().unwrap();
+    if meter.is_none() {
+        *meter = Some(start_meter_pipeline()?);
+        info!("Started input metering");
+    }
+    Ok(())
+}
+
+pub fn stop_input_metering() {
+    if let Some(meter) = INPUT_METER.lock().unwrap().take() {
+        let _ = meter.set_state(gst::State::Null);
+        info!("Stopped input metering");
+    }
+    *INPUT_LEVEL_CALLBACK.lock().unwrap() = None;
+}
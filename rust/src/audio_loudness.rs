@@ -0,0 +1,45 @@
+use std::time::Duration;
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::info;
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::types::LoudnessSettings;
+
+// audioloudnorm only accepts 192 kHz doubles, so true peaks are measured at 4x oversampling
+const LOUDNORM_CAPS: &str = "audio/x-raw,format=F64LE,rate=192000";
+/// Audio audioloudnorm holds back to measure ahead of its output; streams muxed alongside the
+/// master stage need to queue at least this much
+pub const MASTER_STAGE_LATENCY: Duration = Duration::from_secs(3);
+
+/// Final stage normalizing integrated loudness to the target and limiting true peaks, for the
+/// end of a recording's audio chain. Returns None when normalization is disabled.
+pub fn build_master_stage(settings: &LoudnessSettings) -> Result<Option<gst::Element>> {
+    if !settings.enabled {
+        return Ok(None);
+    }
+    if gst::ElementFactory::find("audioloudnorm").is_none() {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::MissingPlugin,
+            "Loudness normalization needs audioloudnorm from gst-plugins-rs",
+        )));
+    }
+
+    // Resampling back is left to the caller, which knows its output rate
+    let description = format!(
+        "audioconvert ! audioresample ! {} ! audioloudnorm name=loudnorm ! audioconvert",
+        LOUDNORM_CAPS,
+    );
+    let bin = gst::parse::bin_from_description(&description, true)
+        .map_err(|e| anyhow!("Failed to create loudness stage: {}", e))?;
+    let loudnorm = bin.by_name("loudnorm").ok_or_else(|| anyhow!("Loudness stage has no audioloudnorm"))?;
+    loudnorm.set_property("loudness-target", settings.target_lufs.clamp(-70.0, -5.0));
+    loudnorm.set_property("max-true-peak", settings.true_peak_db.clamp(-9.0, 0.0));
+    if let Some(range) = settings.loudness_range_lu {
+        loudnorm.set_property("loudness-range-target", range.clamp(1.0, 20.0));
+    }
+
+    info!("Normalizing mix to {} LUFS, true peak {} dBTP", settings.target_lufs, settings.true_peak_db);
+    Ok(Some(bin.upcast()))
+}
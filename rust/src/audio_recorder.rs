@@ -0,0 +1,85 @@
+use std::time::Duration;
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{info, warn};
+
+use crate::audio_input;
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+
+// Time allowed for the WAV header to be finalized after the input stops
+const FINALIZE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Records the selected input device to a WAV file
+pub struct VoiceoverRecorder {
+    pipeline: gst::Pipeline,
+    output_path: String,
+}
+
+impl VoiceoverRecorder {
+    /// Start capturing `<input> ! audioconvert ! audioresample ! wavenc ! filesink`
+    pub fn start(output_path: &str) -> Result<Self> {
+        let pipeline = gst::Pipeline::with_name("voiceover_recorder");
+        let source = audio_input::create_input_source()?;
+        let audioconvert = gst::ElementFactory::make("audioconvert").build()
+            .map_err(|e| anyhow!("Failed to create audioconvert: {}", e))?;
+        let audioresample = gst::ElementFactory::make("audioresample").build()
+            .map_err(|e| anyhow!("Failed to create audioresample: {}", e))?;
+        let wavenc = gst::ElementFactory::make("wavenc").build()
+            .map_err(|e| anyhow!("Failed to create wavenc: {}", e))?;
+        let filesink = gst::ElementFactory::make("filesink")
+            .property("location", output_path)
+            .build()
+            .map_err(|e| anyhow!("Failed to create filesink: {}", e))?;
+        pipeline.add_many([&source, &audioconvert, &audioresample, &wavenc, &filesink])?;
+        gst::Element::link_many([&source, &audioconvert, &audioresample, &wavenc, &filesink])?;
+
+        let recorder = Self { pipeline, output_path: output_path.to_string() };
+        recorder.pipeline.set_state(gst::State::Playing)
+            .map_err(|_| anyhow!(FlipEditError::new(FlipEditErrorCode::StateChangeFailed, "Failed to start the audio input")))?;
+        // Live sources don't preroll, so wait for the capture to actually run
+        let (state_result, _, _) = recorder.pipeline.state(Some(gst::ClockTime::from_seconds(5)));
+        state_result.map_err(|_| anyhow!(FlipEditError::new(FlipEditErrorCode::StateChangeFailed, "Audio input didn't start")))?;
+
+        info!("Recording voice-over to {}", output_path);
+        Ok(recorder)
+    }
+
+    pub fn output_path(&self) -> &str {
+        &self.output_path
+    }
+
+    /// Length of audio captured so far
+    pub fn position_ms(&self) -> u64 {
+        self.pipeline.query_position::<gst::ClockTime>()
+            .map_or(0, |position| position.mseconds())
+    }
+
+    /// Stop capturing and finalize the file, returning the recorded length
+    pub fn stop(self) -> Result<u64> {
+        let length_ms = self.position_ms();
+        // wavenc only writes the final header sizes on EOS
+        self.pipeline.send_event(gst::event::Eos::new());
+        let bus = self.pipeline.bus().ok_or_else(|| anyhow!("Recorder pipeline has no bus"))?;
+        let message = bus.timed_pop_filtered(
+            gst::ClockTime::from_mseconds(FINALIZE_TIMEOUT.as_millis() as u64),
+            &[gst::MessageType::Eos, gst::MessageType::Error],
+        );
+        let result = match message.as_ref().map(|m| m.view()) {
+            Some(gst::MessageView::Error(err)) => Err(anyhow!(FlipEditError::from_error_message(err))),
+            Some(_) => Ok(length_ms),
+            None => {
+                warn!("Voice-over recording didn't finish within {:?}, the file may be truncated", FINALIZE_TIMEOUT);
+                Ok(length_ms)
+            }
+        };
+        info!("Stopped voice-over recording after {}ms", length_ms);
+        result
+    }
+}
+
+impl Drop for VoiceoverRecorder {
+    fn drop(&mut self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+    }
+}
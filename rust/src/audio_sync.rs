@@ -0,0 +1,277 @@
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gst::prelude::*;
+use log::{info, warn};
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::media_uri::source_uri;
+use crate::common::types::{ClipSyncResult, TimelineClip, TimelineData};
+use crate::video::clip_index::ClipIndex;
+use crate::video::clip_timing::ClipTiming;
+
+// Decode rate for analysis; onsets don't need more bandwidth than speech
+const ANALYSIS_RATE: usize = 16_000;
+// One energy value per millisecond
+const SAMPLES_PER_MS: usize = ANALYSIS_RATE / 1000;
+// The full search runs on 10ms frames, then the best lag is refined to the millisecond
+const COARSE_FACTOR: usize = 10;
+const REFINE_RADIUS_MS: i64 = 2 * COARSE_FACTOR as i64;
+// Only the start of each clip is analyzed to bound the search cost
+const MAX_ANALYSIS_MS: u64 = 120_000;
+// Alignments sharing less audio than this are ignored
+const MIN_OVERLAP_MS: usize = 2_000;
+const SAMPLE_TIMEOUT_SECS: u64 = 10;
+
+/// Offset of a clip's in-point relative to the reference clip's in-point
+#[derive(Debug, Clone, Copy)]
+pub struct AudioOffset {
+    pub offset_ms: i64,
+    /// Normalized correlation of the aligned onset envelopes, 0.0 to 1.0
+    pub confidence: f64,
+}
+
+/// Find where each of `others` lines up against `reference` by cross-correlating audio onsets
+pub fn clip_offsets(reference: &TimelineClip, others: &[TimelineClip]) -> Result<Vec<AudioOffset>> {
+    let reference_energy = decode_energy(reference)?;
+    let reference_fine = onset_envelope(&reference_energy);
+    let reference_coarse = onset_envelope(&downsample(&reference_energy, COARSE_FACTOR));
+
+    others.iter().map(|clip| {
+        let energy = decode_energy(clip)?;
+        let coarse = onset_envelope(&downsample(&energy, COARSE_FACTOR));
+        let min_overlap = MIN_OVERLAP_MS / COARSE_FACTOR;
+        let lags = -(coarse.len() as i64 - min_overlap as i64)..=(reference_coarse.len() as i64 - min_overlap as i64);
+        let (coarse_lag, _) = best_lag(&reference_coarse, &coarse, lags, min_overlap).ok_or_else(|| {
+            anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Clip {:?} has too little audio to synchronize", clip.id),
+            ))
+        })?;
+
+        let fine = onset_envelope(&energy);
+        let center = coarse_lag * COARSE_FACTOR as i64;
+        let (offset_ms, confidence) = best_lag(
+            &reference_fine,
+            &fine,
+            center - REFINE_RADIUS_MS..=center + REFINE_RADIUS_MS,
+            MIN_OVERLAP_MS,
+        ).unwrap_or((center, 0.0));
+        info!("Clip {:?} aligns {}ms after the reference (confidence {:.2})", clip.id, offset_ms, confidence);
+        if confidence < 0.2 {
+            warn!("Weak audio match for clip {:?}, the alignment may be wrong", clip.id);
+        }
+        Ok(AudioOffset { offset_ms, confidence: confidence.max(0.0) })
+    }).collect()
+}
+
+/// Where to move clips recorded at the same time so their audio lines up. The first clip is the
+/// reference; the others get their own tracks unless theirs is free at the new position.
+/// Decodes every clip's audio, so run it off the player's worker.
+pub fn sync_clips(timeline_data: &TimelineData, clip_ids: &[i32]) -> Result<Vec<ClipSyncResult>> {
+    if clip_ids.len() < 2 {
+        return Err(anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, "At least two clips are needed to synchronize")));
+    }
+    let clips = clip_ids.iter().map(|&clip_id| {
+        timeline_data.tracks.iter()
+            .flat_map(|t| &t.clips)
+            .find(|c| c.id == Some(clip_id))
+            .cloned()
+            .ok_or_else(|| anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Clip {} not found in timeline", clip_id),
+            )))
+    }).collect::<Result<Vec<_>>>()?;
+
+    let (reference, others) = clips.split_first().unwrap();
+    let offsets = clip_offsets(reference, others)?;
+
+    // Clips starting before the reference push the whole group right
+    let earliest_ms = offsets.iter().map(|o| o.offset_ms).min().unwrap_or(0).min(0);
+    let base_ms = (reference.start_time_on_track_ms as i64).max(-earliest_ms);
+    let mut next_track_id = timeline_data.tracks.iter().map(|t| t.id).max().unwrap_or(0) + 1;
+    let index = ClipIndex::new(timeline_data);
+    let mut results = vec![ClipSyncResult {
+        clip_id: clip_ids[0],
+        track_id: reference.track_id,
+        start_time_on_track_ms: base_ms as i32,
+        offset_ms: 0,
+        confidence: 1.0,
+    }];
+    for ((clip, offset), &clip_id) in others.iter().zip(&offsets).zip(&clip_ids[1..]) {
+        let start_ms = (base_ms + offset.offset_ms) as i32;
+        let end_ms = start_ms + (clip.end_time_on_track_ms - clip.start_time_on_track_ms);
+        let track_is_free = !results.iter().any(|r| r.track_id == clip.track_id)
+            && index.overlapping_on_track(clip.track_id, start_ms as i64, end_ms as i64)
+                .iter()
+                .all(|c| c.id.is_some_and(|id| clip_ids.contains(&id)));
+        let track_id = if track_is_free {
+            clip.track_id
+        } else {
+            next_track_id += 1;
+            next_track_id - 1
+        };
+        results.push(ClipSyncResult {
+            clip_id,
+            track_id,
+            start_time_on_track_ms: start_ms,
+            offset_ms: offset.offset_ms,
+            confidence: offset.confidence,
+        });
+    }
+    Ok(results)
+}
+
+/// Lag with the highest Pearson correlation, where `other[i]` is compared with `reference[i + lag]`
+fn best_lag(
+    reference: &[f32],
+    other: &[f32],
+    lags: std::ops::RangeInclusive<i64>,
+    min_overlap: usize,
+) -> Option<(i64, f64)> {
+    let mut best: Option<(i64, f64)> = None;
+    for lag in lags {
+        let other_start = (-lag).max(0) as usize;
+        let reference_start = lag.max(0) as usize;
+        if other_start >= other.len() || reference_start >= reference.len() {
+            continue;
+        }
+        let overlap = (other.len() - other_start).min(reference.len() - reference_start);
+        if overlap < min_overlap {
+            continue;
+        }
+        let a = &reference[reference_start..reference_start + overlap];
+        let b = &other[other_start..other_start + overlap];
+        let score = pearson(a, b);
+        if best.is_none_or(|(_, best_score)| score > best_score) {
+            best = Some((lag, score));
+        }
+    }
+    best
+}
+
+fn pearson(a: &[f32], b: &[f32]) -> f64 {
+    let n = a.len() as f64;
+    let (mut sum_a, mut sum_b, mut sum_aa, mut sum_bb, mut sum_ab) = (0.0f64, 0.0f64, 0.0f64, 0.0f64, 0.0f64);
+    for (&x, &y) in a.iter().zip(b) {
+        let (x, y) = (x as f64, y as f64);
+        sum_a += x;
+        sum_b += y;
+        sum_aa += x * x;
+        sum_bb += y * y;
+        sum_ab += x * y;
+    }
+    let covariance = sum_ab - sum_a * sum_b / n;
+    let variance = (sum_aa - sum_a * sum_a / n) * (sum_bb - sum_b * sum_b / n);
+    if variance <= f64::EPSILON {
+        return 0.0;
+    }
+    covariance / variance.sqrt()
+}
+
+fn downsample(energy: &[f32], factor: usize) -> Vec<f32> {
+    energy.chunks(factor).map(|chunk| chunk.iter().sum::<f32>() / chunk.len() as f32).collect()
+}
+
+/// Rises in log energy, which line up across microphones regardless of their gain and tone
+fn onset_envelope(energy: &[f32]) -> Vec<f32> {
+    let log_energy: Vec<f32> = energy.iter().map(|e| (e + 1e-9).ln()).collect();
+    std::iter::once(0.0)
+        .chain(log_energy.windows(2).map(|pair| (pair[1] - pair[0]).max(0.0)))
+        .collect()
+}
+
+/// Mean square of the clip's mono audio per millisecond, from its in-point
+fn decode_energy(clip: &TimelineClip) -> Result<Vec<f32>> {
+    FlipEditError::check_file_exists(&clip.source_path)?;
+    let timing = ClipTiming::from_clip(clip);
+    let length_ms = timing.out_point_ms().saturating_sub(timing.in_point_ms).min(MAX_ANALYSIS_MS);
+
+    let pipeline = gst::Pipeline::new();
+    let decodebin = gst::ElementFactory::make("uridecodebin")
+        .property("uri", source_uri(&clip.source_path))
+        .build()
+        .map_err(|e| anyhow!("Failed to create uridecodebin: {}", e))?;
+    let audioconvert = gst::ElementFactory::make("audioconvert").build()
+        .map_err(|e| anyhow!("Failed to create audioconvert: {}", e))?;
+    let audioresample = gst::ElementFactory::make("audioresample").build()
+        .map_err(|e| anyhow!("Failed to create audioresample: {}", e))?;
+    let appsink = gst_app::AppSink::builder()
+        .caps(&gst::Caps::builder("audio/x-raw")
+            .field("format", "F32LE")
+            .field("layout", "interleaved")
+            .field("channels", 1i32)
+            .field("rate", ANALYSIS_RATE as i32)
+            .build())
+        .sync(false)
+        .build();
+    pipeline.add_many([&decodebin, &audioconvert, &audioresample, appsink.upcast_ref()])?;
+    gst::Element::link_many([&audioconvert, &audioresample, appsink.upcast_ref()])?;
+
+    let audioconvert_weak = audioconvert.downgrade();
+    decodebin.connect_pad_added(move |_, src_pad| {
+        let is_audio = src_pad.current_caps()
+            .and_then(|caps| caps.structure(0).map(|s| s.name().starts_with("audio/")))
+            .unwrap_or(false);
+        let Some(audioconvert) = audioconvert_weak.upgrade() else {
+            return;
+        };
+        if let Some(sink_pad) = audioconvert.static_pad("sink") {
+            if is_audio && !sink_pad.is_linked() {
+                if let Err(e) = src_pad.link(&sink_pad) {
+                    warn!("Failed to link audio for synchronization: {:?}", e);
+                }
+            }
+        }
+    });
+
+    let result = (|| -> Result<Vec<f32>> {
+        pipeline.set_state(gst::State::Paused)?;
+        let (state_result, _, _) = pipeline.state(Some(gst::ClockTime::from_seconds(10)));
+        state_result.map_err(|e| anyhow!("Synchronization pipeline failed to preroll: {}", e))?;
+        pipeline.seek(
+            1.0,
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::SeekType::Set,
+            gst::ClockTime::from_mseconds(timing.in_point_ms),
+            gst::SeekType::Set,
+            gst::ClockTime::from_mseconds(timing.in_point_ms + length_ms),
+        )?;
+        pipeline.set_state(gst::State::Playing)?;
+
+        let bus = pipeline.bus().ok_or_else(|| anyhow!("Synchronization pipeline has no bus"))?;
+        let mut energy = Vec::with_capacity(length_ms as usize);
+        let (mut window_sum, mut window_len) = (0.0f32, 0usize);
+        while let Some(sample) = appsink.try_pull_sample(gst::ClockTime::from_seconds(SAMPLE_TIMEOUT_SECS)) {
+            let Some(buffer) = sample.buffer() else {
+                continue;
+            };
+            let map = buffer.map_readable()?;
+            for bytes in map.as_slice().chunks_exact(4) {
+                let value = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                window_sum += value * value;
+                window_len += 1;
+                if window_len == SAMPLES_PER_MS {
+                    energy.push(window_sum / SAMPLES_PER_MS as f32);
+                    (window_sum, window_len) = (0.0, 0);
+                }
+            }
+        }
+        if let Some(message) = bus.pop_filtered(&[gst::MessageType::Error]) {
+            if let gst::MessageView::Error(err) = message.view() {
+                return Err(anyhow!(FlipEditError::from_error_message(err)));
+            }
+        }
+        Ok(energy)
+    })();
+
+    let _ = pipeline.set_state(gst::State::Null);
+    let energy = result?;
+    if energy.len() < MIN_OVERLAP_MS {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Clip {:?} has no usable audio to synchronize", clip.id),
+        )));
+    }
+    Ok(energy)
+}
@@ -0,0 +1,192 @@
+use std::sync::{Arc, Mutex};
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gst::prelude::*;
+use log::{info, warn};
+
+use crate::audio_automation;
+use crate::audio_effects;
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::media_uri::source_uri;
+use crate::common::types::{TimelineClip, TimelineData, TimelineTrack, TrackType};
+use crate::video::clip_timing::{apply_clip_timing, ClipTiming};
+use crate::video::direct_pipeline_player::discard_pad;
+use crate::video::render_cache::make_element;
+use crate::video::transitions::{self, ClipFades};
+
+// Mix rate for the overview; peaks don't need the top octaves
+const MIX_RATE: u32 = 16_000;
+const MIX_CHANNELS: usize = 2;
+const PREROLL_TIMEOUT_SECS: u64 = 10;
+const SAMPLE_TIMEOUT_SECS: u64 = 10;
+
+/// Peak level (0.0 to 1.0) of the mixed timeline audio for every 1/`samples_per_second` of
+/// the timeline, with clip and track effects and automation applied. Rendered offline, as fast as
+/// the sources decode.
+pub fn timeline_peaks(timeline: &TimelineData, samples_per_second: u32) -> Result<Vec<f32>> {
+    if samples_per_second == 0 || samples_per_second > MIX_RATE {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Waveform resolution must be 1 to {} samples per second", MIX_RATE),
+        )));
+    }
+    let duration_ms = timeline.tracks.iter()
+        .flat_map(|track| &track.clips)
+        .map(|clip| clip.end_time_on_track_ms.max(0) as u64)
+        .max()
+        .unwrap_or(0);
+    if duration_ms == 0 {
+        return Ok(Vec::new());
+    }
+    gst::init()?;
+
+    let pipeline = gst::Pipeline::new();
+    // Silence keeps the mix running through gaps and up to the end of the timeline
+    let silence = gst::ElementFactory::make("audiotestsrc")
+        .property_from_str("wave", "silence")
+        .build()
+        .map_err(|e| anyhow!("Failed to create audiotestsrc: {}", e))?;
+    let audiomixer = make_element("audiomixer")?;
+    let audioconvert = make_element("audioconvert")?;
+    let audioresample = make_element("audioresample")?;
+    let appsink = gst_app::AppSink::builder()
+        .caps(&gst::Caps::builder("audio/x-raw")
+            .field("format", "F32LE")
+            .field("layout", "interleaved")
+            .field("channels", MIX_CHANNELS as i32)
+            .field("rate", MIX_RATE as i32)
+            .build())
+        .sync(false)
+        .build();
+    pipeline.add_many([&silence, &audiomixer, &audioconvert, &audioresample, appsink.upcast_ref()])?;
+    gst::Element::link_many([&silence, &audiomixer, &audioconvert, &audioresample, appsink.upcast_ref()])?;
+
+    let fades = transitions::clip_fades(timeline);
+    let mut sources = 0;
+    for track in &timeline.tracks {
+        for clip in &track.clips {
+            if !clip.track_type.on_track(track.track_type).is_some_and(TrackType::has_audio) {
+                continue;
+            }
+            if FlipEditError::check_file_exists(&clip.source_path).is_err() {
+                warn!("Leaving missing file out of the waveform: {}", clip.source_path);
+                continue;
+            }
+            let clip_fades = clip.id.and_then(|id| fades.get(&id)).copied().unwrap_or_default();
+            add_audio_source(&pipeline, &audiomixer, track, clip, clip_fades)?;
+            sources += 1;
+        }
+    }
+    info!("Rendering {}ms timeline waveform from {} clip(s)", duration_ms, sources);
+
+    let total = (duration_ms * samples_per_second as u64).div_ceil(1000) as usize;
+    let result = (|| -> Result<Vec<f32>> {
+        pipeline.set_state(gst::State::Paused)?;
+        let (state_result, _, _) = pipeline.state(Some(gst::ClockTime::from_seconds(PREROLL_TIMEOUT_SECS)));
+        state_result.map_err(|e| anyhow!("Waveform pipeline failed to preroll: {}", e))?;
+        pipeline.seek(
+            1.0,
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::SeekType::Set,
+            gst::ClockTime::ZERO,
+            gst::SeekType::Set,
+            gst::ClockTime::from_mseconds(duration_ms),
+        )?;
+        pipeline.set_state(gst::State::Playing)?;
+
+        let bus = pipeline.bus().ok_or_else(|| anyhow!("Waveform pipeline has no bus"))?;
+        let frames_per_peak = MIX_RATE as f64 / samples_per_second as f64;
+        let mut peaks = Vec::with_capacity(total);
+        let (mut peak, mut frames, mut peak_end) = (0.0f32, 0u64, frames_per_peak);
+        while let Some(sample) = appsink.try_pull_sample(gst::ClockTime::from_seconds(SAMPLE_TIMEOUT_SECS)) {
+            let Some(buffer) = sample.buffer() else {
+                continue;
+            };
+            let map = buffer.map_readable()?;
+            for frame in map.as_slice().chunks_exact(4 * MIX_CHANNELS) {
+                for bytes in frame.chunks_exact(4) {
+                    peak = peak.max(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]).abs());
+                }
+                frames += 1;
+                if frames as f64 >= peak_end {
+                    peaks.push(peak.min(1.0));
+                    peak = 0.0;
+                    peak_end += frames_per_peak;
+                }
+            }
+        }
+        if let Some(message) = bus.pop_filtered(&[gst::MessageType::Error]) {
+            if let gst::MessageView::Error(err) = message.view() {
+                return Err(anyhow!(FlipEditError::from_error_message(err)));
+            }
+        }
+        if frames as f64 > peak_end - frames_per_peak {
+            peaks.push(peak.min(1.0));
+        }
+        Ok(peaks)
+    })();
+
+    let _ = pipeline.set_state(gst::State::Null);
+    let mut peaks = result?;
+    // One value per step of the timeline, whatever the rounding of the last buffer
+    peaks.resize(total, 0.0);
+    Ok(peaks)
+}
+
+/// Decode `clip`'s audio through its own and its track's effects, its track's automation and
+/// its crossfades into `audiomixer`
+fn add_audio_source(pipeline: &gst::Pipeline, audiomixer: &gst::Element, track: &TimelineTrack, clip: &TimelineClip, fades: ClipFades) -> Result<()> {
+    let uridecodebin = gst::ElementFactory::make("uridecodebin")
+        .property("uri", source_uri(&clip.source_path))
+        .build()
+        .map_err(|e| anyhow!("Failed to create uridecodebin for {}: {}", clip.source_path, e))?;
+    pipeline.add(&uridecodebin)?;
+
+    let pipeline_weak = pipeline.downgrade();
+    let audiomixer_weak = audiomixer.downgrade();
+    let clip_timing = Arc::new(Mutex::new(ClipTiming::from_clip(clip)));
+    // The clip's own effects run before its track's, as in the player
+    let effects: Vec<_> = clip.audio_effects.iter().chain(&track.audio_effects).cloned().collect();
+    let volume_keyframes = track.volume_keyframes.clone();
+    uridecodebin.connect_pad_added(move |_src, src_pad| {
+        let (Some(pipeline), Some(audiomixer)) = (pipeline_weak.upgrade(), audiomixer_weak.upgrade()) else {
+            return;
+        };
+        let is_audio = src_pad.current_caps()
+            .and_then(|caps| caps.structure(0).map(|s| s.name().starts_with("audio/")))
+            .unwrap_or(false);
+        if !is_audio {
+            discard_pad(&pipeline, src_pad);
+            return;
+        }
+
+        apply_clip_timing(src_pad, Arc::clone(&clip_timing));
+        let result: Result<()> = (|| {
+            let audioconvert = make_element("audioconvert")?;
+            let audioresample = make_element("audioresample")?;
+            let effect_chain = audio_effects::build_effect_chain(&effects)?;
+            let volume_envelope = audio_automation::build_envelope_filter(&volume_keyframes, fades, Arc::clone(&clip_timing))?;
+            let mut chain = vec![audioconvert];
+            chain.extend(effect_chain);
+            chain.extend(volume_envelope);
+            chain.push(audioresample);
+            pipeline.add_many(&chain)?;
+            gst::Element::link_many(&chain)?;
+            let mixer_pad = audiomixer.request_pad_simple("sink_%u")
+                .ok_or_else(|| anyhow!("Failed to request audiomixer pad"))?;
+            chain[chain.len() - 1].static_pad("src")
+                .ok_or_else(|| anyhow!("Failed to get audioresample src pad"))?
+                .link(&mixer_pad)?;
+            for element in &chain {
+                element.sync_state_with_parent()?;
+            }
+            src_pad.link(&chain[0].static_pad("sink").ok_or_else(|| anyhow!("Failed to get audioconvert sink pad"))?)?;
+            Ok(())
+        })();
+        if let Err(e) = result {
+            warn!("Failed to link waveform audio pad: {}", e);
+        }
+    });
+    Ok(())
+}
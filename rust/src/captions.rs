@@ -0,0 +1,320 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{debug, info, warn};
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::types::{CaptionAppearance, CaptionCue, CaptionFormat, CaptionStyle};
+
+/// Caption cues of the loaded timeline, shared with the overlay's streaming thread
+pub type SharedCaptions = Arc<Mutex<Vec<CaptionCue>>>;
+
+/// Name of the caption overlay in the preview pipeline
+pub const OVERLAY_NAME: &str = "caption_overlay";
+// Distance of the bottom line from the bottom of the frame, in pixels
+const CAPTION_MARGIN: i32 = 48;
+
+/// Read an SRT or WebVTT file. Bold and italic tags become the cue's style and other tags are
+/// dropped; ids are left at 0 for the timeline to assign.
+pub fn import(path: &str) -> Result<Vec<CaptionCue>> {
+    FlipEditError::check_file_exists(path)?;
+    let bytes = std::fs::read(path).map_err(|e| anyhow!("Failed to read {}: {}", path, e))?;
+    let text = String::from_utf8_lossy(&bytes);
+    let text = text.trim_start_matches('\u{feff}');
+    let is_vtt = text.starts_with("WEBVTT")
+        || Path::new(path).extension().is_some_and(|ext| ext.eq_ignore_ascii_case("vtt"));
+
+    let cues = parse(text, is_vtt);
+    if cues.is_empty() {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("No caption cues found in {}", path),
+        )));
+    }
+    info!("Imported {} caption cues from {}", cues.len(), path);
+    Ok(cues)
+}
+
+/// Parse SRT or WebVTT text. Both are blank-line separated blocks of an optional identifier,
+/// a `start --> end` timing line and the cue text; WebVTT adds a header and NOTE, STYLE and
+/// REGION blocks, which are skipped.
+pub fn parse(text: &str, is_vtt: bool) -> Vec<CaptionCue> {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut cues = Vec::new();
+    for block in text.split("\n\n") {
+        let lines: Vec<&str> = block.lines().filter(|line| !line.trim().is_empty()).collect();
+        let Some(first) = lines.first() else {
+            continue;
+        };
+        if is_vtt && ["WEBVTT", "NOTE", "STYLE", "REGION"].iter().any(|keyword| first.starts_with(keyword)) {
+            continue;
+        }
+        let Some(timing_index) = lines.iter().position(|line| line.contains("-->")) else {
+            debug!("Skipping caption block without timing: {:?}", first);
+            continue;
+        };
+        let mut times = lines[timing_index].split("-->");
+        let start = times.next().and_then(parse_timestamp);
+        // WebVTT cue settings follow the end time
+        let end = times.next().and_then(|end| end.split_whitespace().next()).and_then(parse_timestamp);
+        let (Some(start_ms), Some(end_ms)) = (start, end) else {
+            warn!("Skipping caption with invalid timing: {}", lines[timing_index]);
+            continue;
+        };
+        let text_lines = &lines[timing_index + 1..];
+        let has_tag = |tag: &str| text_lines.iter().any(|line| line.contains(tag));
+        let style = CaptionStyle {
+            bold: has_tag("<b>"),
+            italic: has_tag("<i>"),
+            // SRT's top-centre ASS override, or a WebVTT line setting at the top
+            top: has_tag("{\\an8}") || lines[timing_index].contains("line:0"),
+        };
+        let text = text_lines.iter()
+            .map(|line| strip_tags(line.trim()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if end_ms > start_ms && !text.is_empty() {
+            cues.push(CaptionCue { id: 0, start_ms, end_ms, text, style });
+        }
+    }
+    cues.sort_by_key(|cue| (cue.start_ms, cue.end_ms));
+    cues
+}
+
+/// `HH:MM:SS,mmm` (SRT) or `[HH:]MM:SS.mmm` (WebVTT) in milliseconds
+fn parse_timestamp(timestamp: &str) -> Option<i32> {
+    let timestamp = timestamp.trim();
+    let (clock, millis) = timestamp.rsplit_once([',', '.'])?;
+    let millis: i64 = match millis.len() {
+        1..=3 => millis.parse::<i64>().ok()? * 10_i64.pow(3 - millis.len() as u32),
+        _ => return None,
+    };
+    let mut seconds: i64 = 0;
+    for part in clock.split(':') {
+        seconds = seconds * 60 + part.parse::<i64>().ok()?;
+    }
+    i32::try_from(seconds * 1000 + millis).ok()
+}
+
+/// Drop `<i>`, `<c.yellow>`, `<v Speaker>`, `{\an8}` and similar tags and decode the common
+/// entities
+fn strip_tags(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut closing = None;
+    for c in line.chars() {
+        match (closing, c) {
+            (None, '<') => closing = Some('>'),
+            (None, '{') => closing = Some('}'),
+            (Some(end), _) if c == end => closing = None,
+            (None, _) => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+/// Write the cues as an SRT or WebVTT file, replacing any file at `path`
+pub fn export(cues: &[CaptionCue], format: CaptionFormat, path: &str) -> Result<()> {
+    std::fs::write(path, write(cues, format)).map_err(|e| anyhow!("Failed to write {}: {}", path, e))?;
+    info!("Exported {} caption cues to {}", cues.len(), path);
+    Ok(())
+}
+
+/// SRT or WebVTT text of the cues, numbered in start order
+pub fn write(cues: &[CaptionCue], format: CaptionFormat) -> String {
+    let mut sorted: Vec<&CaptionCue> = cues.iter().filter(|cue| cue.end_ms > cue.start_ms).collect();
+    sorted.sort_by_key(|cue| (cue.start_ms, cue.end_ms));
+
+    let mut out = String::new();
+    if format == CaptionFormat::WebVtt {
+        out.push_str("WEBVTT\n\n");
+    }
+    for (index, cue) in sorted.iter().enumerate() {
+        let (separator, settings) = match format {
+            CaptionFormat::Srt => (',', ""),
+            CaptionFormat::WebVtt => ('.', if cue.style.top { " line:0" } else { "" }),
+        };
+        out.push_str(&format!(
+            "{}\n{} --> {}{}\n{}\n\n",
+            index + 1,
+            format_timestamp(cue.start_ms, separator),
+            format_timestamp(cue.end_ms, separator),
+            settings,
+            styled_text(cue, format),
+        ));
+    }
+    out
+}
+
+/// `HH:MM:SS,mmm`, with `.` before the milliseconds for WebVTT
+fn format_timestamp(ms: i32, separator: char) -> String {
+    let ms = ms.max(0);
+    format!("{:02}:{:02}:{:02}{}{:03}", ms / 3_600_000, ms / 60_000 % 60, ms / 1000 % 60, separator, ms % 1000)
+}
+
+/// Cue text wrapped in the tags `parse` reads back as its style
+fn styled_text(cue: &CaptionCue, format: CaptionFormat) -> String {
+    let mut text = match format {
+        CaptionFormat::Srt => cue.text.clone(),
+        CaptionFormat::WebVtt => cue.text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;"),
+    };
+    // Blank lines would end the cue early
+    text = text.lines().filter(|line| !line.trim().is_empty()).collect::<Vec<_>>().join("\n");
+    if cue.style.italic {
+        text = format!("<i>{}</i>", text);
+    }
+    if cue.style.bold {
+        text = format!("<b>{}</b>", text);
+    }
+    if cue.style.top && format == CaptionFormat::Srt {
+        text = format!("{{\\an8}}{}", text);
+    }
+    text
+}
+
+/// Apply a ripple edit to the cues: times at or after `at_ms` move by `delta_ms`, and for a
+/// negative delta, times inside the removed `at_ms + delta_ms..at_ms` collapse to its start.
+/// Cues left without a duration are dropped.
+pub fn ripple(cues: &mut Vec<CaptionCue>, at_ms: i32, delta_ms: i32) {
+    let gap_start = at_ms.saturating_add(delta_ms.min(0));
+    let shift = |ms: i32| {
+        if ms >= at_ms {
+            ms.saturating_add(delta_ms).max(0)
+        } else if ms > gap_start {
+            gap_start
+        } else {
+            ms
+        }
+    };
+    for cue in cues.iter_mut() {
+        cue.start_ms = shift(cue.start_ms);
+        cue.end_ms = shift(cue.end_ms);
+    }
+    cues.retain(|cue| cue.end_ms > cue.start_ms);
+}
+
+/// `cues` with their parts inside `ranges` cut out, e.g. where they're burnt into rendered video
+pub fn outside_ranges(cues: &[CaptionCue], ranges: &[(i32, i32)]) -> Vec<CaptionCue> {
+    let mut remaining = cues.to_vec();
+    for &(start_ms, end_ms) in ranges {
+        remaining = remaining.into_iter()
+            .flat_map(|cue| {
+                let before = (cue.start_ms < start_ms).then(|| CaptionCue { end_ms: cue.end_ms.min(start_ms), ..cue.clone() });
+                let after = (cue.end_ms > end_ms).then(|| CaptionCue { start_ms: cue.start_ms.max(end_ms), ..cue.clone() });
+                before.into_iter().chain(after)
+            })
+            .collect();
+    }
+    remaining
+}
+
+/// Pango markup drawing a cue's text in its style
+fn cue_markup(cue: &CaptionCue) -> String {
+    let mut markup = gst::glib::markup_escape_text(&cue.text).to_string();
+    if cue.style.italic {
+        markup = format!("<i>{}</i>", markup);
+    }
+    if cue.style.bold {
+        markup = format!("<b>{}</b>", markup);
+    }
+    markup
+}
+
+/// Check a cue's timing before it goes on the caption track
+pub fn validate_timing(start_ms: i32, end_ms: i32) -> Result<()> {
+    if start_ms < 0 || end_ms <= start_ms {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Caption timing {}ms to {}ms is invalid", start_ms, end_ms),
+        )));
+    }
+    Ok(())
+}
+
+pub fn not_found(id: i32) -> anyhow::Error {
+    anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, format!("No caption cue with id {}", id)))
+}
+
+pub fn validate_appearance(appearance: &CaptionAppearance) -> Result<()> {
+    if appearance.font_family.trim().is_empty() || appearance.font_size == 0 {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Invalid caption font \"{}\" at {}pt", appearance.font_family, appearance.font_size),
+        )));
+    }
+    Ok(())
+}
+
+/// Set the font and outline of a caption overlay; takes effect from the next frame
+pub fn apply_appearance(overlay: &gst::Element, appearance: &CaptionAppearance) {
+    overlay.set_property("font-desc", format!("{} {}", appearance.font_family, appearance.font_size));
+    overlay.set_property("draw-outline", appearance.outline);
+    overlay.set_property("draw-shadow", appearance.shadow);
+}
+
+/// Cue shown at `position_ms`, if any
+pub fn cue_at(cues: &[CaptionCue], position_ms: i64) -> Option<&CaptionCue> {
+    cues.iter().rev().find(|cue| cue.start_ms as i64 <= position_ms && position_ms < cue.end_ms as i64)
+}
+
+/// `textoverlay` drawing the cue shown at each frame's timeline position, or `None` if the
+/// pango plugin isn't installed. Link video into its `video_sink` pad.
+pub fn create_overlay(captions: &SharedCaptions, appearance: &CaptionAppearance) -> Option<gst::Element> {
+    let overlay = match gst::ElementFactory::make("textoverlay")
+        .name(OVERLAY_NAME)
+        .property("ypad", CAPTION_MARGIN)
+        .property("silent", true)
+        .build()
+    {
+        Ok(overlay) => overlay,
+        Err(e) => {
+            warn!("Captions won't be shown: {}", e);
+            return None;
+        }
+    };
+    overlay.set_property_from_str("valignment", "bottom");
+    overlay.set_property_from_str("halignment", "center");
+    overlay.set_property_from_str("line-alignment", "center");
+    apply_appearance(&overlay, appearance);
+
+    let sink_pad = overlay.static_pad("video_sink")?;
+    let captions = Arc::clone(captions);
+    let overlay_weak = overlay.downgrade();
+    let shown: Mutex<Option<(String, bool)>> = Mutex::new(None);
+    sink_pad.add_probe(gst::PadProbeType::BUFFER, move |pad, info| {
+        let Some(buffer) = info.buffer() else {
+            return gst::PadProbeReturn::Ok;
+        };
+        // Positions are in stream time, i.e. timeline time
+        let Some(position) = buffer.pts().and_then(|pts| {
+            pad.sticky_event::<gst::event::Segment>(0)
+                .and_then(|event| event.segment().downcast_ref::<gst::ClockTime>().and_then(|segment| segment.to_stream_time(pts)))
+        }) else {
+            return gst::PadProbeReturn::Ok;
+        };
+        let cue = cue_at(&captions.lock().unwrap(), position.mseconds() as i64)
+            .map(|cue| (cue_markup(cue), cue.style.top));
+
+        let mut shown = shown.lock().unwrap();
+        if *shown != cue {
+            if let Some(overlay) = overlay_weak.upgrade() {
+                match &cue {
+                    Some((markup, top)) => {
+                        overlay.set_property("text", markup.as_str());
+                        overlay.set_property_from_str("valignment", if *top { "top" } else { "bottom" });
+                        overlay.set_property("silent", false);
+                    }
+                    None => overlay.set_property("silent", true),
+                }
+            }
+            *shown = cue;
+        }
+        gst::PadProbeReturn::Ok
+    });
+    Some(overlay)
+}
@@ -0,0 +1,87 @@
+use anyhow::{anyhow, Result};
+use log::warn;
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::types::TimelineMarker;
+
+// YouTube ignores a chapter list with fewer chapters or any chapter shorter than this
+const MIN_CHAPTERS: usize = 3;
+const MIN_CHAPTER_MS: i32 = 10_000;
+// Title of the chapter added at 0:00 when no marker is near the start
+const OPENING_TITLE: &str = "Intro";
+
+/// Chapter list for a YouTube description, one "M:SS Title" line per chapter ("H:MM:SS" on
+/// timelines an hour or longer). YouTube requires the first chapter at 0:00, at least three
+/// chapters and ten seconds per chapter, so markers are adjusted to fit: a marker in the
+/// first ten seconds moves to 0:00 (otherwise an "Intro" chapter is added), markers closer
+/// than ten seconds to the previous chapter are dropped, and so is a last chapter that ends
+/// too soon.
+pub fn youtube_chapters(markers: &[TimelineMarker], timeline_end_ms: i32) -> Result<String> {
+    let mut sorted: Vec<&TimelineMarker> = markers.iter()
+        .filter(|marker| marker.position_ms >= 0 && marker.position_ms < timeline_end_ms)
+        .collect();
+    sorted.sort_by_key(|marker| marker.position_ms);
+
+    let mut chapters: Vec<(i32, String)> = Vec::new();
+    for marker in sorted {
+        let title = marker.name.split_whitespace().collect::<Vec<_>>().join(" ");
+        let title = if title.is_empty() { format!("Chapter {}", chapters.len() + 1) } else { title };
+        match chapters.last() {
+            None if marker.position_ms < MIN_CHAPTER_MS => chapters.push((0, title)),
+            None => {
+                chapters.push((0, OPENING_TITLE.to_string()));
+                chapters.push((marker.position_ms, title));
+            }
+            Some((start_ms, _)) if marker.position_ms - start_ms < MIN_CHAPTER_MS => {
+                warn!("Skipping chapter \"{}\", less than {}s after the previous one", title, MIN_CHAPTER_MS / 1000);
+            }
+            Some(_) => chapters.push((marker.position_ms, title)),
+        }
+    }
+    while chapters.last().is_some_and(|(start_ms, _)| timeline_end_ms - start_ms < MIN_CHAPTER_MS) {
+        chapters.pop();
+    }
+
+    if chapters.len() < MIN_CHAPTERS {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!(
+                "YouTube needs at least {} chapters of {}s or longer; the markers give {}",
+                MIN_CHAPTERS, MIN_CHAPTER_MS / 1000, chapters.len(),
+            ),
+        )));
+    }
+    let with_hours = timeline_end_ms >= 3_600_000;
+    Ok(chapters.iter()
+        .map(|(start_ms, title)| format!("{} {}", format_start(*start_ms, with_hours), title))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Chapters spanning the timeline, `(start_ms, end_ms, title)`, one from each marker to the
+/// next (the last to the end), for container chapter tables
+pub fn timeline_chapters(markers: &[TimelineMarker], timeline_end_ms: i32) -> Vec<(i32, i32, String)> {
+    let mut sorted: Vec<&TimelineMarker> = markers.iter()
+        .filter(|marker| marker.position_ms >= 0 && marker.position_ms < timeline_end_ms)
+        .collect();
+    sorted.sort_by_key(|marker| marker.position_ms);
+    sorted.dedup_by_key(|marker| marker.position_ms);
+
+    sorted.iter().enumerate()
+        .map(|(i, marker)| {
+            let end_ms = sorted.get(i + 1).map_or(timeline_end_ms, |next| next.position_ms);
+            let title = marker.name.trim();
+            let title = if title.is_empty() { format!("Chapter {}", i + 1) } else { title.to_string() };
+            (marker.position_ms, end_ms, title)
+        })
+        .collect()
+}
+
+fn format_start(ms: i32, with_hours: bool) -> String {
+    let seconds = ms / 1000;
+    if with_hours {
+        format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{debug, info, warn};
+
+use crate::common::plugins;
+use crate::common::types::DecoderPolicy;
+
+// Saved policy, under the platform's per-user config directory
+const SETTINGS_DIR_NAME: &str = "flipedit";
+const POLICY_FILE_NAME: &str = "decoder_policy.json";
+
+lazy_static::lazy_static! {
+    // None until the saved policy has been read
+    static ref POLICY: Mutex<Option<DecoderPolicy>> = Mutex::new(None);
+    // Ranks hardware decoders had before a policy changed them, for going back to Auto
+    static ref DEFAULT_RANKS: Mutex<HashMap<String, gst::Rank>> = Mutex::new(HashMap::new());
+}
+
+/// A hardware decoder error, with the URI of the `uridecodebin` it happened in
+#[derive(Debug, Clone)]
+pub struct DecoderFailure {
+    pub uri: String,
+    pub decoder: String,
+    pub reason: String,
+}
+
+fn policy_path() -> Option<PathBuf> {
+    let home = || std::env::var_os("HOME").map(PathBuf::from);
+    let config_dir = if cfg!(target_os = "windows") {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home().map(|home| home.join("Library").join("Application Support"))
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from).or_else(|| home().map(|home| home.join(".config")))
+    };
+    config_dir.map(|dir| dir.join(SETTINGS_DIR_NAME).join(POLICY_FILE_NAME))
+}
+
+fn load_policy() -> DecoderPolicy {
+    let Some(path) = policy_path() else {
+        return DecoderPolicy::default();
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(json) => serde_json::from_str(&json).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable decoder policy in {}: {}", path.display(), e);
+            DecoderPolicy::default()
+        }),
+        Err(_) => DecoderPolicy::default(),
+    }
+}
+
+fn save_policy(policy: DecoderPolicy) -> Result<()> {
+    let path = policy_path().ok_or_else(|| anyhow!("No config directory to save the decoder policy in"))?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, serde_json::to_string(&policy)?)?;
+    Ok(())
+}
+
+/// The policy in effect, as last saved
+pub fn policy() -> DecoderPolicy {
+    *POLICY.lock().unwrap().get_or_insert_with(load_policy)
+}
+
+/// Re-rank decoders for `policy` and save it for the next launch. Pipelines already built
+/// keep their decoders; media loaded afterwards uses the new ranks.
+pub fn set_policy(policy: DecoderPolicy) -> Result<()> {
+    gst::init().map_err(|e| anyhow!("Failed to initialize GStreamer: {}", e))?;
+    *POLICY.lock().unwrap() = Some(policy);
+    apply(policy);
+    save_policy(policy)
+}
+
+/// Rank decoders for the saved policy; needs GStreamer initialized
+pub fn apply_policy() {
+    apply(policy());
+}
+
+fn apply(policy: DecoderPolicy) {
+    let software_only = match policy {
+        DecoderPolicy::Auto => cfg!(target_os = "macos"),
+        DecoderPolicy::HardwareFirst => false,
+        DecoderPolicy::SoftwareOnly => true,
+    };
+    let mut default_ranks = DEFAULT_RANKS.lock().unwrap();
+    for factory in gst::ElementFactory::factories_with_type(gst::ElementFactoryType::DECODER, gst::Rank::NONE) {
+        if !plugins::is_hardware(&factory) {
+            continue;
+        }
+        let default_rank = *default_ranks.entry(factory.name().to_string()).or_insert_with(|| factory.rank());
+        let rank = if software_only {
+            gst::Rank::NONE
+        } else if policy == DecoderPolicy::HardwareFirst {
+            // Above the PRIMARY rank of the libav decoders
+            default_rank.max(gst::Rank::PRIMARY + 1)
+        } else {
+            default_rank
+        };
+        if factory.rank() != rank {
+            debug!("Ranking decoder {} at {:?}", factory.name(), rank);
+            factory.set_rank(rank);
+        }
+    }
+    info!("Decoder policy {:?} applied", policy);
+}
+
+/// The failure, if `src` of an error message is a hardware decoder inside a `uridecodebin`
+pub fn hardware_decoder_failure(src: &gst::Object, reason: String) -> Option<DecoderFailure> {
+    let factory = src.downcast_ref::<gst::Element>()?.factory()?;
+    if !plugins::is_hardware(&factory) || !factory.klass().contains("Decoder") {
+        return None;
+    }
+    let mut parent = src.parent();
+    while let Some(object) = parent {
+        let is_uridecodebin = object
+            .downcast_ref::<gst::Element>()
+            .and_then(|element| element.factory())
+            .is_some_and(|factory| factory.name() == "uridecodebin");
+        if is_uridecodebin {
+            return Some(DecoderFailure {
+                uri: object.property::<Option<String>>("uri")?,
+                decoder: factory.name().to_string(),
+                reason,
+            });
+        }
+        parent = object.parent();
+    }
+    None
+}
+
+/// Make a `decodebin` or `uridecodebin` pass over hardware decoders, whatever their rank
+pub fn skip_hardware_decoders(decodebin: &gst::Element) {
+    // Registered with decodebin's signals, which have no Rust binding for their result enum
+    let Some(result_type) = gst::glib::Type::from_name("GstAutoplugSelectResult") else {
+        warn!("Can't skip hardware decoders: decodebin's autoplug-select result type isn't registered");
+        return;
+    };
+    decodebin.connect("autoplug-select", false, move |args| {
+        let hardware = args
+            .get(3)
+            .and_then(|value| value.get::<gst::ElementFactory>().ok())
+            .is_some_and(|factory| plugins::is_hardware(&factory));
+        let nick = if hardware { "skip" } else { "try" };
+        gst::glib::EnumClass::with_type(result_type).and_then(|class| class.to_value_by_nick(nick))
+    });
+}
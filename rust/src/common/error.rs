@@ -0,0 +1,135 @@
+use gstreamer as gst;
+use gstreamer::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Error categories Flutter can react to programmatically
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlipEditErrorCode {
+    FileNotFound,
+    MissingPlugin,
+    DecodeError,
+    NotLoaded,
+    StateChangeFailed,
+    SeekFailed,
+    ResourceError,
+    InvalidArgument,
+    /// The Dart bindings were generated for a different version of the native library
+    IncompatibleVersion,
+    /// The player was disposed, or the GStreamer worker that owns it has stopped
+    PlayerStopped,
+    Internal,
+}
+
+/// Serializable error returned across the FFI boundary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlipEditError {
+    pub code: FlipEditErrorCode,
+    pub message: String,
+    /// Path of the GStreamer element that raised the error, if any
+    pub element: Option<String>,
+    /// GStreamer debug details, useful for bug reports
+    pub debug: Option<String>,
+}
+
+impl FlipEditError {
+    pub fn new(code: FlipEditErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            element: None,
+            debug: None,
+        }
+    }
+
+    pub fn not_loaded() -> Self {
+        Self::new(FlipEditErrorCode::NotLoaded, "Pipeline not loaded")
+    }
+
+    pub fn file_not_found(path: &str) -> Self {
+        Self::new(FlipEditErrorCode::FileNotFound, format!("Video file not found: {}", path))
+    }
+
+    /// Fail with `FileNotFound` before building a pipeline for a missing file; network sources
+    /// are checked by the pipeline when they're opened
+    pub fn check_file_exists(path: &str) -> Result<(), Self> {
+        if crate::common::media_uri::is_network_source(path) || std::path::Path::new(path).exists() {
+            Ok(())
+        } else {
+            Err(Self::file_not_found(path))
+        }
+    }
+
+    /// Map a GStreamer error domain/code onto our error categories
+    pub fn from_glib_error(error: &gst::glib::Error) -> Self {
+        let code = if let Some(core_error) = error.kind::<gst::CoreError>() {
+            match core_error {
+                gst::CoreError::MissingPlugin => FlipEditErrorCode::MissingPlugin,
+                gst::CoreError::StateChange => FlipEditErrorCode::StateChangeFailed,
+                gst::CoreError::Seek => FlipEditErrorCode::SeekFailed,
+                _ => FlipEditErrorCode::Internal,
+            }
+        } else if let Some(resource_error) = error.kind::<gst::ResourceError>() {
+            match resource_error {
+                gst::ResourceError::NotFound | gst::ResourceError::OpenRead => FlipEditErrorCode::FileNotFound,
+                _ => FlipEditErrorCode::ResourceError,
+            }
+        } else if let Some(stream_error) = error.kind::<gst::StreamError>() {
+            match stream_error {
+                gst::StreamError::CodecNotFound | gst::StreamError::TypeNotFound => FlipEditErrorCode::MissingPlugin,
+                gst::StreamError::Decode | gst::StreamError::Demux | gst::StreamError::Format
+                | gst::StreamError::WrongType => FlipEditErrorCode::DecodeError,
+                _ => FlipEditErrorCode::Internal,
+            }
+        } else {
+            FlipEditErrorCode::Internal
+        };
+
+        Self::new(code, error.message())
+    }
+
+    /// Build an error from a bus ERROR message, keeping the element path and debug info
+    pub fn from_error_message(err: &gst::message::Error) -> Self {
+        let mut error = Self::from_glib_error(&err.error());
+        error.element = err.src().map(|src| src.path_string().to_string());
+        error.debug = err.debug().map(|debug| debug.to_string());
+        error
+    }
+}
+
+impl fmt::Display for FlipEditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.message)?;
+        if let Some(ref element) = self.element {
+            write!(f, " ({})", element)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FlipEditError {}
+
+impl From<String> for FlipEditError {
+    fn from(message: String) -> Self {
+        Self::new(FlipEditErrorCode::Internal, message)
+    }
+}
+
+impl From<&str> for FlipEditError {
+    fn from(message: &str) -> Self {
+        Self::new(FlipEditErrorCode::Internal, message)
+    }
+}
+
+impl From<anyhow::Error> for FlipEditError {
+    fn from(error: anyhow::Error) -> Self {
+        // Keep typed errors raised inside the players, otherwise fall back to Internal
+        match error.downcast::<FlipEditError>() {
+            Ok(flip_error) => flip_error,
+            Err(error) => match error.downcast::<gst::glib::Error>() {
+                Ok(glib_error) => Self::from_glib_error(&glib_error),
+                Err(error) => Self::new(FlipEditErrorCode::Internal, error.to_string()),
+            },
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use log::info;
+
+use crate::common::types::LogLine;
+
+pub type LogLineCallback = Arc<dyn Fn(LogLine) + Send + Sync>;
+
+// Lines kept for a viewer opened after the problem happened
+const LOG_BUFFER_LINES: usize = 2000;
+
+lazy_static::lazy_static! {
+    static ref LOG_BUFFER: Mutex<VecDeque<LogLine>> = Mutex::new(VecDeque::with_capacity(LOG_BUFFER_LINES));
+    static ref LOG_CALLBACK: Mutex<Option<LogLineCallback>> = Mutex::new(None);
+    static ref LOG_FUNCTION: Mutex<Option<gst::log::DebugLogFunction>> = Mutex::new(None);
+}
+
+fn capture(line: LogLine) {
+    // Buffered and the callback picked under the buffer lock, so a stream starting now either
+    // replays this line or receives it; the callback itself runs without any lock held
+    let callback = {
+        let mut buffer = LOG_BUFFER.lock().unwrap();
+        if buffer.len() == LOG_BUFFER_LINES {
+            buffer.pop_front();
+        }
+        buffer.push_back(line.clone());
+        LOG_CALLBACK.lock().unwrap().clone()
+    };
+    if let Some(callback) = callback {
+        callback(line);
+    }
+}
+
+/// Capture GStreamer's debug log into the ring buffer; the default stderr output is kept
+fn ensure_capturing() {
+    let mut function = LOG_FUNCTION.lock().unwrap();
+    if function.is_some() {
+        return;
+    }
+    *function = Some(gst::log::add_log_function(|category, level, file, _function, line, object, message| {
+        let Some(message) = message.get() else {
+            return;
+        };
+        capture(LogLine {
+            timestamp_ms: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |t| t.as_millis() as u64),
+            level: level.name().to_string(),
+            category: category.name().to_string(),
+            object: object.map(|object| object.to_string()),
+            message: message.to_string(),
+            location: format!("{}:{}", file, line),
+        });
+    }));
+}
+
+/// Set GStreamer debug thresholds at runtime with a `GST_DEBUG` style spec, e.g.
+/// "2,compositor:6,uridecodebin:5", and start capturing what they let through
+pub fn set_debug_threshold(spec: &str) -> Result<()> {
+    gst::init().map_err(|e| anyhow!("Failed to initialize GStreamer: {}", e))?;
+    gst::log::set_active(true);
+    gst::log::set_threshold_from_string(spec, true);
+    ensure_capturing();
+    info!("GStreamer debug threshold set to \"{}\"", spec);
+    Ok(())
+}
+
+/// Stream captured lines to `callback`, starting with those already in the ring buffer.
+/// Installs the capture, so lines let through by `GST_DEBUG` arrive without a
+/// `set_debug_threshold` call first.
+pub fn start_log_stream(callback: LogLineCallback) -> Result<()> {
+    gst::init().map_err(|e| anyhow!("Failed to initialize GStreamer: {}", e))?;
+    ensure_capturing();
+    // Hold the buffer lock while replaying so no live line arrives out of order
+    let buffer = LOG_BUFFER.lock().unwrap();
+    for line in buffer.iter() {
+        callback(line.clone());
+    }
+    *LOG_CALLBACK.lock().unwrap() = Some(callback);
+    Ok(())
+}
+
+pub fn stop_log_stream() {
+    *LOG_CALLBACK.lock().unwrap() = None;
+}
+
+/// Lines in the ring buffer, oldest first
+pub fn recent_log_lines() -> Vec<LogLine> {
+    LOG_BUFFER.lock().unwrap().iter().cloned().collect()
+}
+
+pub fn clear_log_lines() {
+    LOG_BUFFER.lock().unwrap().clear();
+}
@@ -0,0 +1,52 @@
+use gstreamer as gst;
+
+// Sources streamed over the network rather than read from disk; HLS playlists are plain
+// http(s) URLs that uridecodebin hands to hlsdemux
+const NETWORK_SCHEMES: [&str; 3] = ["http://", "https://", "srt://"];
+// Contribution feeds that only play live and can't be seeked
+const LIVE_SCHEMES: [&str; 1] = ["srt://"];
+
+fn has_scheme(source_path: &str, schemes: &[&str]) -> bool {
+    let lower = source_path.to_ascii_lowercase();
+    schemes.iter().any(|scheme| lower.starts_with(scheme))
+}
+
+/// Whether a clip's source path is a network URL, e.g. cloud-hosted review media
+pub fn is_network_source(source_path: &str) -> bool {
+    has_scheme(source_path, &NETWORK_SCHEMES)
+}
+
+/// Whether a clip's source is a live feed, which makes the whole pipeline live
+pub fn is_live_source(source_path: &str) -> bool {
+    has_scheme(source_path, &LIVE_SCHEMES)
+}
+
+/// URI for a clip's source path, passing network URLs through unchanged
+pub fn source_uri(source_path: &str) -> String {
+    if is_network_source(source_path) {
+        source_path.to_string()
+    } else {
+        format!("file://{}", source_path)
+    }
+}
+
+/// `srt://` URI carrying the connection options that srtsrc and srtsink read from its query
+pub fn srt_uri(address: &str, latency_ms: Option<u32>, passphrase: Option<&str>, stream_id: Option<&str>) -> String {
+    let mut params = Vec::new();
+    if let Some(latency_ms) = latency_ms {
+        params.push(format!("latency={}", latency_ms));
+    }
+    if let Some(passphrase) = passphrase.filter(|passphrase| !passphrase.is_empty()) {
+        params.push(format!("passphrase={}", gst::glib::Uri::escape_string(passphrase, None, false)));
+    }
+    if let Some(stream_id) = stream_id.filter(|stream_id| !stream_id.is_empty()) {
+        params.push(format!("streamid={}", gst::glib::Uri::escape_string(stream_id, None, false)));
+    }
+    let address = address.trim_end_matches('/');
+    if params.is_empty() {
+        address.to_string()
+    } else {
+        let separator = if address.contains('?') { '&' } else { '?' };
+        format!("{}{}{}", address, separator, params.join("&"))
+    }
+}
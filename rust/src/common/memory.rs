@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Instant;
+use log::debug;
+
+use crate::common::types::{MemoryCacheKind, MemoryUsage};
+
+// Budget until the app sets one
+const DEFAULT_BUDGET_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// A cache or pool whose memory counts against the global budget
+pub trait MemoryConsumer: Send + Sync {
+    fn memory_bytes(&self) -> usize;
+    /// Free at least `bytes` if possible, least recently used entries first; returns the bytes freed
+    fn evict(&self, bytes: usize) -> usize;
+    /// Last time an entry was read or added, so the least recently used consumer is trimmed first
+    fn last_used(&self) -> Instant;
+}
+
+struct Registered {
+    kind: MemoryCacheKind,
+    consumer: Weak<dyn MemoryConsumer>,
+}
+
+static BUDGET_BYTES: AtomicU64 = AtomicU64::new(DEFAULT_BUDGET_BYTES);
+static EVICTED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+lazy_static::lazy_static! {
+    static ref CONSUMERS: Mutex<Vec<Registered>> = Mutex::new(Vec::new());
+}
+
+/// Count `consumer` against the budget until it's dropped
+pub fn register<C: MemoryConsumer + 'static>(kind: MemoryCacheKind, consumer: &Arc<C>) {
+    let consumer: Arc<dyn MemoryConsumer> = consumer.clone();
+    CONSUMERS.lock().unwrap().push(Registered { kind, consumer: Arc::downgrade(&consumer) });
+}
+
+// Consumers still alive, dropping the registrations of those that aren't. The registry lock
+// is released before any consumer is locked, so consumers may call back in here.
+fn live_consumers() -> Vec<(MemoryCacheKind, Arc<dyn MemoryConsumer>)> {
+    let mut consumers = CONSUMERS.lock().unwrap();
+    consumers.retain(|registered| registered.consumer.strong_count() > 0);
+    consumers
+        .iter()
+        .filter_map(|registered| registered.consumer.upgrade().map(|consumer| (registered.kind, consumer)))
+        .collect()
+}
+
+pub fn set_budget(bytes: u64) {
+    BUDGET_BYTES.store(bytes, Ordering::Relaxed);
+    enforce_budget();
+}
+
+/// Evict from the least recently used consumers until usage is back within the budget.
+/// Consumers call this after allocating.
+pub fn enforce_budget() {
+    let budget = BUDGET_BYTES.load(Ordering::Relaxed) as usize;
+    let mut consumers = live_consumers();
+    let used: usize = consumers.iter().map(|(_, consumer)| consumer.memory_bytes()).sum();
+    if used <= budget {
+        return;
+    }
+
+    consumers.sort_by_key(|(_, consumer)| consumer.last_used());
+    let mut excess = used - budget;
+    for (kind, consumer) in consumers {
+        let freed = consumer.evict(excess);
+        if freed > 0 {
+            debug!("Evicted {} bytes from {:?} to stay within the memory budget", freed, kind);
+            EVICTED_BYTES.fetch_add(freed as u64, Ordering::Relaxed);
+        }
+        excess = excess.saturating_sub(freed);
+        if excess == 0 {
+            break;
+        }
+    }
+}
+
+pub fn usage() -> MemoryUsage {
+    let mut usage = MemoryUsage {
+        budget_bytes: BUDGET_BYTES.load(Ordering::Relaxed),
+        evicted_bytes: EVICTED_BYTES.load(Ordering::Relaxed),
+        ..Default::default()
+    };
+    for (kind, consumer) in live_consumers() {
+        let bytes = consumer.memory_bytes() as u64;
+        match kind {
+            MemoryCacheKind::FrameCache => usage.frame_cache_bytes += bytes,
+            MemoryCacheKind::BufferPool => usage.buffer_pool_bytes += bytes,
+        }
+        usage.used_bytes += bytes;
+    }
+    usage
+}
@@ -1,2 +1,11 @@
 pub mod types;
-pub mod logging; 
\ No newline at end of file
+pub mod logging;
+pub mod gst_log;
+pub mod error;
+pub mod media_uri;
+pub mod resources;
+pub mod memory;
+pub mod plugins;
+pub mod decoders;
+pub mod timecode;
+pub mod timeline_json;
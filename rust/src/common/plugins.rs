@@ -0,0 +1,164 @@
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::glib::translate::IntoGlib;
+use gst::prelude::*;
+use gstreamer_pbutils as gst_pbutils;
+
+use crate::common::types::{CodecInfo, PlaybackEvent, SystemCapabilities};
+
+// GStreamer module that provides a decoder or demuxer for each media type, for telling the
+// user what to install; distributions package these under similar names
+const CODEC_PACKAGES: [(&str, &str); 24] = [
+    ("video/x-h264", "gst-libav"),
+    ("video/x-h265", "gst-libav"),
+    ("video/mpeg", "gst-libav"),
+    ("video/x-prores", "gst-libav"),
+    ("video/x-dnxhd", "gst-libav"),
+    ("video/x-divx", "gst-libav"),
+    ("video/x-msmpeg", "gst-libav"),
+    ("video/x-wmv", "gst-libav"),
+    ("audio/mpeg", "gst-libav"),
+    ("audio/x-ac3", "gst-libav"),
+    ("audio/x-eac3", "gst-libav"),
+    ("audio/x-wma", "gst-libav"),
+    ("video/x-av1", "gst-plugins-bad"),
+    ("video/x-vp8", "gst-plugins-good"),
+    ("video/x-vp9", "gst-plugins-good"),
+    ("audio/x-flac", "gst-plugins-good"),
+    ("video/quicktime", "gst-plugins-good"),
+    ("video/x-matroska", "gst-plugins-good"),
+    ("video/x-msvideo", "gst-plugins-good"),
+    ("audio/x-opus", "gst-plugins-base"),
+    ("audio/x-vorbis", "gst-plugins-base"),
+    ("video/x-theora", "gst-plugins-base"),
+    ("application/ogg", "gst-plugins-base"),
+    ("video/mpegts", "gst-plugins-bad"),
+];
+
+/// GStreamer module to install for a decoder of `media_type`, if it's a well-known one
+pub fn suggested_package(media_type: &str) -> Option<String> {
+    CODEC_PACKAGES
+        .iter()
+        .find(|(codec, _)| *codec == media_type)
+        .map(|(_, package)| package.to_string())
+}
+
+/// Turn a decodebin "missing-plugin" element message into a `MissingCodec` event
+pub fn parse_missing_plugin(message: &gst::MessageRef) -> Option<PlaybackEvent> {
+    let structure = message.structure().filter(|s| s.name() == "missing-plugin")?;
+    let plugin_type = structure.get::<String>("type").unwrap_or_default();
+    let (caps, media_type) = match structure.get::<gst::Caps>("detail") {
+        Ok(caps) => {
+            let media_type = caps.structure(0).map(|s| s.name().to_string()).unwrap_or_default();
+            (caps.to_string(), media_type)
+        }
+        // Elements and URI handlers are named by a string rather than caps
+        Err(_) => {
+            let detail = structure.get::<String>("detail").unwrap_or_default();
+            (detail.clone(), detail)
+        }
+    };
+    let description = structure
+        .get::<String>("name")
+        .ok()
+        .or_else(|| structure.get::<gst::Caps>("detail").ok().map(|caps| match plugin_type.as_str() {
+            "encoder" => gst_pbutils::pb_utils_get_encoder_description(&caps).to_string(),
+            _ => gst_pbutils::pb_utils_get_decoder_description(&caps).to_string(),
+        }))
+        .unwrap_or_else(|| format!("{} {}", media_type, plugin_type));
+
+    Some(PlaybackEvent::MissingCodec {
+        suggested_package: suggested_package(&media_type),
+        caps,
+        description,
+    })
+}
+
+fn max_dimension(caps: &gst::Caps, field: &str) -> Option<i32> {
+    caps.iter()
+        .filter_map(|s| s.get::<gst::IntRange<i32>>(field).map(|range| range.max()).ok())
+        .max()
+}
+
+/// Whether a decoder or encoder runs on a hardware block
+pub fn is_hardware(factory: &gst::ElementFactory) -> bool {
+    factory.klass().contains("Hardware")
+}
+
+fn codec_info(factory: &gst::ElementFactory, direction: gst::PadDirection) -> Option<CodecInfo> {
+    let klass = factory.klass();
+    let is_video = klass.contains("Video");
+    if !is_video && !klass.contains("Audio") {
+        return None;
+    }
+    // Decoders are described by their input and encoders by their output
+    let caps: Vec<gst::Caps> = factory
+        .static_pad_templates()
+        .iter()
+        .filter(|template| template.direction() == direction)
+        .map(|template| template.caps())
+        .collect();
+    let mut media_types: Vec<String> = caps.iter()
+        .flat_map(|caps| caps.iter().map(|s| s.name().to_string()).collect::<Vec<_>>())
+        .filter(|name| !name.ends_with("/x-raw"))
+        .collect();
+    media_types.sort();
+    media_types.dedup();
+    if media_types.is_empty() {
+        return None;
+    }
+
+    // Frame size limits are on the raw side
+    let raw_caps: Vec<gst::Caps> = factory
+        .static_pad_templates()
+        .iter()
+        .filter(|template| template.direction() != direction)
+        .map(|template| template.caps())
+        .collect();
+    Some(CodecInfo {
+        factory_name: factory.name().to_string(),
+        long_name: factory.longname().to_string(),
+        media_types,
+        is_video,
+        hardware: is_hardware(factory),
+        rank: factory.rank().into_glib(),
+        max_width: raw_caps.iter().filter_map(|caps| max_dimension(caps, "width")).max(),
+        max_height: raw_caps.iter().filter_map(|caps| max_dimension(caps, "height")).max(),
+    })
+}
+
+fn codecs(kind: gst::ElementFactoryType, direction: gst::PadDirection) -> Vec<CodecInfo> {
+    let mut codecs: Vec<CodecInfo> = gst::ElementFactory::factories_with_type(kind, gst::Rank::NONE)
+        .iter()
+        .filter_map(|factory| codec_info(factory, direction))
+        .collect();
+    codecs.sort_by(|a, b| b.rank.cmp(&a.rank).then_with(|| a.factory_name.cmp(&b.factory_name)));
+    codecs
+}
+
+fn has_element(factory: &str) -> bool {
+    gst::ElementFactory::find(factory).is_some()
+}
+
+/// Installed decoders and encoders with their limits, and GPU API availability, so the app
+/// can disable what can't work before the user tries it
+pub fn system_capabilities() -> Result<SystemCapabilities> {
+    gst::init().map_err(|e| anyhow!("Failed to initialize GStreamer: {}", e))?;
+    let decoders = codecs(gst::ElementFactoryType::DECODER, gst::PadDirection::Sink);
+    let encoders = codecs(gst::ElementFactoryType::ENCODER, gst::PadDirection::Src);
+    let video_max = |codecs: &[CodecInfo], dimension: fn(&CodecInfo) -> Option<i32>| {
+        codecs.iter().filter(|codec| codec.is_video).filter_map(dimension).max()
+    };
+
+    Ok(SystemCapabilities {
+        gstreamer_version: gst::version_string().to_string(),
+        max_decode_width: video_max(&decoders, |codec| codec.max_width),
+        max_decode_height: video_max(&decoders, |codec| codec.max_height),
+        max_encode_width: video_max(&encoders, |codec| codec.max_width),
+        max_encode_height: video_max(&encoders, |codec| codec.max_height),
+        decoders,
+        encoders,
+        gl_available: has_element("glupload") && has_element("glvideomixer"),
+        vulkan_available: has_element("vulkanupload"),
+    })
+}
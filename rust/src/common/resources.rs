@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+use log::{debug, warn};
+
+use crate::common::types::{ActiveResource, ResourceKind};
+
+struct TrackedResource {
+    description: String,
+    engine_handle: Option<i64>,
+    created: Instant,
+    leaked: bool,
+}
+
+static NEXT_OWNER_ID: AtomicU64 = AtomicU64::new(1);
+
+lazy_static::lazy_static! {
+    // A player holds at most one resource of each kind, so they're keyed by owner and kind
+    static ref RESOURCES: Mutex<HashMap<(u64, ResourceKind), TrackedResource>> = Mutex::new(HashMap::new());
+}
+
+/// Id for a new resource owner, e.g. a player
+pub fn next_owner_id() -> u64 {
+    NEXT_OWNER_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Record that `owner` now holds a resource of `kind`, replacing the one it held before
+pub fn track(owner: u64, kind: ResourceKind, engine_handle: Option<i64>, description: impl Into<String>) {
+    let description = description.into();
+    debug!("Player {} acquired {:?}: {}", owner, kind, description);
+    RESOURCES.lock().unwrap().insert((owner, kind), TrackedResource {
+        description,
+        engine_handle,
+        created: Instant::now(),
+        leaked: false,
+    });
+}
+
+/// Record that `owner` released its resource of `kind`, if it held one
+pub fn release(owner: u64, kind: ResourceKind) {
+    match RESOURCES.lock().unwrap().remove(&(owner, kind)) {
+        Some(resource) if resource.leaked => debug!("Player {} released leaked {:?}", owner, kind),
+        Some(_) => debug!("Player {} released {:?}", owner, kind),
+        None => {}
+    }
+}
+
+fn to_active(&(owner_id, kind): &(u64, ResourceKind), resource: &TrackedResource) -> ActiveResource {
+    ActiveResource {
+        owner_id,
+        kind,
+        description: resource.description.clone(),
+        engine_handle: resource.engine_handle,
+        age_ms: resource.created.elapsed().as_millis() as u64,
+        leaked: resource.leaked,
+    }
+}
+
+/// Every resource currently held, oldest first
+pub fn active_resources() -> Vec<ActiveResource> {
+    let mut resources: Vec<ActiveResource> = RESOURCES.lock().unwrap()
+        .iter()
+        .map(|(key, resource)| to_active(key, resource))
+        .collect();
+    resources.sort_by(|a, b| b.age_ms.cmp(&a.age_ms));
+    resources
+}
+
+/// Check that `owner` released everything after disposing, returning what it leaked. Leaked
+/// resources stay listed, marked as leaked, until something releases them.
+pub fn verify_released(owner: u64) -> Vec<ActiveResource> {
+    let mut resources = RESOURCES.lock().unwrap();
+    resources
+        .iter_mut()
+        .filter(|((id, _), _)| *id == owner)
+        .map(|(key, resource)| {
+            resource.leaked = true;
+            to_active(key, resource)
+        })
+        .inspect(|leaked| warn!("Player {} leaked {:?} after dispose: {}", owner, leaked.kind, leaked.description))
+        .collect()
+}
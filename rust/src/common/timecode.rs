@@ -0,0 +1,126 @@
+use anyhow::{anyhow, Result};
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+
+fn invalid(message: String) -> anyhow::Error {
+    anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, message))
+}
+
+fn check_rate(fps_num: u32, fps_den: u32) -> Result<()> {
+    if fps_num == 0 || fps_den == 0 {
+        return Err(invalid(format!("Invalid frame rate {}/{}", fps_num, fps_den)));
+    }
+    Ok(())
+}
+
+/// Frames counted per timecode second, e.g. 30 for 29.97
+fn nominal_fps(fps_num: u32, fps_den: u32) -> i64 {
+    ((fps_num as f64 / fps_den as f64).round() as i64).max(1)
+}
+
+/// Frame numbers skipped each minute (except every tenth) when the rate has a drop-frame form:
+/// 2 for 29.97 and 4 for 59.94
+fn dropped_per_minute(fps_num: u32, fps_den: u32) -> Option<i64> {
+    let nominal = nominal_fps(fps_num, fps_den);
+    (fps_den == 1001 && nominal % 30 == 0).then_some(nominal / 15)
+}
+
+/// Whether drop-frame timecode exists for this rate (29.97 and 59.94)
+pub fn supports_drop_frame(fps_num: u32, fps_den: u32) -> bool {
+    fps_den != 0 && dropped_per_minute(fps_num, fps_den).is_some()
+}
+
+/// Start of `frame` in whole milliseconds, rounded up so `ms_to_frame` maps it back to `frame`
+pub fn frame_to_ms(frame: i64, fps_num: u32, fps_den: u32) -> i64 {
+    let num = fps_num.max(1) as i128;
+    let scaled = frame as i128 * 1000 * fps_den as i128;
+    scaled.div_euclid(num) as i64 + (scaled.rem_euclid(num) != 0) as i64
+}
+
+/// Frame shown at `ms`
+pub fn ms_to_frame(ms: i64, fps_num: u32, fps_den: u32) -> i64 {
+    let den = fps_den.max(1) as i128 * 1000;
+    (ms as i128 * fps_num as i128).div_euclid(den) as i64
+}
+
+/// Timecode of a frame count, "HH:MM:SS:FF", or "HH:MM:SS;FF" for drop-frame. Drop-frame
+/// is ignored for rates without a drop-frame form. Hours keep counting past 24.
+pub fn frame_to_timecode(frame: i64, fps_num: u32, fps_den: u32, drop_frame: bool) -> String {
+    let nominal = nominal_fps(fps_num, fps_den.max(1));
+    let sign = if frame < 0 { "-" } else { "" };
+    let mut label = frame.abs();
+    let drop = if drop_frame { dropped_per_minute(fps_num, fps_den.max(1)) } else { None };
+    if let Some(drop) = drop {
+        // Count back in the frame numbers that drop-frame skips
+        let per_minute = nominal * 60 - drop;
+        let per_ten_minutes = nominal * 600 - drop * 9;
+        let tens = label / per_ten_minutes;
+        let rest = label % per_ten_minutes;
+        label += drop * 9 * tens;
+        if rest > drop {
+            label += drop * ((rest - drop) / per_minute);
+        }
+    }
+
+    let frames = label % nominal;
+    let seconds = label / nominal;
+    format!(
+        "{}{:02}:{:02}:{:02}{}{:02}",
+        sign,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        if drop.is_some() { ';' } else { ':' },
+        frames,
+    )
+}
+
+/// Frame count of a timecode. Any `;` or `.` separator marks it as drop-frame, and a
+/// drop-frame timecode naming a skipped frame number is rejected.
+pub fn timecode_to_frame(timecode: &str, fps_num: u32, fps_den: u32) -> Result<i64> {
+    check_rate(fps_num, fps_den)?;
+    let text = timecode.trim();
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let drop_frame = text.contains(';') || text.contains('.');
+    let fields: Vec<&str> = text.split([':', ';', '.']).collect();
+    let parsed: Option<Vec<i64>> = fields.iter()
+        .map(|field| {
+            Some(field)
+                .filter(|field| !field.is_empty() && field.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|field| field.parse().ok())
+        })
+        .collect();
+    let [hours, minutes, seconds, frames] = parsed.as_deref() else {
+        return Err(invalid(format!("\"{}\" is not a HH:MM:SS:FF timecode", timecode)));
+    };
+    let (hours, minutes, seconds, frames) = (*hours, *minutes, *seconds, *frames);
+
+    let nominal = nominal_fps(fps_num, fps_den);
+    if minutes >= 60 || seconds >= 60 || frames >= nominal {
+        return Err(invalid(format!("Timecode \"{}\" is out of range at {}/{} fps", timecode, fps_num, fps_den)));
+    }
+    let total_minutes = hours * 60 + minutes;
+    let mut frame = (total_minutes * 60 + seconds) * nominal + frames;
+    if drop_frame {
+        let drop = dropped_per_minute(fps_num, fps_den)
+            .ok_or_else(|| invalid(format!("{}/{} fps has no drop-frame timecode", fps_num, fps_den)))?;
+        if seconds == 0 && frames < drop && minutes % 10 != 0 {
+            return Err(invalid(format!("Drop-frame timecode \"{}\" names a skipped frame", timecode)));
+        }
+        frame -= drop * (total_minutes - total_minutes / 10);
+    }
+    Ok(if negative { -frame } else { frame })
+}
+
+/// Timecode of the frame shown at `ms`
+pub fn format_timecode(ms: i64, fps_num: u32, fps_den: u32, drop_frame: bool) -> String {
+    frame_to_timecode(ms_to_frame(ms, fps_num, fps_den.max(1)), fps_num, fps_den, drop_frame)
+}
+
+/// Start of the frame a timecode names, in milliseconds
+pub fn parse_timecode(timecode: &str, fps_num: u32, fps_den: u32) -> Result<i64> {
+    Ok(frame_to_ms(timecode_to_frame(timecode, fps_num, fps_den)?, fps_num, fps_den))
+}
@@ -0,0 +1,35 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::types::TimelineData;
+
+// Bumped when a change to TimelineData can't be read by `#[serde(default)]` alone
+const FORMAT_VERSION: u32 = 1;
+
+/// The timeline as saved in project files
+#[derive(Serialize, Deserialize)]
+struct TimelineDocument {
+    version: u32,
+    timeline: TimelineData,
+}
+
+/// Pretty-printed so project files diff line by line
+pub fn to_json(timeline: &TimelineData) -> Result<String> {
+    let document = TimelineDocument { version: FORMAT_VERSION, timeline: timeline.clone() };
+    serde_json::to_string_pretty(&document).map_err(|e| anyhow!("Failed to serialize timeline: {}", e))
+}
+
+/// Read a timeline written by `to_json`; fields added since it was written take their defaults
+pub fn from_json(json: &str) -> Result<TimelineData> {
+    let invalid = |message: String| anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, message));
+    let document: TimelineDocument = serde_json::from_str(json)
+        .map_err(|e| invalid(format!("Invalid timeline JSON: {}", e)))?;
+    if document.version > FORMAT_VERSION {
+        return Err(invalid(format!(
+            "Timeline was saved by a newer version (format {}, this build reads up to {})",
+            document.version, FORMAT_VERSION,
+        )));
+    }
+    Ok(document.timeline)
+}
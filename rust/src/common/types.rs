@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+use crate::common::error::FlipEditError;
+use crate::common::memory::{self, MemoryConsumer};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrameData {
@@ -10,13 +13,72 @@ pub struct FrameData {
     pub texture_id: Option<u64>, // GPU texture ID for direct rendering
 }
 
+/// Encoding of an extracted thumbnail
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThumbnailFormat {
+    /// Tightly packed RGBA rows, ready for a texture or `decodeImageFromPixels`
+    #[default]
+    Rgba,
+    /// JPEG file bytes; far smaller, for caching thumbnails on disk
+    Jpeg,
+}
+
+/// A frame of a source file scaled down for display, e.g. on the timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Thumbnail {
+    /// Source position the frame was taken at
+    pub position_ms: u64,
+    pub width: u32,
+    pub height: u32,
+    pub format: ThumbnailFormat,
+    pub data: Vec<u8>,
+}
+
 // Frame buffer pool for reusing allocations (still used for CPU fallback)
 pub struct FrameBufferPool {
-    buffers: Arc<Mutex<VecDeque<Vec<u8>>>>,
+    buffers: Arc<PooledBuffers>,
     max_capacity: usize,
     buffer_size: usize,
 }
 
+// Idle buffers of a pool, oldest first; they count against the memory budget
+struct PooledBuffers {
+    buffers: Mutex<VecDeque<Vec<u8>>>,
+    last_used: Mutex<Instant>,
+}
+
+impl PooledBuffers {
+    fn lock(&self) -> std::sync::LockResult<std::sync::MutexGuard<'_, VecDeque<Vec<u8>>>> {
+        if let Ok(mut last_used) = self.last_used.lock() {
+            *last_used = Instant::now();
+        }
+        self.buffers.lock()
+    }
+}
+
+impl MemoryConsumer for PooledBuffers {
+    fn memory_bytes(&self) -> usize {
+        self.buffers.lock().map(|buffers| buffers.iter().map(Vec::capacity).sum()).unwrap_or(0)
+    }
+
+    fn evict(&self, bytes: usize) -> usize {
+        let mut freed = 0;
+        if let Ok(mut buffers) = self.buffers.lock() {
+            while freed < bytes {
+                match buffers.pop_front() {
+                    Some(buffer) => freed += buffer.capacity(),
+                    None => break,
+                }
+            }
+        }
+        freed
+    }
+
+    fn last_used(&self) -> Instant {
+        *self.last_used.lock().unwrap()
+    }
+}
+
 // Texture ID data for GPU-centric rendering
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TextureFrame {
@@ -35,8 +97,15 @@ impl FrameBufferPool {
             buffers.push_back(vec![0u8; buffer_size]);
         }
         
+        let buffers = Arc::new(PooledBuffers {
+            buffers: Mutex::new(buffers),
+            last_used: Mutex::new(Instant::now()),
+        });
+        memory::register(MemoryCacheKind::BufferPool, &buffers);
+        memory::enforce_budget();
+        
         Self {
-            buffers: Arc::new(Mutex::new(buffers)),
+            buffers,
             max_capacity: initial_capacity.max(16), // Increased buffer pool size
             buffer_size,
         }
@@ -53,16 +122,37 @@ impl FrameBufferPool {
             }
         }
         
-        // Create new buffer if pool is empty
-        vec![0u8; self.buffer_size]
+        // Create new buffer if pool is empty; it counts against the budget once returned
+        let buffer = vec![0u8; self.buffer_size];
+        memory::enforce_budget();
+        buffer
+    }
+
+    /// Copy `data` into a pooled buffer, only allocating when the pool is empty or too small
+    pub fn buffer_from_slice(&self, data: &[u8]) -> Vec<u8> {
+        let pooled = self.buffers.lock().ok().and_then(|mut buffers| buffers.pop_front());
+        match pooled {
+            Some(mut buffer) => {
+                buffer.clear();
+                buffer.extend_from_slice(data);
+                buffer
+            }
+            None => {
+                let buffer = data.to_vec();
+                memory::enforce_budget();
+                buffer
+            }
+        }
     }
 
+    /// Pool `buffer` for reuse. The budget was enforced when it was allocated, so returning
+    /// one on every frame doesn't walk the consumers.
     pub fn return_buffer(&self, buffer: Vec<u8>) {
         if let Ok(mut buffers) = self.buffers.lock() {
+            // If pool is full, buffer will be dropped
             if buffers.len() < self.max_capacity {
                 buffers.push_back(buffer);
             }
-            // If pool is full, buffer will be dropped
         }
     }
 
@@ -78,6 +168,7 @@ impl FrameBufferPool {
                     buffers.push_back(vec![0u8; new_size]);
                 }
             }
+            memory::enforce_budget();
         }
     }
 }
@@ -140,6 +231,63 @@ pub struct TimelineClip {
     pub preview_position_y: f64,
     pub preview_width: f64,
     pub preview_height: f64,
+    /// 0.0 (transparent) to 1.0 (opaque)
+    #[serde(default = "default_preview_opacity")]
+    pub preview_opacity: f64,
+    /// Streams of the source this clip uses, e.g. `Audio` to drop a clip's picture
+    #[serde(default)]
+    pub track_type: TrackType,
+    /// How the picture fills the preview box
+    #[serde(default)]
+    pub fit_mode: ClipFitMode,
+    #[serde(default)]
+    pub orientation: ClipOrientation,
+    /// Pan and zoom over the source; empty uses the fit mode's crop
+    #[serde(default)]
+    pub crop_keyframes: Vec<CropKeyframe>,
+    /// The clip's own audio effects, applied before its track's
+    #[serde(default)]
+    pub audio_effects: Vec<TrackAudioEffect>,
+    /// Tone mapping forced on or off for this clip; `None` follows the player setting
+    #[serde(default)]
+    pub tone_mapping: Option<bool>,
+    /// Length of the crossfade from the clip this one overlaps on its track, 0 for a cut
+    #[serde(default)]
+    pub transition_in_ms: i32,
+}
+
+/// Which streams a track or clip plays. A clip plays a stream only if both it and its track
+/// allow it, so an audio track never shows video.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum TrackType {
+    #[default]
+    Both,
+    Video,
+    Audio,
+}
+
+impl TrackType {
+    pub fn has_video(self) -> bool {
+        self != TrackType::Audio
+    }
+
+    pub fn has_audio(self) -> bool {
+        self != TrackType::Video
+    }
+
+    /// Streams a clip of this type plays on a track of type `track`, `None` if neither
+    pub fn on_track(self, track: TrackType) -> Option<TrackType> {
+        match (self, track) {
+            (TrackType::Both, track) => Some(track),
+            (clip, TrackType::Both) => Some(clip),
+            (clip, track) if clip == track => Some(clip),
+            _ => None,
+        }
+    }
+}
+
+fn default_preview_opacity() -> f64 {
+    1.0
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -147,9 +295,1003 @@ pub struct TimelineTrack {
     pub id: i32,
     pub name: String,
     pub clips: Vec<TimelineClip>,
+    /// Effect chain applied to the track's audio, in order
+    #[serde(default)]
+    pub audio_effects: Vec<TrackAudioEffect>,
+    /// Volume automation, sorted by time; e.g. generated by ducking
+    #[serde(default)]
+    pub volume_keyframes: Vec<VolumeKeyframe>,
+    #[serde(default)]
+    pub track_type: TrackType,
+    /// Compositing order: higher priorities draw on top, equal ones in list order (later on top)
+    #[serde(default)]
+    pub priority: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimelineData {
     pub tracks: Vec<TimelineTrack>,
-} 
\ No newline at end of file
+    /// The caption track, sorted by start time
+    #[serde(default)]
+    pub captions: Vec<CaptionCue>,
+    #[serde(default)]
+    pub markers: Vec<TimelineMarker>,
+    /// Crossfade where an added or moved clip overlaps another clip on its track
+    #[serde(default)]
+    pub auto_transition: bool,
+}
+
+/// A named point on the timeline, e.g. the start of a chapter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineMarker {
+    pub position_ms: i32,
+    pub name: String,
+}
+
+/// Descriptive tags and chapters written into an exported file's container
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExportMetadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub description: Option<String>,
+    /// ISO 8601, e.g. "2024-05-01T12:00:00Z"
+    pub creation_date: Option<String>,
+    /// Write the timeline markers as chapters; only Matroska stores them
+    #[serde(default)]
+    pub chapters: bool,
+}
+
+/// One subtitle on the caption track, shown from `start_ms` until `end_ms` on the timeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaptionCue {
+    pub id: i32,
+    pub start_ms: i32,
+    pub end_ms: i32,
+    /// Plain text; lines are separated by `\n`
+    pub text: String,
+    #[serde(default)]
+    pub style: CaptionStyle,
+}
+
+/// Per-cue formatting, kept to what both SRT and WebVTT can carry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct CaptionStyle {
+    pub bold: bool,
+    pub italic: bool,
+    /// Top of the frame instead of the bottom, e.g. to keep clear of on-screen text
+    pub top: bool,
+}
+
+/// Look of the caption track as drawn over the picture
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaptionAppearance {
+    /// Pango font family, e.g. "Sans" or "Noto Sans"
+    pub font_family: String,
+    /// Point size at the output resolution
+    pub font_size: u32,
+    pub outline: bool,
+    pub shadow: bool,
+}
+
+impl Default for CaptionAppearance {
+    fn default() -> Self {
+        Self { font_family: "Sans".to_string(), font_size: 28, outline: true, shadow: false }
+    }
+}
+
+/// Sidecar subtitle file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CaptionFormat {
+    Srt,
+    WebVtt,
+}
+
+/// Exact frame rate, e.g. 30000/1001 for 29.97 or 24000/1001 for 23.976
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrameRate {
+    pub numerator: i32,
+    pub denominator: i32,
+}
+
+impl FrameRate {
+    pub fn fps(self) -> f64 {
+        self.numerator as f64 / self.denominator.max(1) as f64
+    }
+
+    /// Frame shown at a position in whole milliseconds. Positions are truncated from
+    /// nanoseconds, which at fractional rates can land just before the frame's true start,
+    /// so they count from the middle of the millisecond.
+    pub fn frame_at_ms(self, position_ms: u64) -> u64 {
+        let numerator = self.numerator.max(1) as u128;
+        let denominator = self.denominator.max(1) as u128;
+        ((position_ms as u128 * 2 + 1) * numerator / (2000 * denominator)) as u64
+    }
+
+    /// First whole millisecond of `frame`
+    pub fn frame_start_ms(self, frame: u64) -> u64 {
+        crate::common::timecode::frame_to_ms(frame as i64, self.numerator.max(1) as u32, self.denominator.max(1) as u32) as u64
+    }
+}
+
+impl Default for FrameRate {
+    fn default() -> Self {
+        Self { numerator: 30, denominator: 1 }
+    }
+}
+
+/// Width of a pixel relative to its height, e.g. 4/3 for HDV's 1440x1080 anamorphic frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PixelAspectRatio {
+    pub numerator: i32,
+    pub denominator: i32,
+}
+
+impl PixelAspectRatio {
+    pub const SQUARE: Self = Self { numerator: 1, denominator: 1 };
+
+    /// Width `width` pixels take up on a square-pixel display
+    pub fn display_width(self, width: i32) -> i32 {
+        (width as i64 * self.numerator as i64 / self.denominator.max(1) as i64) as i32
+    }
+}
+
+impl Default for PixelAspectRatio {
+    fn default() -> Self {
+        Self::SQUARE
+    }
+}
+
+/// Seek precision used when moving the playhead.
+/// `Fast` snaps to the nearest keyframe for instant feedback while dragging,
+/// `Accurate` decodes up to the exact requested position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ScrubMode {
+    Fast,
+    #[default]
+    Accurate,
+}
+
+/// Pipeline state as reported to Flutter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlaybackState {
+    Stopped,
+    Paused,
+    Playing,
+}
+
+/// Asynchronous playback notifications delivered through the playback event stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PlaybackEvent {
+    EndOfStream,
+    Error {
+        error: FlipEditError,
+    },
+    StateChanged {
+        state: PlaybackState,
+    },
+    /// The timeline got longer or shorter, after a load or an edit
+    DurationChanged {
+        duration_ms: u64,
+    },
+    /// Source buffering progress (0-100), e.g. for assets on slow network mounts
+    Buffering {
+        percent: i32,
+    },
+    /// The pipeline started prerolling after a state change or flushing seek
+    Prerolling,
+    /// Preroll completed and the current frame is ready (ASYNC_DONE)
+    Prerolled,
+    /// The rendered frame size changed, e.g. a portrait source was loaded into the source monitor
+    VideoSizeChanged {
+        width: u32,
+        height: u32,
+        /// Width to show the frame at, which differs from `width` for non-square pixels
+        display_width: u32,
+    },
+    /// The preview caps changed. Sent after `VideoSizeChanged`, and also when only the pixel
+    /// aspect ratio or frame rate changes, so the viewport can follow without polling
+    VideoInfoChanged {
+        width: u32,
+        height: u32,
+        par: PixelAspectRatio,
+        /// `None` for variable frame rate output
+        fps: Option<FrameRate>,
+    },
+    /// A clip needs a decoder or element that isn't installed. Sent instead of the generic
+    /// error that follows, so the UI can say what to install.
+    MissingCodec {
+        /// Caps of the stream that couldn't be decoded, or the missing element's name
+        caps: String,
+        /// Human readable name, e.g. "H.265 (Main Profile) decoder"
+        description: String,
+        /// GStreamer module providing it, e.g. "gst-libav", when known
+        suggested_package: Option<String>,
+    },
+    /// A hardware decoder failed on `source_path`. Its clips were rebuilt with software
+    /// decoders, which the player keeps using for that file, instead of playback stopping.
+    DecoderFallback {
+        source_path: String,
+        /// Factory name of the decoder that failed, e.g. "vah264dec"
+        decoder: String,
+        reason: String,
+    },
+    /// The watchdog is trying `stage` after a pipeline error or stalled playback
+    Recovering {
+        stage: RecoveryStage,
+        reason: String,
+    },
+    /// Playback stayed healthy after `stage`
+    Recovered {
+        stage: RecoveryStage,
+    },
+    /// Every recovery stage failed; the preview stays down until the timeline is reloaded
+    RecoveryFailed {
+        reason: String,
+    },
+}
+
+/// Steps the playback watchdog escalates through while a failure persists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecoveryStage {
+    /// Flushing seek to the playhead, restarting the streaming threads
+    FlushSeek,
+    /// Replace the video sink, then seek
+    RebuildSink,
+    /// Rebuild the whole pipeline at the playhead
+    RebuildPipeline,
+}
+
+/// Progress of a background timeline load, tagged with the job id returned when it started
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TimelineLoadEvent {
+    /// A clip's source was added to the pipeline (or skipped, if missing); `loaded` of `total` done
+    ClipLoaded {
+        job_id: u64,
+        clip_id: Option<i32>,
+        loaded: u32,
+        total: u32,
+    },
+    /// All clips are added and the pipeline is prerolling the first frame
+    Prerolling {
+        job_id: u64,
+    },
+    Completed {
+        job_id: u64,
+        duration_ms: u64,
+    },
+    Failed {
+        job_id: u64,
+        error: FlipEditError,
+    },
+}
+
+/// What the timeline player does when the playhead reaches the end
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EndBehavior {
+    /// Pause and rewind to the start so the next play starts over
+    Pause,
+    /// Jump back to the start and keep playing
+    Loop,
+    /// Pause on the final frame
+    #[default]
+    HoldLastFrame,
+    /// Pause and clear the preview to black
+    BlackOut,
+}
+
+/// Preview monitor a player renders into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MonitorRole {
+    /// Plays a single raw source file, e.g. for marking in/out points before editing
+    Source,
+    /// Plays the edited timeline
+    Program,
+}
+
+/// Which render path a player uses and why, for diagnosing slow playback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderPathInfo {
+    pub preferred: RenderPath,
+    pub active: RenderPath,
+    /// Whether clips are mixed on the GPU
+    pub gpu_mixing: bool,
+    /// GL driver strings of Flutter's context, when a GL path has captured it
+    pub gpu_vendor: Option<String>,
+    pub gpu_renderer: Option<String>,
+    pub gl_version: Option<String>,
+    /// Why `active` differs from `preferred`
+    pub fallback_reason: Option<String>,
+}
+
+/// A player created in the player registry and the texture it renders into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerTexture {
+    pub player_id: u32,
+    pub texture_id: i64,
+}
+
+/// Kind of resource a player holds and must release on dispose
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResourceKind {
+    Pipeline,
+    Texture,
+    Timer,
+    BusWatch,
+}
+
+/// A resource still held by a player, for finding leaks such as textures that outlived
+/// their Flutter engine across a hot restart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveResource {
+    /// Player that created the resource; unique for the life of the process
+    pub owner_id: u64,
+    pub kind: ResourceKind,
+    pub description: String,
+    /// Engine the owning player renders into, once it has a texture
+    pub engine_handle: Option<i64>,
+    pub age_ms: u64,
+    /// Still held after its player was disposed
+    pub leaked: bool,
+}
+
+/// What a block of budgeted memory is used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemoryCacheKind {
+    FrameCache,
+    BufferPool,
+}
+
+/// Memory held by frame caches and buffer pools, in bytes
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemoryUsage {
+    pub budget_bytes: u64,
+    pub used_bytes: u64,
+    pub frame_cache_bytes: u64,
+    pub buffer_pool_bytes: u64,
+    /// Freed by eviction since startup
+    pub evicted_bytes: u64,
+}
+
+/// Background render state of a heavy timeline range
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RenderStatus {
+    Pending,
+    Rendering {
+        percent: i32,
+    },
+    /// The cached file is used for this range from the next timeline load
+    Rendered,
+    Failed {
+        message: String,
+    },
+}
+
+/// A timeline range rendered to disk at preview resolution, for the UI's "rendered" bar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderSegment {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub status: RenderStatus,
+    pub cache_path: String,
+}
+
+/// A single edit applied to the live timeline without rebuilding the pipeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TimelineChange {
+    AddClip {
+        clip: TimelineClip,
+    },
+    MoveClip {
+        clip_id: i32,
+        track_id: i32,
+        start_time_on_track_ms: i32,
+    },
+    ResizeClip {
+        clip_id: i32,
+        start_time_on_track_ms: i32,
+        end_time_on_track_ms: i32,
+        start_time_in_source_ms: i32,
+        end_time_in_source_ms: i32,
+    },
+    RemoveClip {
+        clip_id: i32,
+    },
+    /// Shift caption cues at or after `at_ms` by `delta_ms`. Sent with the clip moves of a
+    /// ripple edit so captions stay with the picture; a negative delta removes the cues in
+    /// the closed gap and trims those overlapping it.
+    RippleCaptions {
+        at_ms: i32,
+        delta_ms: i32,
+    },
+}
+
+/// A change to the player's timeline, whether requested by the app or made by the player
+/// itself (audio sync moving clips, a recorded voice-over, generated ducking)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TimelineEvent {
+    ClipAdded {
+        clip: TimelineClip,
+    },
+    ClipMoved {
+        clip_id: i32,
+        track_id: i32,
+        start_time_on_track_ms: i32,
+        end_time_on_track_ms: i32,
+    },
+    ClipResized {
+        clip_id: i32,
+        start_time_on_track_ms: i32,
+        end_time_on_track_ms: i32,
+        start_time_in_source_ms: i32,
+        end_time_in_source_ms: i32,
+    },
+    ClipRemoved {
+        clip_id: i32,
+    },
+    /// The track's audio effect chain, an effect parameter, or its volume automation changed
+    EffectChanged {
+        track_id: i32,
+    },
+    /// The clip's own audio effect chain or an effect parameter changed
+    ClipEffectChanged {
+        clip_id: i32,
+    },
+    /// Cues were added, edited, removed, imported or rippled; see `get_caption_cues`
+    CaptionsChanged,
+}
+
+/// How trimming a clip affects the clips around it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EditMode {
+    /// Only the trimmed clip changes, leaving a gap or overlap
+    #[default]
+    Normal,
+    /// Later clips on the track move with the trimmed or moved clip, closing or opening the gap
+    Ripple,
+    /// The adjacent clip's edge moves with the trimmed edge, keeping the cut between them
+    Roll,
+}
+
+/// How decoded frames reach the Flutter texture
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum RenderPath {
+    /// RGBA frames downloaded to system memory and copied into a pixel-buffer texture
+    #[default]
+    CpuRgba,
+    /// Frames stay in GL memory shared with Flutter's GL context and are mixed on the GPU (Linux)
+    GlMemory,
+    /// Clips are converted, scaled and mixed in NV12 on the CPU, and only the mix is uploaded
+    /// and converted to RGBA by a GL shader (Linux)
+    Nv12,
+    /// Hardware-decoded DMA-BUF frames are imported into GL through EGLImage and mixed on the GPU,
+    /// so they never touch system memory (Linux, VA-API)
+    DmaBuf,
+}
+
+/// Everything the player knows about one clip, from `get_clip`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipInfo {
+    /// Timing, in/out points, preview box and clip settings as last sent to the player
+    pub clip: TimelineClip,
+    /// Where the picture is drawn in the output after fitting it into the preview box
+    pub display_x: i32,
+    pub display_y: i32,
+    pub display_width: i32,
+    pub display_height: i32,
+    /// HDR is tone mapped for this clip, from its override or the player setting
+    pub tone_mapping: bool,
+    pub is_hdr: bool,
+    /// Stored size of the source's video; `None` for audio-only files
+    pub source_width: Option<i32>,
+    pub source_height: Option<i32>,
+    pub source_pixel_aspect_ratio: Option<PixelAspectRatio>,
+    pub source_frame_rate: Option<FrameRate>,
+    pub source_color_space: Option<ColorSpace>,
+    /// Effect chain and volume automation of the clip's track
+    pub track_audio_effects: Vec<TrackAudioEffect>,
+    pub track_volume_keyframes: Vec<VolumeKeyframe>,
+    /// Timeline markers within the clip
+    pub markers: Vec<TimelineMarker>,
+}
+
+/// How a clip's picture fills its preview box when their aspect ratios differ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ClipFitMode {
+    /// Scale to the box exactly, distorting the picture
+    #[default]
+    Stretch,
+    /// Scale to fit inside the box, leaving bars
+    Fit,
+    /// Scale to cover the box, cropping the overflow
+    Fill,
+    /// Show at 100%, centred and cropped to the box
+    Original,
+}
+
+/// Clockwise rotation of a clip at a point on the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RotationKeyframe {
+    pub time_ms: u64,
+    pub degrees: f64,
+}
+
+/// Part of a clip's source frame, as fractions of its width and height
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SourceRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl SourceRect {
+    pub const FULL: Self = Self { x: 0.0, y: 0.0, width: 1.0, height: 1.0 };
+
+    /// Non-empty and inside the frame
+    pub fn is_valid(self) -> bool {
+        [self.x, self.y, self.width, self.height].iter().all(|v| v.is_finite())
+            && self.x >= 0.0
+            && self.y >= 0.0
+            && self.width > 0.0
+            && self.height > 0.0
+            && self.x + self.width <= 1.0 + f64::EPSILON
+            && self.y + self.height <= 1.0 + f64::EPSILON
+    }
+}
+
+impl Default for SourceRect {
+    fn default() -> Self {
+        Self::FULL
+    }
+}
+
+/// Part of a clip's source shown at a point on the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CropKeyframe {
+    pub time_ms: u64,
+    pub rect: SourceRect,
+}
+
+/// How a clip's picture is mirrored and turned before it's fitted into its preview box
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ClipOrientation {
+    /// Clockwise. Whole quarter turns turn the frame itself, so a quarter turn makes a
+    /// landscape clip portrait; the rest tilts the picture inside its frame.
+    pub rotation_degrees: f64,
+    /// Mirrored before rotating
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+    /// Rotation over time, interpolated linearly and held past the ends; replaces
+    /// `rotation_degrees` except for its quarter turns
+    pub rotation_keyframes: Vec<RotationKeyframe>,
+}
+
+/// Color space clips are converted to before mixing, so sources shot in different spaces match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ColorSpace {
+    /// SD material
+    Bt601,
+    #[default]
+    Bt709,
+    /// Wide gamut UHD material
+    Bt2020,
+}
+
+impl ColorSpace {
+    /// GStreamer caps `colorimetry` name
+    pub fn colorimetry(self) -> &'static str {
+        match self {
+            Self::Bt601 => "bt601",
+            Self::Bt709 => "bt709",
+            Self::Bt2020 => "bt2020",
+        }
+    }
+}
+
+/// Filter used when clip frames are resized to their preview box
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ScalingMethod {
+    /// Cheapest, but aliases on downscaled 4K sources
+    #[default]
+    Nearest,
+    Bilinear,
+    /// 4-tap filter, sharper than bilinear
+    FourTap,
+    Lanczos,
+}
+
+impl ScalingMethod {
+    /// `videoscale` `method` nick
+    pub fn nick(self) -> &'static str {
+        match self {
+            Self::Nearest => "nearest-neighbour",
+            Self::Bilinear => "bilinear",
+            Self::FourTap => "4-tap",
+            Self::Lanczos => "lanczos",
+        }
+    }
+}
+
+/// How preview pipelines convert and scale clip frames on the CPU
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConversionSettings {
+    /// Threads each `videoconvert` and `videoscale` splits a frame across; 0 for one per CPU core
+    pub threads: u32,
+    pub scaling_method: ScalingMethod,
+}
+
+impl Default for ConversionSettings {
+    fn default() -> Self {
+        Self { threads: 1, scaling_method: ScalingMethod::default() }
+    }
+}
+
+/// Output device changes reported by the audio handler, so the UI can show a toast
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AudioDeviceEvent {
+    /// The output device went away (e.g. headphones unplugged) or its stream failed
+    DeviceLost {
+        device_name: String,
+    },
+    /// Output was rebuilt on a new device, either after a loss or a default-device change
+    DeviceSwitched {
+        device_name: String,
+    },
+    /// No output device is available; playback continues silently until one appears
+    NoDevice,
+}
+
+/// Rolling per-frame timings of a player's video path over the last ~120 frames, in ms.
+/// With GPU mixing the GL upload and color conversion are reported as upload time.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PerformanceStats {
+    pub decode_ms: f64,
+    pub convert_ms: f64,
+    pub upload_ms: f64,
+    pub decode_max_ms: f64,
+    pub convert_max_ms: f64,
+    pub upload_max_ms: f64,
+    /// Frames delivered to the texture since the timeline was loaded
+    pub frames_rendered: u64,
+}
+
+/// An installed decoder or encoder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodecInfo {
+    pub factory_name: String,
+    pub long_name: String,
+    /// Compressed formats it handles, e.g. "video/x-h264"
+    pub media_types: Vec<String>,
+    pub is_video: bool,
+    /// Runs on a hardware block (VA-API, NVDEC/NVENC, VideoToolbox, D3D11...)
+    pub hardware: bool,
+    /// Autoplugging rank; decodebin prefers higher ranks
+    pub rank: i32,
+    /// Largest frame size its caps allow, when they're bounded
+    pub max_width: Option<i32>,
+    pub max_height: Option<i32>,
+}
+
+/// What this machine's GStreamer install can decode, encode and render with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemCapabilities {
+    pub gstreamer_version: String,
+    pub decoders: Vec<CodecInfo>,
+    pub encoders: Vec<CodecInfo>,
+    /// GL elements are installed, needed for GPU mixing
+    pub gl_available: bool,
+    pub vulkan_available: bool,
+    /// Largest frame any video decoder / encoder accepts, when bounded
+    pub max_decode_width: Option<i32>,
+    pub max_decode_height: Option<i32>,
+    pub max_encode_width: Option<i32>,
+    pub max_encode_height: Option<i32>,
+}
+
+/// Which decoders decodebin picks for playback
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DecoderPolicy {
+    /// The platform default: software on macOS, where VideoToolbox decoding has been
+    /// unreliable, and GStreamer's own ranks elsewhere
+    #[default]
+    Auto,
+    /// Hardware decoders ranked above software ones wherever both exist
+    HardwareFirst,
+    /// Hardware decoders never picked
+    SoftwareOnly,
+}
+
+/// A GStreamer debug log message captured for the log viewer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogLine {
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+    /// GStreamer's level name, e.g. "WARN" or "DEBUG"
+    pub level: String,
+    pub category: String,
+    /// Element or pad the message is about, e.g. "<compositor>"
+    pub object: Option<String>,
+    pub message: String,
+    /// Source location, as "file:line"
+    pub location: String,
+}
+
+/// Per-channel output levels in dBFS from the master audio output, for VU meters
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioLevels {
+    pub peak_db: Vec<f64>,
+    pub rms_db: Vec<f64>,
+    /// Peak with falloff, for meters with a peak-hold indicator
+    pub decay_db: Vec<f64>,
+}
+
+/// Sample-rate conversion quality for audio played through the cpal output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ResamplerQuality {
+    /// Cubic polynomial interpolation, lowest CPU cost
+    Fast,
+    /// Short windowed-sinc filter
+    #[default]
+    Balanced,
+    /// Long windowed-sinc filter with minimal aliasing, for critical listening
+    High,
+}
+
+/// Output sample rate of the audio engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AudioSampleRate {
+    #[default]
+    Hz44100,
+    Hz48000,
+    Hz96000,
+}
+
+impl AudioSampleRate {
+    pub fn hz(self) -> u32 {
+        match self {
+            Self::Hz44100 => 44_100,
+            Self::Hz48000 => 48_000,
+            Self::Hz96000 => 96_000,
+        }
+    }
+}
+
+/// Output channel layout of the audio engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AudioChannelLayout {
+    #[default]
+    Stereo,
+    /// 5.1 in SMPTE order (L, R, C, LFE, Ls, Rs)
+    Surround51,
+}
+
+impl AudioChannelLayout {
+    pub fn channels(self) -> u32 {
+        match self {
+            Self::Stereo => 2,
+            Self::Surround51 => 6,
+        }
+    }
+
+    /// GStreamer channel mask of the layout's speaker positions
+    pub fn channel_mask(self) -> u64 {
+        match self {
+            Self::Stereo => 0x3,
+            Self::Surround51 => 0x3f,
+        }
+    }
+}
+
+/// Format every audio path mixes and plays in: the timeline mixer, decoded file audio and
+/// the cpal output stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct AudioEngineSettings {
+    pub sample_rate: AudioSampleRate,
+    pub channel_layout: AudioChannelLayout,
+    #[serde(default)]
+    pub downmix: DownmixPolicy,
+}
+
+/// How 5.1/7.1 sources are folded into a stereo output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DownmixPolicy {
+    /// ITU-R BS.775 matrix: centre and surrounds at -3 dB, LFE dropped
+    #[default]
+    Stereo,
+    /// Only the centre channel, on both outputs, to check dialogue
+    DialogueCenter,
+    /// No explicit matrix; channels are kept when the output layout has room for them
+    Passthrough,
+}
+
+/// An audio effect in a track's chain, applied to every clip on the track
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackAudioEffect {
+    pub effect_id: u32,
+    /// GStreamer element, e.g. `equalizer-10bands` or `audiodynamic`
+    pub factory_name: String,
+    /// Parameter values by name, in the units described by `AudioEffectParameter`
+    pub parameters: HashMap<String, f64>,
+}
+
+/// An adjustable effect parameter, as introspected from its element
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioEffectParameter {
+    pub name: String,
+    pub nick: String,
+    pub description: String,
+    pub min: f64,
+    pub max: f64,
+    pub default_value: f64,
+    /// For choice parameters, the option names; the value is an index into them
+    pub options: Vec<String>,
+}
+
+/// A point of a track's volume automation, in timeline time
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VolumeKeyframe {
+    pub time_ms: u64,
+    pub gain_db: f64,
+}
+
+/// How a music track is ducked under a dialogue track
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DuckingSettings {
+    pub music_track_id: i32,
+    pub dialogue_track_id: i32,
+    /// Dialogue RMS level (dBFS) above which the music is ducked
+    pub threshold_db: f64,
+    /// How far the music is lowered, in dB (positive)
+    pub reduction_db: f64,
+    pub attack_ms: u64,
+    pub release_ms: u64,
+}
+
+/// What is heard from the timeline while a voice-over is recorded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VoiceoverPlayback {
+    /// Timeline audio is silenced so it can't bleed into the microphone
+    #[default]
+    Muted,
+    /// Timeline audio plays quietly, for use with headphones
+    Ducked,
+}
+
+/// An audio capture device that can be used for voice-over
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioInputDevice {
+    /// Stable identifier passed to `set_input_device`
+    pub id: String,
+    pub name: String,
+    pub is_default: bool,
+    pub is_selected: bool,
+    /// Captures what the system plays rather than a microphone
+    pub is_loopback: bool,
+}
+
+/// Placement of a clip after synchronizing a group of clips by their audio
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClipSyncResult {
+    pub clip_id: i32,
+    pub track_id: i32,
+    pub start_time_on_track_ms: i32,
+    /// Offset of the clip's in-point from the reference clip's in-point
+    pub offset_ms: i64,
+    /// Normalized correlation of the match, 0.0 to 1.0; 1.0 for the reference clip
+    pub confidence: f64,
+}
+
+/// How audio played through the cpal output stays in sync with video over long playback
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AudioClockMode {
+    /// Audio is resampled by up to 0.5% so it follows the pipeline clock
+    #[default]
+    ResampleToClock,
+    /// Audio plays untouched at the device's pace and video is delayed to follow it
+    AudioMaster,
+}
+
+/// Where a video player sends its audio, chosen when the player is created
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AudioOutputMode {
+    /// Decoded audio is handed to the cpal output thread, allowing custom routing and metering
+    #[default]
+    Cpal,
+    /// The pipeline plays audio through autoaudiosink, sharing one clock with video
+    GStreamer,
+}
+
+/// Loudness normalization and true-peak limiting of a recording's audio
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LoudnessSettings {
+    pub enabled: bool,
+    /// Integrated loudness target, e.g. -14 for streaming platforms or -23 for EBU R128
+    pub target_lufs: f64,
+    /// Ceiling for true (inter-sample) peaks, in dBTP
+    pub true_peak_db: f64,
+    /// Loudness range target in LU; None keeps the element's default
+    #[serde(default)]
+    pub loudness_range_lu: Option<f64>,
+}
+
+impl Default for LoudnessSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_lufs: -14.0,
+            true_peak_db: -1.0,
+            loudness_range_lu: None,
+        }
+    }
+}
+
+/// Restoration effects added to a track's or clip's effect chain with tuned starting parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioRestoration {
+    /// Broadband noise suppression (rnnoise, or WebRTC's suppressor)
+    Denoise,
+    /// Notches at 50 Hz mains hum and its harmonics at 100 and 150 Hz
+    Dehum50Hz,
+    /// Notches at 60 Hz mains hum and its harmonics at 120 and 180 Hz
+    Dehum60Hz,
+}
+
+/// What a screen recording captures and where it's written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreenCaptureSettings {
+    pub output_path: String,
+    /// Window to capture (X11 window id or HWND), or the PipeWire node granted by the
+    /// screencast portal on Wayland; None captures the whole desktop
+    pub window_id: Option<u64>,
+    /// On Wayland, the PipeWire remote fd from the portal's `OpenPipeWireRemote`; the node in
+    /// `window_id` is only reachable through it
+    #[serde(default)]
+    pub pipewire_fd: Option<i32>,
+    pub framerate: i32,
+    pub show_cursor: bool,
+    /// Also record what the system is playing
+    pub capture_system_audio: bool,
+    /// Normalization of the recorded system audio, e.g. to -14 LUFS for upload
+    #[serde(default)]
+    pub loudness: LoudnessSettings,
+    /// Tags written into the recording's container; a recording has no markers to make chapters of
+    #[serde(default)]
+    pub metadata: ExportMetadata,
+}
+
+/// Progress of a running screen recording, delivered through its event stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ScreenRecordingEvent {
+    Progress {
+        duration_ms: u64,
+        bytes_written: u64,
+    },
+    /// The file was finalized and can be imported
+    Finished {
+        output_path: String,
+        duration_ms: u64,
+    },
+    Error {
+        error: FlipEditError,
+    },
+}
+
+/// Destination and encoding of a live stream of the program output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveStreamSettings {
+    /// Ingest URL, e.g. rtmp://a.rtmp.youtube.com/live2 or srt://ingest.example.com:9000
+    pub url: String,
+    /// Appended to RTMP URLs as their last path segment, sent as the stream id over SRT
+    pub stream_key: String,
+    pub video_bitrate_kbps: u32,
+    pub audio_bitrate_kbps: u32,
+    pub framerate: i32,
+    /// Seconds between keyframes; most platforms ask for 2
+    pub keyframe_interval_secs: u32,
+    /// SRT receive latency; None keeps the library's 120ms
+    #[serde(default)]
+    pub srt_latency_ms: Option<u32>,
+    /// SRT encryption passphrase, 10 to 79 characters
+    #[serde(default)]
+    pub srt_passphrase: Option<String>,
+}
@@ -0,0 +1,69 @@
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{info, warn};
+
+use crate::chapters;
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::types::{ExportMetadata, TimelineMarker};
+
+/// Set `metadata` on a muxer before it starts, so it's written as container tags (MP4 and
+/// Matroska) and, when asked for, the markers as a chapter table (Matroska only)
+pub fn apply_to_muxer(muxer: &gst::Element, metadata: &ExportMetadata, markers: &[TimelineMarker], timeline_end_ms: i32) -> Result<()> {
+    let tag_setter = muxer.dynamic_cast_ref::<gst::TagSetter>()
+        .ok_or_else(|| anyhow!("{} doesn't take metadata tags", muxer.name()))?;
+    let mut tags = gst::TagList::new();
+    {
+        let tags = tags.get_mut().unwrap();
+        if let Some(title) = metadata.title.as_deref().filter(|s| !s.is_empty()) {
+            tags.add::<gst::tags::Title>(&title, gst::TagMergeMode::Replace);
+        }
+        if let Some(author) = metadata.author.as_deref().filter(|s| !s.is_empty()) {
+            tags.add::<gst::tags::Artist>(&author, gst::TagMergeMode::Replace);
+        }
+        if let Some(description) = metadata.description.as_deref().filter(|s| !s.is_empty()) {
+            tags.add::<gst::tags::Description>(&description, gst::TagMergeMode::Replace);
+        }
+        if let Some(creation_date) = metadata.creation_date.as_deref() {
+            let date_time = gst::DateTime::from_iso8601_string(creation_date).map_err(|_| {
+                anyhow!(FlipEditError::new(
+                    FlipEditErrorCode::InvalidArgument,
+                    format!("Creation date \"{}\" isn't ISO 8601", creation_date),
+                ))
+            })?;
+            tags.add::<gst::tags::DateTime>(&date_time, gst::TagMergeMode::Replace);
+        }
+    }
+    tag_setter.merge_tags(&tags, gst::TagMergeMode::Replace);
+
+    if metadata.chapters {
+        let chapters = chapters::timeline_chapters(markers, timeline_end_ms);
+        match muxer.dynamic_cast_ref::<gst::TocSetter>() {
+            Some(toc_setter) if !chapters.is_empty() => {
+                toc_setter.set_toc(Some(&chapter_toc(&chapters)));
+                info!("Writing {} chapters with {}", chapters.len(), muxer.name());
+            }
+            Some(_) => {}
+            None => warn!("{} can't store chapters; exporting without them", muxer.name()),
+        }
+    }
+    Ok(())
+}
+
+fn chapter_toc(chapters: &[(i32, i32, String)]) -> gst::Toc {
+    let mut edition = gst::TocEntry::new(gst::TocEntryType::Edition, "edition");
+    for (index, (start_ms, end_ms, title)) in chapters.iter().enumerate() {
+        let mut chapter = gst::TocEntry::new(gst::TocEntryType::Chapter, &format!("chapter{}", index + 1));
+        let mut tags = gst::TagList::new();
+        tags.get_mut().unwrap().add::<gst::tags::Title>(&title.as_str(), gst::TagMergeMode::Replace);
+        {
+            let chapter = chapter.get_mut().unwrap();
+            chapter.set_start_stop_times(*start_ms as i64 * 1_000_000, *end_ms as i64 * 1_000_000);
+            chapter.set_tags(tags);
+        }
+        edition.get_mut().unwrap().append_sub_entry(chapter);
+    }
+    let mut toc = gst::Toc::new(gst::TocScope::Global);
+    toc.get_mut().unwrap().append_entry(edition);
+    toc
+}
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.7.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -62202027;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1273942107;
 
 // Section: executor
 
@@ -74,7 +74,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_create_texture_impl(
             let api_engine_handle = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -126,7 +126,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_dispose_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -149,6 +149,53 @@ fn wire__crate__api__simple__GesTimelinePlayer_dispose_impl(
         },
     )
 }
+fn wire__crate__api__simple__GesTimelinePlayer_get_auto_transition_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "GesTimelinePlayer_get_auto_transition",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GESTimelinePlayer>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok =
+                    crate::api::simple::GESTimelinePlayer::get_auto_transition(&*api_that_guard)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__simple__GesTimelinePlayer_get_duration_ms_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -174,7 +221,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_get_duration_ms_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GESTimelinePlayer>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -189,9 +236,8 @@ fn wire__crate__api__simple__GesTimelinePlayer_get_duration_ms_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::simple::GESTimelinePlayer::get_duration_ms(&*api_that_guard),
-                )?;
+                let output_ok =
+                    crate::api::simple::GESTimelinePlayer::get_duration_ms(&*api_that_guard)?;
                 Ok(output_ok)
             })())
         },
@@ -318,7 +364,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_get_position_ms_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GESTimelinePlayer>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -333,9 +379,8 @@ fn wire__crate__api__simple__GesTimelinePlayer_get_position_ms_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::simple::GESTimelinePlayer::get_position_ms(&*api_that_guard),
-                )?;
+                let output_ok =
+                    crate::api::simple::GESTimelinePlayer::get_position_ms(&*api_that_guard)?;
                 Ok(output_ok)
             })())
         },
@@ -414,7 +459,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_is_playing_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GESTimelinePlayer>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -429,9 +474,8 @@ fn wire__crate__api__simple__GesTimelinePlayer_is_playing_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::simple::GESTimelinePlayer::is_playing(&*api_that_guard),
-                )?;
+                let output_ok =
+                    crate::api::simple::GESTimelinePlayer::is_playing(&*api_that_guard)?;
                 Ok(output_ok)
             })())
         },
@@ -514,7 +558,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_load_timeline_impl(
                 <crate::common::types::TimelineData>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -561,8 +605,8 @@ fn wire__crate__api__simple__GesTimelinePlayer_new_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::GESTimelinePlayer::new())?;
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
+                let output_ok = crate::api::simple::GESTimelinePlayer::new()?;
                 Ok(output_ok)
             })())
         },
@@ -595,7 +639,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_pause_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -645,7 +689,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_play_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -696,7 +740,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_seek_to_position_impl(
             let api_position_ms = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -721,6 +765,59 @@ fn wire__crate__api__simple__GesTimelinePlayer_seek_to_position_impl(
         },
     )
 }
+fn wire__crate__api__simple__GesTimelinePlayer_set_auto_transition_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "GesTimelinePlayer_set_auto_transition",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<GESTimelinePlayer>,
+            >>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, true,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let mut api_that_guard = api_that_guard.unwrap();
+                    let output_ok = crate::api::simple::GESTimelinePlayer::set_auto_transition(
+                        &mut *api_that_guard,
+                        api_enabled,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__simple__GesTimelinePlayer_setup_frame_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -924,7 +1021,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_stop_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -979,7 +1076,7 @@ fn wire__crate__api__simple__GesTimelinePlayer_update_clip_transform_impl(
             let api_preview_height = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1083,7 +1180,7 @@ fn wire__crate__api__simple__TimelinePlayer_dispose_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1227,7 +1324,7 @@ fn wire__crate__api__simple__TimelinePlayer_get_position_ms_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TimelinePlayer>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1242,9 +1339,8 @@ fn wire__crate__api__simple__TimelinePlayer_get_position_ms_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::simple::TimelinePlayer::get_position_ms(&*api_that_guard),
-                )?;
+                let output_ok =
+                    crate::api::simple::TimelinePlayer::get_position_ms(&*api_that_guard)?;
                 Ok(output_ok)
             })())
         },
@@ -1323,7 +1419,7 @@ fn wire__crate__api__simple__TimelinePlayer_is_playing_impl(
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<TimelinePlayer>,
             >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                 let mut api_that_guard = None;
                 let decode_indices_ =
                     flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1338,9 +1434,8 @@ fn wire__crate__api__simple__TimelinePlayer_is_playing_impl(
                     }
                 }
                 let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::simple::TimelinePlayer::is_playing(&*api_that_guard),
-                )?;
+                let output_ok =
+                    crate::api::simple::TimelinePlayer::is_playing(&*api_that_guard)?;
                 Ok(output_ok)
             })())
         },
@@ -1375,7 +1470,7 @@ fn wire__crate__api__simple__TimelinePlayer_load_timeline_impl(
                 <crate::common::types::TimelineData>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1422,8 +1517,8 @@ fn wire__crate__api__simple__TimelinePlayer_new_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::TimelinePlayer::new())?;
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
+                let output_ok = crate::api::simple::TimelinePlayer::new()?;
                 Ok(output_ok)
             })())
         },
@@ -1456,7 +1551,7 @@ fn wire__crate__api__simple__TimelinePlayer_pause_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1506,7 +1601,7 @@ fn wire__crate__api__simple__TimelinePlayer_play_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1610,7 +1705,7 @@ fn wire__crate__api__simple__TimelinePlayer_stop_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1710,7 +1805,7 @@ fn wire__crate__api__simple__VideoPlayer_dispose_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -1760,7 +1855,7 @@ fn wire__crate__api__simple__VideoPlayer_extract_frame_at_position_impl(
             let api_seconds = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2391,7 +2486,7 @@ fn wire__crate__api__simple__VideoPlayer_load_video_impl(
             let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2501,7 +2596,7 @@ fn wire__crate__api__simple__VideoPlayer_pause_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2550,7 +2645,7 @@ fn wire__crate__api__simple__VideoPlayer_play_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2601,7 +2696,7 @@ fn wire__crate__api__simple__VideoPlayer_seek_and_pause_control_impl(
             let api_was_playing_before = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2655,7 +2750,7 @@ fn wire__crate__api__simple__VideoPlayer_seek_to_frame_impl(
             let api_frame_number = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2823,7 +2918,7 @@ fn wire__crate__api__simple__VideoPlayer_stop_impl(
             >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2924,7 +3019,7 @@ fn wire__crate__api__simple__VideoPlayer_test_pipeline_impl(
             let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let mut api_that_guard = None;
                     let decode_indices_ =
                         flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
@@ -2976,7 +3071,7 @@ fn wire__crate__api__simple__create_ges_timeline_player_impl(
             let api_engine_handle = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                     let output_ok = crate::api::simple::create_ges_timeline_player(
                         api_timeline_data,
                         api_engine_handle,
@@ -2987,14 +3082,14 @@ fn wire__crate__api__simple__create_ges_timeline_player_impl(
         },
     )
 }
-fn wire__crate__api__simple__create_video_texture_impl(
+fn wire__crate__api__simple__get_display_refresh_rate_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_video_texture",
+            debug_name: "get_display_refresh_rate",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3008,16 +3103,9 @@ fn wire__crate__api__simple__create_video_texture_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_width = <u32>::sse_decode(&mut deserializer);
-            let api_height = <u32>::sse_decode(&mut deserializer);
-            let api_engine_handle = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::simple::create_video_texture(
-                    api_width,
-                    api_height,
-                    api_engine_handle,
-                )?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::get_display_refresh_rate())?;
                 Ok(output_ok)
             })())
         },
@@ -3075,7 +3163,7 @@ fn wire__crate__api__simple__get_video_duration_ms_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                 let output_ok = crate::api::simple::get_video_duration_ms(api_file_path)?;
                 Ok(output_ok)
             })())
@@ -3200,7 +3288,7 @@ fn wire__crate__api__simple__play_basic_video_impl(
             let api_file_path = <String>::sse_decode(&mut deserializer);
             let api_engine_handle = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                 let output_ok =
                     crate::api::simple::play_basic_video(api_file_path, api_engine_handle)?;
                 Ok(output_ok)
@@ -3233,7 +3321,7 @@ fn wire__crate__api__simple__play_dual_video_impl(
             let api_file_path_right = <String>::sse_decode(&mut deserializer);
             let api_engine_handle = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, crate::common::error::FlipEditError>((move || {
                 let output_ok = crate::api::simple::play_dual_video(
                     api_file_path_left,
                     api_file_path_right,
@@ -3244,14 +3332,15 @@ fn wire__crate__api__simple__play_dual_video_impl(
         },
     )
 }
-fn wire__crate__api__simple__update_video_frame_impl(
+
+fn wire__crate__api__simple__set_display_refresh_rate_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_video_frame",
+            debug_name: "set_display_refresh_rate",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3265,17 +3354,17 @@ fn wire__crate__api__simple__update_video_frame_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_frame_data = <crate::common::types::FrameData>::sse_decode(&mut deserializer);
+            let api_hz = <f64>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::simple::update_video_frame(api_frame_data))?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::simple::set_display_refresh_rate(api_hz);
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-
 // Section: related_funcs
 
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
@@ -3384,6 +3473,14 @@ impl SseDecode for StreamSink<(f64, u64), flutter_rust_bridge::for_generated::Ss
     }
 }
 
+impl SseDecode for std::collections::HashMap<String, f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, f64)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
 impl SseDecode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3399,6 +3496,81 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::common::types::CaptionCue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_startMs = <i32>::sse_decode(deserializer);
+        let mut var_endMs = <i32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_style = <crate::common::types::CaptionStyle>::sse_decode(deserializer);
+        return crate::common::types::CaptionCue {
+            id: var_id,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            text: var_text,
+            style: var_style,
+        };
+    }
+}
+
+impl SseDecode for crate::common::types::CaptionStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bold = <bool>::sse_decode(deserializer);
+        let mut var_italic = <bool>::sse_decode(deserializer);
+        let mut var_top = <bool>::sse_decode(deserializer);
+        return crate::common::types::CaptionStyle {
+            bold: var_bold,
+            italic: var_italic,
+            top: var_top,
+        };
+    }
+}
+
+impl SseDecode for crate::common::types::ClipFitMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::common::types::ClipFitMode::Stretch,
+            1 => crate::common::types::ClipFitMode::Fit,
+            2 => crate::common::types::ClipFitMode::Fill,
+            3 => crate::common::types::ClipFitMode::Original,
+            _ => unreachable!("Invalid variant for ClipFitMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::common::types::ClipOrientation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rotationDegrees = <f64>::sse_decode(deserializer);
+        let mut var_flipHorizontal = <bool>::sse_decode(deserializer);
+        let mut var_flipVertical = <bool>::sse_decode(deserializer);
+        let mut var_rotationKeyframes =
+            <Vec<crate::common::types::RotationKeyframe>>::sse_decode(deserializer);
+        return crate::common::types::ClipOrientation {
+            rotation_degrees: var_rotationDegrees,
+            flip_horizontal: var_flipHorizontal,
+            flip_vertical: var_flipVertical,
+            rotation_keyframes: var_rotationKeyframes,
+        };
+    }
+}
+
+impl SseDecode for crate::common::types::CropKeyframe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeMs = <u64>::sse_decode(deserializer);
+        let mut var_rect = <crate::common::types::SourceRect>::sse_decode(deserializer);
+        return crate::common::types::CropKeyframe {
+            time_ms: var_timeMs,
+            rect: var_rect,
+        };
+    }
+}
+
 impl SseDecode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3406,6 +3578,43 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::common::error::FlipEditError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_code = <crate::common::error::FlipEditErrorCode>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_element = <Option<String>>::sse_decode(deserializer);
+        let mut var_debug = <Option<String>>::sse_decode(deserializer);
+        return crate::common::error::FlipEditError {
+            code: var_code,
+            message: var_message,
+            element: var_element,
+            debug: var_debug,
+        };
+    }
+}
+
+impl SseDecode for crate::common::error::FlipEditErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::common::error::FlipEditErrorCode::FileNotFound,
+            1 => crate::common::error::FlipEditErrorCode::MissingPlugin,
+            2 => crate::common::error::FlipEditErrorCode::DecodeError,
+            3 => crate::common::error::FlipEditErrorCode::NotLoaded,
+            4 => crate::common::error::FlipEditErrorCode::StateChangeFailed,
+            5 => crate::common::error::FlipEditErrorCode::SeekFailed,
+            6 => crate::common::error::FlipEditErrorCode::ResourceError,
+            7 => crate::common::error::FlipEditErrorCode::InvalidArgument,
+            8 => crate::common::error::FlipEditErrorCode::IncompatibleVersion,
+            9 => crate::common::error::FlipEditErrorCode::PlayerStopped,
+            10 => crate::common::error::FlipEditErrorCode::Internal,
+            _ => unreachable!("Invalid variant for FlipEditErrorCode: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::common::types::FrameData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3436,6 +3645,32 @@ impl SseDecode for i64 {
     }
 }
 
+impl SseDecode for Vec<crate::common::types::CaptionCue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::common::types::CaptionCue>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::common::types::CropKeyframe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::common::types::CropKeyframe>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3448,6 +3683,32 @@ impl SseDecode for Vec<u8> {
     }
 }
 
+impl SseDecode for Vec<(String, f64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, f64)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::common::types::RotationKeyframe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::common::types::RotationKeyframe>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::common::types::TimelineClip> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3462,6 +3723,20 @@ impl SseDecode for Vec<crate::common::types::TimelineClip> {
     }
 }
 
+impl SseDecode for Vec<crate::common::types::TimelineMarker> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::common::types::TimelineMarker>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::common::types::TimelineTrack> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3476,53 +3751,103 @@ impl SseDecode for Vec<crate::common::types::TimelineTrack> {
     }
 }
 
-impl SseDecode for Option<crate::common::types::FrameData> {
+impl SseDecode for Vec<crate::common::types::TrackAudioEffect> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::common::types::FrameData>::sse_decode(deserializer));
-        } else {
-            return None;
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::common::types::TrackAudioEffect>::sse_decode(
+                deserializer,
+            ));
         }
+        return ans_;
     }
 }
 
-impl SseDecode for Option<i32> {
+impl SseDecode for Vec<crate::common::types::VolumeKeyframe> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::common::types::VolumeKeyframe>::sse_decode(
+                deserializer,
+            ));
         }
+        return ans_;
     }
 }
 
-impl SseDecode for Option<crate::common::types::TextureFrame> {
+impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::common::types::TextureFrame>::sse_decode(
-                deserializer,
-            ));
+            return Some(<String>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for Option<u64> {
+impl SseDecode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
+            return Some(<bool>::sse_decode(deserializer));
         } else {
             return None;
         }
     }
 }
 
-impl SseDecode for (GESTimelinePlayer, i64) {
+impl SseDecode for Option<crate::common::types::FrameData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::common::types::FrameData>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::common::types::TextureFrame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::common::types::TextureFrame>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for (GESTimelinePlayer, i64) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_field0 = <GESTimelinePlayer>::sse_decode(deserializer);
@@ -3549,6 +3874,43 @@ impl SseDecode for (i32, i32) {
     }
 }
 
+impl SseDecode for (String, f64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <f64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::common::types::RotationKeyframe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeMs = <u64>::sse_decode(deserializer);
+        let mut var_degrees = <f64>::sse_decode(deserializer);
+        return crate::common::types::RotationKeyframe {
+            time_ms: var_timeMs,
+            degrees: var_degrees,
+        };
+    }
+}
+
+impl SseDecode for crate::common::types::SourceRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <f64>::sse_decode(deserializer);
+        let mut var_y = <f64>::sse_decode(deserializer);
+        let mut var_width = <f64>::sse_decode(deserializer);
+        let mut var_height = <f64>::sse_decode(deserializer);
+        return crate::common::types::SourceRect {
+            x: var_x,
+            y: var_y,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
 impl SseDecode for crate::common::types::TextureFrame {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -3579,6 +3941,16 @@ impl SseDecode for crate::common::types::TimelineClip {
         let mut var_previewPositionY = <f64>::sse_decode(deserializer);
         let mut var_previewWidth = <f64>::sse_decode(deserializer);
         let mut var_previewHeight = <f64>::sse_decode(deserializer);
+        let mut var_previewOpacity = <f64>::sse_decode(deserializer);
+        let mut var_trackType = <crate::common::types::TrackType>::sse_decode(deserializer);
+        let mut var_fitMode = <crate::common::types::ClipFitMode>::sse_decode(deserializer);
+        let mut var_orientation = <crate::common::types::ClipOrientation>::sse_decode(deserializer);
+        let mut var_cropKeyframes =
+            <Vec<crate::common::types::CropKeyframe>>::sse_decode(deserializer);
+        let mut var_audioEffects =
+            <Vec<crate::common::types::TrackAudioEffect>>::sse_decode(deserializer);
+        let mut var_toneMapping = <Option<bool>>::sse_decode(deserializer);
+        let mut var_transitionInMs = <i32>::sse_decode(deserializer);
         return crate::common::types::TimelineClip {
             id: var_id,
             track_id: var_trackId,
@@ -3591,6 +3963,14 @@ impl SseDecode for crate::common::types::TimelineClip {
             preview_position_y: var_previewPositionY,
             preview_width: var_previewWidth,
             preview_height: var_previewHeight,
+            preview_opacity: var_previewOpacity,
+            track_type: var_trackType,
+            fit_mode: var_fitMode,
+            orientation: var_orientation,
+            crop_keyframes: var_cropKeyframes,
+            audio_effects: var_audioEffects,
+            tone_mapping: var_toneMapping,
+            transition_in_ms: var_transitionInMs,
         };
     }
 }
@@ -3599,7 +3979,27 @@ impl SseDecode for crate::common::types::TimelineData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_tracks = <Vec<crate::common::types::TimelineTrack>>::sse_decode(deserializer);
-        return crate::common::types::TimelineData { tracks: var_tracks };
+        let mut var_captions = <Vec<crate::common::types::CaptionCue>>::sse_decode(deserializer);
+        let mut var_markers = <Vec<crate::common::types::TimelineMarker>>::sse_decode(deserializer);
+        let mut var_autoTransition = <bool>::sse_decode(deserializer);
+        return crate::common::types::TimelineData {
+            tracks: var_tracks,
+            captions: var_captions,
+            markers: var_markers,
+            auto_transition: var_autoTransition,
+        };
+    }
+}
+
+impl SseDecode for crate::common::types::TimelineMarker {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_positionMs = <i32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        return crate::common::types::TimelineMarker {
+            position_ms: var_positionMs,
+            name: var_name,
+        };
     }
 }
 
@@ -3609,10 +4009,47 @@ impl SseDecode for crate::common::types::TimelineTrack {
         let mut var_id = <i32>::sse_decode(deserializer);
         let mut var_name = <String>::sse_decode(deserializer);
         let mut var_clips = <Vec<crate::common::types::TimelineClip>>::sse_decode(deserializer);
+        let mut var_audioEffects =
+            <Vec<crate::common::types::TrackAudioEffect>>::sse_decode(deserializer);
+        let mut var_volumeKeyframes =
+            <Vec<crate::common::types::VolumeKeyframe>>::sse_decode(deserializer);
+        let mut var_trackType = <crate::common::types::TrackType>::sse_decode(deserializer);
+        let mut var_priority = <i32>::sse_decode(deserializer);
         return crate::common::types::TimelineTrack {
             id: var_id,
             name: var_name,
             clips: var_clips,
+            audio_effects: var_audioEffects,
+            volume_keyframes: var_volumeKeyframes,
+            track_type: var_trackType,
+            priority: var_priority,
+        };
+    }
+}
+
+impl SseDecode for crate::common::types::TrackAudioEffect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_effectId = <u32>::sse_decode(deserializer);
+        let mut var_factoryName = <String>::sse_decode(deserializer);
+        let mut var_parameters = <std::collections::HashMap<String, f64>>::sse_decode(deserializer);
+        return crate::common::types::TrackAudioEffect {
+            effect_id: var_effectId,
+            factory_name: var_factoryName,
+            parameters: var_parameters,
+        };
+    }
+}
+
+impl SseDecode for crate::common::types::TrackType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::common::types::TrackType::Both,
+            1 => crate::common::types::TrackType::Video,
+            2 => crate::common::types::TrackType::Audio,
+            _ => unreachable!("Invalid variant for TrackType: {}", inner),
         };
     }
 }
@@ -3650,6 +4087,18 @@ impl SseDecode for usize {
     }
 }
 
+impl SseDecode for crate::common::types::VolumeKeyframe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timeMs = <u64>::sse_decode(deserializer);
+        let mut var_gainDb = <f64>::sse_decode(deserializer);
+        return crate::common::types::VolumeKeyframe {
+            time_ms: var_timeMs,
+            gain_db: var_gainDb,
+        };
+    }
+}
+
 fn pde_ffi_dispatcher_primary_impl(
     func_id: i32,
     port: flutter_rust_bridge::for_generated::MessagePort,
@@ -3795,6 +4244,12 @@ fn pde_ffi_dispatcher_primary_impl(
             data_len,
         ),
         66 => wire__crate__api__bridge__init_app_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__simple__GesTimelinePlayer_set_auto_transition_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
         _ => unreachable!(),
     }
 }
@@ -3917,14 +4372,19 @@ fn pde_ffi_dispatcher_sync_impl(
         47 => wire__crate__api__simple__VideoPlayer_is_seekable_impl(ptr, rust_vec_len, data_len),
         49 => wire__crate__api__simple__VideoPlayer_new_impl(ptr, rust_vec_len, data_len),
         50 => wire__crate__api__simple__VideoPlayer_new_player_impl(ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__simple__create_video_texture_impl(ptr, rust_vec_len, data_len),
         62 => wire__crate__api__simple__get_texture_count_impl(ptr, rust_vec_len, data_len),
         63 => wire__crate__api__simple__get_video_duration_ms_impl(ptr, rust_vec_len, data_len),
         64 => wire__crate__api__bridge__greet_impl(ptr, rust_vec_len, data_len),
         65 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
         67 => wire__crate__api__simple__play_basic_video_impl(ptr, rust_vec_len, data_len),
         68 => wire__crate__api__simple__play_dual_video_impl(ptr, rust_vec_len, data_len),
-        69 => wire__crate__api__simple__update_video_frame_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__simple__GesTimelinePlayer_get_auto_transition_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        71 => wire__crate__api__simple__get_display_refresh_rate_impl(ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__simple__set_display_refresh_rate_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -3976,6 +4436,172 @@ impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<VideoPlayer>> for VideoPlayer
     }
 }
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::CaptionCue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.style.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::CaptionCue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::CaptionCue>
+    for crate::common::types::CaptionCue
+{
+    fn into_into_dart(self) -> crate::common::types::CaptionCue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::CaptionStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bold.into_into_dart().into_dart(),
+            self.italic.into_into_dart().into_dart(),
+            self.top.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::CaptionStyle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::CaptionStyle>
+    for crate::common::types::CaptionStyle
+{
+    fn into_into_dart(self) -> crate::common::types::CaptionStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::ClipFitMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::common::types::ClipFitMode::Stretch => 0.into_dart(),
+            crate::common::types::ClipFitMode::Fit => 1.into_dart(),
+            crate::common::types::ClipFitMode::Fill => 2.into_dart(),
+            crate::common::types::ClipFitMode::Original => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::ClipFitMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::ClipFitMode>
+    for crate::common::types::ClipFitMode
+{
+    fn into_into_dart(self) -> crate::common::types::ClipFitMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::ClipOrientation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rotation_degrees.into_into_dart().into_dart(),
+            self.flip_horizontal.into_into_dart().into_dart(),
+            self.flip_vertical.into_into_dart().into_dart(),
+            self.rotation_keyframes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::ClipOrientation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::ClipOrientation>
+    for crate::common::types::ClipOrientation
+{
+    fn into_into_dart(self) -> crate::common::types::ClipOrientation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::CropKeyframe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time_ms.into_into_dart().into_dart(),
+            self.rect.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::CropKeyframe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::CropKeyframe>
+    for crate::common::types::CropKeyframe
+{
+    fn into_into_dart(self) -> crate::common::types::CropKeyframe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::error::FlipEditError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.code.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.element.into_into_dart().into_dart(),
+            self.debug.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::error::FlipEditError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::error::FlipEditError>
+    for crate::common::error::FlipEditError
+{
+    fn into_into_dart(self) -> crate::common::error::FlipEditError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::error::FlipEditErrorCode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::common::error::FlipEditErrorCode::FileNotFound => 0.into_dart(),
+            crate::common::error::FlipEditErrorCode::MissingPlugin => 1.into_dart(),
+            crate::common::error::FlipEditErrorCode::DecodeError => 2.into_dart(),
+            crate::common::error::FlipEditErrorCode::NotLoaded => 3.into_dart(),
+            crate::common::error::FlipEditErrorCode::StateChangeFailed => 4.into_dart(),
+            crate::common::error::FlipEditErrorCode::SeekFailed => 5.into_dart(),
+            crate::common::error::FlipEditErrorCode::ResourceError => 6.into_dart(),
+            crate::common::error::FlipEditErrorCode::InvalidArgument => 7.into_dart(),
+            crate::common::error::FlipEditErrorCode::IncompatibleVersion => 8.into_dart(),
+            crate::common::error::FlipEditErrorCode::PlayerStopped => 9.into_dart(),
+            crate::common::error::FlipEditErrorCode::Internal => 10.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::error::FlipEditErrorCode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::error::FlipEditErrorCode>
+    for crate::common::error::FlipEditErrorCode
+{
+    fn into_into_dart(self) -> crate::common::error::FlipEditErrorCode {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::common::types::FrameData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -4000,6 +4626,50 @@ impl flutter_rust_bridge::IntoIntoDart<crate::common::types::FrameData>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::RotationKeyframe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time_ms.into_into_dart().into_dart(),
+            self.degrees.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::RotationKeyframe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::RotationKeyframe>
+    for crate::common::types::RotationKeyframe
+{
+    fn into_into_dart(self) -> crate::common::types::RotationKeyframe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::SourceRect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::SourceRect
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::SourceRect>
+    for crate::common::types::SourceRect
+{
+    fn into_into_dart(self) -> crate::common::types::SourceRect {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::common::types::TextureFrame {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -4037,6 +4707,14 @@ impl flutter_rust_bridge::IntoDart for crate::common::types::TimelineClip {
             self.preview_position_y.into_into_dart().into_dart(),
             self.preview_width.into_into_dart().into_dart(),
             self.preview_height.into_into_dart().into_dart(),
+            self.preview_opacity.into_into_dart().into_dart(),
+            self.track_type.into_into_dart().into_dart(),
+            self.fit_mode.into_into_dart().into_dart(),
+            self.orientation.into_into_dart().into_dart(),
+            self.crop_keyframes.into_into_dart().into_dart(),
+            self.audio_effects.into_into_dart().into_dart(),
+            self.tone_mapping.into_into_dart().into_dart(),
+            self.transition_in_ms.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4055,7 +4733,13 @@ impl flutter_rust_bridge::IntoIntoDart<crate::common::types::TimelineClip>
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::common::types::TimelineData {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.tracks.into_into_dart().into_dart()].into_dart()
+        [
+            self.tracks.into_into_dart().into_dart(),
+            self.captions.into_into_dart().into_dart(),
+            self.markers.into_into_dart().into_dart(),
+            self.auto_transition.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
@@ -4070,12 +4754,37 @@ impl flutter_rust_bridge::IntoIntoDart<crate::common::types::TimelineData>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::TimelineMarker {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.position_ms.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::TimelineMarker
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::TimelineMarker>
+    for crate::common::types::TimelineMarker
+{
+    fn into_into_dart(self) -> crate::common::types::TimelineMarker {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::common::types::TimelineTrack {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.id.into_into_dart().into_dart(),
             self.name.into_into_dart().into_dart(),
             self.clips.into_into_dart().into_dart(),
+            self.audio_effects.into_into_dart().into_dart(),
+            self.volume_keyframes.into_into_dart().into_dart(),
+            self.track_type.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -4091,6 +4800,71 @@ impl flutter_rust_bridge::IntoIntoDart<crate::common::types::TimelineTrack>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::TrackAudioEffect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.effect_id.into_into_dart().into_dart(),
+            self.factory_name.into_into_dart().into_dart(),
+            self.parameters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::TrackAudioEffect
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::TrackAudioEffect>
+    for crate::common::types::TrackAudioEffect
+{
+    fn into_into_dart(self) -> crate::common::types::TrackAudioEffect {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::TrackType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::common::types::TrackType::Both => 0.into_dart(),
+            crate::common::types::TrackType::Video => 1.into_dart(),
+            crate::common::types::TrackType::Audio => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::TrackType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::TrackType>
+    for crate::common::types::TrackType
+{
+    fn into_into_dart(self) -> crate::common::types::TrackType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::common::types::VolumeKeyframe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time_ms.into_into_dart().into_dart(),
+            self.gain_db.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::common::types::VolumeKeyframe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::common::types::VolumeKeyframe>
+    for crate::common::types::VolumeKeyframe
+{
+    fn into_into_dart(self) -> crate::common::types::VolumeKeyframe {
+        self
+    }
+}
 
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4176,6 +4950,13 @@ impl SseEncode for StreamSink<(f64, u64), flutter_rust_bridge::for_generated::Ss
     }
 }
 
+impl SseEncode for std::collections::HashMap<String, f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, f64)>>::sse_encode(self.into_iter().collect(), serializer);
+    }
+}
+
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4190,6 +4971,65 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::common::types::CaptionCue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <i32>::sse_encode(self.start_ms, serializer);
+        <i32>::sse_encode(self.end_ms, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <crate::common::types::CaptionStyle>::sse_encode(self.style, serializer);
+    }
+}
+
+impl SseEncode for crate::common::types::CaptionStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.bold, serializer);
+        <bool>::sse_encode(self.italic, serializer);
+        <bool>::sse_encode(self.top, serializer);
+    }
+}
+
+impl SseEncode for crate::common::types::ClipFitMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::common::types::ClipFitMode::Stretch => 0,
+                crate::common::types::ClipFitMode::Fit => 1,
+                crate::common::types::ClipFitMode::Fill => 2,
+                crate::common::types::ClipFitMode::Original => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::common::types::ClipOrientation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.rotation_degrees, serializer);
+        <bool>::sse_encode(self.flip_horizontal, serializer);
+        <bool>::sse_encode(self.flip_vertical, serializer);
+        <Vec<crate::common::types::RotationKeyframe>>::sse_encode(
+            self.rotation_keyframes,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::common::types::CropKeyframe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.time_ms, serializer);
+        <crate::common::types::SourceRect>::sse_encode(self.rect, serializer);
+    }
+}
+
 impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4197,6 +5037,41 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::common::error::FlipEditError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::common::error::FlipEditErrorCode>::sse_encode(self.code, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<String>>::sse_encode(self.element, serializer);
+        <Option<String>>::sse_encode(self.debug, serializer);
+    }
+}
+
+impl SseEncode for crate::common::error::FlipEditErrorCode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::common::error::FlipEditErrorCode::FileNotFound => 0,
+                crate::common::error::FlipEditErrorCode::MissingPlugin => 1,
+                crate::common::error::FlipEditErrorCode::DecodeError => 2,
+                crate::common::error::FlipEditErrorCode::NotLoaded => 3,
+                crate::common::error::FlipEditErrorCode::StateChangeFailed => 4,
+                crate::common::error::FlipEditErrorCode::SeekFailed => 5,
+                crate::common::error::FlipEditErrorCode::ResourceError => 6,
+                crate::common::error::FlipEditErrorCode::InvalidArgument => 7,
+                crate::common::error::FlipEditErrorCode::IncompatibleVersion => 8,
+                crate::common::error::FlipEditErrorCode::PlayerStopped => 9,
+                crate::common::error::FlipEditErrorCode::Internal => 10,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::common::types::FrameData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4221,6 +5096,26 @@ impl SseEncode for i64 {
     }
 }
 
+impl SseEncode for Vec<crate::common::types::CaptionCue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::common::types::CaptionCue>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::common::types::CropKeyframe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::common::types::CropKeyframe>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4231,6 +5126,26 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<(String, f64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, f64)>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::common::types::RotationKeyframe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::common::types::RotationKeyframe>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::common::types::TimelineClip> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4241,6 +5156,16 @@ impl SseEncode for Vec<crate::common::types::TimelineClip> {
     }
 }
 
+impl SseEncode for Vec<crate::common::types::TimelineMarker> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::common::types::TimelineMarker>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::common::types::TimelineTrack> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4251,6 +5176,46 @@ impl SseEncode for Vec<crate::common::types::TimelineTrack> {
     }
 }
 
+impl SseEncode for Vec<crate::common::types::TrackAudioEffect> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::common::types::TrackAudioEffect>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::common::types::VolumeKeyframe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::common::types::VolumeKeyframe>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::common::types::FrameData> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4315,6 +5280,32 @@ impl SseEncode for (i32, i32) {
     }
 }
 
+impl SseEncode for (String, f64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <f64>::sse_encode(self.1, serializer);
+    }
+}
+
+impl SseEncode for crate::common::types::RotationKeyframe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.time_ms, serializer);
+        <f64>::sse_encode(self.degrees, serializer);
+    }
+}
+
+impl SseEncode for crate::common::types::SourceRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.x, serializer);
+        <f64>::sse_encode(self.y, serializer);
+        <f64>::sse_encode(self.width, serializer);
+        <f64>::sse_encode(self.height, serializer);
+    }
+}
+
 impl SseEncode for crate::common::types::TextureFrame {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -4339,6 +5330,14 @@ impl SseEncode for crate::common::types::TimelineClip {
         <f64>::sse_encode(self.preview_position_y, serializer);
         <f64>::sse_encode(self.preview_width, serializer);
         <f64>::sse_encode(self.preview_height, serializer);
+        <f64>::sse_encode(self.preview_opacity, serializer);
+        <crate::common::types::TrackType>::sse_encode(self.track_type, serializer);
+        <crate::common::types::ClipFitMode>::sse_encode(self.fit_mode, serializer);
+        <crate::common::types::ClipOrientation>::sse_encode(self.orientation, serializer);
+        <Vec<crate::common::types::CropKeyframe>>::sse_encode(self.crop_keyframes, serializer);
+        <Vec<crate::common::types::TrackAudioEffect>>::sse_encode(self.audio_effects, serializer);
+        <Option<bool>>::sse_encode(self.tone_mapping, serializer);
+        <i32>::sse_encode(self.transition_in_ms, serializer);
     }
 }
 
@@ -4346,6 +5345,17 @@ impl SseEncode for crate::common::types::TimelineData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Vec<crate::common::types::TimelineTrack>>::sse_encode(self.tracks, serializer);
+        <Vec<crate::common::types::CaptionCue>>::sse_encode(self.captions, serializer);
+        <Vec<crate::common::types::TimelineMarker>>::sse_encode(self.markers, serializer);
+        <bool>::sse_encode(self.auto_transition, serializer);
+    }
+}
+
+impl SseEncode for crate::common::types::TimelineMarker {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.position_ms, serializer);
+        <String>::sse_encode(self.name, serializer);
     }
 }
 
@@ -4355,6 +5365,36 @@ impl SseEncode for crate::common::types::TimelineTrack {
         <i32>::sse_encode(self.id, serializer);
         <String>::sse_encode(self.name, serializer);
         <Vec<crate::common::types::TimelineClip>>::sse_encode(self.clips, serializer);
+        <Vec<crate::common::types::TrackAudioEffect>>::sse_encode(self.audio_effects, serializer);
+        <Vec<crate::common::types::VolumeKeyframe>>::sse_encode(self.volume_keyframes, serializer);
+        <crate::common::types::TrackType>::sse_encode(self.track_type, serializer);
+        <i32>::sse_encode(self.priority, serializer);
+    }
+}
+
+impl SseEncode for crate::common::types::TrackAudioEffect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.effect_id, serializer);
+        <String>::sse_encode(self.factory_name, serializer);
+        <std::collections::HashMap<String, f64>>::sse_encode(self.parameters, serializer);
+    }
+}
+
+impl SseEncode for crate::common::types::TrackType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::common::types::TrackType::Both => 0,
+                crate::common::types::TrackType::Video => 1,
+                crate::common::types::TrackType::Audio => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -4394,6 +5434,14 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::common::types::VolumeKeyframe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.time_ms, serializer);
+        <f64>::sse_encode(self.gain_db, serializer);
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
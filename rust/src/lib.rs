@@ -1,5 +1,16 @@
 pub mod api;
 pub mod audio_handler;
+pub mod audio_downmix;
+pub mod audio_effects;
+pub mod audio_automation;
+pub mod audio_loudness;
+pub mod audio_input;
+pub mod audio_recorder;
+pub mod audio_sync;
+pub mod audio_waveform;
+pub mod captions;
+pub mod chapters;
+pub mod export_metadata;
 pub mod video;
 pub mod common;
 pub mod utils;
@@ -0,0 +1,180 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::info;
+
+use crate::common::types::{ClipFitMode, ClipOrientation, TimelineClip, TrackType};
+use crate::video::direct_pipeline_player::{DEFAULT_OUTPUT_HEIGHT, DEFAULT_OUTPUT_WIDTH};
+
+// Generated media lives here and is reused by later runs asking for the same spec
+const FIXTURE_DIR_NAME: &str = "flipedit-fixtures";
+const RENDER_TIMEOUT_SECS: u64 = 60;
+const AUDIO_RATE: u64 = 48_000;
+// 10 ms audio buffers, so any duration in whole 10 ms steps is rendered exactly
+const AUDIO_BUFFER_MS: u64 = 10;
+
+static NEXT_PARTIAL: AtomicU64 = AtomicU64::new(0);
+
+/// Picture of a fixture's video, from `videotestsrc`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VideoPattern {
+    /// Color bars scrolling one pixel per frame, so every frame differs from its neighbours
+    MovingBars,
+    /// A ball bouncing across the frame
+    Ball,
+    Checkers,
+    /// A single color, as 0xAARRGGBB
+    Solid(u32),
+}
+
+/// What to generate. Files are raw video and PCM in Matroska, so fixtures decode identically
+/// on every machine and don't depend on encoder plugins being installed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MediaSpec {
+    pub duration_ms: u64,
+    pub width: i32,
+    pub height: i32,
+    pub fps: i32,
+    /// No video stream when `None`
+    pub pattern: Option<VideoPattern>,
+    /// Sine tone frequency; no audio stream when `None`
+    pub tone_hz: Option<u32>,
+}
+
+impl Default for MediaSpec {
+    fn default() -> Self {
+        Self {
+            duration_ms: 2000,
+            width: 320,
+            height: 240,
+            fps: 30,
+            pattern: Some(VideoPattern::MovingBars),
+            tone_hz: Some(440),
+        }
+    }
+}
+
+impl MediaSpec {
+    fn video_description(&self, pattern: VideoPattern) -> String {
+        let source = match pattern {
+            VideoPattern::MovingBars => "videotestsrc pattern=smpte horizontal-speed=1".to_string(),
+            VideoPattern::Ball => "videotestsrc pattern=ball".to_string(),
+            VideoPattern::Checkers => "videotestsrc pattern=checkers-8".to_string(),
+            VideoPattern::Solid(argb) => format!("videotestsrc pattern=solid-color foreground-color={}", argb),
+        };
+        let frames = self.duration_ms * self.fps as u64 / 1000;
+        format!(
+            "{} num-buffers={} ! video/x-raw,format=I420,width={},height={},framerate={}/1 ! mux.",
+            source, frames, self.width, self.height, self.fps,
+        )
+    }
+
+    fn audio_description(&self, tone_hz: u32) -> String {
+        format!(
+            "audiotestsrc wave=sine freq={} samplesperbuffer={} num-buffers={} \
+             ! audio/x-raw,format=S16LE,rate={},channels=2 ! mux.",
+            tone_hz,
+            AUDIO_RATE * AUDIO_BUFFER_MS / 1000,
+            self.duration_ms / AUDIO_BUFFER_MS,
+            AUDIO_RATE,
+        )
+    }
+}
+
+/// Path of the fixture for `spec`, rendering it first if no earlier run has
+pub fn fixture(spec: &MediaSpec) -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join(FIXTURE_DIR_NAME);
+    std::fs::create_dir_all(&dir)?;
+    let mut hasher = DefaultHasher::new();
+    spec.hash(&mut hasher);
+    let path = dir.join(format!("fixture_{:016x}.mkv", hasher.finish()));
+    if !path.exists() {
+        generate(spec, &path)?;
+    }
+    Ok(path)
+}
+
+/// Render `spec` to `path`, replacing any file there
+pub fn generate(spec: &MediaSpec, path: &Path) -> Result<()> {
+    if spec.pattern.is_none() && spec.tone_hz.is_none() {
+        return Err(anyhow!("A fixture needs a video pattern, a tone, or both"));
+    }
+    gst::init()?;
+
+    let mut description = String::from("matroskamux name=mux ! filesink name=out");
+    if let Some(pattern) = spec.pattern {
+        description.push(' ');
+        description.push_str(&spec.video_description(pattern));
+    }
+    if let Some(tone_hz) = spec.tone_hz {
+        description.push(' ');
+        description.push_str(&spec.audio_description(tone_hz));
+    }
+    let pipeline = gst::parse::launch(&description)?
+        .downcast::<gst::Pipeline>()
+        .map_err(|_| anyhow!("Fixture description didn't produce a pipeline"))?;
+
+    // Written next to the target and renamed, so an interrupted run never leaves a short file.
+    // The name is unique, so tests rendering the same fixture at once don't share a file.
+    let partial = path.with_extension(format!(
+        "{}-{}.partial",
+        std::process::id(),
+        NEXT_PARTIAL.fetch_add(1, Ordering::Relaxed),
+    ));
+    pipeline.by_name("out")
+        .ok_or_else(|| anyhow!("Fixture pipeline has no file sink"))?
+        .set_property("location", partial.to_string_lossy().as_ref());
+
+    info!("Rendering test fixture {}", path.display());
+    pipeline.set_state(gst::State::Playing)?;
+    let bus = pipeline.bus().ok_or_else(|| anyhow!("Fixture pipeline has no bus"))?;
+    let message = bus.timed_pop_filtered(
+        gst::ClockTime::from_seconds(RENDER_TIMEOUT_SECS),
+        &[gst::MessageType::Eos, gst::MessageType::Error],
+    );
+    let _ = pipeline.set_state(gst::State::Null);
+
+    match message.as_ref().map(|message| message.view()) {
+        Some(gst::MessageView::Eos(_)) => {
+            std::fs::rename(&partial, path)?;
+            Ok(())
+        }
+        Some(gst::MessageView::Error(err)) => {
+            let _ = std::fs::remove_file(&partial);
+            Err(anyhow!("Failed to render fixture {}: {}", path.display(), err.error()))
+        }
+        _ => {
+            let _ = std::fs::remove_file(&partial);
+            Err(anyhow!("Timed out rendering fixture {}", path.display()))
+        }
+    }
+}
+
+/// A clip of `source_path` filling the player's timeline canvas, for building test timelines
+pub fn clip(id: i32, track_id: i32, source_path: &Path, start_on_track_ms: i32, in_ms: i32, out_ms: i32) -> TimelineClip {
+    TimelineClip {
+        id: Some(id),
+        track_id,
+        source_path: source_path.to_string_lossy().into_owned(),
+        start_time_on_track_ms: start_on_track_ms,
+        end_time_on_track_ms: start_on_track_ms + (out_ms - in_ms),
+        start_time_in_source_ms: in_ms,
+        end_time_in_source_ms: out_ms,
+        preview_position_x: 0.0,
+        preview_position_y: 0.0,
+        preview_width: DEFAULT_OUTPUT_WIDTH as f64,
+        preview_height: DEFAULT_OUTPUT_HEIGHT as f64,
+        preview_opacity: 1.0,
+        track_type: TrackType::Both,
+        fit_mode: ClipFitMode::default(),
+        orientation: ClipOrientation::default(),
+        crop_keyframes: Vec::new(),
+        audio_effects: Vec::new(),
+        tone_mapping: None,
+        transition_in_ms: 0,
+    }
+}
@@ -0,0 +1,184 @@
+use std::path::Path;
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gstreamer_video as gst_video;
+use gst::prelude::*;
+use log::info;
+
+use crate::common::media_uri::source_uri;
+
+// Set to rewrite golden files from the frames tests produce, e.g. after an intended change
+const UPDATE_GOLDEN_ENV: &str = "FLIPEDIT_UPDATE_GOLDEN";
+const CONVERT_TIMEOUT_SECS: u64 = 5;
+const PREROLL_TIMEOUT_SECS: u64 = 10;
+
+/// A frame as tightly packed RGBA rows
+#[derive(Debug, Clone, PartialEq)]
+pub struct RgbaFrame {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl RgbaFrame {
+    /// Convert a sample of any raw or image format
+    pub fn from_sample(sample: &gst::Sample) -> Result<Self> {
+        let caps = sample.caps().ok_or_else(|| anyhow!("No caps in sample"))?;
+        let is_rgba = gst_video::VideoInfo::from_caps(caps)
+            .is_ok_and(|info| info.format() == gst_video::VideoFormat::Rgba);
+        let converted;
+        let sample = if is_rgba {
+            sample
+        } else {
+            converted = gst_video::convert_sample(
+                sample,
+                &gst::Caps::builder("video/x-raw").field("format", "RGBA").build(),
+                gst::ClockTime::from_seconds(CONVERT_TIMEOUT_SECS),
+            )?;
+            &converted
+        };
+
+        let caps = sample.caps().ok_or_else(|| anyhow!("No caps in converted sample"))?;
+        let buffer = sample.buffer().ok_or_else(|| anyhow!("No buffer in sample"))?;
+        let info = gst_video::VideoInfo::from_caps(caps)?;
+        let frame = gst_video::VideoFrameRef::from_buffer_ref_readable(buffer, &info)
+            .map_err(|_| anyhow!("Failed to map frame"))?;
+        let row_bytes = info.width() as usize * 4;
+        let stride = frame.plane_stride()[0] as usize;
+        let data = frame.plane_data(0)?
+            .chunks(stride)
+            .take(info.height() as usize)
+            .flat_map(|row| &row[..row_bytes])
+            .copied()
+            .collect();
+        Ok(Self { width: info.width(), height: info.height(), data })
+    }
+
+    fn to_sample(&self) -> Result<gst::Sample> {
+        let caps = gst_video::VideoInfo::builder(gst_video::VideoFormat::Rgba, self.width, self.height)
+            .build()?
+            .to_caps()?;
+        Ok(gst::Sample::builder()
+            .buffer(&gst::Buffer::from_slice(self.data.clone()))
+            .caps(&caps)
+            .build())
+    }
+
+    pub fn load_png(path: &Path) -> Result<Self> {
+        let bytes = std::fs::read(path).map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        Self::from_png(bytes)
+    }
+
+    /// Decode PNG bytes, e.g. a frame captured from the player
+    pub fn from_png(bytes: Vec<u8>) -> Result<Self> {
+        let sample = gst::Sample::builder()
+            .buffer(&gst::Buffer::from_slice(bytes))
+            .caps(&gst::Caps::new_empty_simple("image/png"))
+            .build();
+        Self::from_sample(&sample)
+    }
+
+    pub fn save_png(&self, path: &Path) -> Result<()> {
+        let png = gst_video::convert_sample(
+            &self.to_sample()?,
+            &gst::Caps::new_empty_simple("image/png"),
+            gst::ClockTime::from_seconds(CONVERT_TIMEOUT_SECS),
+        )?;
+        let buffer = png.buffer().ok_or_else(|| anyhow!("PNG encoder produced no data"))?;
+        let map = buffer.map_readable()?;
+        std::fs::write(path, map.as_slice()).map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+/// How far two frames of the same size are apart
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameDiff {
+    /// Largest difference of any channel of any pixel, 0-255
+    pub max_delta: u8,
+    /// Average channel difference over the frame
+    pub mean_delta: f64,
+    pub differing_pixels: usize,
+}
+
+pub fn compare(actual: &RgbaFrame, expected: &RgbaFrame) -> Result<FrameDiff> {
+    if (actual.width, actual.height) != (expected.width, expected.height) {
+        return Err(anyhow!(
+            "Frame is {}x{}, expected {}x{}",
+            actual.width, actual.height, expected.width, expected.height,
+        ));
+    }
+    let mut diff = FrameDiff { max_delta: 0, mean_delta: 0.0, differing_pixels: 0 };
+    let mut total: u64 = 0;
+    for (a, e) in actual.data.chunks_exact(4).zip(expected.data.chunks_exact(4)) {
+        let pixel_max = a.iter().zip(e).map(|(a, e)| a.abs_diff(*e)).max().unwrap_or(0);
+        total += a.iter().zip(e).map(|(a, e)| a.abs_diff(*e) as u64).sum::<u64>();
+        diff.max_delta = diff.max_delta.max(pixel_max);
+        if pixel_max > 0 {
+            diff.differing_pixels += 1;
+        }
+    }
+    diff.mean_delta = total as f64 / actual.data.len().max(1) as f64;
+    Ok(diff)
+}
+
+/// Compare `actual` against the golden PNG at `golden_path`, failing when the mean channel
+/// difference exceeds `max_mean_delta`. A missing golden, or `FLIPEDIT_UPDATE_GOLDEN` being
+/// set, writes `actual` as the new golden instead. On a mismatch the frame is saved next to
+/// the golden as `*.actual.png` for inspection.
+pub fn check_golden(actual: &RgbaFrame, golden_path: &Path, max_mean_delta: f64) -> Result<FrameDiff> {
+    if std::env::var_os(UPDATE_GOLDEN_ENV).is_some() || !golden_path.exists() {
+        info!("Writing golden frame {}", golden_path.display());
+        if let Some(dir) = golden_path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        actual.save_png(golden_path)?;
+        return Ok(FrameDiff { max_delta: 0, mean_delta: 0.0, differing_pixels: 0 });
+    }
+
+    let expected = RgbaFrame::load_png(golden_path)?;
+    let diff = compare(actual, &expected)?;
+    if diff.mean_delta > max_mean_delta {
+        let actual_path = golden_path.with_extension("actual.png");
+        actual.save_png(&actual_path)?;
+        return Err(anyhow!(
+            "Frame differs from {} (mean delta {:.2}, max {}, {} pixels); saved as {}",
+            golden_path.display(), diff.mean_delta, diff.max_delta, diff.differing_pixels, actual_path.display(),
+        ));
+    }
+    Ok(diff)
+}
+
+/// Decode the frame of a media file shown at `position_ms`, scaled to `width`x`height`
+pub fn frame_at(source_path: &Path, position_ms: u64, width: i32, height: i32) -> Result<RgbaFrame> {
+    gst::init()?;
+    let description = format!(
+        "uridecodebin name=decode ! videoconvert ! videoscale \
+         ! video/x-raw,format=RGBA,width={},height={} ! appsink name=sink sync=false",
+        width, height,
+    );
+    let pipeline = gst::parse::launch(&description)?
+        .downcast::<gst::Pipeline>()
+        .map_err(|_| anyhow!("Frame description didn't produce a pipeline"))?;
+    pipeline.by_name("decode")
+        .ok_or_else(|| anyhow!("Frame pipeline has no decoder"))?
+        .set_property("uri", source_uri(&source_path.to_string_lossy()));
+    let appsink = pipeline.by_name("sink")
+        .and_then(|sink| sink.downcast::<gst_app::AppSink>().ok())
+        .ok_or_else(|| anyhow!("Frame pipeline has no appsink"))?;
+
+    let result = (|| {
+        pipeline.set_state(gst::State::Paused)?;
+        pipeline.state(Some(gst::ClockTime::from_seconds(PREROLL_TIMEOUT_SECS))).0?;
+        pipeline.seek_simple(
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::ClockTime::from_mseconds(position_ms),
+        )?;
+        pipeline.state(Some(gst::ClockTime::from_seconds(PREROLL_TIMEOUT_SECS))).0?;
+        let sample = appsink.try_pull_preroll(gst::ClockTime::from_seconds(PREROLL_TIMEOUT_SECS))
+            .ok_or_else(|| anyhow!("No frame at {}ms in {}", position_ms, source_path.display()))?;
+        RgbaFrame::from_sample(&sample)
+    })();
+    let _ = pipeline.set_state(gst::State::Null);
+    result
+}
@@ -1,3 +1,9 @@
+// Media and frame helpers for the crate's tests, left out of the shipping library
+#[cfg(test)]
+pub mod fixtures;
+#[cfg(test)]
+pub mod golden;
+
 use gstreamer as gst;
 use gstreamer::prelude::*;
 use log::info;
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::common::types::{TimelineClip, TimelineData, TimelineTrack};
+
+/// Static interval tree over half-open `[start, end)` ranges: entries sorted by start, with
+/// each node of the implicit balanced tree over that order holding the latest end below it.
+/// Overlap queries take O(log n + k) instead of scanning every entry.
+pub struct IntervalTree<T> {
+    entries: Vec<(i64, i64, T)>,
+    max_end: Vec<i64>,
+}
+
+impl<T> IntervalTree<T> {
+    pub fn new(mut entries: Vec<(i64, i64, T)>) -> Self {
+        entries.sort_by_key(|&(start, end, _)| (start, end));
+        let mut tree = Self { max_end: vec![i64::MIN; entries.len()], entries };
+        tree.build(0, tree.entries.len());
+        tree
+    }
+
+    fn build(&mut self, lo: usize, hi: usize) -> i64 {
+        if lo >= hi {
+            return i64::MIN;
+        }
+        let mid = (lo + hi) / 2;
+        let left = self.build(lo, mid);
+        let right = self.build(mid + 1, hi);
+        let max_end = self.entries[mid].1.max(left).max(right);
+        self.max_end[mid] = max_end;
+        max_end
+    }
+
+    /// Values of entries overlapping `[start, end)`; ranges that only touch don't overlap
+    pub fn overlapping(&self, start: i64, end: i64) -> Vec<&T> {
+        let mut found = Vec::new();
+        self.collect(0, self.entries.len(), start, end, &mut found);
+        found
+    }
+
+    fn collect<'a>(&'a self, lo: usize, hi: usize, start: i64, end: i64, found: &mut Vec<&'a T>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        // Nothing below this node ends after `start`
+        if self.max_end[mid] <= start {
+            return;
+        }
+        self.collect(lo, mid, start, end, found);
+        let (entry_start, entry_end, value) = &self.entries[mid];
+        // Entries right of `mid` start no earlier, so they can only overlap if this one starts in time
+        if *entry_start < end {
+            if *entry_end > start {
+                found.push(value);
+            }
+            self.collect(mid + 1, hi, start, end, found);
+        }
+    }
+}
+
+/// Timeline clips indexed by their time on the track, for the whole timeline and per track.
+/// Queries return clips in timeline order (by track, then as listed), like a linear scan would.
+pub struct ClipIndex<'a> {
+    clips: Vec<&'a TimelineClip>,
+    all: IntervalTree<usize>,
+    by_track: HashMap<i32, IntervalTree<usize>>,
+}
+
+impl<'a> ClipIndex<'a> {
+    pub fn new(timeline: &'a TimelineData) -> Self {
+        let clips: Vec<&TimelineClip> = timeline.tracks.iter().flat_map(|t| &t.clips).collect();
+        let span = |index: usize| {
+            let clip = clips[index];
+            (clip.start_time_on_track_ms as i64, clip.end_time_on_track_ms as i64, index)
+        };
+
+        let mut track_entries: HashMap<i32, Vec<(i64, i64, usize)>> = HashMap::new();
+        for (index, clip) in clips.iter().enumerate() {
+            track_entries.entry(clip.track_id).or_default().push(span(index));
+        }
+        let all = IntervalTree::new((0..clips.len()).map(span).collect());
+        let by_track = track_entries.into_iter()
+            .map(|(track_id, entries)| (track_id, IntervalTree::new(entries)))
+            .collect();
+        Self { clips, all, by_track }
+    }
+
+    /// Every clip, in timeline order
+    pub fn clips(&self) -> &[&'a TimelineClip] {
+        &self.clips
+    }
+
+    fn resolve(&self, mut indices: Vec<&usize>) -> Vec<&'a TimelineClip> {
+        indices.sort();
+        indices.into_iter().map(|&index| self.clips[index]).collect()
+    }
+
+    /// Clips on any track overlapping `[start_ms, end_ms)`
+    pub fn overlapping(&self, start_ms: i64, end_ms: i64) -> Vec<&'a TimelineClip> {
+        self.resolve(self.all.overlapping(start_ms, end_ms))
+    }
+
+    /// Clips on `track_id` overlapping `[start_ms, end_ms)`
+    pub fn overlapping_on_track(&self, track_id: i32, start_ms: i64, end_ms: i64) -> Vec<&'a TimelineClip> {
+        match self.by_track.get(&track_id) {
+            Some(tree) => self.resolve(tree.overlapping(start_ms, end_ms)),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Stacking layer of each track, 0 at the bottom: by priority, then later in the list on top
+pub fn track_layers(timeline: &TimelineData) -> HashMap<i32, u32> {
+    let mut tracks: Vec<(usize, &TimelineTrack)> = timeline.tracks.iter().enumerate().collect();
+    tracks.sort_by_key(|(position, track)| (track.priority, *position));
+    tracks.into_iter()
+        .enumerate()
+        .map(|(layer, (_, track))| (track.id, layer as u32))
+        .collect()
+}
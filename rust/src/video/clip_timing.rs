@@ -0,0 +1,94 @@
+use std::sync::{Arc, Mutex};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::debug;
+
+use crate::common::types::TimelineClip;
+
+/// Where a clip sits on the timeline and which part of its source it plays
+#[derive(Debug, Clone, Copy)]
+pub struct ClipTiming {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub in_point_ms: u64,
+}
+
+impl ClipTiming {
+    pub fn from_clip(clip: &TimelineClip) -> Self {
+        let start_ms = clip.start_time_on_track_ms.max(0) as u64;
+        Self {
+            start_ms,
+            end_ms: (clip.end_time_on_track_ms.max(0) as u64).max(start_ms),
+            in_point_ms: clip.start_time_in_source_ms.max(0) as u64,
+        }
+    }
+
+    /// Timeline position of a source (stream time) position
+    pub fn timeline_ms(&self, stream_time: gst::ClockTime) -> f64 {
+        self.start_ms as f64 + stream_time.nseconds() as f64 / 1_000_000.0 - self.in_point_ms as f64
+    }
+
+    pub fn out_point_ms(&self) -> u64 {
+        self.in_point_ms + (self.end_ms - self.start_ms)
+    }
+
+    /// Source position to decode from and running-time offset to apply when the timeline seeks to `timeline_ms`
+    pub fn map_timeline_position(&self, timeline_ms: u64) -> (u64, i64) {
+        if timeline_ms < self.start_ms {
+            // Clip hasn't started yet: decode from the in-point and delay it until the clip starts
+            (self.in_point_ms, ((self.start_ms - timeline_ms) * 1_000_000) as i64)
+        } else {
+            ((self.in_point_ms + (timeline_ms - self.start_ms)).min(self.out_point_ms()), 0)
+        }
+    }
+}
+
+/// Timing shared with the pad probes, so moving or trimming a clip only needs a flushing seek
+pub type SharedClipTiming = Arc<Mutex<ClipTiming>>;
+
+/// Place a decoder output pad on the timeline: offsets its running time so the in-point lands on the
+/// clip's start, and rewrites timeline seeks coming from downstream into source seeks bounded by the out-point.
+pub fn apply_clip_timing(src_pad: &gst::Pad, shared_timing: SharedClipTiming) {
+    // Until the first seek the decoder starts at 0, so approximate by shifting the in-point onto the start
+    let initial = *shared_timing.lock().unwrap();
+    src_pad.set_offset((initial.start_ms as i64 - initial.in_point_ms as i64) * 1_000_000);
+
+    src_pad.add_probe(gst::PadProbeType::EVENT_UPSTREAM, move |pad, info| {
+        let Some(gst::PadProbeData::Event(ref mut event)) = info.data else {
+            return gst::PadProbeReturn::Ok;
+        };
+
+        let rewritten = match event.view() {
+            gst::EventView::Seek(seek) => {
+                let (rate, flags, _, start, _, _) = seek.get();
+                match start {
+                    gst::GenericFormattedValue::Time(Some(position)) => {
+                        let timing = *shared_timing.lock().unwrap();
+                        let (source_ms, offset_ns) = timing.map_timeline_position(position.mseconds());
+                        debug!("Clip at {}ms: timeline seek to {}ms -> source {}ms", timing.start_ms, position.mseconds(), source_ms);
+                        pad.set_offset(offset_ns);
+                        Some(
+                            gst::event::Seek::builder(
+                                rate,
+                                flags,
+                                gst::SeekType::Set,
+                                gst::ClockTime::from_mseconds(source_ms),
+                                gst::SeekType::Set,
+                                gst::ClockTime::from_mseconds(timing.out_point_ms()),
+                            )
+                            .seqnum(event.seqnum())
+                            .build(),
+                        )
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(seek) = rewritten {
+            *event = seek;
+        }
+        gst::PadProbeReturn::Ok
+    });
+}
@@ -0,0 +1,19 @@
+use std::str::FromStr;
+use gstreamer as gst;
+use gstreamer_video as gst_video;
+
+use crate::common::types::ColorSpace;
+
+/// Color space a source declares in its caps `colorimetry`, by its YUV matrix. `None` when
+/// the caps don't say, in which case conversion guesses from the frame size (BT.601 up to
+/// 576 lines, BT.709 above).
+pub fn source_color_space(caps: &gst::CapsRef) -> Option<ColorSpace> {
+    let colorimetry = caps.structure(0)?.get::<&str>("colorimetry").ok()?;
+    let colorimetry = gst_video::VideoColorimetry::from_str(colorimetry).ok()?;
+    match colorimetry.matrix() {
+        gst_video::VideoColorMatrix::Bt601 => Some(ColorSpace::Bt601),
+        gst_video::VideoColorMatrix::Bt709 => Some(ColorSpace::Bt709),
+        gst_video::VideoColorMatrix::Bt2020 => Some(ColorSpace::Bt2020),
+        _ => None,
+    }
+}
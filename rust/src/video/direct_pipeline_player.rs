@@ -1,33 +1,244 @@
 use anyhow::{anyhow, Result};
 use gstreamer as gst;
 use gstreamer_app as gst_app;
+use gstreamer_pbutils as gst_pbutils;
+use gstreamer_video as gst_video;
 use gst::prelude::*;
 use log::{debug, info, warn};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use crate::common::types::{FrameData, TimelineData, TimelineClip};
-use crate::video::irondash_texture::create_player_texture;
+use crate::audio_automation;
+use crate::audio_downmix;
+use crate::audio_effects;
+use crate::audio_handler;
+use crate::audio_recorder::VoiceoverRecorder;
+use crate::captions::{self, SharedCaptions};
+use crate::chapters;
+use crate::common::decoders::{self, DecoderFailure};
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::media_uri::{is_live_source, is_network_source, source_uri};
+use crate::common::plugins;
+use crate::common::resources;
+use crate::common::timeline_json;
+use crate::common::types::{AudioLevels, AudioRestoration, EndBehavior, LiveStreamSettings, TrackAudioEffect, VolumeKeyframe, FrameBufferPool, FrameData, PlaybackEvent, PlaybackState, RenderPath, RenderPathInfo, RenderSegment, ScrubMode, TimelineChange, TimelineData, TimelineClip, TimelineEvent, TimelineLoadEvent, TimelineTrack, TrackType, EditMode, ResourceKind, PerformanceStats, RecoveryStage, VoiceoverPlayback, FrameRate, ColorSpace, PixelAspectRatio, ClipFitMode, ClipInfo, ClipOrientation, ConversionSettings, CropKeyframe, RotationKeyframe, SourceRect, CaptionAppearance, CaptionCue, CaptionFormat, CaptionStyle};
+use crate::video::hdr::SourceTransfer;
+use crate::video::color;
+use crate::video::fit::{self, Placement};
+use crate::video::orientation::{self, SharedOrientation};
+use crate::video::pan_zoom::{self, SharedCropKeyframes};
+use crate::video::transitions::{self, ClipFades, SharedOpacity};
+use crate::video::clip_timing::{apply_clip_timing, ClipTiming, SharedClipTiming};
+use crate::video::irondash_texture::{create_player_texture, TextureUpdateFn};
+use crate::video::live_output::{self, LiveOutput, PROGRAM_AUDIO_TEE, PROGRAM_VIDEO_TEE};
+use crate::video::clip_index;
+use crate::video::frame_cache::{FrameCache, RenderSettings};
+use crate::video::hover_preview::HoverPreview;
+use crate::video::perf_stats::{self, PerformanceCounters, Stage};
+use crate::video::watchdog::{Failure, Verdict, Watchdog};
+use crate::video::render_cache::{RenderCache, RenderStatusCallback, RENDER_TRACK_ID};
 
 pub type PositionUpdateCallback = Box<dyn Fn(f64, u64) -> Result<()> + Send + Sync>;
 pub type SeekCompletionCallback = Box<dyn Fn(u64) -> Result<()> + Send + Sync>;
+pub type PlaybackEventCallback = Box<dyn Fn(PlaybackEvent) -> Result<()> + Send + Sync>;
+pub type AudioLevelCallback = Box<dyn Fn(AudioLevels) -> Result<()> + Send + Sync>;
+pub type TimelineLoadCallback = Box<dyn Fn(TimelineLoadEvent) + Send + Sync>;
+pub type TimelineEventCallback = Box<dyn Fn(TimelineEvent) + Send + Sync>;
+/// Queues work on the GStreamer worker that owns the player, e.g. from a bus handler
+pub type PlayerTaskPoster = Arc<dyn Fn(Box<dyn FnOnce(&mut DirectPipelinePlayer) + Send>) + Send + Sync>;
+
+const DEFAULT_POSITION_UPDATE_INTERVAL_MS: u64 = 16;
+// Timeline canvas, also used for sources whose size can't be probed
+pub const DEFAULT_OUTPUT_WIDTH: i32 = 1920;
+pub const DEFAULT_OUTPUT_HEIGHT: i32 = 1080;
+const SOURCE_PROBE_TIMEOUT_SECS: u64 = 5;
+// Most files probed at once when a timeline loads
+const MAX_PROBE_THREADS: usize = 8;
+// Clip z-orders are the track's layer above this bit and the clip's index below it
+const ZORDER_LAYER_SHIFT: u32 = 16;
+const ZORDER_INDEX_MASK: u32 = (1 << ZORDER_LAYER_SHIFT) - 1;
+// Meter update rate, fast enough for smooth VU ballistics
+const AUDIO_LEVEL_INTERVAL_MS: u64 = 50;
+// Timeline level heard while recording a ducked voice-over (-18 dB)
+const VOICEOVER_DUCK_GAIN: f64 = 0.125;
+
+lazy_static::lazy_static! {
+    // Output volume elements of every player, kept in sync with the master volume
+    static ref MASTER_VOLUME_ELEMENTS: Mutex<Vec<gst::glib::WeakRef<gst::Element>>> = Mutex::new(Vec::new());
+}
+
+fn set_master_volume_properties(volume: &gst::Element) {
+    volume.set_property("volume", audio_handler::get_master_volume());
+    volume.set_property("mute", audio_handler::is_muted());
+}
+
+/// Keep an output volume element in sync with the master volume from now on
+pub(crate) fn follow_master_volume(volume: &gst::Element) {
+    set_master_volume_properties(volume);
+    MASTER_VOLUME_ELEMENTS.lock().unwrap().push(volume.downgrade());
+}
+
+/// Drain a decoder pad whose stream isn't played, so it doesn't stop the others with not-linked
+pub(crate) fn discard_pad(pipeline: &gst::Pipeline, pad: &gst::Pad) {
+    let result = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .property("async", false)
+        .build()
+        .map_err(|e| anyhow!("Failed to create fakesink: {}", e))
+        .and_then(|sink| {
+            pipeline.add(&sink)?;
+            sink.sync_state_with_parent()?;
+            pad.link(&sink.static_pad("sink").unwrap())?;
+            Ok(())
+        });
+    if let Err(e) = result {
+        warn!("Failed to discard unused stream: {}", e);
+    }
+}
+
+/// Push the current master volume and mute state to every player's audio output
+pub fn apply_master_volume() {
+    let mut elements = MASTER_VOLUME_ELEMENTS.lock().unwrap();
+    elements.retain(|weak| match weak.upgrade() {
+        Some(volume) => {
+            set_master_volume_properties(&volume);
+            true
+        }
+        None => false,
+    });
+}
 
 /// A direct GStreamer pipeline player that replaces GES with a custom compositor-based approach.
 /// This gives us full control over video mixing, positioning, and scaling without GES format negotiation issues.
+/// What probing a source file found out, once per file
+#[derive(Clone, Copy)]
+struct SourceProbe {
+    transfer: SourceTransfer,
+    /// `None` when the file doesn't declare it
+    color_space: Option<ColorSpace>,
+    /// Stored size and pixel aspect ratio of the first video stream
+    geometry: Option<((i32, i32), PixelAspectRatio)>,
+    /// `None` for still images and files without video
+    frame_rate: Option<FrameRate>,
+}
+
 pub struct DirectPipelinePlayer {
     pipeline: Option<gst::Pipeline>,
     compositor: Option<gst::Element>,
     audiomixer: Option<gst::Element>,
     clip_sources: HashMap<String, ClipSource>,
+    // Key in `clip_sources` of each clip with an id, so lookups by id don't scan every source
+    clip_keys: HashMap<i32, String>,
     texture_id: Option<i64>,
-    texture_update_fn: Option<Box<dyn Fn(FrameData) + Send + Sync>>,
+    texture_update_fn: Option<TextureUpdateFn>,
+    hover_preview: Option<HoverPreview>,
+    // Frame buffers recycled between the appsink and the texture
+    buffer_pool: Arc<Mutex<FrameBufferPool>>,
     is_playing: Arc<Mutex<bool>>,
+    buffering: Arc<Mutex<BufferingState>>,
     current_position_ms: Arc<Mutex<u64>>,
     duration_ms: Arc<Mutex<Option<u64>>>,
     position_callback: Arc<Mutex<Option<PositionUpdateCallback>>>,
     seek_completion_callback: Arc<Mutex<Option<SeekCompletionCallback>>>,
-    position_timer_id: Arc<Mutex<Option<gst::glib::SourceId>>>,
+    playback_event_callback: Arc<Mutex<Option<PlaybackEventCallback>>>,
+    audio_level_callback: Arc<Mutex<Option<AudioLevelCallback>>>,
+    bus_watch: Option<gst::bus::BusWatchGuard>,
+    position_timer: Arc<Mutex<Option<gst::glib::Source>>>,
+    position_update_interval_ms: u64,
     flutter_engine_handle: Option<i64>,
+    // Identifies this player's entries in the resource tracker
+    resource_owner: u64,
+    perf_counters: Arc<PerformanceCounters>,
+    // Composited frames shown while paused, reused when scrubbing back over them
+    frame_cache: FrameCache,
+    // Position shown from `frame_cache` that the pipeline hasn't been seeked to yet
+    deferred_seek_ms: Mutex<Option<u64>>,
+    watchdog: Watchdog,
+    // Hardware decoder errors posted on the bus, for the worker to fall back on
+    decoder_failures: Arc<Mutex<Vec<DecoderFailure>>>,
+    task_poster: Option<PlayerTaskPoster>,
+    // Sources whose hardware decoder failed, decoded in software from then on
+    software_decode_sources: HashSet<String>,
+    // What the current pipeline was built from (e.g. a soloed clip), for rebuilding it
+    pipeline_timeline: Option<TimelineData>,
+    video_sink: Option<gst::Element>,
+    scrub_mode: ScrubMode,
+    audio_sink: Arc<Mutex<Option<gst::Element>>>,
+    av_offset_ms: Arc<Mutex<i64>>,
+    end_behavior: Arc<Mutex<EndBehavior>>,
+    timeline_data: Option<TimelineData>,
+    solo_preview: Option<SoloPreviewState>,
+    render_cache: RenderCache,
+    next_clip_index: usize,
+    // Next id for clips that arrive without one; never reused, so ids stay stable across reloads
+    next_clip_id: i32,
+    next_caption_id: i32,
+    // Cues drawn by the caption overlay, from the pipeline's timeline
+    captions: SharedCaptions,
+    caption_appearance: CaptionAppearance,
+    preferred_render_path: RenderPath,
+    active_render_path: RenderPath,
+    render_path_fallback: Option<String>,
+    output_size: (i32, i32),
+    // Pixel shape of the output: the project's for timelines, the source's own in the source monitor
+    output_par: PixelAspectRatio,
+    project_par: PixelAspectRatio,
+    hdr_tone_mapping: bool,
+    color_space: ColorSpace,
+    conversion_settings: ConversionSettings,
+    source_probes: HashMap<String, SourceProbe>,
+    // Rate frame numbers are reported and seeked in: the override, or the first video clip's
+    frame_rate: Arc<Mutex<FrameRate>>,
+    frame_rate_override: Option<FrameRate>,
+    timeline_has_hdr: bool,
+    // Effect chains and volume automation of the loaded pipeline's tracks, by track id
+    track_audio_effects: HashMap<i32, Vec<TrackAudioEffect>>,
+    track_volume_keyframes: HashMap<i32, Vec<VolumeKeyframe>>,
+    track_types: HashMap<i32, TrackType>,
+    // Crossfades of the loaded pipeline's clips, by clip id
+    clip_fades: HashMap<i32, ClipFades>,
+    // Compositing layer of each track of the loaded pipeline, see `clip_index::track_layers`
+    track_layers: HashMap<i32, u32>,
+    edit_mode: EditMode,
+    auto_commit: bool,
+    // Edits made with auto-commit off, applied by `commit_timeline`
+    pending_changes: Vec<TimelineChange>,
+    voiceover: Option<VoiceoverSession>,
+    // Kept across pipeline rebuilds so the stream reconnects to the new pipeline
+    live_stream: Option<LiveStreamSettings>,
+    live_output: Option<LiveOutput>,
+    // Job id and progress callback of the background load in progress, if any
+    load_job: Option<(u64, TimelineLoadCallback)>,
+    timeline_event_callback: Option<TimelineEventCallback>,
+    #[cfg(target_os = "linux")]
+    gl_output: Option<Arc<crate::video::gl_texture::GlTextureOutput>>,
+}
+
+/// Timeline state saved while a single clip is previewed, restored on exit
+struct SoloPreviewState {
+    clip_id: i32,
+    position_ms: u64,
+    was_playing: bool,
+}
+
+/// Fill level of every network source posting buffering messages
+#[derive(Default)]
+struct BufferingState {
+    // Percent last reported by each source, keyed by its element path
+    percent: HashMap<String, i32>,
+    // Playback was paused by the player while a source refills its buffer
+    paused: bool,
+}
+
+/// A voice-over being recorded against the timeline
+struct VoiceoverSession {
+    recorder: VoiceoverRecorder,
+    track_id: i32,
+    start_ms: u64,
+    // Audio captured before timeline playback started, trimmed from the clip
+    lead_in_ms: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -36,57 +247,113 @@ struct ClipSource {
     videoconvert: gst::Element,
     videoscale: gst::Element,
     caps_filter: gst::Element,
+    // Crops the source for `ClipFitMode::Fill` and `Original`; CPU mixing only
+    fit_crop: Option<gst::Element>,
+    // `videoflip` or `glvideoflip` for mirroring and quarter turns
+    orientation_flip: Option<gst::Element>,
+    // `rotate` for tilts and rotation keyframes; only built for clips that tilt, on CPU mixing
+    tilt: Option<gst::Element>,
+    orientation: SharedOrientation,
+    // Animates `fit_crop` in place of the fit placement's crop while not empty
+    crop_keyframes: SharedCropKeyframes,
+    // Opacity the fade-in probe ramps the compositor pad up to; only for clips that crossfade in
+    fade_opacity: Option<SharedOpacity>,
     compositor_pad: Option<gst::Pad>,
-    audiomixer_pad: Option<gst::Pad>,
     clip_data: TimelineClip,
+    timing: SharedClipTiming,
+    audio_branch: Arc<Mutex<Option<AudioBranch>>>,
 }
 
-// SAFETY: We manually implement Send and Sync for DirectPipelinePlayer
-// This is necessary because GStreamer objects are not Send/Sync by default,
-// but we ensure that all GStreamer operations happen on the main thread.
-unsafe impl Send for DirectPipelinePlayer {}
-unsafe impl Sync for DirectPipelinePlayer {}
+/// Per-clip audio elements created once the decoder exposes audio
+#[derive(Debug)]
+struct AudioBranch {
+    audioconvert: gst::Element,
+    effect_chain: Option<gst::Element>,
+    volume_envelope: Option<gst::Element>,
+    audioresample: gst::Element,
+    audiomixer_pad: gst::Pad,
+}
 
 impl DirectPipelinePlayer {
     pub fn new() -> Result<Self> {
         gst::init().map_err(|e| anyhow!("Failed to initialize GStreamer: {}", e))?;
         
-        // Configure plugin rankings for macOS compatibility
-        #[cfg(target_os = "macos")]
-        {
-            use gst::prelude::*;
-            let registry = gst::Registry::get();
-            // Lower the rank of both vtdec variants to force software decoding
-            if let Some(vtdec_factory) = registry.find_feature("vtdec", gst::PluginFeature::static_type()) {
-                vtdec_factory.set_rank(gst::Rank::NONE);
-                info!("Disabled vtdec decoder on macOS during initialization");
-            }
-            if let Some(vtdec_hw_factory) = registry.find_feature("vtdec_hw", gst::PluginFeature::static_type()) {
-                vtdec_hw_factory.set_rank(gst::Rank::NONE);
-                info!("Disabled vtdec_hw decoder on macOS during initialization");
-            }
-            // Ensure avdec_h264 (software decoder) has higher priority
-            if let Some(avdec_factory) = registry.find_feature("avdec_h264", gst::PluginFeature::static_type()) {
-                avdec_factory.set_rank(gst::Rank::PRIMARY + 1);
-                info!("Prioritized avdec_h264 software decoder on macOS during initialization");
-            }
-        }
-        
+        // Hardware or software decoding, as the user last chose
+        crate::common::decoders::apply_policy();
+
         info!("GStreamer initialized successfully for direct pipeline approach.");
         Ok(Self {
             pipeline: None,
             compositor: None,
             audiomixer: None,
             clip_sources: HashMap::new(),
+            clip_keys: HashMap::new(),
             texture_id: None,
             texture_update_fn: None,
+            hover_preview: None,
+            buffer_pool: Arc::new(Mutex::new(FrameBufferPool::new(0, 0))),
             is_playing: Arc::new(Mutex::new(false)),
+            buffering: Arc::new(Mutex::new(BufferingState::default())),
             current_position_ms: Arc::new(Mutex::new(0)),
             duration_ms: Arc::new(Mutex::new(None)),
             position_callback: Arc::new(Mutex::new(None)),
             seek_completion_callback: Arc::new(Mutex::new(None)),
-            position_timer_id: Arc::new(Mutex::new(None)),
+            playback_event_callback: Arc::new(Mutex::new(None)),
+            audio_level_callback: Arc::new(Mutex::new(None)),
+            bus_watch: None,
+            position_timer: Arc::new(Mutex::new(None)),
+            position_update_interval_ms: DEFAULT_POSITION_UPDATE_INTERVAL_MS,
             flutter_engine_handle: None,
+            resource_owner: resources::next_owner_id(),
+            perf_counters: Arc::new(PerformanceCounters::default()),
+            frame_cache: FrameCache::new(),
+            deferred_seek_ms: Mutex::new(None),
+            watchdog: Watchdog::new(),
+            decoder_failures: Arc::new(Mutex::new(Vec::new())),
+            task_poster: None,
+            software_decode_sources: HashSet::new(),
+            pipeline_timeline: None,
+            video_sink: None,
+            scrub_mode: ScrubMode::default(),
+            audio_sink: Arc::new(Mutex::new(None)),
+            av_offset_ms: Arc::new(Mutex::new(0)),
+            end_behavior: Arc::new(Mutex::new(EndBehavior::default())),
+            timeline_data: None,
+            solo_preview: None,
+            render_cache: RenderCache::new(),
+            next_clip_index: 0,
+            next_clip_id: 1,
+            next_caption_id: 1,
+            captions: Arc::new(Mutex::new(Vec::new())),
+            caption_appearance: CaptionAppearance::default(),
+            preferred_render_path: RenderPath::default(),
+            active_render_path: RenderPath::default(),
+            render_path_fallback: None,
+            output_size: (DEFAULT_OUTPUT_WIDTH, DEFAULT_OUTPUT_HEIGHT),
+            output_par: PixelAspectRatio::SQUARE,
+            project_par: PixelAspectRatio::SQUARE,
+            color_space: ColorSpace::default(),
+            conversion_settings: ConversionSettings::default(),
+            hdr_tone_mapping: true,
+            source_probes: HashMap::new(),
+            frame_rate: Arc::new(Mutex::new(FrameRate::default())),
+            frame_rate_override: None,
+            timeline_has_hdr: false,
+            track_audio_effects: HashMap::new(),
+            track_types: HashMap::new(),
+            clip_fades: HashMap::new(),
+            track_layers: HashMap::new(),
+            edit_mode: EditMode::default(),
+            auto_commit: true,
+            pending_changes: Vec::new(),
+            track_volume_keyframes: HashMap::new(),
+            voiceover: None,
+            live_stream: None,
+            live_output: None,
+            load_job: None,
+            timeline_event_callback: None,
+            #[cfg(target_os = "linux")]
+            gl_output: None,
         })
     }
 
@@ -94,90 +361,979 @@ impl DirectPipelinePlayer {
     pub fn create_texture(&mut self, engine_handle: i64) -> Result<i64> {
         self.flutter_engine_handle = Some(engine_handle);
         
-        let (texture_id, update_fn) = create_player_texture(1920, 1080, engine_handle)?;
+        self.render_path_fallback = None;
+        #[cfg(target_os = "linux")]
+        if self.preferred_render_path != RenderPath::CpuRgba {
+            match crate::video::gl_texture::create_gl_player_texture(engine_handle) {
+                Ok((texture_id, gl_output)) => {
+                    self.texture_id = Some(texture_id);
+                    self.gl_output = Some(gl_output);
+                    self.active_render_path = self.preferred_render_path;
+                    resources::track(self.resource_owner, ResourceKind::Texture, Some(engine_handle), format!("GL texture {}", texture_id));
+                    return Ok(texture_id);
+                }
+                Err(e) => {
+                    warn!("GL texture unavailable, using CPU frames: {}", e);
+                    self.render_path_fallback = Some(format!("GL texture unavailable: {}", e));
+                }
+            }
+        }
+        #[cfg(not(target_os = "linux"))]
+        if self.preferred_render_path != RenderPath::CpuRgba {
+            self.render_path_fallback = Some(format!("{:?} is only implemented on Linux", self.preferred_render_path));
+        }
+        self.active_render_path = RenderPath::CpuRgba;
+        
+        // Pixel buffer textures take their size from each frame, so they follow output size changes
+        let (texture_id, update_fn) = create_player_texture(DEFAULT_OUTPUT_WIDTH as u32, DEFAULT_OUTPUT_HEIGHT as u32, engine_handle, Arc::clone(&self.buffer_pool))?;
         self.texture_id = Some(texture_id);
         self.texture_update_fn = Some(update_fn);
+        resources::track(self.resource_owner, ResourceKind::Texture, Some(engine_handle), format!("pixel buffer texture {}", texture_id));
         
         info!("Created GL-enabled texture with ID: {}", texture_id);
         Ok(texture_id)
     }
 
     pub fn load_timeline(&mut self, timeline_data: TimelineData) -> Result<()> {
+        self.output_size = (DEFAULT_OUTPUT_WIDTH, DEFAULT_OUTPUT_HEIGHT);
+        self.output_par = self.project_par;
+        self.load_timeline_data(timeline_data)
+    }
+
+    /// Load `timeline_data` as job `job_id`, reporting each clip and the preroll through
+    /// `on_event` and finishing with `Completed` or `Failed`. Meant to run on the player
+    /// thread while the caller returns the job id straight away.
+    pub fn load_timeline_job(&mut self, job_id: u64, timeline_data: TimelineData, on_event: TimelineLoadCallback) {
+        self.load_job = Some((job_id, on_event));
+        let result = self.load_timeline(timeline_data).map(|()| {
+            // Complete once the first frame is up, as a reload that restores the playhead does
+            if let Some(pipeline) = self.pipeline.as_ref() {
+                if pipeline.current_state() < gst::State::Paused {
+                    self.report_load_progress(TimelineLoadEvent::Prerolling { job_id });
+                    self.preroll_at_current_position(pipeline);
+                }
+            }
+        });
+        let event = match result {
+            Ok(()) => TimelineLoadEvent::Completed { job_id, duration_ms: self.get_duration_ms().unwrap_or(0) },
+            Err(e) => {
+                warn!("Timeline load {} failed: {}", job_id, e);
+                TimelineLoadEvent::Failed { job_id, error: FlipEditError::from(e) }
+            }
+        };
+        self.report_load_progress(event);
+        self.load_job = None;
+    }
+
+    /// Give clips without an id, or with one another clip already uses, an id of their own.
+    /// Existing ids are kept, so references held by Flutter survive reloads and saved projects.
+    fn assign_clip_ids(&mut self, timeline_data: &mut TimelineData) {
+        let max_id = timeline_data.tracks.iter()
+            .flat_map(|t| &t.clips)
+            .filter_map(|c| c.id)
+            .max()
+            .unwrap_or(0);
+        self.next_clip_id = self.next_clip_id.max(max_id + 1);
+        
+        let mut seen_ids = HashSet::new();
+        for clip in timeline_data.tracks.iter_mut().flat_map(|t| t.clips.iter_mut()) {
+            match clip.id {
+                Some(id) if seen_ids.insert(id) => {}
+                duplicate => {
+                    let id = self.allocate_clip_id();
+                    if let Some(duplicate) = duplicate {
+                        warn!("Clip id {} is used more than once, reassigning {} to {}", duplicate, clip.source_path, id);
+                    }
+                    clip.id = Some(id);
+                    seen_ids.insert(id);
+                }
+            }
+        }
+    }
+
+    /// Give cues without a unique id one and keep them in start order
+    fn assign_caption_ids(&mut self, captions: &mut [CaptionCue]) {
+        let max_id = captions.iter().map(|cue| cue.id).max().unwrap_or(0);
+        self.next_caption_id = self.next_caption_id.max(max_id + 1);
+        let mut seen_ids = HashSet::new();
+        for cue in captions.iter_mut() {
+            if cue.id <= 0 || !seen_ids.insert(cue.id) {
+                cue.id = self.next_caption_id;
+                self.next_caption_id += 1;
+                seen_ids.insert(cue.id);
+            }
+        }
+        captions.sort_by_key(|cue| (cue.start_ms, cue.end_ms));
+    }
+
+    fn allocate_clip_id(&mut self) -> i32 {
+        let id = self.next_clip_id;
+        self.next_clip_id += 1;
+        id
+    }
+
+    /// The loaded timeline, including ids assigned to clips that arrived without one
+    pub fn get_timeline_data(&self) -> Option<TimelineData> {
+        self.timeline_data.clone()
+    }
+
+    /// The loaded timeline as versioned JSON for project files
+    pub fn serialize_timeline(&self) -> Result<String> {
+        let timeline_data = self.timeline_data.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        timeline_json::to_json(timeline_data)
+    }
+
+    /// Replace the caption track with the cues of an SRT or WebVTT file
+    pub fn import_captions(&mut self, path: &str) -> Result<Vec<CaptionCue>> {
+        let mut cues = captions::import(path)?;
+        self.assign_caption_ids(&mut cues);
+        self.set_captions(cues.clone())?;
+        Ok(cues)
+    }
+
+    pub fn get_caption_cues(&self) -> Vec<CaptionCue> {
+        self.timeline_data.as_ref().map(|t| t.captions.clone()).unwrap_or_default()
+    }
+
+    /// Put a new cue on the caption track, returning it with its id
+    pub fn add_caption_cue(&mut self, text: String, start_ms: i32, end_ms: i32, style: CaptionStyle) -> Result<CaptionCue> {
+        captions::validate_timing(start_ms, end_ms)?;
+        let mut cues = self.get_caption_cues();
+        let cue = CaptionCue { id: self.next_caption_id, start_ms, end_ms, text, style };
+        cues.push(cue.clone());
+        self.assign_caption_ids(&mut cues);
+        self.set_captions(cues)?;
+        Ok(cue)
+    }
+
+    /// Change a cue's text, timing and style; the track is re-sorted if its start moved
+    pub fn update_caption_cue(&mut self, id: i32, text: String, start_ms: i32, end_ms: i32, style: CaptionStyle) -> Result<()> {
+        captions::validate_timing(start_ms, end_ms)?;
+        let mut cues = self.get_caption_cues();
+        let cue = cues.iter_mut().find(|cue| cue.id == id).ok_or_else(|| captions::not_found(id))?;
+        *cue = CaptionCue { id, start_ms, end_ms, text, style };
+        self.assign_caption_ids(&mut cues);
+        self.set_captions(cues)
+    }
+
+    /// Restyle the captions drawn over the preview, keeping the cues' own bold, italic and
+    /// placement
+    pub fn set_caption_appearance(&mut self, appearance: CaptionAppearance) -> Result<()> {
+        captions::validate_appearance(&appearance)?;
+        if let Some(overlay) = self.pipeline.as_ref().and_then(|p| p.by_name(captions::OVERLAY_NAME)) {
+            captions::apply_appearance(&overlay, &appearance);
+            // Cached frames have the old captions burnt in
+            self.frame_cache.clear();
+            self.refresh_paused_frame();
+        }
+        self.caption_appearance = appearance;
+        if self.render_cache.burns_captions() {
+            self.render_cache.set_caption_burn_in(Some(self.caption_appearance.clone()));
+            self.refresh_burnt_captions()?;
+        }
+        Ok(())
+    }
+
+    /// Burn the caption track into background-rendered segments, so the files rendered from the
+    /// timeline carry it; the preview then draws captions only outside rendered ranges.
+    /// Segments render again with or without them and are played once rendered.
+    pub fn set_caption_burn_in(&mut self, enabled: bool) -> Result<()> {
+        if enabled == self.render_cache.burns_captions() {
+            return Ok(());
+        }
+        self.render_cache.set_caption_burn_in(enabled.then(|| self.caption_appearance.clone()));
+        self.refresh_burnt_captions().map(|_| ())
+    }
+
+    pub fn get_caption_burn_in(&self) -> bool {
+        self.render_cache.burns_captions()
+    }
+
+    /// Render segments again for the captions to burn in, reloading if the live graph plays a
+    /// segment with other captions burnt in; returns whether it reloaded
+    fn refresh_burnt_captions(&mut self) -> Result<bool> {
+        let Some(timeline_data) = self.timeline_data.clone() else {
+            return Ok(false);
+        };
+        if self.solo_preview.is_none() && self.plays_rendered_range(&[(0, i32::MAX)]) {
+            self.load_timeline_data(timeline_data)?;
+            Ok(true)
+        } else {
+            // Leaving a solo preview substitutes the segments rendered by then
+            self.render_cache.update_timeline(&timeline_data, Arc::clone(&self.is_playing));
+            Ok(false)
+        }
+    }
+
+    pub fn get_caption_appearance(&self) -> CaptionAppearance {
+        self.caption_appearance.clone()
+    }
+
+    /// The timeline's markers as a YouTube description chapter list
+    pub fn export_chapters(&self) -> Result<String> {
+        let timeline_data = self.timeline_data.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        let timeline_end_ms = timeline_data.tracks.iter()
+            .flat_map(|t| &t.clips)
+            .map(|c| c.end_time_on_track_ms)
+            .max()
+            .unwrap_or(0);
+        chapters::youtube_chapters(&timeline_data.markers, timeline_end_ms)
+    }
+
+    /// Write the caption track as a sidecar subtitle file
+    pub fn export_captions(&self, format: CaptionFormat, path: &str) -> Result<()> {
+        let timeline_data = self.timeline_data.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        captions::export(&timeline_data.captions, format, path)
+    }
+
+    pub fn remove_caption_cue(&mut self, id: i32) -> Result<()> {
+        let mut cues = self.get_caption_cues();
+        let count = cues.len();
+        cues.retain(|cue| cue.id != id);
+        if cues.len() == count {
+            return Err(captions::not_found(id));
+        }
+        self.set_captions(cues)
+    }
+
+    /// Swap the caption track of the loaded timeline and redraw, without rebuilding the pipeline
+    fn set_captions(&mut self, cues: Vec<CaptionCue>) -> Result<()> {
+        self.timeline_data.as_mut().ok_or_else(FlipEditError::not_loaded)?.captions = cues.clone();
+        let reloaded = self.render_cache.burns_captions() && self.refresh_burnt_captions()?;
+        // Soloed clips are shown without captions
+        if !reloaded && self.solo_preview.is_none() {
+            let settings = self.render_settings();
+            if let Some(pipeline_timeline) = self.pipeline_timeline.as_mut() {
+                pipeline_timeline.captions = cues.clone();
+                self.frame_cache.set_timeline(pipeline_timeline, &settings);
+            }
+            *self.captions.lock().unwrap() = cues;
+            self.refresh_paused_frame();
+        }
+        self.emit_timeline_event(TimelineEvent::CaptionsChanged);
+        Ok(())
+    }
+
+    /// Receive every change made to the timeline model
+    pub fn set_timeline_event_callback(&mut self, callback: TimelineEventCallback) {
+        self.timeline_event_callback = Some(callback);
+    }
+
+    fn emit_timeline_event(&self, event: TimelineEvent) {
+        if let Some(callback) = self.timeline_event_callback.as_ref() {
+            callback(event);
+        }
+    }
+
+    fn report_load_progress(&self, event: TimelineLoadEvent) {
+        if let Some((_, on_event)) = self.load_job.as_ref() {
+            on_event(event);
+        }
+    }
+
+    /// Load a timeline into the current output size
+    fn load_timeline_data(&mut self, mut timeline_data: TimelineData) -> Result<()> {
         println!("🔥 LOAD_TIMELINE CALLED with {} tracks", timeline_data.tracks.len());
         info!("Loading timeline with {} tracks using direct GStreamer pipeline", timeline_data.tracks.len());
+        self.assign_clip_ids(&mut timeline_data);
+        self.assign_caption_ids(&mut timeline_data.captions);
+        self.watchdog.reset();
+        if !self.pending_changes.is_empty() {
+            warn!("Dropping {} uncommitted timeline changes", self.pending_changes.len());
+            self.pending_changes.clear();
+        }
+        
+        // Reloads keep the playhead where the user left it (the timeline one, if a clip is soloed)
+        let resume_state = match self.solo_preview.as_ref() {
+            Some(state) => Some((state.position_ms, state.was_playing)),
+            None if self.pipeline.is_some() => {
+                self.update_position();
+                Some((self.get_current_position_ms(), self.is_playing()))
+            }
+            None => None,
+        };
+
+        // Play cached renders in place of heavy ranges, and queue the rest for rendering
+        self.render_cache.update_timeline(&timeline_data, Arc::clone(&self.is_playing));
+        let playback_timeline = self.render_cache.substitute(&timeline_data);
+        self.load_pipeline(&playback_timeline)?;
+        
+        // A new timeline replaces whatever a solo preview would have restored
+        self.timeline_data = Some(timeline_data);
+        self.solo_preview = None;
+        
+        if let Some((position_ms, was_playing)) = resume_state {
+            self.restore_playhead(position_ms, was_playing)?;
+        }
+        Ok(())
+    }
+
+    /// Put the playhead back after a rebuild, showing the frame there and resuming if needed
+    fn restore_playhead(&self, position_ms: u64, was_playing: bool) -> Result<()> {
+        let position_ms = match self.get_duration_ms() {
+            Some(duration_ms) => position_ms.min(duration_ms),
+            None => position_ms,
+        };
+        info!("Restoring playhead to {}ms (playing: {})", position_ms, was_playing);
+        if let Some(&(job_id, _)) = self.load_job.as_ref() {
+            self.report_load_progress(TimelineLoadEvent::Prerolling { job_id });
+        }
+        *self.current_position_ms.lock().unwrap() = position_ms;
+        
+        if was_playing {
+            // play() prerolls and seeks to the current position first
+            self.play()?;
+        } else if let Some(pipeline) = self.pipeline.as_ref() {
+            self.preroll_at_current_position(pipeline);
+        }
+        Self::notify_position(&self.position_callback, self.get_frame_rate(), position_ms);
+        Ok(())
+    }
+
+    /// Rebuild the pipeline for `timeline_data` without touching the stored timeline
+    fn load_pipeline(&mut self, timeline_data: &TimelineData) -> Result<()> {
         self.stop_pipeline()?;
 
-        // Calculate timeline duration
+        // The timeline ends with its last clip
         let all_clips: Vec<_> = timeline_data.tracks.iter().flat_map(|t| &t.clips).collect();
-        let max_clip_end = all_clips
+        let duration_ms = all_clips
             .iter()
-            .map(|c| c.end_time_on_track_ms as u64)
+            .map(|c| c.end_time_on_track_ms.max(0) as u64)
             .max()
             .unwrap_or(0);
-        let duration_ms = max_clip_end.max(30000);
         
         info!("Timeline duration: {}ms with {} clips", duration_ms, all_clips.len());
-        *self.duration_ms.lock().unwrap() = Some(duration_ms);
+        self.set_duration_ms(duration_ms);
+        self.perf_counters.reset();
+        self.frame_cache.set_timeline(timeline_data, &self.render_settings());
+        self.pipeline_timeline = Some(timeline_data.clone());
+        *self.captions.lock().unwrap() = timeline_data.captions.clone();
 
         // Create the main pipeline
-        let pipeline = self.create_direct_pipeline(&timeline_data)?;
+        let pipeline = self.create_direct_pipeline(timeline_data)?;
+        if let Some(settings) = self.live_stream.as_ref() {
+            match LiveOutput::attach(&pipeline, settings, self.uses_gl_mixing()) {
+                Ok(output) => self.live_output = Some(output),
+                Err(e) => warn!("Failed to reconnect the live stream: {}", e),
+            }
+        }
+        resources::track(
+            self.resource_owner,
+            ResourceKind::Pipeline,
+            self.flutter_engine_handle,
+            format!("timeline pipeline {} ({} clips)", pipeline.name(), all_clips.len()),
+        );
         self.pipeline = Some(pipeline);
 
         info!("Direct pipeline loaded successfully, duration: {}ms", duration_ms);
         Ok(())
     }
 
-    fn create_direct_pipeline(&mut self, timeline_data: &TimelineData) -> Result<gst::Pipeline> {
-        println!("🔥 CREATING COMPOSITOR-BASED PIPELINE...");
-        let pipeline = gst::Pipeline::new();
-        println!("✅ Created new pipeline instance");
+    /// Render heavy ranges (at least `min_layers` overlapping clips) into `cache_dir` while idle.
+    /// Rendered ranges are played from the cache on the next timeline load.
+    pub fn enable_background_render(&mut self, cache_dir: &str, min_layers: usize) -> Result<()> {
+        self.render_cache.enable(cache_dir, min_layers)?;
+        if let Some(timeline_data) = self.timeline_data.clone() {
+            self.render_cache.update_timeline(&timeline_data, Arc::clone(&self.is_playing));
+        }
+        Ok(())
+    }
+
+    pub fn disable_background_render(&mut self) {
+        self.render_cache.disable();
+    }
+
+    pub fn get_render_segments(&self) -> Vec<RenderSegment> {
+        self.render_cache.get_segments()
+    }
+
+    pub fn set_render_status_callback(&mut self, callback: RenderStatusCallback) {
+        self.render_cache.set_status_callback(callback);
+    }
+
+    /// Choose how frames reach Flutter. Takes effect on the next `create_texture`.
+    pub fn set_preferred_render_path(&mut self, path: RenderPath) {
+        self.preferred_render_path = path;
+    }
+
+    /// Path actually in use, after any fallback at texture creation
+    pub fn get_active_render_path(&self) -> RenderPath {
+        self.active_render_path
+    }
+
+    /// Active render path, the reason for any fallback and the GPU driver, for bug reports
+    pub fn get_render_path_info(&self) -> RenderPathInfo {
+        #[cfg(target_os = "linux")]
+        let (gpu_vendor, gpu_renderer, gl_version) = match crate::video::gl_texture::flutter_gl_driver_info() {
+            Some(driver) => (Some(driver.vendor), Some(driver.renderer), Some(driver.version)),
+            None => (None, None, None),
+        };
+        #[cfg(not(target_os = "linux"))]
+        let (gpu_vendor, gpu_renderer, gl_version) = (None, None, None);
         
-        // Get all clips from timeline
-        let all_clips: Vec<_> = timeline_data.tracks.iter().flat_map(|t| &t.clips).collect();
-        if all_clips.is_empty() {
-            return Err(anyhow!("No clips to play"));
+        RenderPathInfo {
+            preferred: self.preferred_render_path,
+            active: self.active_render_path,
+            gpu_mixing: self.uses_gl_mixing(),
+            gpu_vendor,
+            gpu_renderer,
+            gl_version,
+            fallback_reason: self.render_path_fallback.clone(),
+        }
+    }
+
+    /// Whether clips are uploaded and mixed in GL rather than converted on the CPU.
+    /// Both GL texture paths mix on the GPU so several 4K layers stay realtime.
+    fn uses_gl_mixing(&self) -> bool {
+        matches!(self.active_render_path, RenderPath::GlMemory | RenderPath::DmaBuf)
+    }
+
+    /// Tone map PQ HDR clips to SDR in the preview. Takes effect on the next load, and needs
+    /// GPU mixing (a GL render path); other paths only keep HDR clips at 16 bits until output.
+    pub fn set_hdr_tone_mapping(&mut self, enabled: bool) {
+        self.hdr_tone_mapping = enabled;
+    }
+
+    pub fn get_hdr_tone_mapping(&self) -> bool {
+        self.hdr_tone_mapping
+    }
+
+    /// Tone map one clip regardless of the player setting, or follow it again with `None`;
+    /// applies on the next load
+    pub fn set_clip_tone_mapping(&mut self, clip_id: i32, enabled: Option<bool>) -> Result<()> {
+        self.update_timeline_clip(clip_id, |clip| clip.tone_mapping = enabled)
+    }
+
+    pub fn get_clip_tone_mapping(&self, clip_id: i32) -> Option<bool> {
+        self.timeline_clip(clip_id).ok().and_then(|clip| clip.tone_mapping)
+    }
+
+    /// Shader tone mapping a clip to SDR, if it's HDR and tone mapping is on for it
+    fn clip_tone_map_fragment(&mut self, clip_data: &TimelineClip) -> Option<&'static str> {
+        let enabled = clip_data.tone_mapping.unwrap_or(self.hdr_tone_mapping);
+        if !enabled {
+            return None;
+        }
+        self.source_probe(&clip_data.source_path).transfer.tone_map_fragment()
+    }
+
+    /// Color space clips are converted to before mixing; applies on the next load
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    pub fn get_color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Color space a source file declares, if any
+    pub fn get_source_color_space(&mut self, file_path: &str) -> Option<ColorSpace> {
+        self.source_probe(file_path).color_space
+    }
+
+    /// Threading and scaling filter of the CPU converters; applies on the next load
+    pub fn set_conversion_settings(&mut self, settings: ConversionSettings) {
+        self.conversion_settings = settings;
+    }
+
+    pub fn get_conversion_settings(&self) -> ConversionSettings {
+        self.conversion_settings
+    }
+
+    fn apply_conversion_threads(&self, element: &gst::Element) {
+        // videoscale only has n-threads from GStreamer 1.20
+        if element.find_property("n-threads").is_some() {
+            element.set_property("n-threads", self.conversion_settings.threads);
+        }
+    }
+
+    /// What cached preview frames were rendered with, besides the timeline
+    fn render_settings(&self) -> RenderSettings {
+        RenderSettings {
+            output_size: self.output_size,
+            output_par: self.output_par,
+            render_path: self.active_render_path,
+            color_space: self.color_space,
+            hdr_tone_mapping: self.hdr_tone_mapping,
+            conversion: self.conversion_settings,
+        }
+    }
+
+    /// Pixel format clips are mixed in on the CPU, when pinned by the render path
+    fn cpu_mixing_format(&self) -> Option<&'static str> {
+        match self.active_render_path {
+            RenderPath::Nv12 => Some("NV12"),
+            _ if self.mixes_high_bit_depth() => Some("AYUV64"),
+            _ => None,
+        }
+    }
+
+    /// Whether HDR clips are mixed at 16 bits per channel and dithered to RGBA only at the output,
+    /// so 10-bit gradients don't band
+    fn mixes_high_bit_depth(&self) -> bool {
+        self.timeline_has_hdr && !self.uses_gl_mixing() && self.active_render_path != RenderPath::Nv12
+    }
+
+    fn source_probe(&mut self, file_path: &str) -> SourceProbe {
+        if let Some(probe) = self.source_probes.get(file_path) {
+            return *probe;
+        }
+        let probe = Self::probe_source(file_path);
+        self.source_probes.insert(file_path.to_string(), probe);
+        probe
+    }
+
+    fn probe_source(file_path: &str) -> SourceProbe {
+        let stream = Self::discover_video_stream(file_path);
+        let caps = stream.as_ref().and_then(|stream| stream.caps());
+        let transfer = caps.as_ref()
+            .map(|caps| SourceTransfer::from_caps(caps))
+            .unwrap_or(SourceTransfer::Sdr);
+        if transfer.is_hdr() {
+            info!("{} is HDR ({:?})", file_path, transfer);
+        }
+        let color_space = caps.as_ref().and_then(|caps| color::source_color_space(caps));
+        let geometry = stream.as_ref().and_then(|stream| {
+            let (width, height) = (stream.width() as i32, stream.height() as i32);
+            let par = stream.par();
+            let par = if par.numer() > 0 && par.denom() > 0 {
+                PixelAspectRatio { numerator: par.numer(), denominator: par.denom() }
+            } else {
+                PixelAspectRatio::SQUARE
+            };
+            (width > 0 && height > 0).then_some(((width, height), par))
+        });
+        // Images report 0/1
+        let frame_rate = stream.as_ref()
+            .map(|stream| stream.framerate())
+            .filter(|rate| rate.numer() > 0 && rate.denom() > 0)
+            .map(|rate| FrameRate { numerator: rate.numer(), denominator: rate.denom() });
+        SourceProbe { transfer, color_space, geometry, frame_rate }
+    }
+
+    /// Pick the rate frame numbers are counted in for a newly loaded timeline
+    fn update_frame_rate(&mut self, timeline_data: &TimelineData) {
+        let frame_rate = self.frame_rate_override
+            .or_else(|| {
+                timeline_data.tracks.iter()
+                    .flat_map(|t| &t.clips)
+                    .find_map(|clip| self.source_probe(&clip.source_path).frame_rate)
+            })
+            .unwrap_or_default();
+        info!("Counting frames at {}/{} fps", frame_rate.numerator, frame_rate.denominator);
+        *self.frame_rate.lock().unwrap() = frame_rate;
+    }
+
+    /// Probe every distinct source of a timeline that hasn't been probed yet, several at a time.
+    /// Discovery blocks on each file's demuxer, so projects with many assets open much faster
+    /// than probing them one by one as clips are added.
+    fn probe_sources(&mut self, timeline_data: &TimelineData) {
+        let mut pending: Vec<&str> = timeline_data.tracks.iter()
+            .flat_map(|t| &t.clips)
+            .map(|c| c.source_path.as_str())
+            .filter(|path| !self.source_probes.contains_key(*path))
+            .collect();
+        pending.sort();
+        pending.dedup();
+        if pending.is_empty() {
+            return;
         }
         
-        info!("Creating compositor pipeline with {} clips", all_clips.len());
+        let threads = std::thread::available_parallelism()
+            .map_or(4, |n| n.get())
+            .min(MAX_PROBE_THREADS)
+            .min(pending.len());
+        info!("Probing {} sources on {} threads", pending.len(), threads);
+        let next = AtomicUsize::new(0);
+        let probed = Mutex::new(Vec::with_capacity(pending.len()));
+        std::thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    while let Some(path) = pending.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let probe = Self::probe_source(path);
+                        probed.lock().unwrap().push((path.to_string(), probe));
+                    }
+                });
+            }
+        });
+        self.source_probes.extend(probed.into_inner().unwrap());
+    }
+
+    /// Caps forcing an exact size on a clip before the mixer, or on the mixer output. Clip boxes
+    /// are in output pixels, so clips share the output's pixel aspect ratio.
+    fn mixing_caps(&self, width: i32, height: i32) -> gst::Caps {
+        let mut builder = gst::Caps::builder("video/x-raw")
+            .field("width", width)
+            .field("height", height)
+            .field("pixel-aspect-ratio", gst::Fraction::new(self.output_par.numerator, self.output_par.denominator));
+        if let Some(format) = self.cpu_mixing_format() {
+            builder = builder.field("format", format);
+        }
+        // GL conversion only handles the YUV matrix, so the project space is applied on the CPU path
+        if !self.uses_gl_mixing() {
+            builder = builder.field("colorimetry", self.color_space.colorimetry());
+        }
+        let mut caps = builder.build();
+        if self.uses_gl_mixing() {
+            caps.get_mut().unwrap().set_features(0, Some(gst::CapsFeatures::new(["memory:GLMemory"])));
+        }
+        caps
+    }
+
+    /// Temporarily play only `clip_id` full-frame without altering the loaded timeline.
+    /// Call `exit_clip_preview` to return to the timeline at the previous playhead.
+    pub fn preview_clip(&mut self, clip_id: i32) -> Result<()> {
+        let timeline_data = self.timeline_data.clone().ok_or_else(FlipEditError::not_loaded)?;
+        let clip = timeline_data.tracks.iter()
+            .flat_map(|t| &t.clips)
+            .find(|c| c.id == Some(clip_id))
+            .cloned()
+            .ok_or_else(|| FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Clip {} not found in timeline", clip_id),
+            ))?;
         
-        // Create compositor and audiomixer for combining multiple clips
-        let compositor = gst::ElementFactory::make("compositor")
-            .name("compositor")
-            .build()
-            .map_err(|e| anyhow!("Failed to create compositor: {}", e))?;
+        // Keep the original state when switching between solo clips
+        let saved_state = match self.solo_preview.take() {
+            Some(state) => state,
+            None => {
+                self.update_position();
+                SoloPreviewState {
+                    clip_id,
+                    position_ms: self.get_current_position_ms(),
+                    was_playing: self.is_playing(),
+                }
+            }
+        };
         
-        let audiomixer = gst::ElementFactory::make("audiomixer")
-            .name("audiomixer")
-            .build()
-            .map_err(|e| anyhow!("Failed to create audiomixer: {}", e))?;
+        info!("Previewing clip {} ({}) in isolation", clip_id, clip.source_path);
+        (self.output_size, self.output_par) = self.source_probe(&clip.source_path).geometry
+            .unwrap_or(((DEFAULT_OUTPUT_WIDTH, DEFAULT_OUTPUT_HEIGHT), PixelAspectRatio::SQUARE));
+        let clip_length_ms = (clip.end_time_on_track_ms - clip.start_time_on_track_ms).max(0);
+        let solo_clip = TimelineClip {
+            start_time_on_track_ms: 0,
+            end_time_on_track_ms: clip_length_ms,
+            preview_position_x: 0.0,
+            preview_position_y: 0.0,
+            preview_width: self.output_size.0 as f64,
+            preview_height: self.output_size.1 as f64,
+            preview_opacity: 1.0,
+            ..clip.clone()
+        };
+        // The solo track keeps the clip's effects and automation, shifted to start at 0
+        let track = timeline_data.tracks.iter().find(|t| t.id == clip.track_id);
+        let audio_effects = track.map(|t| t.audio_effects.clone()).unwrap_or_default();
+        let clip_start_ms = clip.start_time_on_track_ms.max(0) as u64;
+        let volume_keyframes = match track {
+            Some(track) if !track.volume_keyframes.is_empty() => {
+                let first = VolumeKeyframe {
+                    time_ms: 0,
+                    gain_db: audio_automation::gain_db_at(&track.volume_keyframes, clip_start_ms as f64),
+                };
+                std::iter::once(first)
+                    .chain(track.volume_keyframes.iter()
+                        .filter(|k| k.time_ms > clip_start_ms)
+                        .map(|k| VolumeKeyframe { time_ms: k.time_ms - clip_start_ms, ..*k }))
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        let solo_timeline = TimelineData {
+            tracks: vec![TimelineTrack {
+                id: clip.track_id,
+                name: "Solo".to_string(),
+                clips: vec![solo_clip],
+                audio_effects,
+                volume_keyframes,
+                track_type: track.map(|t| t.track_type).unwrap_or_default(),
+                priority: 0,
+            }],
+            captions: Vec::new(),
+            markers: Vec::new(),
+            auto_transition: false,
+        };
         
-        // Create video sink
-        let video_sink = self.create_texture_video_sink()?;
+        self.load_pipeline(&solo_timeline)?;
+        self.set_duration_ms(clip_length_ms as u64);
+        self.solo_preview = Some(SoloPreviewState { clip_id, ..saved_state });
         
-        // Add elements to pipeline
-        pipeline.add(&compositor)?;
-        pipeline.add(&audiomixer)?;
-        pipeline.add(&video_sink)?;
+        self.play()
+    }
+
+    /// Leave solo clip preview and reload the timeline at the saved playhead
+    pub fn exit_clip_preview(&mut self) -> Result<()> {
+        let Some(state) = self.solo_preview.take() else {
+            return Ok(());
+        };
+        let timeline_data = self.timeline_data.clone().ok_or_else(FlipEditError::not_loaded)?;
         
-        // Link compositor to video sink
-        compositor.link(&video_sink)?;
+        info!("Leaving preview of clip {}, restoring timeline at {}ms", state.clip_id, state.position_ms);
+        self.output_size = (DEFAULT_OUTPUT_WIDTH, DEFAULT_OUTPUT_HEIGHT);
+        self.output_par = self.project_par;
+        let playback_timeline = self.render_cache.substitute(&timeline_data);
+        self.load_pipeline(&playback_timeline)?;
+        self.restore_playhead(state.position_ms, state.was_playing)
+    }
+
+    /// Clip currently shown by `preview_clip`, if any
+    pub fn get_previewed_clip_id(&self) -> Option<i32> {
+        self.solo_preview.as_ref().map(|state| state.clip_id)
+    }
+
+    /// Load a single source file full-frame, e.g. for the source monitor
+    pub fn load_source_clip(&mut self, file_path: &str, duration_ms: u64) -> Result<()> {
+        FlipEditError::check_file_exists(file_path)?;
+        // A different file starts from the beginning rather than the previous playhead
+        self.stop_pipeline()?;
         
-        // Store references for later use
-        self.compositor = Some(compositor.clone());
-        self.audiomixer = Some(audiomixer.clone());
+        // Render at the source's own size and pixel shape so portrait, 4K and anamorphic files
+        // aren't letterboxed or resampled into the canvas
+        (self.output_size, self.output_par) = self.source_probe(file_path).geometry
+            .unwrap_or(((DEFAULT_OUTPUT_WIDTH, DEFAULT_OUTPUT_HEIGHT), PixelAspectRatio::SQUARE));
         
-        // Add each clip to the pipeline
-        for (index, clip) in all_clips.iter().enumerate() {
-            info!("Adding clip {} to pipeline: {}", index + 1, clip.source_path);
-            
-            // Check if file exists
-            if !std::path::Path::new(&clip.source_path).exists() {
-                warn!("Video file does not exist, skipping: {}", clip.source_path);
-                continue;
-            }
-            
-            self.add_clip_source(&pipeline, &compositor, &audiomixer, clip, index)?;
+        let duration_ms = duration_ms.min(i32::MAX as u64) as i32;
+        let clip = TimelineClip {
+            id: None,
+            track_id: 0,
+            source_path: file_path.to_string(),
+            start_time_on_track_ms: 0,
+            end_time_on_track_ms: duration_ms,
+            start_time_in_source_ms: 0,
+            end_time_in_source_ms: duration_ms,
+            preview_position_x: 0.0,
+            preview_position_y: 0.0,
+            preview_width: self.output_size.0 as f64,
+            preview_height: self.output_size.1 as f64,
+            preview_opacity: 1.0,
+            track_type: TrackType::Both,
+            fit_mode: ClipFitMode::default(),
+            orientation: ClipOrientation::default(),
+            crop_keyframes: Vec::new(),
+            audio_effects: Vec::new(),
+            tone_mapping: None,
+            transition_in_ms: 0,
+        };
+        
+        self.load_timeline_data(TimelineData {
+            tracks: vec![TimelineTrack {
+                id: 0,
+                name: "Source".to_string(),
+                clips: vec![clip],
+                audio_effects: Vec::new(),
+                volume_keyframes: Vec::new(),
+                track_type: TrackType::Both,
+                priority: 0,
+            }],
+            captions: Vec::new(),
+            markers: Vec::new(),
+            auto_transition: false,
+        })?;
+        
+        // Source monitors report the real file length rather than the timeline minimum
+        self.set_duration_ms(duration_ms as u64);
+        Ok(())
+    }
+
+    /// First video stream of a file, as reported by the discoverer
+    fn discover_video_stream(file_path: &str) -> Option<gst_pbutils::DiscovererVideoInfo> {
+        let discoverer = gst_pbutils::Discoverer::new(gst::ClockTime::from_seconds(SOURCE_PROBE_TIMEOUT_SECS)).ok()?;
+        match discoverer.discover_uri(&source_uri(file_path)) {
+            Ok(info) => info.video_streams().into_iter().next(),
+            Err(e) => {
+                warn!("Failed to probe {}: {}", file_path, e);
+                None
+            }
+        }
+    }
+
+    /// Pixel aspect ratio of the timeline canvas, e.g. 4/3 to edit 1440x1080 HDV at its stored
+    /// size; applies on the next load
+    pub fn set_pixel_aspect_ratio(&mut self, par: PixelAspectRatio) {
+        if par.numerator > 0 && par.denominator > 0 {
+            self.project_par = par;
+        }
+    }
+
+    pub fn get_pixel_aspect_ratio(&self) -> PixelAspectRatio {
+        self.project_par
+    }
+
+    /// Tell Flutter the new frame size and rate whenever the sink negotiates different caps
+    fn watch_output_size(&self, video_sink: &gst::Element) {
+        let Some(sink_pad) = video_sink.static_pad("sink") else {
+            return;
+        };
+        let playback_event_callback = Arc::clone(&self.playback_event_callback);
+        let last_size = Mutex::new(None);
+        let last_info = Mutex::new(None);
+        
+        sink_pad.add_probe(gst::PadProbeType::EVENT_DOWNSTREAM, move |_, info| {
+            let Some(gst::PadProbeData::Event(ref event)) = info.data else {
+                return gst::PadProbeReturn::Ok;
+            };
+            let gst::EventView::Caps(caps_event) = event.view() else {
+                return gst::PadProbeReturn::Ok;
+            };
+            let Some(s) = caps_event.caps().structure(0) else {
+                return gst::PadProbeReturn::Ok;
+            };
+            if let (Ok(width), Ok(height)) = (s.get::<i32>("width"), s.get::<i32>("height")) {
+                let par = s.get::<gst::Fraction>("pixel-aspect-ratio")
+                    .map(|par| PixelAspectRatio { numerator: par.numer(), denominator: par.denom() })
+                    .unwrap_or_default();
+                let mut last_size = last_size.lock().unwrap();
+                if *last_size != Some((width, height, par)) {
+                    *last_size = Some((width, height, par));
+                    info!("Video output size is now {}x{} (PAR {}/{})", width, height, par.numerator, par.denominator);
+                    Self::emit_playback_event(&playback_event_callback, PlaybackEvent::VideoSizeChanged {
+                        width: width as u32,
+                        height: height as u32,
+                        display_width: par.display_width(width) as u32,
+                    });
+                }
+                let fps = s.get::<gst::Fraction>("framerate")
+                    .ok()
+                    .filter(|fps| fps.numer() > 0 && fps.denom() > 0)
+                    .map(|fps| FrameRate { numerator: fps.numer(), denominator: fps.denom() });
+                let mut last_info = last_info.lock().unwrap();
+                if *last_info != Some((width, height, par, fps)) {
+                    *last_info = Some((width, height, par, fps));
+                    Self::emit_playback_event(&playback_event_callback, PlaybackEvent::VideoInfoChanged {
+                        width: width as u32,
+                        height: height as u32,
+                        par,
+                        fps,
+                    });
+                }
+            }
+            gst::PadProbeReturn::Ok
+        });
+    }
+
+    fn create_direct_pipeline(&mut self, timeline_data: &TimelineData) -> Result<gst::Pipeline> {
+        println!("🔥 CREATING COMPOSITOR-BASED PIPELINE...");
+        let pipeline = gst::Pipeline::new();
+        println!("✅ Created new pipeline instance");
+        
+        // Get all clips from timeline
+        let all_clips: Vec<_> = timeline_data.tracks.iter().flat_map(|t| &t.clips).collect();
+        if all_clips.is_empty() {
+            return Err(anyhow!("No clips to play"));
+        }
+        
+        info!("Creating compositor pipeline with {} clips", all_clips.len());
+        self.track_audio_effects = timeline_data.tracks.iter()
+            .filter(|t| !t.audio_effects.is_empty())
+            .map(|t| (t.id, t.audio_effects.clone()))
+            .collect();
+        self.track_volume_keyframes = timeline_data.tracks.iter()
+            .filter(|t| !t.volume_keyframes.is_empty())
+            .map(|t| (t.id, t.volume_keyframes.clone()))
+            .collect();
+        self.track_types = timeline_data.tracks.iter()
+            .filter(|t| t.track_type != TrackType::Both)
+            .map(|t| (t.id, t.track_type))
+            .collect();
+        self.clip_fades = transitions::clip_fades(timeline_data);
+        self.track_layers = clip_index::track_layers(timeline_data);
+        self.probe_sources(timeline_data);
+        self.update_frame_rate(timeline_data);
+        self.timeline_has_hdr = all_clips.iter().any(|clip| self.source_probe(&clip.source_path).transfer.is_hdr());
+        if self.timeline_has_hdr && self.hdr_tone_mapping && !self.uses_gl_mixing() {
+            info!("HDR clips are mixed at 16 bits but not tone mapped; tone mapping needs a GL render path");
+        }
+        
+        // Create compositor and audiomixer for combining multiple clips. GL mixing keeps
+        // frames on the GPU; its pads take the same xpos/ypos/width/height/zorder properties.
+        let mixer_factory = if self.uses_gl_mixing() { "glvideomixer" } else { "compositor" };
+        let compositor = gst::ElementFactory::make(mixer_factory)
+            .name("compositor")
+            .build()
+            .map_err(|e| anyhow!("Failed to create {}: {}", mixer_factory, e))?;
+        
+        let audiomixer = gst::ElementFactory::make("audiomixer")
+            .name("audiomixer")
+            .build()
+            .map_err(|e| anyhow!("Failed to create audiomixer: {}", e))?;
+        
+        // Create video sink
+        let video_sink = self.create_texture_video_sink()?;
+        
+        // The program output is split so live streams can be attached while playing
+        let program_tee = gst::ElementFactory::make("tee")
+            .name(PROGRAM_VIDEO_TEE)
+            .property("allow-not-linked", true)
+            .build()
+            .map_err(|e| anyhow!("Failed to create program tee: {}", e))?;
+        
+        // Add elements to pipeline
+        pipeline.add(&compositor)?;
+        pipeline.add(&audiomixer)?;
+        pipeline.add(&program_tee)?;
+        pipeline.add(&video_sink)?;
+        
+        // Captions are drawn over the mix before it's split, so live streams carry them too.
+        // textoverlay draws into system memory, so GPU mixing goes without.
+        let caption_overlay = if self.uses_gl_mixing() {
+            None
+        } else {
+            captions::create_overlay(&self.captions, &self.caption_appearance)
+        };
+        let (program_input, program_input_pad) = match caption_overlay {
+            Some(overlay) => {
+                pipeline.add(&overlay)?;
+                overlay.link(&program_tee)?;
+                (overlay, Some("video_sink"))
+            }
+            None => (program_tee.clone(), None),
+        };
+        
+        // Link compositor to video sink at the output size. In NV12 mode the GL sink converts to RGBA on the GPU.
+        let (output_width, output_height) = self.output_size;
+        let output_caps = self.mixing_caps(output_width, output_height);
+        if self.mixes_high_bit_depth() {
+            // Quantize the 16-bit mix to the sink's 8-bit RGBA once, with dithering
+            let output_convert = gst::ElementFactory::make("videoconvert")
+                .name("output_convert")
+                .build()
+                .map_err(|e| anyhow!("Failed to create output videoconvert: {}", e))?;
+            output_convert.set_property_from_str("dither", "floyd-steinberg");
+            self.apply_conversion_threads(&output_convert);
+            pipeline.add(&output_convert)?;
+            compositor.link_filtered(&output_convert, &output_caps)?;
+            output_convert.link_pads(None, &program_input, program_input_pad)?;
+        } else {
+            compositor.link_pads_filtered(None, &program_input, program_input_pad, &output_caps)?;
+        }
+        program_tee.link(&video_sink)?;
+        self.watch_output_size(&video_sink);
+        self.video_sink = Some(video_sink);
+        
+        // Store references for later use
+        self.compositor = Some(compositor.clone());
+        self.audiomixer = Some(audiomixer.clone());
+        
+        // Add each clip to the pipeline
+        for (index, clip) in all_clips.iter().enumerate() {
+            info!("Adding clip {} to pipeline: {}", index + 1, clip.source_path);
+            
+            // Check if file exists
+            if FlipEditError::check_file_exists(&clip.source_path).is_err() {
+                warn!("Video file does not exist, skipping: {}", clip.source_path);
+            } else {
+                self.add_clip_source(&pipeline, &compositor, &audiomixer, clip, index)?;
+                self.next_clip_index = index + 1;
+            }
+            if let Some(&(job_id, _)) = self.load_job.as_ref() {
+                self.report_load_progress(TimelineLoadEvent::ClipLoaded {
+                    job_id,
+                    clip_id: clip.id,
+                    loaded: index as u32 + 1,
+                    total: all_clips.len() as u32,
+                });
+            }
         }
+        self.raise_crossfading_clips();
         
         // Set up message bus handling
         println!("🔥 SETTING UP MESSAGE BUS...");
@@ -196,7 +1352,7 @@ impl DirectPipelinePlayer {
         clip_data: &TimelineClip,
         index: usize,
     ) -> Result<()> {
-        let uri = format!("file://{}", clip_data.source_path);
+        let uri = source_uri(&clip_data.source_path);
         info!("Adding clip {} from URI: {}", index + 1, uri);
         
         // Create uridecodebin for this clip
@@ -204,32 +1360,109 @@ impl DirectPipelinePlayer {
             .property("uri", &uri)
             .build()
             .map_err(|e| anyhow!("Failed to create uridecodebin for clip {}: {}", index + 1, e))?;
+        if is_network_source(&clip_data.source_path) && !is_live_source(&clip_data.source_path) {
+            // Post buffering messages so playback waits for the download instead of stuttering
+            uridecodebin.set_property("use-buffering", true);
+        }
+        if self.software_decode_sources.contains(&clip_data.source_path) {
+            decoders::skip_hardware_decoders(&uridecodebin);
+        }
         
         // Create video processing elements
-        let videoconvert = gst::ElementFactory::make("videoconvert")
-            .build()
-            .map_err(|e| anyhow!("Failed to create videoconvert for clip {}: {}", index + 1, e))?;
+        let tone_map_fragment = self.clip_tone_map_fragment(clip_data);
+        let clip_orientation = clip_data.orientation.clone();
+        let wants_tilt = orientation::needs_tilt(&clip_orientation);
+        let (videoconvert, videoscale, fit_crop) = if self.uses_gl_mixing() {
+            let upload = if self.active_render_path == RenderPath::DmaBuf {
+                // Narrowed to DMA-BUF once the decoder's pad shows it can output it
+                "capsfilter name=dmabuf_caps ! glupload"
+            } else {
+                "glupload"
+            };
+            let description = if tone_map_fragment.is_some() {
+                // Keep the HDR signal at 16 bits until the shader has tone mapped it
+                format!("{} ! glcolorconvert ! video/x-raw(memory:GLMemory),format=RGBA64_LE ! glshader name=tone_map ! glvideoflip name=orientation", upload)
+            } else {
+                format!("{} ! glcolorconvert ! glvideoflip name=orientation", upload)
+            };
+            if wants_tilt {
+                warn!("Clip {} is tilted, which needs a CPU render path; only its quarter turns and flips are shown", index + 1);
+            }
+            let upload_bin = gst::parse::bin_from_description(&description, true)
+                .map_err(|e| anyhow!("Failed to create GL upload for clip {}: {}", index + 1, e))?;
+            if let (Some(shader), Some(fragment)) = (upload_bin.by_name("tone_map"), tone_map_fragment) {
+                shader.set_property("fragment", fragment);
+                info!("Tone mapping HDR clip {} on the GPU", index + 1);
+            }
+            let videoconvert = upload_bin.upcast::<gst::Element>();
+            let videoscale = gst::ElementFactory::make("glcolorscale")
+                .build()
+                .map_err(|e| anyhow!("Failed to create glcolorscale for clip {}: {}", index + 1, e))?;
+            (videoconvert, videoscale, None)
+        } else {
+            let tilt = if !wants_tilt {
+                ""
+            } else if gst::ElementFactory::find("rotate").is_some() {
+                "rotate name=tilt ! "
+            } else {
+                warn!("Can't tilt clip {}: the rotate element (gst-plugins-bad) isn't installed", index + 1);
+                ""
+            };
+            let description = format!("videoconvert name=convert ! videoflip name=orientation ! {}videocrop name=fit_crop", tilt);
+            let convert_bin = gst::parse::bin_from_description(&description, true)
+                .map_err(|e| anyhow!("Failed to create videoconvert for clip {}: {}", index + 1, e))?;
+            let fit_crop = convert_bin.by_name("fit_crop");
+            // Without this only the matrix changes, and BT.2020 clips look washed out in BT.709
+            if let Some(convert) = convert_bin.by_name("convert") {
+                convert.set_property_from_str("primaries-mode", "fast");
+                self.apply_conversion_threads(&convert);
+            }
+            let videoconvert = convert_bin.upcast::<gst::Element>();
+            match self.source_probe(&clip_data.source_path).color_space {
+                Some(source_space) if source_space != self.color_space => {
+                    info!("Converting clip {} from {:?} to {:?}", index + 1, source_space, self.color_space);
+                }
+                Some(_) => {}
+                None => debug!("Clip {} doesn't declare its color space; guessing from its size", index + 1),
+            }
+            
+            let videoscale = gst::ElementFactory::make("videoscale")
+                .property("add-borders", false)
+                .build()
+                .map_err(|e| anyhow!("Failed to create videoscale for clip {}: {}", index + 1, e))?;
+            videoscale.set_property_from_str("method", self.conversion_settings.scaling_method.nick());
+            self.apply_conversion_threads(&videoscale);
+            (videoconvert, videoscale, fit_crop)
+        };
         
-        let videoscale = gst::ElementFactory::make("videoscale")
-            .property("add-borders", false)
-            .build()
-            .map_err(|e| anyhow!("Failed to create videoscale for clip {}: {}", index + 1, e))?;
+        let bin = videoconvert.downcast_ref::<gst::Bin>();
+        let orientation_flip = bin.and_then(|bin| bin.by_name("orientation"));
+        if let Some(flip) = orientation_flip.as_ref() {
+            flip.set_property_from_str("method", orientation::flip_method(&clip_orientation));
+        }
+        let tilt = bin.and_then(|bin| bin.by_name("tilt"));
+        #[cfg(target_os = "linux")]
+        let dmabuf_caps = bin.and_then(|bin| bin.by_name("dmabuf_caps")).map(|capsfilter| capsfilter.downgrade());
+        let shared_orientation: SharedOrientation = Arc::new(Mutex::new(clip_orientation));
+        let crop_keyframes = clip_data.crop_keyframes.clone();
+        if fit_crop.is_none() && !crop_keyframes.is_empty() {
+            warn!("Clip {} pans and zooms, which needs a CPU render path; showing it unanimated", index + 1);
+        }
+        let animates_crop = !crop_keyframes.is_empty();
+        let shared_crop_keyframes: SharedCropKeyframes = Arc::new(Mutex::new(crop_keyframes));
         
-        // Set scaling method to nearest neighbor for performance
-        videoscale.set_property_from_str("method", "nearest-neighbour");
+        // With GPU mixing the upload bin is where frames reach the GPU
+        perf_stats::instrument_decoders(&uridecodebin, &self.perf_counters);
+        let convert_stage = if self.uses_gl_mixing() { Stage::Upload } else { Stage::Convert };
+        perf_stats::instrument_element(&videoconvert, convert_stage, &self.perf_counters);
         
         // Create caps filter for explicit width/height sizing without aspect ratio preservation
         let caps_filter = gst::ElementFactory::make("capsfilter")
             .build()
             .map_err(|e| anyhow!("Failed to create capsfilter for clip {}: {}", index + 1, e))?;
         
-        // Set explicit caps to force exact dimensions from inspector values
-        let caps = gst::Caps::builder("video/x-raw")
-            .field("width", clip_data.preview_width as i32)
-            .field("height", clip_data.preview_height as i32)
-            .field("pixel-aspect-ratio", gst::Fraction::new(1, 1)) // Force square pixels
-            .build();
-        caps_filter.set_property("caps", &caps);
+        // Size the clip from the inspector box and its fit mode
+        let placement = self.clip_placement(clip_data, fit_crop.is_some());
         
         // Add elements to pipeline
         pipeline.add(&uridecodebin)?;
@@ -241,34 +1474,56 @@ impl DirectPipelinePlayer {
         videoconvert.link(&videoscale)?;
         videoscale.link(&caps_filter)?;
         
-        // Request pads from compositor and audiomixer
+        // Request a pad from the compositor; the audiomixer pad is requested once the
+        // decoder exposes an audio stream so silent clips don't stall the mixer
         let compositor_pad = compositor.request_pad_simple("sink_%u")
             .ok_or_else(|| anyhow!("Failed to request compositor pad for clip {}", index + 1))?;
         
-        let audiomixer_pad = audiomixer.request_pad_simple("sink_%u")
-            .ok_or_else(|| anyhow!("Failed to request audiomixer pad for clip {}", index + 1))?;
-        
         // Link caps_filter directly to compositor
         let caps_filter_src_pad = caps_filter.static_pad("src")
             .ok_or_else(|| anyhow!("Failed to get src pad from caps_filter for clip {}", index + 1))?;
         caps_filter_src_pad.link(&compositor_pad)?;
         
         // Set compositor pad properties for positioning and sizing
-        compositor_pad.set_property("zorder", index as u32);
-        compositor_pad.set_property("xpos", clip_data.preview_position_x as i32);
-        compositor_pad.set_property("ypos", clip_data.preview_position_y as i32);
-        compositor_pad.set_property("width", clip_data.preview_width as i32);
-        compositor_pad.set_property("height", clip_data.preview_height as i32);
+        compositor_pad.set_property("zorder", self.clip_zorder(clip_data.track_id, index));
+        compositor_pad.set_property("alpha", clip_data.preview_opacity.clamp(0.0, 1.0));
+        self.apply_placement(&placement, &caps_filter, Some(&compositor_pad), fit_crop.as_ref().filter(|_| !animates_crop));
         
         info!("Set compositor pad properties for clip {}: pos=({}, {}), size=({}, {})", 
-            index + 1, clip_data.preview_position_x, clip_data.preview_position_y, 
-            clip_data.preview_width, clip_data.preview_height);
+            index + 1, placement.x, placement.y, placement.width, placement.height);
         
-        // Set up pad-added callback for uridecodebin first (before moving audiomixer_pad)
+        // Set up pad-added callback for uridecodebin
         let pipeline_weak = pipeline.downgrade();
         let videoconvert_weak = videoconvert.downgrade();
         let audiomixer_weak = audiomixer.downgrade();
-        let audiomixer_pad_weak = audiomixer_pad.downgrade();
+        let audio_sink = Arc::clone(&self.audio_sink);
+        let av_offset_ms = Arc::clone(&self.av_offset_ms);
+        let clip_timing: SharedClipTiming = Arc::new(Mutex::new(ClipTiming::from_clip(clip_data)));
+        let audio_branch = Arc::new(Mutex::new(None));
+        let audio_branch_for_pad = Arc::clone(&audio_branch);
+        // The clip's own effects run before its track's
+        let effects: Vec<TrackAudioEffect> = clip_data.audio_effects.iter()
+            .chain(self.track_audio_effects.get(&clip_data.track_id).into_iter().flatten())
+            .cloned()
+            .collect();
+        let track_keyframes = self.track_volume_keyframes.get(&clip_data.track_id).cloned().unwrap_or_default();
+        let fades = clip_data.id.and_then(|id| self.clip_fades.get(&id)).copied().unwrap_or_default();
+        let track_type = self.track_types.get(&clip_data.track_id).copied().unwrap_or_default();
+        let plays = clip_data.track_type.on_track(track_type);
+        let plays_video = plays.is_some_and(TrackType::has_video);
+        let plays_audio = plays.is_some_and(TrackType::has_audio);
+        let envelope_timing = Arc::clone(&clip_timing);
+        if let Some(tilt) = tilt.as_ref() {
+            orientation::follow_tilt(tilt, Arc::clone(&shared_orientation), Arc::clone(&clip_timing));
+        }
+        if let Some(fit_crop) = fit_crop.as_ref() {
+            pan_zoom::follow_crop_keyframes(fit_crop, Arc::clone(&shared_crop_keyframes), Arc::clone(&clip_timing));
+        }
+        let fade_opacity = fades.fade_in.map(|fade_in| {
+            let opacity: SharedOpacity = Arc::new(Mutex::new(clip_data.preview_opacity.clamp(0.0, 1.0)));
+            transitions::follow_fade_in(&caps_filter_src_pad, &compositor_pad, fade_in, Arc::clone(&opacity), Arc::clone(&clip_timing));
+            opacity
+        });
         
         // Store the clip source
         let clip_source = ClipSource {
@@ -276,14 +1531,43 @@ impl DirectPipelinePlayer {
             videoconvert: videoconvert.clone(),
             videoscale,
             caps_filter,
+            fit_crop,
+            orientation_flip,
+            tilt,
+            orientation: shared_orientation,
+            crop_keyframes: shared_crop_keyframes,
+            fade_opacity,
             compositor_pad: Some(compositor_pad),
-            audiomixer_pad: Some(audiomixer_pad),
             clip_data: clip_data.clone(),
+            timing: Arc::clone(&clip_timing),
+            audio_branch,
         };
         
         let clip_id = format!("clip_{}", index);
+        if let Some(id) = clip_data.id {
+            self.clip_keys.insert(id, clip_id.clone());
+        }
         self.clip_sources.insert(clip_id.clone(), clip_source);
         
+        // Audio-only sources never feed their compositor pad, which would stall the compositor
+        let compositor_weak = compositor.downgrade();
+        let unused_videoconvert = videoconvert.downgrade();
+        let unused_caps_filter = caps_filter_src_pad.downgrade();
+        uridecodebin.connect_no_more_pads(move |_| {
+            let (Some(compositor), Some(videoconvert), Some(src_pad)) =
+                (compositor_weak.upgrade(), unused_videoconvert.upgrade(), unused_caps_filter.upgrade()) else {
+                return;
+            };
+            if videoconvert.static_pad("sink").is_some_and(|pad| pad.is_linked()) {
+                return;
+            }
+            if let Some(compositor_pad) = src_pad.peer() {
+                let _ = src_pad.unlink(&compositor_pad);
+                compositor.release_request_pad(&compositor_pad);
+                info!("Released compositor pad of audio-only clip");
+            }
+        });
+        
         uridecodebin.connect_pad_added(move |_src, src_pad| {
             let Some(pipeline) = pipeline_weak.upgrade() else { 
                 warn!("Pipeline weak reference is gone");
@@ -293,14 +1577,10 @@ impl DirectPipelinePlayer {
                 warn!("Videoconvert weak reference is gone");
                 return; 
             };
-            let Some(_audiomixer) = audiomixer_weak.upgrade() else { 
+            let Some(audiomixer) = audiomixer_weak.upgrade() else { 
                 warn!("Audiomixer weak reference is gone");
                 return; 
             };
-            let Some(audiomixer_pad) = audiomixer_pad_weak.upgrade() else { 
-                warn!("Audiomixer pad weak reference is gone");
-                return; 
-            };
             
             let caps = src_pad.current_caps().or_else(|| Some(src_pad.query_caps(None)));
             if let Some(caps) = caps {
@@ -309,13 +1589,29 @@ impl DirectPipelinePlayer {
                 
                 info!("Connecting pad with caps: {}", caps);
                 
-                if media_type.starts_with("video/") {
+                // Position the stream on the timeline before any data flows
+                if media_type.starts_with("video/") || media_type.starts_with("audio/") {
+                    apply_clip_timing(src_pad, Arc::clone(&clip_timing));
+                }
+                
+                if (media_type.starts_with("video/") && !plays_video) || (media_type.starts_with("audio/") && !plays_audio) {
+                    // The clip or its track doesn't use this stream
+                    discard_pad(&pipeline, src_pad);
+                } else if media_type.starts_with("video/") {
                     // Link video pad
                     let sink_pad = videoconvert.static_pad("sink").unwrap();
                     if sink_pad.is_linked() {
                         warn!("Video sink pad is already linked");
                         return;
                     }
+                    #[cfg(target_os = "linux")]
+                    if let Some(capsfilter) = dmabuf_caps.as_ref().and_then(|weak| weak.upgrade()) {
+                        if crate::video::gl_texture::request_dmabuf(src_pad, &capsfilter) {
+                            info!("Importing clip video as DMA-BUF");
+                        } else {
+                            warn!("Decoder can't output DMA-BUF for this clip; uploading it from system memory");
+                        }
+                    }
                     
                     match src_pad.link(&sink_pad) {
                         Ok(_) => {
@@ -328,32 +1624,64 @@ impl DirectPipelinePlayer {
                 } else if media_type.starts_with("audio/") {
                     info!("Linking audio pad");
                     
-                    // Create audio processing chain
-                    let audioconvert = gst::ElementFactory::make("audioconvert")
-                        .build().unwrap();
-                    let audioresample = gst::ElementFactory::make("audioresample")
-                        .build().unwrap();
-                    
-                    pipeline.add(&audioconvert).unwrap();
-                    pipeline.add(&audioresample).unwrap();
-                    
-                    audioconvert.link(&audioresample).unwrap();
+                    let offset_ms = *av_offset_ms.lock().unwrap();
+                    if let Err(e) = Self::ensure_audio_output(&pipeline, &audiomixer, &audio_sink, offset_ms) {
+                        warn!("Failed to set up audio output: {}", e);
+                        return;
+                    }
                     
-                    // Link audio chain to mixer
-                    let audioresample_src_pad = audioresample.static_pad("src").unwrap();
-                    if let Err(e) = audioresample_src_pad.link(&audiomixer_pad) {
-                        warn!("Failed to link audioresample to mixer: {:?}", e);
+                    // Create audio processing chain
+                    let converters = gst::ElementFactory::make("audioconvert").build()
+                        .and_then(|audioconvert| Ok((audioconvert, gst::ElementFactory::make("audioresample").build()?)));
+                    let (audioconvert, audioresample) = match converters {
+                        Ok(converters) => converters,
+                        Err(e) => {
+                            warn!("Failed to create audio chain for clip: {}", e);
+                            return;
+                        }
+                    };
+                    let settings = audio_handler::get_audio_engine_settings();
+                    if let Some(matrix) = audio_downmix::mix_matrix(settings.downmix, &caps, settings.channel_layout) {
+                        info!("Downmixing surround audio ({:?})", settings.downmix);
+                        audioconvert.set_property("mix-matrix", matrix);
                     }
+                    let effect_chain = match audio_effects::build_effect_chain(&effects) {
+                        Ok(chain) => chain,
+                        Err(e) => {
+                            warn!("Playing clip without its audio effects: {}", e);
+                            None
+                        }
+                    };
+                    let volume_envelope = match audio_automation::build_envelope_filter(&track_keyframes, fades, Arc::clone(&envelope_timing)) {
+                        Ok(envelope) => envelope,
+                        Err(e) => {
+                            warn!("Playing clip without its track's volume automation and crossfades: {}", e);
+                            None
+                        }
+                    };
+                    
+                    let Some(audiomixer_pad) = audiomixer.request_pad_simple("sink_%u") else {
+                        warn!("Failed to request audiomixer pad");
+                        return;
+                    };
                     
-                    // Link source to audio chain
-                    let audioconvert_sink_pad = audioconvert.static_pad("sink").unwrap();
-                    if src_pad.link(&audioconvert_sink_pad).is_err() {
-                        warn!("Failed to link audio pad for clip");
+                    // audioconvert ! [effects] ! [volume envelope] ! audioresample
+                    let mut chain = vec![audioconvert.clone()];
+                    chain.extend(effect_chain.iter().chain(volume_envelope.iter()).cloned());
+                    chain.push(audioresample.clone());
+                    if let Err(e) = Self::add_audio_chain(&pipeline, &chain, src_pad, &audiomixer_pad) {
+                        warn!("Failed to set up audio chain for clip: {}", e);
+                        audiomixer.release_request_pad(&audiomixer_pad);
+                        return;
                     }
                     
-                    // Sync state with pipeline
-                    audioconvert.sync_state_with_parent().unwrap();
-                    audioresample.sync_state_with_parent().unwrap();
+                    *audio_branch_for_pad.lock().unwrap() = Some(AudioBranch {
+                        audioconvert,
+                        effect_chain,
+                        volume_envelope,
+                        audioresample,
+                        audiomixer_pad,
+                    });
                     
                     info!("Successfully set up audio chain");
                 }
@@ -370,7 +1698,126 @@ impl DirectPipelinePlayer {
         Ok(())
     }
 
+    /// Add a clip's audio chain to the pipeline and link it between the decoder pad and its
+    /// mixer pad. If a step fails, whatever was added is removed again.
+    fn add_audio_chain(pipeline: &gst::Pipeline, chain: &[gst::Element], src_pad: &gst::Pad, audiomixer_pad: &gst::Pad) -> Result<()> {
+        let mut added = Vec::new();
+        let result = (|| -> Result<()> {
+            for element in chain {
+                pipeline.add(element)?;
+                added.push(element.clone());
+            }
+            gst::Element::link_many(chain)?;
+            
+            let (Some(first), Some(last)) = (chain.first(), chain.last()) else {
+                return Err(anyhow!("Audio chain is empty"));
+            };
+            let chain_src_pad = last.static_pad("src")
+                .ok_or_else(|| anyhow!("Audio chain has no src pad"))?;
+            chain_src_pad.link(audiomixer_pad)
+                .map_err(|e| anyhow!("Failed to link audio chain to mixer: {:?}", e))?;
+            let chain_sink_pad = first.static_pad("sink")
+                .ok_or_else(|| anyhow!("Audio chain has no sink pad"))?;
+            src_pad.link(&chain_sink_pad)
+                .map_err(|e| anyhow!("Failed to link audio pad to chain: {:?}", e))?;
+            
+            for element in chain {
+                element.sync_state_with_parent()?;
+            }
+            Ok(())
+        })();
+        if result.is_err() {
+            for element in &added {
+                let _ = element.set_state(gst::State::Null);
+            }
+            let _ = pipeline.remove_many(&added);
+        }
+        result
+    }
+
+    /// Build the audiomixer -> audio sink branch the first time a clip exposes audio,
+    /// so timelines without audio never wait on an audio sink to preroll
+    fn ensure_audio_output(
+        pipeline: &gst::Pipeline,
+        audiomixer: &gst::Element,
+        audio_sink: &Arc<Mutex<Option<gst::Element>>>,
+        av_offset_ms: i64,
+    ) -> Result<()> {
+        let mut sink_guard = audio_sink.lock().unwrap();
+        if sink_guard.is_some() {
+            return Ok(());
+        }
+        
+        // Clips are converted and resampled into the engine format before the mixer
+        let settings = audio_handler::get_audio_engine_settings();
+        let mix_caps = gst::ElementFactory::make("capsfilter")
+            .name("audio_mix_caps")
+            .property("caps", gst::Caps::builder("audio/x-raw")
+                .field("rate", settings.sample_rate.hz() as i32)
+                .field("channels", settings.channel_layout.channels() as i32)
+                .field("channel-mask", gst::Bitmask::new(settings.channel_layout.channel_mask()))
+                .build())
+            .build()
+            .map_err(|e| anyhow!("Failed to create audio mix capsfilter: {}", e))?;
+        
+        // Live streams take the mix here, ahead of the monitor's master volume
+        let audio_tee = gst::ElementFactory::make("tee")
+            .name(PROGRAM_AUDIO_TEE)
+            .property("allow-not-linked", true)
+            .build()
+            .map_err(|e| anyhow!("Failed to create program audio tee: {}", e))?;
+        
+        let volume = gst::ElementFactory::make("volume")
+            .name("master_volume")
+            .build()
+            .map_err(|e| anyhow!("Failed to create master volume: {}", e))?;
+        follow_master_volume(&volume);
+        
+        // Metered after the master volume so the meters follow the fader and mute
+        let level = gst::ElementFactory::make("level")
+            .name("audio_level")
+            .property("interval", AUDIO_LEVEL_INTERVAL_MS * 1_000_000)
+            .property("post-messages", true)
+            .build()
+            .map_err(|e| anyhow!("Failed to create audio level meter: {}", e))?;
+        
+        let audioconvert = gst::ElementFactory::make("audioconvert")
+            .build()
+            .map_err(|e| anyhow!("Failed to create output audioconvert: {}", e))?;
+        
+        let sink = gst::ElementFactory::make("autoaudiosink")
+            .name("audio_sink0")
+            .property("ts-offset", av_offset_ms * 1_000_000)
+            .build()
+            .map_err(|e| anyhow!("Failed to create audio sink: {}", e))?;
+        
+        pipeline.add_many([&mix_caps, &audio_tee, &volume, &level, &audioconvert, &sink])?;
+        gst::Element::link_many([audiomixer, &mix_caps, &audio_tee, &volume, &level, &audioconvert, &sink])?;
+        
+        mix_caps.sync_state_with_parent()?;
+        audio_tee.sync_state_with_parent()?;
+        volume.sync_state_with_parent()?;
+        level.sync_state_with_parent()?;
+        audioconvert.sync_state_with_parent()?;
+        sink.sync_state_with_parent()?;
+        
+        if let Err(e) = live_output::link_program_audio(pipeline, &audio_tee) {
+            warn!("Failed to add program audio to the live stream: {}", e);
+        }
+        info!("Created audio output branch ({}Hz, {:?}, A/V offset: {}ms)",
+              settings.sample_rate.hz(), settings.channel_layout, av_offset_ms);
+        *sink_guard = Some(sink);
+        Ok(())
+    }
+
     fn create_texture_video_sink(&self) -> Result<gst::Element> {
+        #[cfg(target_os = "linux")]
+        if let Some(gl_output) = self.gl_output.clone() {
+            let video_sink = crate::video::gl_texture::create_gl_video_sink(gl_output)?;
+            perf_stats::count_rendered_frames(&video_sink, &self.perf_counters);
+            return Ok(video_sink);
+        }
+        
         let video_sink = gst::ElementFactory::make("appsink")
             .name("texture_video_sink0")
             .property("emit-signals", true)
@@ -380,11 +1827,9 @@ impl DirectPipelinePlayer {
             .build()
             .map_err(|e| anyhow!("Failed to create appsink: {}", e))?;
 
-        // Set caps for RGBA output to texture
+        // Set caps for RGBA output to texture; the size is pinned by the compositor link
         let caps = gst::Caps::builder("video/x-raw")
             .field("format", "RGBA")
-            .field("width", 1920i32)
-            .field("height", 1080i32)
             .build();
         video_sink.set_property("caps", &caps);
 
@@ -393,15 +1838,35 @@ impl DirectPipelinePlayer {
             .dynamic_cast::<gst_app::AppSink>()
             .unwrap();
         
-        if let Some(texture_id) = self.texture_id {
+        if let (Some(texture_id), Some(update_fn)) = (self.texture_id, self.texture_update_fn.clone()) {
+            let preroll_update_fn = Arc::clone(&update_fn);
+            let buffer_pool = Arc::clone(&self.buffer_pool);
+            let preroll_buffer_pool = Arc::clone(&self.buffer_pool);
+            let perf_counters = Arc::clone(&self.perf_counters);
+            let frame_cache = self.frame_cache.clone();
             appsink.set_callbacks(
                 gst_app::AppSinkCallbacks::builder()
                     .new_sample(move |sink| {
-                        match Self::handle_video_sample(sink, texture_id) {
-                            Ok(_) => Ok(gst::FlowSuccess::Ok),
+                        let started = Instant::now();
+                        match Self::handle_video_sample(sink, texture_id, &update_fn, &buffer_pool) {
+                            Ok(_) => {
+                                perf_counters.record(Stage::Upload, started.elapsed());
+                                perf_counters.frame_rendered();
+                                Ok(gst::FlowSuccess::Ok)
+                            }
                             Err(_) => Err(gst::FlowError::Error),
                         }
                     })
+                    .new_preroll(move |sink| {
+                        // Render prerolled frames so seeks while paused update the preview
+                        if let Ok(sample) = sink.pull_preroll() {
+                            match Self::handle_video_sample_from_buffer(&sample, texture_id, &preroll_update_fn, &preroll_buffer_pool) {
+                                Ok(()) => frame_cache.insert_sample(&sample),
+                                Err(e) => debug!("Failed to render preroll frame: {}", e),
+                            }
+                        }
+                        Ok(gst::FlowSuccess::Ok)
+                    })
                     .build(),
             );
         }
@@ -412,6 +1877,8 @@ impl DirectPipelinePlayer {
     fn handle_video_sample(
         appsink: &gst_app::AppSink,
         texture_id: i64,
+        update_fn: &TextureUpdateFn,
+        buffer_pool: &Arc<Mutex<FrameBufferPool>>,
     ) -> Result<(), gst::FlowError> {
         let sample = appsink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
         let buffer = sample.buffer().ok_or(gst::FlowError::Error)?;
@@ -423,19 +1890,34 @@ impl DirectPipelinePlayer {
         let height = s.get::<i32>("height").unwrap() as u32;
 
         let frame_data = FrameData {
-            data: map.as_slice().to_vec(),
+            data: buffer_pool.lock().unwrap().buffer_from_slice(map.as_slice()),
             width,
             height,
             texture_id: Some(texture_id as u64),
         };
 
-        if !crate::api::simple::update_video_frame(frame_data.clone()) {
-            debug!("Failed to update video frame");
-        }
+        update_fn(frame_data);
 
         Ok(())
     }
 
+    /// Hold playback while any network source's buffer is below 100% and resume once all of
+    /// them are full, unless playback was paused meanwhile
+    fn handle_buffering(pipeline: &gst::Pipeline, source: String, percent: i32, buffering: &Mutex<BufferingState>) {
+        let mut buffering = buffering.lock().unwrap();
+        buffering.percent.insert(source, percent);
+        let lowest = buffering.percent.values().copied().min().unwrap_or(100);
+        if lowest < 100 && !buffering.paused && pipeline.current_state() == gst::State::Playing {
+            info!("Pausing playback while buffering ({}%)", lowest);
+            buffering.paused = true;
+            let _ = pipeline.set_state(gst::State::Paused);
+        } else if lowest >= 100 && buffering.paused {
+            info!("Buffering complete, resuming playback");
+            buffering.paused = false;
+            let _ = pipeline.set_state(gst::State::Playing);
+        }
+    }
+
     fn setup_message_bus_handling(&mut self, pipeline: &gst::Pipeline) -> Result<()> {
         println!("🔥 Setting up message bus handling for direct pipeline");
         info!("Setting up message bus handling for direct pipeline");
@@ -443,24 +1925,73 @@ impl DirectPipelinePlayer {
         let bus = pipeline.bus().ok_or_else(|| anyhow!("Failed to get pipeline bus"))?;
         println!("✅ Got pipeline bus successfully");
         
+        // GL elements must use a context shared with Flutter's for the texture to be visible
+        #[cfg(target_os = "linux")]
+        if self.gl_output.is_some() {
+            crate::video::gl_texture::install_gl_context_handler(&bus)?;
+        }
+        
         // Clone Arc references for the message handler
         let is_playing = Arc::clone(&self.is_playing);
+        // Sources of the previous pipeline won't report again
+        self.buffering.lock().unwrap().percent.clear();
+        let buffering = Arc::clone(&self.buffering);
         let seek_completion_callback = Arc::clone(&self.seek_completion_callback);
+        let playback_event_callback = Arc::clone(&self.playback_event_callback);
+        let audio_level_callback = Arc::clone(&self.audio_level_callback);
         let current_position_ms = Arc::clone(&self.current_position_ms);
+        let end_behavior = Arc::clone(&self.end_behavior);
+        let black_out = self.texture_blanker();
+        let pipeline_weak = pipeline.downgrade();
+        let watchdog_failure = self.watchdog.failure_slot();
+        let decoder_failures = Arc::clone(&self.decoder_failures);
+        let task_poster = self.task_poster.clone();
+        // The error decodebin posts after a missing-plugin message adds nothing for the user
+        let mut missing_codec_reported = false;
         
-        let _watch_guard = bus.add_watch(move |_bus, message| {
+        // Attaches to the thread default main context, the worker's
+        let watch_guard = bus.add_watch(move |_bus, message| {
             println!("🔥 BUS MESSAGE: {:?} from {:?}", message.type_(), message.src().map(|s| s.name()));
             match message.type_() {
                 gst::MessageType::Eos => {
                     println!("=== RECEIVED EOS (End of Stream) ===");
                     info!("=== RECEIVED EOS (End of Stream) ===");
-                    *is_playing.lock().unwrap() = false;
+                    let behavior = *end_behavior.lock().unwrap();
+                    let keep_playing = pipeline_weak.upgrade().is_some_and(|pipeline| {
+                        Self::handle_end_of_timeline(&pipeline, behavior, &black_out, &current_position_ms)
+                    });
+                    
+                    if !keep_playing {
+                        *is_playing.lock().unwrap() = false;
+                        Self::emit_playback_event(&playback_event_callback, PlaybackEvent::EndOfStream);
+                    }
                 },
                 gst::MessageType::Error => {
                     let error_msg = message.view();
                     if let gst::MessageView::Error(err) = error_msg {
                         println!("❌ Pipeline error: {} - {}", err.error(), err.debug().unwrap_or_default());
                         warn!("Pipeline error: {} - {}", err.error(), err.debug().unwrap_or_default());
+                        // Only that clip's branch stops, so retry it in software rather than failing
+                        if let Some(failure) = message.src().and_then(|src| decoders::hardware_decoder_failure(src, err.error().to_string())) {
+                            warn!("Hardware decoder {} failed on {}", failure.decoder, failure.uri);
+                            decoder_failures.lock().unwrap().push(failure);
+                            // Rebuilt now rather than at the next watchdog check
+                            if let Some(post) = task_poster.as_ref() {
+                                post(Box::new(|player| player.fall_back_to_software_decoding()));
+                            }
+                            return gst::glib::ControlFlow::Continue;
+                        }
+                        let error = FlipEditError::from_error_message(err);
+                        // Missing plugins stay missing, so only other errors are worth recovering from
+                        if error.code != FlipEditErrorCode::MissingPlugin {
+                            *watchdog_failure.lock().unwrap() = Some(Failure {
+                                reason: error.message.clone(),
+                                was_playing: *is_playing.lock().unwrap(),
+                            });
+                        }
+                        if !(missing_codec_reported && error.code == FlipEditErrorCode::MissingPlugin) {
+                            Self::emit_playback_event(&playback_event_callback, PlaybackEvent::Error { error });
+                        }
                     }
                     *is_playing.lock().unwrap() = false;
                 },
@@ -494,8 +2025,33 @@ impl DirectPipelinePlayer {
                                     },
                                     _ => {}
                                 }
+                                
+                                let state = match new_state {
+                                    gst::State::Playing => Some(PlaybackState::Playing),
+                                    gst::State::Paused => Some(PlaybackState::Paused),
+                                    gst::State::Ready | gst::State::Null => Some(PlaybackState::Stopped),
+                                    _ => None,
+                                };
+                                if let Some(state) = state {
+                                    if old_state != new_state {
+                                        Self::emit_playback_event(&playback_event_callback, PlaybackEvent::StateChanged { state });
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                gst::MessageType::Element => {
+                    if let Some(levels) = message.structure().and_then(Self::parse_audio_levels) {
+                        if let Some(ref callback) = *audio_level_callback.lock().unwrap() {
+                            if let Err(e) = callback(levels) {
+                                warn!("Audio level callback error: {}", e);
                             }
                         }
+                    } else if let Some(event) = plugins::parse_missing_plugin(message) {
+                        warn!("Missing plugin: {:?}", event);
+                        missing_codec_reported = true;
+                        Self::emit_playback_event(&playback_event_callback, event);
                     }
                 },
                 gst::MessageType::ClockLost => {
@@ -507,8 +2063,20 @@ impl DirectPipelinePlayer {
                         info!("New clock selected: {:?}", new_clock.clock().map(|c| c.name()));
                     }
                 },
+                gst::MessageType::Buffering => {
+                    if let gst::MessageView::Buffering(buffering) = message.view() {
+                        let percent = buffering.percent();
+                        debug!("Buffering: {}%", percent);
+                        let source = message.src().map(|src| src.path_string().to_string()).unwrap_or_default();
+                        if let Some(pipeline) = pipeline_weak.upgrade() {
+                            Self::handle_buffering(&pipeline, source, percent, &buffering);
+                        }
+                        Self::emit_playback_event(&playback_event_callback, PlaybackEvent::Buffering { percent });
+                    }
+                },
                 gst::MessageType::AsyncDone => {
                     debug!("Received ASYNC_DONE – seek operation completed");
+                    Self::emit_playback_event(&playback_event_callback, PlaybackEvent::Prerolled);
                     let pos = *current_position_ms.lock().unwrap();
                     if let Ok(callback_guard) = seek_completion_callback.lock() {
                         if let Some(ref callback) = *callback_guard {
@@ -522,7 +2090,8 @@ impl DirectPipelinePlayer {
                     info!("Stream started");
                 },
                 gst::MessageType::DurationChanged => {
-                    debug!("Duration changed");
+                    // The sources' lengths, not the timeline's; `set_duration_ms` reports that
+                    debug!("Pipeline duration changed");
                 },
                 _ => {
                     debug!("Received message type: {:?}", message.type_());
@@ -532,17 +2101,124 @@ impl DirectPipelinePlayer {
             gst::glib::ControlFlow::Continue
         }).map_err(|e| anyhow!("Failed to add bus watch: {}", e))?;
         
+        // Keep the guard alive for the pipeline's lifetime - dropping it removes the watch
+        self.bus_watch = Some(watch_guard);
+        resources::track(self.resource_owner, ResourceKind::BusWatch, self.flutter_engine_handle, format!("bus watch on {}", pipeline.name()));
+        
         println!("✅ Message bus handling setup completed for direct pipeline");
         info!("Message bus handling setup completed for direct pipeline");
         Ok(())
     }
 
+    /// Read the per-channel values of a `level` element message
+    pub(crate) fn parse_audio_levels(structure: &gst::StructureRef) -> Option<AudioLevels> {
+        if structure.name() != "level" {
+            return None;
+        }
+        let channel_values = |field: &str| -> Option<Vec<f64>> {
+            let values = structure.get::<gst::glib::ValueArray>(field).ok()?;
+            Some(values.iter().filter_map(|value| value.get::<f64>().ok()).collect())
+        };
+        Some(AudioLevels {
+            peak_db: channel_values("peak")?,
+            rms_db: channel_values("rms")?,
+            decay_db: channel_values("decay")?,
+        })
+    }
+
+    /// Apply the configured end behavior after EOS. Returns true if playback continues.
+    fn handle_end_of_timeline(
+        pipeline: &gst::Pipeline,
+        behavior: EndBehavior,
+        black_out: &dyn Fn(),
+        current_position_ms: &Arc<Mutex<u64>>,
+    ) -> bool {
+        info!("Reached end of timeline, applying {:?}", behavior);
+        
+        if behavior == EndBehavior::Loop {
+            match pipeline.seek_simple(gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE, gst::ClockTime::ZERO) {
+                Ok(_) => {
+                    *current_position_ms.lock().unwrap() = 0;
+                    return true;
+                }
+                Err(e) => warn!("Failed to loop timeline, pausing instead: {}", e),
+            }
+        }
+        
+        if let Err(e) = pipeline.set_state(gst::State::Paused) {
+            warn!("Failed to pause pipeline at end of timeline: {}", e);
+        }
+        
+        match behavior {
+            EndBehavior::Pause => {
+                // The rewound frame is rendered by the appsink preroll callback
+                if let Err(e) = pipeline.seek_simple(gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE, gst::ClockTime::ZERO) {
+                    warn!("Failed to rewind timeline: {}", e);
+                } else {
+                    *current_position_ms.lock().unwrap() = 0;
+                }
+            }
+            EndBehavior::BlackOut => black_out(),
+            EndBehavior::HoldLastFrame | EndBehavior::Loop => {}
+        }
+        
+        false
+    }
+
+    /// Blanks the preview texture until the next frame, whichever render path feeds it
+    fn texture_blanker(&self) -> impl Fn() + Send + 'static {
+        let (width, height) = self.output_size;
+        let texture_update_fn = self.texture_update_fn.clone();
+        #[cfg(target_os = "linux")]
+        let gl_output = self.gl_output.clone();
+        move || {
+            #[cfg(target_os = "linux")]
+            if let Some(gl_output) = gl_output.as_ref() {
+                gl_output.clear();
+                return;
+            }
+            if let Some(update_fn) = texture_update_fn.as_ref() {
+                update_fn(FrameData {
+                    data: vec![0u8; width as usize * height as usize * 4],
+                    width: width as u32,
+                    height: height as u32,
+                    texture_id: None,
+                });
+            }
+        }
+    }
+
+    /// Store the timeline length, telling the app when an edit or load changed it
+    fn set_duration_ms(&self, duration_ms: u64) {
+        let previous = self.duration_ms.lock().unwrap().replace(duration_ms);
+        if previous != Some(duration_ms) {
+            info!("Timeline duration changed to {}ms", duration_ms);
+            Self::emit_playback_event(&self.playback_event_callback, PlaybackEvent::DurationChanged { duration_ms });
+        }
+    }
+
+    fn emit_playback_event(callback: &Arc<Mutex<Option<PlaybackEventCallback>>>, event: PlaybackEvent) {
+        if let Ok(callback_guard) = callback.lock() {
+            if let Some(ref callback) = *callback_guard {
+                if let Err(e) = callback(event) {
+                    warn!("Playback event callback error: {}", e);
+                }
+            }
+        }
+    }
+
     pub fn play(&self) -> Result<()> {
         println!("🔥 PLAY CALLED - Simple playbin approach");
         info!("Setting playbin pipeline to PLAYING");
         let pipeline = self.pipeline
             .as_ref()
-            .ok_or_else(|| anyhow!("Pipeline not loaded"))?;
+            .ok_or_else(FlipEditError::not_loaded)?;
+        
+        // Clips only start at their in-points after a seek, so preroll and seek before the first play
+        if pipeline.current_state() < gst::State::Paused {
+            self.preroll_at_current_position(pipeline);
+        }
+        self.apply_deferred_seek(pipeline);
         
         println!("🔥 SETTING PLAYBIN TO PLAYING...");
         
@@ -556,6 +2232,7 @@ impl DirectPipelinePlayer {
                 println!("⏳ Playbin transitioning to PLAYING asynchronously");
                 // Let the state change happen in the background
                 *self.is_playing.lock().unwrap() = true;
+                Self::emit_playback_event(&self.playback_event_callback, PlaybackEvent::Prerolling);
             },
             Ok(gst::StateChangeSuccess::NoPreroll) => {
                 println!("✅ Playbin transitioned to PLAYING (no preroll)");
@@ -563,243 +2240,1909 @@ impl DirectPipelinePlayer {
             },
             Err(e) => {
                 println!("❌ Failed to set playbin to PLAYING state: {}", e);
-                return Err(anyhow!("Failed to set playbin to PLAYING state: {}", e));
+                return Err(FlipEditError::new(
+                    FlipEditErrorCode::StateChangeFailed,
+                    format!("Failed to set playbin to PLAYING state: {}", e),
+                ).into());
             }
         }
         
         println!("✅ Playbin play command sent successfully");
         info!("Playbin play command sent successfully");
+        
+        self.start_position_timer();
         Ok(())
     }
 
-    pub fn pause(&self) -> Result<()> {
-        info!("Setting direct pipeline to PAUSED");
-        let pipeline = self.pipeline
-            .as_ref()
-            .ok_or_else(|| anyhow!("Pipeline not loaded"))?;
-            
-        pipeline.set_state(gst::State::Paused)?;
-        *self.is_playing.lock().unwrap() = false;
-        info!("Direct pipeline paused");
-        Ok(())
-    }
-
-    fn stop_pipeline(&mut self) -> Result<()> {
-        if let Some(timer_id) = self.position_timer_id.lock().unwrap().take() {
-            timer_id.remove();
-            info!("Stopped position monitoring timer");
+    fn preroll_at_current_position(&self, pipeline: &gst::Pipeline) {
+        if let Err(e) = pipeline.set_state(gst::State::Paused) {
+            warn!("Failed to preroll pipeline: {}", e);
+            return;
         }
-        
-        if let Some(pipeline) = &self.pipeline {
-            info!("Setting direct pipeline to NULL");
-            pipeline.set_state(gst::State::Null)?;
-            *self.is_playing.lock().unwrap() = false;
-            *self.current_position_ms.lock().unwrap() = 0;
+        if let Err(e) = pipeline.state(Some(gst::ClockTime::from_seconds(5))).0 {
+            warn!("Pipeline preroll did not complete: {}", e);
+            return;
         }
         
-        // Clear pipeline reference to prevent element name collisions
-        self.pipeline = None;
-        self.compositor = None;
-        self.audiomixer = None;
+        let position_ms = self.get_current_position_ms();
+        *self.deferred_seek_ms.lock().unwrap() = None;
+        if let Err(e) = pipeline.seek_simple(
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::ClockTime::from_mseconds(position_ms),
+        ) {
+            warn!("Failed to position clips at {}ms: {}", position_ms, e);
+        }
+    }
+
+    /// Seek the pipeline to a position so far only shown from the frame cache
+    fn apply_deferred_seek(&self, pipeline: &gst::Pipeline) {
+        let Some(position_ms) = self.deferred_seek_ms.lock().unwrap().take() else {
+            return;
+        };
+        if let Err(e) = pipeline.seek_simple(
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::ClockTime::from_mseconds(position_ms),
+        ) {
+            warn!("Failed to seek to cached frame position {}ms: {}", position_ms, e);
+        }
+    }
+
+    /// Turn automatic recovery from pipeline errors and stalls on or off (on by default)
+    pub fn set_auto_recovery(&mut self, enabled: bool) {
+        self.watchdog.set_enabled(enabled);
+    }
+
+    pub fn get_auto_recovery(&self) -> bool {
+        self.watchdog.is_enabled()
+    }
+
+    /// Let bus handlers queue work on the worker that owns this player
+    pub fn set_task_poster(&mut self, poster: PlayerTaskPoster) {
+        self.task_poster = Some(poster);
+    }
+
+    /// Run one watchdog check; the worker calls this every check interval, between commands
+    pub fn check_health(&mut self) {
+        self.fall_back_to_software_decoding();
+        let Some(pipeline) = self.pipeline.clone() else {
+            return;
+        };
+        let (_, state, pending) = pipeline.state(gst::ClockTime::ZERO);
+        let should_progress = self.is_playing()
+            && !self.buffering.lock().unwrap().paused
+            && state == gst::State::Playing
+            && pending == gst::State::VoidPending;
+        let position_ms = should_progress
+            .then(|| pipeline.query_position::<gst::ClockTime>().map(|position| position.mseconds()))
+            .flatten();
+        
+        match self.watchdog.check(position_ms) {
+            Verdict::Healthy => {}
+            Verdict::Recovered(stage) => {
+                info!("Playback recovered after {:?}", stage);
+                Self::emit_playback_event(&self.playback_event_callback, PlaybackEvent::Recovered { stage });
+            }
+            Verdict::Recover { stage, reason, was_playing } => {
+                warn!("Recovering from \"{}\" with {:?}", reason, stage);
+                Self::emit_playback_event(&self.playback_event_callback, PlaybackEvent::Recovering { stage, reason });
+                if let Err(e) = self.recover(stage, was_playing) {
+                    warn!("Recovery step {:?} failed: {}", stage, e);
+                }
+            }
+            Verdict::GiveUp { reason } => {
+                warn!("Giving up recovering from \"{}\"", reason);
+                Self::emit_playback_event(&self.playback_event_callback, PlaybackEvent::RecoveryFailed { reason });
+            }
+        }
+    }
+
+    /// Rebuild the clips of each source whose hardware decoder failed, decoding in software
+    fn fall_back_to_software_decoding(&mut self) {
+        let failures = std::mem::take(&mut *self.decoder_failures.lock().unwrap());
+        for failure in failures {
+            let keys: Vec<String> = self.clip_sources.iter()
+                .filter(|(_, source)| source_uri(&source.clip_data.source_path) == failure.uri)
+                .map(|(key, _)| key.clone())
+                .collect();
+            let Some(source_path) = keys.first()
+                .and_then(|key| self.clip_sources.get(key))
+                .map(|source| source.clip_data.source_path.clone()) else {
+                continue;
+            };
+            // Other clips of the file may have failed too before their rebuild
+            if !self.software_decode_sources.insert(source_path.clone()) {
+                continue;
+            }
+            
+            info!("Rebuilding {} clip(s) of {} with software decoding", keys.len(), source_path);
+            for key in keys {
+                if let Err(e) = self.rebuild_clip_source(&key) {
+                    warn!("Failed to rebuild clip source {} for software decoding: {}", key, e);
+                }
+            }
+            self.frame_cache.clear();
+            self.resync_clips();
+            Self::emit_playback_event(&self.playback_event_callback, PlaybackEvent::DecoderFallback {
+                source_path,
+                decoder: failure.decoder,
+                reason: failure.reason,
+            });
+        }
+    }
+
+    fn recover(&mut self, stage: RecoveryStage, was_playing: bool) -> Result<()> {
+        let position_ms = self.get_current_position_ms();
+        match stage {
+            RecoveryStage::FlushSeek => {}
+            RecoveryStage::RebuildSink => self.rebuild_video_sink()?,
+            RecoveryStage::RebuildPipeline => {
+                let timeline_data = self.pipeline_timeline.clone().ok_or_else(FlipEditError::not_loaded)?;
+                self.load_pipeline(&timeline_data)?;
+                return self.restore_playhead(position_ms, was_playing);
+            }
+        }
+        
+        let pipeline = self.pipeline.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        pipeline.set_state(gst::State::Paused)?;
+        *self.deferred_seek_ms.lock().unwrap() = None;
+        pipeline.seek_simple(
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::ClockTime::from_mseconds(position_ms),
+        )?;
+        if was_playing {
+            self.play()?;
+        }
+        Ok(())
+    }
+
+    /// Swap the video sink for a new one, e.g. after its texture upload failed
+    fn rebuild_video_sink(&mut self) -> Result<()> {
+        let pipeline = self.pipeline.clone().ok_or_else(FlipEditError::not_loaded)?;
+        let program_tee = pipeline.by_name(PROGRAM_VIDEO_TEE)
+            .ok_or_else(|| anyhow!("Program tee not found"))?;
+        if let Some(old_sink) = self.video_sink.take() {
+            if let Some(sink_pad) = old_sink.static_pad("sink") {
+                if let Some(tee_pad) = sink_pad.peer() {
+                    let _ = tee_pad.unlink(&sink_pad);
+                    program_tee.release_request_pad(&tee_pad);
+                }
+            }
+            let _ = old_sink.set_state(gst::State::Null);
+            pipeline.remove(&old_sink)?;
+        }
+        
+        let video_sink = self.create_texture_video_sink()?;
+        pipeline.add(&video_sink)?;
+        program_tee.link(&video_sink)?;
+        self.watch_output_size(&video_sink);
+        video_sink.sync_state_with_parent()?;
+        self.video_sink = Some(video_sink);
+        info!("Replaced the video sink");
+        Ok(())
+    }
+
+    /// Show the cached frame for `position_ms` without seeking, if the cache has one
+    fn show_cached_frame(&self, position_ms: u64) -> bool {
+        let (Some(texture_id), Some(update_fn)) = (self.texture_id, self.texture_update_fn.as_ref()) else {
+            return false;
+        };
+        let Some(frame) = self.frame_cache.get(position_ms) else {
+            return false;
+        };
+        update_fn(FrameData {
+            data: self.buffer_pool.lock().unwrap().buffer_from_slice(&frame.data),
+            width: frame.width,
+            height: frame.height,
+            texture_id: Some(texture_id as u64),
+        });
+        true
+    }
+
+    pub fn pause(&self) -> Result<()> {
+        info!("Setting direct pipeline to PAUSED");
+        let pipeline = self.pipeline
+            .as_ref()
+            .ok_or_else(FlipEditError::not_loaded)?;
+            
+        pipeline.set_state(gst::State::Paused)?;
+        *self.is_playing.lock().unwrap() = false;
+        // A pause requested while buffering must not be undone when the buffer fills
+        self.buffering.lock().unwrap().paused = false;
+        
+        // Positions are pushed on demand while paused
+        self.stop_position_timer();
+        self.update_position();
+        Self::notify_position(&self.position_callback, self.get_frame_rate(), self.get_current_position_ms());
+        info!("Direct pipeline paused");
+        Ok(())
+    }
+
+    /// Start pushing positions to the position callback at the configured interval.
+    /// The timer only runs while playing and removes itself once playback stops.
+    fn start_position_timer(&self) {
+        self.stop_position_timer();
+        
+        let has_callback = self.position_callback.lock().unwrap().is_some();
+        if !has_callback {
+            debug!("No position callback set, skipping timer start");
+            return;
+        }
+        
+        let Some(pipeline) = &self.pipeline else {
+            return;
+        };
+        
+        let pipeline_weak = pipeline.downgrade();
+        let is_playing = Arc::clone(&self.is_playing);
+        let current_position_ms = Arc::clone(&self.current_position_ms);
+        let position_callback = Arc::clone(&self.position_callback);
+        let frame_rate = Arc::clone(&self.frame_rate);
+        let timer = Arc::clone(&self.position_timer);
+        let owner = self.resource_owner;
+        // A restart may have stored a newer timer by the time this one stops; only clear our own
+        let own_source = Arc::new(std::sync::OnceLock::new());
+        let stop_own_timer = {
+            let own_source = Arc::clone(&own_source);
+            move || {
+                let mut timer = timer.lock().unwrap();
+                if own_source.get().is_some_and(|own: &gst::glib::Source| timer.as_ref() == Some(own)) {
+                    timer.take();
+                    resources::release(owner, ResourceKind::Timer);
+                }
+            }
+        };
+        
+        let interval = Duration::from_millis(self.position_update_interval_ms);
+        let source = gst::glib::timeout_source_new(interval, None, gst::glib::Priority::DEFAULT, move || {
+            let Some(pipeline) = pipeline_weak.upgrade() else {
+                stop_own_timer();
+                return gst::glib::ControlFlow::Break;
+            };
+            
+            if let Some(position) = pipeline.query_position::<gst::ClockTime>() {
+                *current_position_ms.lock().unwrap() = position.mseconds();
+            }
+            let position_ms = *current_position_ms.lock().unwrap();
+            Self::notify_position(&position_callback, *frame_rate.lock().unwrap(), position_ms);
+            
+            // Stop once playback ended (EOS/pause), but not during the PAUSED->PLAYING transition
+            let (_, _, pending_state) = pipeline.state(gst::ClockTime::ZERO);
+            if !*is_playing.lock().unwrap() && pending_state != gst::State::Playing {
+                stop_own_timer();
+                return gst::glib::ControlFlow::Break;
+            }
+            
+            gst::glib::ControlFlow::Continue
+        });
+        
+        let mut stored = self.position_timer.lock().unwrap();
+        // The worker's context rather than the global default one `timeout_add` would use
+        source.attach(Some(&gst::glib::MainContext::ref_thread_default()));
+        let _ = own_source.set(source.clone());
+        *stored = Some(source);
+        drop(stored);
+        resources::track(
+            owner,
+            ResourceKind::Timer,
+            self.flutter_engine_handle,
+            format!("position timer ({}ms)", self.position_update_interval_ms),
+        );
+        debug!("Started position timer ({}ms interval)", self.position_update_interval_ms);
+    }
+    
+    fn stop_position_timer(&self) {
+        if let Some(timer) = self.position_timer.lock().unwrap().take() {
+            timer.destroy();
+            resources::release(self.resource_owner, ResourceKind::Timer);
+            info!("Stopped position monitoring timer");
+        }
+    }
+    
+    fn notify_position(callback: &Arc<Mutex<Option<PositionUpdateCallback>>>, frame_rate: FrameRate, position_ms: u64) {
+        let position_seconds = position_ms as f64 / 1000.0;
+        let frame_number = frame_rate.frame_at_ms(position_ms);
+        
+        if let Ok(callback_guard) = callback.lock() {
+            if let Some(ref callback) = *callback_guard {
+                if let Err(e) = callback(position_seconds, frame_number) {
+                    warn!("Position callback error: {}", e);
+                }
+            }
+        }
+    }
+    
+    /// Count frames at `frame_rate` instead of the rate of the timeline's first video clip;
+    /// `None` goes back to following the sources
+    pub fn set_frame_rate(&mut self, frame_rate: Option<FrameRate>) {
+        self.frame_rate_override = frame_rate.filter(|rate| rate.numerator > 0 && rate.denominator > 0);
+        match self.timeline_data.clone() {
+            Some(timeline_data) => self.update_frame_rate(&timeline_data),
+            None => *self.frame_rate.lock().unwrap() = self.frame_rate_override.unwrap_or_default(),
+        }
+    }
+
+    pub fn get_frame_rate(&self) -> FrameRate {
+        *self.frame_rate.lock().unwrap()
+    }
+
+    pub fn get_current_frame_number(&self) -> u64 {
+        self.get_frame_rate().frame_at_ms(self.get_current_position_ms())
+    }
+
+    pub fn seek_to_frame(&self, frame: u64) -> Result<()> {
+        self.seek(self.get_frame_rate().frame_start_ms(frame))
+    }
+
+    /// Change how often positions are pushed while playing
+    pub fn set_position_update_interval_ms(&mut self, interval_ms: u64) {
+        self.position_update_interval_ms = interval_ms.max(1);
+        info!("Position update interval set to {}ms", self.position_update_interval_ms);
+        
+        // Restart a running timer so the new cadence applies immediately
+        let timer_running = self.position_timer.lock().unwrap().is_some();
+        if timer_running {
+            self.start_position_timer();
+        }
+    }
+    
+    pub fn get_position_update_interval_ms(&self) -> u64 {
+        self.position_update_interval_ms
+    }
+
+    fn stop_pipeline(&mut self) -> Result<()> {
+        self.stop_position_timer();
+        
+        if let Some(pipeline) = &self.pipeline {
+            info!("Setting direct pipeline to NULL");
+            pipeline.set_state(gst::State::Null)?;
+            *self.is_playing.lock().unwrap() = false;
+            *self.current_position_ms.lock().unwrap() = 0;
+        }
+        *self.deferred_seek_ms.lock().unwrap() = None;
+        
+        // Clear pipeline reference to prevent element name collisions
+        self.bus_watch = None;
+        self.pipeline = None;
+        resources::release(self.resource_owner, ResourceKind::BusWatch);
+        resources::release(self.resource_owner, ResourceKind::Pipeline);
+        self.compositor = None;
+        self.audiomixer = None;
+        *self.audio_sink.lock().unwrap() = None;
+        self.live_output = None;
+        self.video_sink = None;
         self.clip_sources.clear();
+        self.clip_keys.clear();
+        self.next_clip_index = 0;
         
         info!("Direct pipeline stopped and cleared");
         Ok(())
     }
 
+    /// Set the default seek precision used by `seek()`
+    pub fn set_scrub_mode(&mut self, mode: ScrubMode) {
+        info!("Scrub mode set to {:?}", mode);
+        self.scrub_mode = mode;
+    }
+
+    pub fn get_scrub_mode(&self) -> ScrubMode {
+        self.scrub_mode
+    }
+
     pub fn seek(&self, position_ms: u64) -> Result<()> {
-        info!("Seeking direct pipeline to {}ms", position_ms);
+        self.seek_with_mode(position_ms, self.scrub_mode)
+    }
+
+    /// Seek using an explicit precision, e.g. `Fast` while dragging and `Accurate` on release
+    pub fn seek_with_mode(&self, position_ms: u64, mode: ScrubMode) -> Result<()> {
+        info!("Seeking direct pipeline to {}ms ({:?})", position_ms, mode);
         let Some(pipeline) = self.pipeline.as_ref() else {
-            return Err(anyhow!("Pipeline not loaded"));
+            return Err(FlipEditError::not_loaded().into());
         };
         
+        // Scrubbing back over frames already shown skips the decode; the pipeline catches up on play
+        if pipeline.current_state() == gst::State::Paused && self.show_cached_frame(position_ms) {
+            debug!("Showing cached frame for {}ms", position_ms);
+            *self.deferred_seek_ms.lock().unwrap() = Some(position_ms);
+            *self.current_position_ms.lock().unwrap() = position_ms;
+            Self::notify_position(&self.position_callback, self.get_frame_rate(), position_ms);
+            if let Some(callback) = self.seek_completion_callback.lock().unwrap().as_ref() {
+                if let Err(e) = callback(position_ms) {
+                    warn!("Seek completion callback error: {}", e);
+                }
+            }
+            return Ok(());
+        }
+        *self.deferred_seek_ms.lock().unwrap() = None;
+        
         let seek_result = pipeline.seek_simple(
-            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            Self::seek_flags_for_mode(mode),
             gst::ClockTime::from_mseconds(position_ms),
         );
         
         if seek_result.is_err() {
-            return Err(anyhow!("Failed to seek to position {}ms", position_ms));
+            return Err(FlipEditError::new(
+                FlipEditErrorCode::SeekFailed,
+                format!("Failed to seek to position {}ms", position_ms),
+            ).into());
+        }
+        
+        // Flushing seeks always trigger a new preroll
+        Self::emit_playback_event(&self.playback_event_callback, PlaybackEvent::Prerolling);
+        *self.current_position_ms.lock().unwrap() = position_ms;
+        Self::notify_position(&self.position_callback, self.get_frame_rate(), position_ms);
+        
+        // If pipeline is not playing, pull preroll to show the seeked frame
+        let current_state = pipeline.current_state();
+        if current_state != gst::State::Playing {
+            // Ensure pipeline is in PAUSED state
+            if current_state != gst::State::Paused {
+                if let Err(e) = pipeline.set_state(gst::State::Paused) {
+                    warn!("Failed to set pipeline to PAUSED after seek: {}", e);
+                    return Ok(());
+                }
+                // Wait for state change to complete
+                let timeout = gst::ClockTime::from_seconds(1);
+                if let Err(e) = pipeline.state(Some(timeout)).0 {
+                    warn!("Failed to complete state change to PAUSED after seek: {}", e);
+                    return Ok(());
+                }
+            }
+            
+            // Pull preroll sample to show the frame at the new position
+            if let Err(e) = self.pull_preroll_and_render() {
+                warn!("Failed to pull preroll sample after seek to {}ms: {}", position_ms, e);
+            } else {
+                info!("Successfully showed frame at position {}ms after seek", position_ms);
+            }
+        }
+        
+        Ok(())
+    }
+
+    fn seek_flags_for_mode(mode: ScrubMode) -> gst::SeekFlags {
+        match mode {
+            // Keyframe-only seeks skip decoding intermediate frames, so drags update instantly
+            ScrubMode::Fast => gst::SeekFlags::FLUSH | gst::SeekFlags::KEY_UNIT | gst::SeekFlags::SNAP_NEAREST,
+            ScrubMode::Accurate => gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+        }
+    }
+
+    pub fn get_current_position_seconds(&self) -> f64 {
+        if self.deferred_seek_ms.lock().unwrap().is_some() {
+            return *self.current_position_ms.lock().unwrap() as f64 / 1000.0;
+        }
+        if let Some(pipeline) = &self.pipeline {
+            if let Some(position) = pipeline.query_position::<gst::ClockTime>() {
+                let position_ns = position.nseconds();
+                return position_ns as f64 / 1_000_000_000.0;
+            }
+        }
+        *self.current_position_ms.lock().unwrap() as f64 / 1000.0
+    }
+
+    pub fn update_position(&self) {
+        // The pipeline is still at its last real seek
+        if self.deferred_seek_ms.lock().unwrap().is_some() {
+            return;
+        }
+        if let Some(pipeline) = &self.pipeline {
+            if let Some(position) = pipeline.query_position::<gst::ClockTime>() {
+                let position_ns = position.nseconds();
+                let position_ms = (position_ns as f64 / 1_000_000.0) as u64;
+                *self.current_position_ms.lock().unwrap() = position_ms;
+            }
+        }
+    }
+
+    pub fn get_duration_ms(&self) -> Option<u64> {
+        *self.duration_ms.lock().unwrap()
+    }
+    
+    pub fn get_current_position_ms(&self) -> u64 {
+        *self.current_position_ms.lock().unwrap()
+    }
+
+    pub fn is_playing(&self) -> bool {
+        *self.is_playing.lock().unwrap()
+    }
+
+    /// Shift audio against video to compensate output latency (e.g. Bluetooth headphones).
+    /// Positive values delay audio, negative values play it earlier.
+    pub fn set_av_offset_ms(&self, offset_ms: i64) {
+        *self.av_offset_ms.lock().unwrap() = offset_ms;
+        
+        if let Some(sink) = self.audio_sink.lock().unwrap().as_ref() {
+            sink.set_property("ts-offset", offset_ms * 1_000_000);
+            info!("Applied A/V offset of {}ms to audio sink", offset_ms);
+        }
+    }
+
+    pub fn get_av_offset_ms(&self) -> i64 {
+        *self.av_offset_ms.lock().unwrap()
+    }
+
+    pub fn set_position_update_callback(&mut self, callback: PositionUpdateCallback) -> Result<()> {
+        let mut guard = self.position_callback.lock().unwrap();
+        *guard = Some(callback);
+        Ok(())
+    }
+    
+    pub fn set_seek_completion_callback(&mut self, callback: SeekCompletionCallback) -> Result<()> {
+        let mut guard = self.seek_completion_callback.lock().unwrap();
+        *guard = Some(callback);
+        Ok(())
+    }
+    
+    pub fn set_end_behavior(&self, behavior: EndBehavior) {
+        info!("End behavior set to {:?}", behavior);
+        *self.end_behavior.lock().unwrap() = behavior;
+    }
+
+    pub fn get_end_behavior(&self) -> EndBehavior {
+        *self.end_behavior.lock().unwrap()
+    }
+
+    pub fn set_playback_event_callback(&mut self, callback: PlaybackEventCallback) -> Result<()> {
+        let mut guard = self.playback_event_callback.lock().unwrap();
+        *guard = Some(callback);
+        Ok(())
+    }
+    
+    /// Receive master output levels while audio plays
+    pub fn set_audio_level_callback(&mut self, callback: AudioLevelCallback) -> Result<()> {
+        *self.audio_level_callback.lock().unwrap() = Some(callback);
+        Ok(())
+    }
+    
+    /// Re-render the current frame after a mixer property change. Playing pipelines pick
+    /// the change up on their next frame.
+    fn refresh_paused_frame(&self) {
+        if let Some(pipeline) = &self.pipeline {
+            let current_position = *self.current_position_ms.lock().unwrap();
+            let current_state = pipeline.current_state();
+            
+            if current_state == gst::State::Playing {
+                // Already playing, the frame will update naturally
+                info!("Pipeline is playing, change will be visible in next frame");
+            } else {
+                // Pipeline is paused/stopped, need to manually trigger frame update
+                info!("Pipeline is paused, manually triggering frame update for clip property change");
+                
+                // Ensure pipeline is in PAUSED state
+                if current_state != gst::State::Paused {
+                    if let Err(e) = pipeline.set_state(gst::State::Paused) {
+                        warn!("Failed to set pipeline to PAUSED: {}", e);
+                        return;
+                    }
+                    // Wait for state change to complete
+                    let timeout = gst::ClockTime::from_seconds(1);
+                    if let Err(e) = pipeline.state(Some(timeout)).0 {
+                        warn!("Failed to complete state change to PAUSED: {}", e);
+                        return;
+                    }
+                }
+                
+                // Force seek to current position to trigger frame render with new clip properties
+                *self.deferred_seek_ms.lock().unwrap() = None;
+                let seek_result = pipeline.seek_simple(
+                    gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+                    gst::ClockTime::from_mseconds(current_position),
+                );
+                
+                if seek_result.is_ok() {
+                    info!("Forced seek to {}ms to update frame with new clip properties", current_position);
+                    
+                    // Now pull the preroll sample to force the frame render
+                    if let Err(e) = self.pull_preroll_and_render() {
+                        warn!("Failed to pull preroll sample after clip property update: {}", e);
+                    }
+                } else {
+                    warn!("Failed to seek for frame update after clip property change");
+                }
+                
+                // Note: We DON'T restore to playing state here because the pipeline
+                // was already paused when we started, so it should stay paused
+            }
+        }
+    }
+
+    /// Append `factory_name` (e.g. `equalizer-10bands`, `audiodynamic`) to a track's audio
+    /// effect chain. The pipeline is rebuilt at the playhead so every clip on the track uses it.
+    pub fn add_track_audio_effect(&mut self, track_id: i32, factory_name: &str) -> Result<u32> {
+        audio_effects::check_effect_factory(factory_name)?;
+        let effect_ids = self.push_track_audio_effects(track_id, vec![(factory_name.to_string(), HashMap::new())])?;
+        Ok(effect_ids[0])
+    }
+
+    /// Add denoise or de-hum effects, preset for restoration and adjustable like any other
+    /// effect; returns their ids in chain order
+    pub fn add_track_audio_restoration(&mut self, track_id: i32, restoration: AudioRestoration) -> Result<Vec<u32>> {
+        let effects = audio_effects::restoration_effects(restoration)?;
+        self.push_track_audio_effects(track_id, effects)
+    }
+
+    // Track and clip effects share ids, since a clip's chain holds both
+    fn next_audio_effect_id(timeline_data: &TimelineData) -> u32 {
+        timeline_data.tracks.iter()
+            .flat_map(|t| t.audio_effects.iter().chain(t.clips.iter().flat_map(|c| &c.audio_effects)))
+            .map(|e| e.effect_id)
+            .max()
+            .map_or(1, |id| id + 1)
+    }
+
+    fn push_track_audio_effects(&mut self, track_id: i32, effects: Vec<(String, HashMap<String, f64>)>) -> Result<Vec<u32>> {
+        let mut timeline_data = self.timeline_data.clone().ok_or_else(FlipEditError::not_loaded)?;
+        let first_id = Self::next_audio_effect_id(&timeline_data);
+        let track = Self::find_track_mut(&mut timeline_data, track_id)?;
+        let mut effect_ids = Vec::with_capacity(effects.len());
+        for (effect_id, (factory_name, parameters)) in (first_id..).zip(effects) {
+            info!("Added {} to track {} as effect {}", factory_name, track_id, effect_id);
+            track.audio_effects.push(TrackAudioEffect { effect_id, factory_name, parameters });
+            effect_ids.push(effect_id);
+        }
+        self.reload_with_track_audio(timeline_data)?;
+        self.emit_timeline_event(TimelineEvent::EffectChanged { track_id });
+        Ok(effect_ids)
+    }
+
+    pub fn remove_track_audio_effect(&mut self, track_id: i32, effect_id: u32) -> Result<()> {
+        let mut timeline_data = self.timeline_data.clone().ok_or_else(FlipEditError::not_loaded)?;
+        let effects = &mut Self::find_track_mut(&mut timeline_data, track_id)?.audio_effects;
+        let count = effects.len();
+        effects.retain(|e| e.effect_id != effect_id);
+        if effects.len() == count {
+            return Err(Self::effect_not_found(track_id, effect_id));
+        }
+        self.reload_with_track_audio(timeline_data)?;
+        self.emit_timeline_event(TimelineEvent::EffectChanged { track_id });
+        Ok(())
+    }
+
+    /// Change an effect parameter; playing clips pick it up immediately
+    pub fn set_track_audio_effect_parameter(&mut self, track_id: i32, effect_id: u32, name: &str, value: f64) -> Result<()> {
+        let timeline_data = self.timeline_data.as_mut().ok_or_else(FlipEditError::not_loaded)?;
+        let track = Self::find_track_mut(timeline_data, track_id)?;
+        let effect = track.audio_effects.iter_mut()
+            .find(|e| e.effect_id == effect_id)
+            .ok_or_else(|| Self::effect_not_found(track_id, effect_id))?;
+
+        // Validate against a scratch instance so bad names fail even when no clip has audio
+        let scratch = gst::ElementFactory::make(&effect.factory_name)
+            .build()
+            .map_err(|e| anyhow!("Failed to create {}: {}", effect.factory_name, e))?;
+        audio_effects::set_parameter(&scratch, name, value)?;
+        effect.parameters.insert(name.to_string(), value);
+        let effects = track.audio_effects.clone();
+        self.track_audio_effects.insert(track_id, effects);
+
+        let element_name = audio_effects::effect_element_name(effect_id);
+        for source in self.clip_sources.values().filter(|s| s.clip_data.track_id == track_id) {
+            let branch = source.audio_branch.lock().unwrap();
+            let element = branch.as_ref()
+                .and_then(|b| b.effect_chain.as_ref())
+                .and_then(|chain| chain.downcast_ref::<gst::Bin>())
+                .and_then(|bin| bin.by_name(&element_name));
+            if let Some(element) = element {
+                audio_effects::set_parameter(&element, name, value)?;
+            }
+        }
+        self.emit_timeline_event(TimelineEvent::EffectChanged { track_id });
+        Ok(())
+    }
+
+    pub fn get_track_audio_effects(&self, track_id: i32) -> Vec<TrackAudioEffect> {
+        self.timeline_data.as_ref()
+            .and_then(|timeline| timeline.tracks.iter().find(|t| t.id == track_id))
+            .map(|t| t.audio_effects.clone())
+            .unwrap_or_default()
+    }
+
+    /// Append `factory_name` to a single clip's audio effects, which run before its track's.
+    /// Only that clip's branch is rebuilt.
+    pub fn add_clip_audio_effect(&mut self, clip_id: i32, factory_name: &str) -> Result<u32> {
+        audio_effects::check_effect_factory(factory_name)?;
+        let effect_ids = self.push_clip_audio_effects(clip_id, vec![(factory_name.to_string(), HashMap::new())])?;
+        Ok(effect_ids[0])
+    }
+
+    /// Add denoise or de-hum effects to a single clip, e.g. the one interview recorded next to
+    /// a fridge; returns their ids in chain order
+    pub fn add_clip_audio_restoration(&mut self, clip_id: i32, restoration: AudioRestoration) -> Result<Vec<u32>> {
+        let effects = audio_effects::restoration_effects(restoration)?;
+        self.push_clip_audio_effects(clip_id, effects)
+    }
+
+    /// Shift a clip's pitch by `semitones` without changing its speed, e.g. to disguise a voice.
+    /// The effect's `semitones` parameter changes it later.
+    pub fn add_clip_pitch_shift(&mut self, clip_id: i32, semitones: f64) -> Result<u32> {
+        if !semitones.is_finite() {
+            return Err(anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, format!("Invalid pitch shift {}", semitones))));
+        }
+        audio_effects::check_effect_factory(audio_effects::PITCH_FACTORY)?;
+        let parameters = HashMap::from([(audio_effects::SEMITONES_PARAMETER.to_string(), semitones)]);
+        let effect_ids = self.push_clip_audio_effects(clip_id, vec![(audio_effects::PITCH_FACTORY.to_string(), parameters)])?;
+        Ok(effect_ids[0])
+    }
+
+    fn push_clip_audio_effects(&mut self, clip_id: i32, effects: Vec<(String, HashMap<String, f64>)>) -> Result<Vec<u32>> {
+        let timeline_data = self.timeline_data.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        let first_id = Self::next_audio_effect_id(timeline_data);
+        let effects: Vec<TrackAudioEffect> = (first_id..)
+            .zip(effects)
+            .map(|(effect_id, (factory_name, parameters))| TrackAudioEffect { effect_id, factory_name, parameters })
+            .collect();
+        self.update_timeline_clip(clip_id, |clip| clip.audio_effects.extend(effects.iter().cloned()))?;
+        for effect in &effects {
+            info!("Added {} to clip {} as effect {}", effect.factory_name, clip_id, effect.effect_id);
+        }
+        self.rebuild_clip_audio(clip_id)?;
+        self.emit_timeline_event(TimelineEvent::ClipEffectChanged { clip_id });
+        Ok(effects.iter().map(|effect| effect.effect_id).collect())
+    }
+
+    pub fn remove_clip_audio_effect(&mut self, clip_id: i32, effect_id: u32) -> Result<()> {
+        let removed = self.update_timeline_clip(clip_id, |clip| {
+            let count = clip.audio_effects.len();
+            clip.audio_effects.retain(|e| e.effect_id != effect_id);
+            clip.audio_effects.len() != count
+        })?;
+        if !removed {
+            return Err(Self::clip_effect_not_found(clip_id, effect_id));
+        }
+        self.rebuild_clip_audio(clip_id)?;
+        self.emit_timeline_event(TimelineEvent::ClipEffectChanged { clip_id });
+        Ok(())
+    }
+
+    /// Change a clip effect's parameter; the clip picks it up immediately
+    pub fn set_clip_audio_effect_parameter(&mut self, clip_id: i32, effect_id: u32, name: &str, value: f64) -> Result<()> {
+        let effect = self.timeline_clip(clip_id)?.audio_effects.into_iter()
+            .find(|e| e.effect_id == effect_id)
+            .ok_or_else(|| Self::clip_effect_not_found(clip_id, effect_id))?;
+
+        // Validate against a scratch instance so bad names fail even when the clip isn't playing
+        let scratch = gst::ElementFactory::make(&effect.factory_name)
+            .build()
+            .map_err(|e| anyhow!("Failed to create {}: {}", effect.factory_name, e))?;
+        audio_effects::set_parameter(&scratch, name, value)?;
+        self.update_timeline_clip(clip_id, |clip| {
+            if let Some(effect) = clip.audio_effects.iter_mut().find(|e| e.effect_id == effect_id) {
+                effect.parameters.insert(name.to_string(), value);
+            }
+        })?;
+
+        if let Some(source) = self.find_clip_key(clip_id).and_then(|key| self.clip_sources.get(&key)) {
+            let branch = source.audio_branch.lock().unwrap();
+            let element = branch.as_ref()
+                .and_then(|b| b.effect_chain.as_ref())
+                .and_then(|chain| chain.downcast_ref::<gst::Bin>())
+                .and_then(|bin| bin.by_name(&audio_effects::effect_element_name(effect_id)));
+            if let Some(element) = element {
+                audio_effects::set_parameter(&element, name, value)?;
+            }
+        }
+        self.emit_timeline_event(TimelineEvent::ClipEffectChanged { clip_id });
+        Ok(())
+    }
+
+    pub fn get_clip_audio_effects(&self, clip_id: i32) -> Vec<TrackAudioEffect> {
+        self.timeline_clip(clip_id).map(|clip| clip.audio_effects).unwrap_or_default()
+    }
+
+    fn clip_effect_not_found(clip_id: i32, effect_id: u32) -> anyhow::Error {
+        anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Clip {} has no audio effect {}", clip_id, effect_id),
+        ))
+    }
+
+    /// Rebuild a clip's branch so its audio runs through its changed effects
+    fn rebuild_clip_audio(&mut self, clip_id: i32) -> Result<()> {
+        let Some(clip_key) = self.find_clip_key(clip_id) else {
+            return Ok(());
+        };
+        self.rebuild_clip_source(&clip_key)?;
+        self.resync_clips();
+        Ok(())
+    }
+
+    /// Replace a track's volume automation, e.g. with the envelope from
+    /// `audio_automation::timeline_ducking_keyframes`
+    pub fn set_volume_keyframes(&mut self, track_id: i32, keyframes: Vec<VolumeKeyframe>) -> Result<()> {
+        let mut timeline_data = self.timeline_data.clone().ok_or_else(FlipEditError::not_loaded)?;
+        Self::find_track_mut(&mut timeline_data, track_id)?.volume_keyframes = keyframes;
+        self.reload_with_track_audio(timeline_data)?;
+        self.emit_timeline_event(TimelineEvent::EffectChanged { track_id });
+        Ok(())
+    }
+
+    /// Remove a track's volume automation, e.g. to undo ducking
+    pub fn clear_volume_keyframes(&mut self, track_id: i32) -> Result<()> {
+        self.set_volume_keyframes(track_id, Vec::new())
+    }
+
+    pub fn get_volume_keyframes(&self, track_id: i32) -> Vec<VolumeKeyframe> {
+        self.timeline_data.as_ref()
+            .and_then(|timeline| timeline.tracks.iter().find(|t| t.id == track_id))
+            .map(|t| t.volume_keyframes.clone())
+            .unwrap_or_default()
+    }
+    
+    /// Record the selected input to `output_path` while the timeline plays from `start_ms`
+    pub fn start_voiceover(&mut self, track_id: i32, start_ms: u64, output_path: &str, playback: VoiceoverPlayback) -> Result<()> {
+        if self.voiceover.is_some() {
+            return Err(anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, "A voice-over is already being recorded")));
+        }
+        let timeline_data = self.timeline_data.as_mut().ok_or_else(FlipEditError::not_loaded)?;
+        Self::find_track_mut(timeline_data, track_id)?;
+        
+        self.pause()?;
+        self.seek_with_mode(start_ms, ScrubMode::Accurate)?;
+        
+        let recorder = VoiceoverRecorder::start(output_path)?;
+        if let Some(volume) = self.master_volume_element() {
+            match playback {
+                VoiceoverPlayback::Muted => volume.set_property("mute", true),
+                VoiceoverPlayback::Ducked => volume.set_property("volume", audio_handler::get_master_volume() * VOICEOVER_DUCK_GAIN),
+            }
+        }
+        
+        let lead_in_ms = recorder.position_ms();
+        if let Err(e) = self.play() {
+            self.restore_voiceover_monitor();
+            return Err(e);
+        }
+        
+        info!("Recording voice-over on track {} from {}ms ({:?})", track_id, start_ms, playback);
+        self.voiceover = Some(VoiceoverSession { recorder, track_id, start_ms, lead_in_ms });
+        Ok(())
+    }
+    
+    /// Stop the voice-over and add the recording to its track, returning the new clip
+    pub fn stop_voiceover(&mut self) -> Result<TimelineClip> {
+        let session = self.voiceover.take().ok_or_else(|| {
+            anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, "No voice-over is being recorded"))
+        })?;
+        let pause_result = self.pause();
+        self.restore_voiceover_monitor();
+        
+        let output_path = session.recorder.output_path().to_string();
+        let length_ms = session.recorder.stop()?;
+        pause_result?;
+        if length_ms <= session.lead_in_ms {
+            return Err(anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, "Nothing was recorded")));
+        }
+        
+        let recorded_ms = (length_ms - session.lead_in_ms) as i32;
+        let clip = TimelineClip {
+            id: Some(self.allocate_clip_id()),
+            track_id: session.track_id,
+            source_path: output_path,
+            start_time_on_track_ms: session.start_ms as i32,
+            end_time_on_track_ms: session.start_ms as i32 + recorded_ms,
+            start_time_in_source_ms: session.lead_in_ms as i32,
+            end_time_in_source_ms: length_ms as i32,
+            preview_position_x: 0.0,
+            preview_position_y: 0.0,
+            preview_width: 0.0,
+            preview_height: 0.0,
+            preview_opacity: 1.0,
+            track_type: TrackType::Audio,
+            fit_mode: ClipFitMode::default(),
+            orientation: ClipOrientation::default(),
+            crop_keyframes: Vec::new(),
+            audio_effects: Vec::new(),
+            tone_mapping: None,
+            transition_in_ms: 0,
+        };
+        self.apply_timeline_diff(vec![TimelineChange::AddClip { clip: clip.clone() }])?;
+        Ok(clip)
+    }
+    
+    pub fn is_recording_voiceover(&self) -> bool {
+        self.voiceover.is_some()
+    }
+    
+    /// Stream the program output to an RTMP (e.g. YouTube or Twitch) or SRT ingest while it plays.
+    /// The stream follows the playhead, so it pauses and seeks along with the preview.
+    pub fn start_live_stream(&mut self, settings: LiveStreamSettings) -> Result<()> {
+        let pipeline = self.pipeline.clone().ok_or_else(FlipEditError::not_loaded)?;
+        if let Some(output) = self.live_output.take() {
+            output.detach(&pipeline);
+        }
+        self.live_output = Some(LiveOutput::attach(&pipeline, &settings, self.uses_gl_mixing())?);
+        self.live_stream = Some(settings);
+        Ok(())
+    }
+    
+    pub fn stop_live_stream(&mut self) {
+        self.live_stream = None;
+        if let (Some(output), Some(pipeline)) = (self.live_output.take(), self.pipeline.as_ref()) {
+            output.detach(pipeline);
+        }
+    }
+    
+    pub fn is_live_streaming(&self) -> bool {
+        self.live_output.is_some()
+    }
+    
+    fn master_volume_element(&self) -> Option<gst::Element> {
+        self.pipeline.as_ref().and_then(|pipeline| pipeline.by_name("master_volume"))
+    }
+    
+    fn restore_voiceover_monitor(&self) {
+        if let Some(volume) = self.master_volume_element() {
+            set_master_volume_properties(&volume);
+        }
+    }
+
+    /// Store a timeline whose effect chains or automation changed and rebuild for it. A soloed clip keeps
+    /// playing as it was; the change is heard once the preview exits.
+    fn reload_with_track_audio(&mut self, timeline_data: TimelineData) -> Result<()> {
+        if self.solo_preview.is_some() {
+            self.timeline_data = Some(timeline_data);
+            return Ok(());
+        }
+        self.load_timeline_data(timeline_data)
+    }
+
+    fn find_track_mut(timeline_data: &mut TimelineData, track_id: i32) -> Result<&mut TimelineTrack> {
+        timeline_data.tracks.iter_mut()
+            .find(|t| t.id == track_id)
+            .ok_or_else(|| anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Track {} not found in timeline", track_id),
+            )))
+    }
+
+    fn effect_not_found(track_id: i32, effect_id: u32) -> anyhow::Error {
+        anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Track {} has no audio effect {}", track_id, effect_id),
+        ))
+    }
+
+    /// Change a clip's opacity without reloading the timeline
+    pub fn update_clip_opacity(&mut self, clip_id: i32, opacity: f64) -> Result<()> {
+        let opacity = opacity.clamp(0.0, 1.0);
+        let clip_source = self.find_clip_key(clip_id)
+            .and_then(|key| self.clip_sources.get_mut(&key))
+            .ok_or_else(|| anyhow!("Clip with ID {} not found", clip_id))?;
+        
+        clip_source.clip_data.preview_opacity = opacity;
+        if let Some(fade_opacity) = clip_source.fade_opacity.as_ref() {
+            *fade_opacity.lock().unwrap() = opacity;
+        }
+        if let Some(ref compositor_pad) = clip_source.compositor_pad {
+            compositor_pad.set_property("alpha", opacity);
+        }
+        let (start_ms, end_ms) = (clip_source.clip_data.start_time_on_track_ms, clip_source.clip_data.end_time_on_track_ms);
+        self.frame_cache.invalidate_range(start_ms.max(0) as u64, end_ms.max(0) as u64);
+        if let Some(timeline_clip) = self.timeline_data.as_mut()
+            .and_then(|data| data.tracks.iter_mut().flat_map(|t| t.clips.iter_mut()).find(|c| c.id == Some(clip_id)))
+        {
+            timeline_clip.preview_opacity = opacity;
+        }
+        
+        self.refresh_paused_frame();
+        info!("Set clip {} opacity to {}", clip_id, opacity);
+        Ok(())
+    }
+
+    /// Update a specific clip's transform properties without reloading the entire timeline
+    pub fn update_clip_transform(
+        &mut self,
+        clip_id: i32,
+        preview_position_x: f64,
+        preview_position_y: f64,
+        preview_width: f64,
+        preview_height: f64,
+    ) -> Result<()> {
+        info!("Updating clip transform for clip_id {} to pos=({}, {}), size=({}, {})",
+              clip_id, preview_position_x, preview_position_y, preview_width, preview_height);
+        
+        let clip_key = self.find_clip_key(clip_id).ok_or_else(|| anyhow!("Clip with ID {} not found", clip_id))?;
+        
+        // Get the clip source
+        let clip_source = self.clip_sources.get_mut(&clip_key)
+            .ok_or_else(|| anyhow!("Clip source not found for key {}", clip_key))?;
+        
+        // Update the clip data
+        clip_source.clip_data.preview_position_x = preview_position_x;
+        clip_source.clip_data.preview_position_y = preview_position_y;
+        clip_source.clip_data.preview_width = preview_width;
+        clip_source.clip_data.preview_height = preview_height;
+        
+        self.update_clip_placement(&clip_key)?;
+        info!("Successfully updated clip {} transform properties", clip_id);
+        Ok(())
+    }
+
+    /// Choose how a clip fills its preview box; applies to the live pipeline right away
+    pub fn set_clip_fit_mode(&mut self, clip_id: i32, mode: ClipFitMode) -> Result<()> {
+        self.update_timeline_clip(clip_id, |clip| clip.fit_mode = mode)?;
+        match self.find_clip_key(clip_id) {
+            Some(clip_key) => self.update_clip_placement(&clip_key),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get_clip_fit_mode(&self, clip_id: i32) -> ClipFitMode {
+        self.timeline_clip(clip_id).map(|clip| clip.fit_mode).unwrap_or_default()
+    }
+
+    /// Rotate a clip clockwise by `degrees`; quarter turns turn its frame, anything else tilts
+    /// the picture within it. Replaces any rotation keyframes and applies right away.
+    pub fn set_clip_rotation(&mut self, clip_id: i32, degrees: f64) -> Result<()> {
+        if !degrees.is_finite() {
+            return Err(anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, format!("Invalid rotation {}", degrees))));
+        }
+        self.update_timeline_clip(clip_id, |clip| {
+            clip.orientation.rotation_degrees = degrees;
+            clip.orientation.rotation_keyframes.clear();
+        })?;
+        self.apply_clip_orientation(clip_id)
+    }
+
+    /// Mirror a clip horizontally and/or vertically; applies right away
+    pub fn set_clip_flip(&mut self, clip_id: i32, horizontal: bool, vertical: bool) -> Result<()> {
+        self.update_timeline_clip(clip_id, |clip| {
+            clip.orientation.flip_horizontal = horizontal;
+            clip.orientation.flip_vertical = vertical;
+        })?;
+        self.apply_clip_orientation(clip_id)
+    }
+
+    /// Animate a clip's rotation; keyframe times are on the timeline. The frame keeps the
+    /// quarter turns of the clip's `set_clip_rotation` angle, so keyframes only tilt it.
+    pub fn set_clip_rotation_keyframes(&mut self, clip_id: i32, mut keyframes: Vec<RotationKeyframe>) -> Result<()> {
+        if let Some(keyframe) = keyframes.iter().find(|k| !k.degrees.is_finite()) {
+            return Err(anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Invalid rotation {} at {}ms", keyframe.degrees, keyframe.time_ms),
+            )));
+        }
+        keyframes.sort_by_key(|k| k.time_ms);
+        keyframes.dedup_by_key(|k| k.time_ms);
+        self.update_timeline_clip(clip_id, |clip| clip.orientation.rotation_keyframes = keyframes.clone())?;
+        self.apply_clip_orientation(clip_id)
+    }
+
+    pub fn get_clip_orientation(&self, clip_id: i32) -> ClipOrientation {
+        self.timeline_clip(clip_id).map(|clip| clip.orientation).unwrap_or_default()
+    }
+
+    fn apply_clip_orientation(&mut self, clip_id: i32) -> Result<()> {
+        let Some(clip_key) = self.find_clip_key(clip_id) else {
+            return Ok(());
+        };
+        let clip_orientation = self.get_clip_orientation(clip_id);
+        let Some(clip_source) = self.clip_sources.get(&clip_key) else {
+            return Ok(());
+        };
+        let can_tilt = !self.uses_gl_mixing() && gst::ElementFactory::find("rotate").is_some();
+        if clip_source.tilt.is_none() && can_tilt && orientation::needs_tilt(&clip_orientation) && self.solo_preview.is_none() {
+            // The clip was built without a tilt element; cached frames show it untilted
+            self.frame_cache.clear();
+            let timeline_data = self.timeline_data.clone().ok_or_else(FlipEditError::not_loaded)?;
+            return self.load_timeline(timeline_data);
+        }
+        if let Some(flip) = clip_source.orientation_flip.as_ref() {
+            flip.set_property_from_str("method", orientation::flip_method(&clip_orientation));
+        }
+        *clip_source.orientation.lock().unwrap() = clip_orientation;
+        self.update_clip_placement(&clip_key)
+    }
+
+    /// Ken Burns move: pan and zoom from `start_rect` of the clip's source at its start to
+    /// `end_rect` at its end. Each rect is stretched to the clip's box, so give them the box's
+    /// shape. Replaces any crop keyframes; needs a CPU render path.
+    pub fn set_pan_zoom(&mut self, clip_id: i32, start_rect: SourceRect, end_rect: SourceRect) -> Result<()> {
+        let clip = self.timeline_clip(clip_id)?;
+        let (start_ms, end_ms) = (clip.start_time_on_track_ms.max(0) as u64, clip.end_time_on_track_ms.max(0) as u64);
+        self.set_clip_crop_keyframes(clip_id, vec![
+            CropKeyframe { time_ms: start_ms, rect: start_rect },
+            CropKeyframe { time_ms: end_ms.max(start_ms), rect: end_rect },
+        ])
+    }
+
+    /// Animate which part of a clip's source is shown; keyframe times are on the timeline.
+    /// An empty list goes back to the fit mode's crop.
+    pub fn set_clip_crop_keyframes(&mut self, clip_id: i32, mut keyframes: Vec<CropKeyframe>) -> Result<()> {
+        if let Some(keyframe) = keyframes.iter().find(|k| !k.rect.is_valid()) {
+            return Err(anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Crop rect {:?} at {}ms isn't inside the frame", keyframe.rect, keyframe.time_ms),
+            )));
+        }
+        keyframes.sort_by_key(|k| k.time_ms);
+        keyframes.dedup_by_key(|k| k.time_ms);
+        self.update_timeline_clip(clip_id, |clip| clip.crop_keyframes = keyframes.clone())?;
+
+        let Some(clip_key) = self.find_clip_key(clip_id) else {
+            return Ok(());
+        };
+        if let Some(clip_source) = self.clip_sources.get(&clip_key) {
+            *clip_source.crop_keyframes.lock().unwrap() = keyframes;
+        }
+        self.update_clip_placement(&clip_key)
+    }
+
+    pub fn get_clip_crop_keyframes(&self, clip_id: i32) -> Vec<CropKeyframe> {
+        self.timeline_clip(clip_id).map(|clip| clip.crop_keyframes).unwrap_or_default()
+    }
+
+    /// A clip's timing together with how the player shows it and what its source is
+    pub fn get_clip(&mut self, clip_id: i32) -> Result<ClipInfo> {
+        let timeline_data = self.timeline_data.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        let (track, clip) = timeline_data.tracks.iter()
+            .find_map(|t| t.clips.iter().find(|c| c.id == Some(clip_id)).map(|c| (t, c)))
+            .ok_or_else(|| anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Clip {} not found in timeline", clip_id),
+            )))?;
+        let clip = clip.clone();
+        let track_audio_effects = track.audio_effects.clone();
+        let track_volume_keyframes = track.volume_keyframes.clone();
+        let markers = timeline_data.markers.iter()
+            .filter(|m| m.position_ms >= clip.start_time_on_track_ms && m.position_ms < clip.end_time_on_track_ms)
+            .cloned()
+            .collect();
+
+        let can_crop = match self.find_clip_key(clip_id).and_then(|key| self.clip_sources.get(&key)) {
+            Some(source) => source.fit_crop.is_some(),
+            None => !self.uses_gl_mixing(),
+        };
+        let placement = self.clip_placement(&clip, can_crop);
+        let probe = self.source_probe(&clip.source_path);
+        Ok(ClipInfo {
+            display_x: placement.x,
+            display_y: placement.y,
+            display_width: placement.width,
+            display_height: placement.height,
+            tone_mapping: clip.tone_mapping.unwrap_or(self.hdr_tone_mapping),
+            is_hdr: probe.transfer.is_hdr(),
+            source_width: probe.geometry.map(|((width, _), _)| width),
+            source_height: probe.geometry.map(|((_, height), _)| height),
+            source_pixel_aspect_ratio: probe.geometry.map(|(_, par)| par),
+            source_frame_rate: probe.frame_rate,
+            source_color_space: probe.color_space,
+            track_audio_effects,
+            track_volume_keyframes,
+            markers,
+            clip,
+        })
+    }
+
+    fn clip_placement(&mut self, clip_data: &TimelineClip, can_crop: bool) -> Placement {
+        let mode = clip_data.fit_mode;
+        let source = if mode == ClipFitMode::Stretch {
+            None
+        } else {
+            self.source_probe(&clip_data.source_path).geometry
+                .map(|geometry| orientation::turned_geometry(geometry, &clip_data.orientation))
+        };
+        fit::place(mode, clip_data, source, self.output_par, can_crop)
+    }
+
+    fn apply_placement(&self, placement: &Placement, caps_filter: &gst::Element, compositor_pad: Option<&gst::Pad>, fit_crop: Option<&gst::Element>) {
+        caps_filter.set_property("caps", self.mixing_caps(placement.width, placement.height));
+        if let Some(compositor_pad) = compositor_pad {
+            compositor_pad.set_property("xpos", placement.x);
+            compositor_pad.set_property("ypos", placement.y);
+            compositor_pad.set_property("width", placement.width);
+            compositor_pad.set_property("height", placement.height);
+        }
+        if let Some(fit_crop) = fit_crop {
+            fit_crop.set_property("left", placement.crop_left);
+            fit_crop.set_property("right", placement.crop_right);
+            fit_crop.set_property("top", placement.crop_top);
+            fit_crop.set_property("bottom", placement.crop_bottom);
+        }
+    }
+
+    /// Re-place a loaded clip after its box or fit mode changed, and show the result
+    fn update_clip_placement(&mut self, clip_key: &str) -> Result<()> {
+        let clip_source = self.clip_sources.get(clip_key)
+            .ok_or_else(|| anyhow!("Clip source not found for key {}", clip_key))?;
+        let clip_data = clip_source.clip_data.clone();
+        let can_crop = clip_source.fit_crop.is_some();
+        let placement = self.clip_placement(&clip_data, can_crop);
+        
+        let clip_source = &self.clip_sources[clip_key];
+        let fit_crop = clip_source.fit_crop.as_ref().filter(|_| clip_source.crop_keyframes.lock().unwrap().is_empty());
+        self.apply_placement(&placement, &clip_source.caps_filter, clip_source.compositor_pad.as_ref(), fit_crop);
+        let (start_ms, end_ms) = (clip_data.start_time_on_track_ms, clip_data.end_time_on_track_ms);
+        self.frame_cache.invalidate_range(start_ms.max(0) as u64, end_ms.max(0) as u64);
+        
+        // Trigger a frame update by forcing a seek and pulling preroll sample
+        self.refresh_paused_frame();
+        Ok(())
+    }
+    
+    /// Apply add/move/resize/remove edits to the live pipeline and resync once, instead of a full
+    /// reload. With auto-commit off the edits are checked and queued for `commit_timeline`.
+    pub fn apply_timeline_diff(&mut self, mut changes: Vec<TimelineChange>) -> Result<()> {
+        let mut timeline_data = self.timeline_data.clone().ok_or_else(FlipEditError::not_loaded)?;
+        for change in &mut changes {
+            if let TimelineChange::AddClip { clip } = change {
+                match clip.id {
+                    Some(id) => self.next_clip_id = self.next_clip_id.max(id + 1),
+                    None => clip.id = Some(self.allocate_clip_id()),
+                }
+            }
+        }
+        if !self.auto_commit {
+            for change in self.pending_changes.iter().chain(&changes) {
+                Self::apply_change_to_timeline_data(&mut timeline_data, change)?;
+            }
+            self.pending_changes.extend(changes);
+            return Ok(());
+        }
+        self.commit_changes(timeline_data, changes)
+    }
+
+    /// Commit edits every `apply_timeline_diff` call (the default), or only on `commit_timeline`,
+    /// e.g. while the user drags a trim handle. Turning it back on commits queued edits.
+    pub fn set_auto_commit(&mut self, enabled: bool) -> Result<()> {
+        self.auto_commit = enabled;
+        if enabled {
+            self.commit_timeline()?;
+        }
+        Ok(())
+    }
+
+    pub fn get_auto_commit(&self) -> bool {
+        self.auto_commit
+    }
+
+    /// Apply the edits queued while auto-commit is off in one pipeline update
+    pub fn commit_timeline(&mut self) -> Result<()> {
+        if self.pending_changes.is_empty() {
+            return Ok(());
+        }
+        let changes = std::mem::take(&mut self.pending_changes);
+        let timeline_data = self.timeline_data.clone().ok_or_else(FlipEditError::not_loaded)?;
+        self.commit_changes(timeline_data, changes)
+    }
+
+    /// Apply `changes` to `timeline_data` (the committed timeline) and the pipeline
+    fn commit_changes(&mut self, mut timeline_data: TimelineData, mut changes: Vec<TimelineChange>) -> Result<()> {
+        let edit_mode_changes = Self::edit_mode_changes(self.edit_mode, &timeline_data, &changes);
+        changes.extend(edit_mode_changes);
+        let crossfaded_tracks = Self::auto_transition_tracks(&timeline_data, &changes);
+        let fades_before = transitions::clip_fades(&timeline_data);
+        // Where the edited clips were and where they end up
+        let mut changed_ranges = Vec::new();
+        for change in &changes {
+            changed_ranges.extend(Self::changed_range(&timeline_data, change));
+            Self::apply_change_to_timeline_data(&mut timeline_data, change)?;
+            changed_ranges.extend(Self::changed_range(&timeline_data, change));
+        }
+        for track in timeline_data.tracks.iter_mut().filter(|t| crossfaded_tracks.contains(&t.id)) {
+            transitions::crossfade_overlaps(track);
+        }
+        // Crossfades are built into the clips' branches, so changing one needs a reload
+        let fades_changed = transitions::clip_fades(&timeline_data) != fades_before;
+        // Rippled cues after the ripple point may be burnt into rendered segments
+        if self.render_cache.burns_captions() {
+            changed_ranges.extend(changes.iter().filter_map(|change| match change {
+                TimelineChange::RippleCaptions { at_ms, .. } => Some((*at_ms, i32::MAX)),
+                _ => None,
+            }));
+        }
+        let events: Vec<TimelineEvent> = changes.iter().map(|change| Self::timeline_event(&timeline_data, change)).collect();
+        
+        // Solo previews and cache-substituted clips aren't in the live graph, and a rendered
+        // segment it plays has the old edit baked in, so fall back to a reload
+        let live_edit_possible = self.solo_preview.is_none()
+            && self.pipeline.is_some()
+            && !fades_changed
+            && !self.plays_rendered_range(&changed_ranges)
+            && changes.iter().all(|change| match change {
+                TimelineChange::AddClip { .. } | TimelineChange::RippleCaptions { .. } => true,
+                TimelineChange::MoveClip { clip_id, .. }
+                | TimelineChange::ResizeClip { clip_id, .. }
+                | TimelineChange::RemoveClip { clip_id } => self.find_clip_key(*clip_id).is_some(),
+            });
+        
+        if !live_edit_possible {
+            if self.solo_preview.is_some() {
+                // Picked up when leaving the solo preview
+                self.timeline_data = Some(timeline_data);
+            } else {
+                info!("Timeline diff can't be applied live, reloading timeline");
+                self.load_timeline(timeline_data)?;
+            }
+            events.into_iter().for_each(|event| self.emit_timeline_event(event));
+            return Ok(());
+        }
+        
+        info!("Applying {} timeline changes to the live pipeline", changes.len());
+        for change in changes {
+            self.apply_change_to_pipeline(change)?;
+        }
+        
+        let max_clip_end = timeline_data.tracks.iter()
+            .flat_map(|t| &t.clips)
+            .map(|c| c.end_time_on_track_ms.max(0) as u64)
+            .max()
+            .unwrap_or(0);
+        self.set_duration_ms(max_clip_end);
+        
+        self.render_cache.update_timeline(&timeline_data, Arc::clone(&self.is_playing));
+        self.frame_cache.set_timeline(&timeline_data, &self.render_settings());
+        let pipeline_timeline = self.render_cache.substitute(&timeline_data);
+        // Clips moved to another track, or onto a track the diff added, draw at its layer
+        self.track_layers = clip_index::track_layers(&pipeline_timeline);
+        self.restack_clips();
+        // Without the cues burnt into rendered segments
+        *self.captions.lock().unwrap() = pipeline_timeline.captions.clone();
+        self.pipeline_timeline = Some(pipeline_timeline);
+        self.timeline_data = Some(timeline_data);
+        
+        self.resync_clips();
+        events.into_iter().for_each(|event| self.emit_timeline_event(event));
+        Ok(())
+    }
+
+    /// Tracks whose overlaps crossfade after `changes` with auto-transition on: the tracks
+    /// clips are added to, moved to and moved from
+    fn auto_transition_tracks(timeline_data: &TimelineData, changes: &[TimelineChange]) -> HashSet<i32> {
+        if !timeline_data.auto_transition {
+            return HashSet::new();
+        }
+        changes.iter()
+            .flat_map(|change| match change {
+                TimelineChange::AddClip { clip } => vec![clip.track_id],
+                TimelineChange::MoveClip { clip_id, track_id, .. } => {
+                    let from_track = timeline_data.tracks.iter()
+                        .find(|t| t.clips.iter().any(|c| c.id == Some(*clip_id)))
+                        .map(|t| t.id);
+                    std::iter::once(*track_id).chain(from_track).collect()
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Track range of the clip `change` edits, as it is in `timeline_data`
+    fn changed_range(timeline_data: &TimelineData, change: &TimelineChange) -> Option<(i32, i32)> {
+        let clip_id = match change {
+            TimelineChange::AddClip { clip } => return Some((clip.start_time_on_track_ms, clip.end_time_on_track_ms)),
+            TimelineChange::MoveClip { clip_id, .. }
+            | TimelineChange::ResizeClip { clip_id, .. }
+            | TimelineChange::RemoveClip { clip_id } => *clip_id,
+            TimelineChange::RippleCaptions { .. } => return None,
+        };
+        timeline_data.tracks.iter()
+            .flat_map(|t| &t.clips)
+            .find(|c| c.id == Some(clip_id))
+            .map(|c| (c.start_time_on_track_ms, c.end_time_on_track_ms))
+    }
+
+    /// Whether the live graph plays a rendered segment overlapping one of `ranges`
+    fn plays_rendered_range(&self, ranges: &[(i32, i32)]) -> bool {
+        let Some(pipeline_timeline) = self.pipeline_timeline.as_ref() else {
+            return false;
+        };
+        pipeline_timeline.tracks.iter()
+            .filter(|t| t.id == RENDER_TRACK_ID)
+            .flat_map(|t| &t.clips)
+            .any(|segment| ranges.iter().any(|&(start_ms, end_ms)| {
+                start_ms < segment.end_time_on_track_ms && end_ms > segment.start_time_on_track_ms
+            }))
+    }
+
+    /// Trims (resizes) and moves are applied in the edit mode. Rippling moves the later clips on
+    /// the trimmed clip's track by the change of its length; a start trim also puts the clip
+    /// back at its old start, so the later clips take up the change in start. A rippled move
+    /// carries the later clips with it on its track, or closes its gap there and opens one at
+    /// its new place on another track. Rolling moves the edge of the clip that touched the
+    /// trimmed edge. Clips the diff changes itself are left alone.
+    pub fn set_edit_mode(&mut self, mode: EditMode) {
+        self.edit_mode = mode;
+    }
+
+    pub fn get_edit_mode(&self) -> EditMode {
+        self.edit_mode
+    }
+
+    /// Crossfade the loaded timeline's clips where a clip is added or moved over another on its
+    /// track, for as long as they overlap. Saved with the timeline; existing overlaps keep
+    /// their cut or crossfade until they're edited.
+    pub fn set_auto_transition(&mut self, enabled: bool) -> Result<()> {
+        self.timeline_data.as_mut().ok_or_else(FlipEditError::not_loaded)?.auto_transition = enabled;
+        Ok(())
+    }
+
+    pub fn get_auto_transition(&self) -> bool {
+        self.timeline_data.as_ref().is_some_and(|timeline| timeline.auto_transition)
+    }
+
+    /// Changes the edit mode adds to `changes`, worked out against the timeline before them
+    fn edit_mode_changes(mode: EditMode, timeline_data: &TimelineData, changes: &[TimelineChange]) -> Vec<TimelineChange> {
+        if mode == EditMode::Normal {
+            return Vec::new();
+        }
+        let edited: HashSet<i32> = changes.iter()
+            .filter_map(|change| match change {
+                TimelineChange::MoveClip { clip_id, .. }
+                | TimelineChange::ResizeClip { clip_id, .. }
+                | TimelineChange::RemoveClip { clip_id } => Some(*clip_id),
+                TimelineChange::AddClip { .. } | TimelineChange::RippleCaptions { .. } => None,
+            })
+            .collect();
+        let clips: Vec<&TimelineClip> = timeline_data.tracks.iter().flat_map(|t| &t.clips).collect();
+
+        let untouched = |track_id: i32| {
+            let edited = &edited;
+            clips.iter().filter(move |c| c.track_id == track_id && c.id.is_some_and(|id| !edited.contains(&id)))
+        };
+        let shift_from = |track_id: i32, from_ms: i32, shift_ms: i32| -> Vec<TimelineChange> {
+            untouched(track_id)
+                .filter(|c| c.start_time_on_track_ms >= from_ms)
+                .map(|c| TimelineChange::MoveClip {
+                    clip_id: c.id.unwrap(),
+                    track_id: c.track_id,
+                    start_time_on_track_ms: c.start_time_on_track_ms + shift_ms,
+                })
+                .collect()
+        };
+
+        let mut extra = Vec::new();
+        for change in changes {
+            if let (EditMode::Ripple, TimelineChange::MoveClip { clip_id, track_id, start_time_on_track_ms: new_start }) = (mode, change) {
+                let Some(moved) = clips.iter().find(|c| c.id == Some(*clip_id)) else {
+                    continue;
+                };
+                let length_ms = moved.end_time_on_track_ms - moved.start_time_on_track_ms;
+                if *track_id == moved.track_id {
+                    if *new_start != moved.start_time_on_track_ms {
+                        extra.extend(shift_from(moved.track_id, moved.end_time_on_track_ms, new_start - moved.start_time_on_track_ms));
+                    }
+                } else {
+                    extra.extend(shift_from(moved.track_id, moved.end_time_on_track_ms, -length_ms));
+                    extra.extend(shift_from(*track_id, *new_start, length_ms));
+                }
+                continue;
+            }
+            let TimelineChange::ResizeClip { clip_id, start_time_on_track_ms: new_start, end_time_on_track_ms: new_end, .. } = change else {
+                continue;
+            };
+            let Some(trimmed) = clips.iter().find(|c| c.id == Some(*clip_id)) else {
+                continue;
+            };
+            let (old_start, old_end) = (trimmed.start_time_on_track_ms, trimmed.end_time_on_track_ms);
+            match mode {
+                EditMode::Ripple if *new_start != old_start || *new_end != old_end => {
+                    if *new_start != old_start {
+                        // The trimmed content is taken from the clip's head, not its place on the track
+                        extra.push(TimelineChange::MoveClip {
+                            clip_id: *clip_id,
+                            track_id: trimmed.track_id,
+                            start_time_on_track_ms: old_start,
+                        });
+                    }
+                    let shift_ms = (new_end - new_start) - (old_end - old_start);
+                    if shift_ms != 0 {
+                        extra.extend(shift_from(trimmed.track_id, old_end, shift_ms));
+                    }
+                }
+                EditMode::Roll => {
+                    for clip in untouched(trimmed.track_id) {
+                        let resized = if *new_end != old_end && clip.start_time_on_track_ms == old_end {
+                            // The next clip starts where the trimmed one now ends
+                            let shift = new_end - old_end;
+                            TimelineClip {
+                                start_time_on_track_ms: *new_end,
+                                start_time_in_source_ms: clip.start_time_in_source_ms + shift,
+                                ..(*clip).clone()
+                            }
+                        } else if *new_start != old_start && clip.end_time_on_track_ms == old_start {
+                            // The previous clip ends where the trimmed one now starts
+                            let shift = new_start - old_start;
+                            TimelineClip {
+                                end_time_on_track_ms: *new_start,
+                                end_time_in_source_ms: clip.end_time_in_source_ms + shift,
+                                ..(*clip).clone()
+                            }
+                        } else {
+                            continue;
+                        };
+                        if resized.start_time_in_source_ms < 0 || resized.end_time_on_track_ms <= resized.start_time_on_track_ms {
+                            warn!("Can't roll clip {:?} past its edge", clip.id);
+                            continue;
+                        }
+                        extra.push(TimelineChange::ResizeClip {
+                            clip_id: clip.id.unwrap(),
+                            start_time_on_track_ms: resized.start_time_on_track_ms,
+                            end_time_on_track_ms: resized.end_time_on_track_ms,
+                            start_time_in_source_ms: resized.start_time_in_source_ms,
+                            end_time_in_source_ms: resized.end_time_in_source_ms,
+                        });
+                    }
+                }
+                _ => {}
+            }
         }
-        
-        *self.current_position_ms.lock().unwrap() = position_ms;
-        
-        // If pipeline is not playing, pull preroll to show the seeked frame
-        let current_state = pipeline.current_state();
-        if current_state != gst::State::Playing {
-            // Ensure pipeline is in PAUSED state
-            if current_state != gst::State::Paused {
-                if let Err(e) = pipeline.set_state(gst::State::Paused) {
-                    warn!("Failed to set pipeline to PAUSED after seek: {}", e);
-                    return Ok(());
+        extra
+    }
+
+    /// Event reporting an applied change; `timeline_data` already has the change
+    fn timeline_event(timeline_data: &TimelineData, change: &TimelineChange) -> TimelineEvent {
+        match change {
+            TimelineChange::AddClip { clip } => TimelineEvent::ClipAdded { clip: clip.clone() },
+            TimelineChange::MoveClip { clip_id, track_id, start_time_on_track_ms } => {
+                let end_time_on_track_ms = timeline_data.tracks.iter()
+                    .flat_map(|t| &t.clips)
+                    .find(|c| c.id == Some(*clip_id))
+                    .map_or(*start_time_on_track_ms, |c| c.end_time_on_track_ms);
+                TimelineEvent::ClipMoved {
+                    clip_id: *clip_id,
+                    track_id: *track_id,
+                    start_time_on_track_ms: *start_time_on_track_ms,
+                    end_time_on_track_ms,
                 }
-                // Wait for state change to complete
-                let timeout = gst::ClockTime::from_seconds(1);
-                if let Err(e) = pipeline.state(Some(timeout)).0 {
-                    warn!("Failed to complete state change to PAUSED after seek: {}", e);
-                    return Ok(());
+            }
+            TimelineChange::ResizeClip { clip_id, start_time_on_track_ms, end_time_on_track_ms, start_time_in_source_ms, end_time_in_source_ms } => {
+                TimelineEvent::ClipResized {
+                    clip_id: *clip_id,
+                    start_time_on_track_ms: *start_time_on_track_ms,
+                    end_time_on_track_ms: *end_time_on_track_ms,
+                    start_time_in_source_ms: *start_time_in_source_ms,
+                    end_time_in_source_ms: *end_time_in_source_ms,
                 }
             }
-            
-            // Pull preroll sample to show the frame at the new position
-            if let Err(e) = self.pull_preroll_and_render() {
-                warn!("Failed to pull preroll sample after seek to {}ms: {}", position_ms, e);
-            } else {
-                info!("Successfully showed frame at position {}ms after seek", position_ms);
+            TimelineChange::RemoveClip { clip_id } => TimelineEvent::ClipRemoved { clip_id: *clip_id },
+            TimelineChange::RippleCaptions { .. } => TimelineEvent::CaptionsChanged,
+        }
+    }
+
+    fn timeline_clip(&self, clip_id: i32) -> Result<TimelineClip> {
+        self.timeline_data.as_ref()
+            .ok_or_else(FlipEditError::not_loaded)?
+            .tracks.iter()
+            .flat_map(|t| &t.clips)
+            .find(|c| c.id == Some(clip_id))
+            .cloned()
+            .ok_or_else(|| Self::clip_not_found(clip_id))
+    }
+
+    /// Change a clip's settings in the timeline and in its loaded source, if it has one.
+    /// Returns what `update` returned for the timeline's copy.
+    fn update_timeline_clip<T>(&mut self, clip_id: i32, update: impl Fn(&mut TimelineClip) -> T) -> Result<T> {
+        let clip = self.timeline_data.as_mut()
+            .ok_or_else(FlipEditError::not_loaded)?
+            .tracks.iter_mut()
+            .flat_map(|t| t.clips.iter_mut())
+            .find(|c| c.id == Some(clip_id))
+            .ok_or_else(|| Self::clip_not_found(clip_id))?;
+        let result = update(clip);
+        if let Some(clip_source) = self.find_clip_key(clip_id).and_then(|key| self.clip_sources.get_mut(&key)) {
+            update(&mut clip_source.clip_data);
+        }
+        Ok(result)
+    }
+
+    fn clip_not_found(clip_id: i32) -> anyhow::Error {
+        anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Clip {} not found in timeline", clip_id),
+        ))
+    }
+
+    /// Compositor z-order of a clip: its track's layer, then the order clips were added
+    fn clip_zorder(&self, track_id: i32, index: usize) -> u32 {
+        // Tracks added by a diff since the pipeline was built go on top
+        let layer = self.track_layers.get(&track_id)
+            .copied()
+            .unwrap_or_else(|| self.track_layers.values().max().map_or(0, |layer| layer + 1));
+        (layer << ZORDER_LAYER_SHIFT) | (index as u32 & ZORDER_INDEX_MASK)
+    }
+
+    /// Move a track up or down the compositing order; higher priorities draw on top
+    pub fn set_track_priority(&mut self, track_id: i32, priority: i32) -> Result<()> {
+        let timeline_data = self.timeline_data.as_mut().ok_or_else(FlipEditError::not_loaded)?;
+        Self::find_track_mut(timeline_data, track_id)?.priority = priority;
+        if self.solo_preview.is_some() {
+            // Picked up when leaving the solo preview
+            return Ok(());
+        }
+        if self.render_cache.is_enabled() {
+            // Rendered segments have the old order baked in
+            let timeline_data = timeline_data.clone();
+            return self.load_timeline(timeline_data);
+        }
+
+        if let Some(pipeline_timeline) = self.pipeline_timeline.as_mut() {
+            if let Some(track) = pipeline_timeline.tracks.iter_mut().find(|t| t.id == track_id) {
+                track.priority = priority;
             }
+            self.track_layers = clip_index::track_layers(pipeline_timeline);
         }
-        
+        self.restack_clips();
+        self.frame_cache.clear();
+        self.refresh_paused_frame();
         Ok(())
     }
 
-    pub fn get_current_position_seconds(&self) -> f64 {
-        if let Some(pipeline) = &self.pipeline {
-            if let Some(position) = pipeline.query_position::<gst::ClockTime>() {
-                let position_ns = position.nseconds();
-                return position_ns as f64 / 1_000_000_000.0;
+    /// Draw each clip that crossfades in above the clip it fades from
+    fn raise_crossfading_clips(&self) {
+        let pad_of = |clip_id: i32| {
+            self.find_clip_key(clip_id)
+                .and_then(|key| self.clip_sources.get(&key))
+                .and_then(|source| source.compositor_pad.clone())
+        };
+        for (&clip_id, fades) in &self.clip_fades {
+            let (Some(pad), Some(from_pad)) = (pad_of(clip_id), fades.from_clip_id.and_then(pad_of)) else {
+                continue;
+            };
+            let (zorder, from_zorder) = (pad.property::<u32>("zorder"), from_pad.property::<u32>("zorder"));
+            if zorder < from_zorder {
+                pad.set_property("zorder", from_zorder);
+                from_pad.set_property("zorder", zorder);
             }
         }
-        *self.current_position_ms.lock().unwrap() as f64 / 1000.0
     }
 
-    pub fn update_position(&self) {
-        if let Some(pipeline) = &self.pipeline {
-            if let Some(position) = pipeline.query_position::<gst::ClockTime>() {
-                let position_ns = position.nseconds();
-                let position_ms = (position_ns as f64 / 1_000_000.0) as u64;
-                *self.current_position_ms.lock().unwrap() = position_ms;
-            }
+    /// Give every compositor pad the z-order of its clip's current track
+    fn restack_clips(&self) {
+        for source in self.clip_sources.values() {
+            let Some(pad) = source.compositor_pad.as_ref() else {
+                continue;
+            };
+            let index = pad.property::<u32>("zorder") & ZORDER_INDEX_MASK;
+            pad.set_property("zorder", self.clip_zorder(source.clip_data.track_id, index as usize));
         }
     }
 
-    pub fn get_duration_ms(&self) -> Option<u64> {
-        *self.duration_ms.lock().unwrap()
+    /// Slip a clip: show its source from `in_point_ms` without moving or resizing it on the track
+    pub fn set_clip_in_point(&mut self, clip_id: i32, in_point_ms: i32) -> Result<()> {
+        if in_point_ms < 0 {
+            return Err(anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("In-point {}ms is before the start of the source", in_point_ms),
+            )));
+        }
+        let clip = self.timeline_clip(clip_id)?;
+        let length_ms = clip.end_time_in_source_ms - clip.start_time_in_source_ms;
+        self.apply_timeline_diff(vec![TimelineChange::ResizeClip {
+            clip_id,
+            start_time_on_track_ms: clip.start_time_on_track_ms,
+            end_time_on_track_ms: clip.end_time_on_track_ms,
+            start_time_in_source_ms: in_point_ms,
+            end_time_in_source_ms: in_point_ms + length_ms,
+        }])
     }
-    
-    pub fn get_current_position_ms(&self) -> u64 {
-        *self.current_position_ms.lock().unwrap()
+
+    /// Drag a clip's left edge to `start_time_on_track_ms`: the in-point moves by the same
+    /// amount and the out-point and end stay where they are
+    pub fn trim_clip_start(&mut self, clip_id: i32, start_time_on_track_ms: i32) -> Result<()> {
+        let clip = self.timeline_clip(clip_id)?;
+        let in_point_ms = clip.start_time_in_source_ms + (start_time_on_track_ms - clip.start_time_on_track_ms);
+        if in_point_ms < 0 || start_time_on_track_ms < 0 || start_time_on_track_ms >= clip.end_time_on_track_ms {
+            return Err(anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Clip {} can't start at {}ms", clip_id, start_time_on_track_ms),
+            )));
+        }
+        self.apply_timeline_diff(vec![TimelineChange::ResizeClip {
+            clip_id,
+            start_time_on_track_ms,
+            end_time_on_track_ms: clip.end_time_on_track_ms,
+            start_time_in_source_ms: in_point_ms,
+            end_time_in_source_ms: clip.end_time_in_source_ms,
+        }])
     }
 
-    pub fn is_playing(&self) -> bool {
-        *self.is_playing.lock().unwrap()
+    /// Add many clips in one diff, e.g. a folder dropped onto the timeline, returning them
+    /// with their ids
+    pub fn add_clips(&mut self, mut clips: Vec<TimelineClip>) -> Result<Vec<TimelineClip>> {
+        for clip in &mut clips {
+            if clip.end_time_on_track_ms <= clip.start_time_on_track_ms {
+                return Err(anyhow!(FlipEditError::new(
+                    FlipEditErrorCode::InvalidArgument,
+                    format!("Clip of {} ends before it starts", clip.source_path),
+                )));
+            }
+            match clip.id {
+                Some(id) => self.next_clip_id = self.next_clip_id.max(id + 1),
+                None => clip.id = Some(self.allocate_clip_id()),
+            }
+        }
+        let changes = clips.iter().map(|clip| TimelineChange::AddClip { clip: clip.clone() }).collect();
+        self.apply_timeline_diff(changes)?;
+        Ok(clips)
     }
 
-    pub fn set_position_update_callback(&mut self, callback: PositionUpdateCallback) -> Result<()> {
-        let mut guard = self.position_callback.lock().unwrap();
-        *guard = Some(callback);
+    fn apply_change_to_timeline_data(timeline_data: &mut TimelineData, change: &TimelineChange) -> Result<()> {
+        let clip_not_found = |clip_id: i32| -> anyhow::Error {
+            FlipEditError::new(FlipEditErrorCode::InvalidArgument, format!("Clip {} not found in timeline", clip_id)).into()
+        };
+        
+        match change {
+            TimelineChange::AddClip { clip } => {
+                Self::track_mut(timeline_data, clip.track_id).clips.push(clip.clone());
+            }
+            TimelineChange::MoveClip { clip_id, track_id, start_time_on_track_ms } => {
+                let mut clip = Self::take_clip(timeline_data, *clip_id).ok_or_else(|| clip_not_found(*clip_id))?;
+                let length_ms = clip.end_time_on_track_ms - clip.start_time_on_track_ms;
+                clip.track_id = *track_id;
+                clip.start_time_on_track_ms = *start_time_on_track_ms;
+                clip.end_time_on_track_ms = start_time_on_track_ms + length_ms;
+                Self::track_mut(timeline_data, *track_id).clips.push(clip);
+            }
+            TimelineChange::ResizeClip { clip_id, start_time_on_track_ms, end_time_on_track_ms, start_time_in_source_ms, end_time_in_source_ms } => {
+                let clip = timeline_data.tracks.iter_mut()
+                    .flat_map(|t| t.clips.iter_mut())
+                    .find(|c| c.id == Some(*clip_id))
+                    .ok_or_else(|| clip_not_found(*clip_id))?;
+                clip.start_time_on_track_ms = *start_time_on_track_ms;
+                clip.end_time_on_track_ms = *end_time_on_track_ms;
+                clip.start_time_in_source_ms = *start_time_in_source_ms;
+                clip.end_time_in_source_ms = *end_time_in_source_ms;
+            }
+            TimelineChange::RemoveClip { clip_id } => {
+                Self::take_clip(timeline_data, *clip_id).ok_or_else(|| clip_not_found(*clip_id))?;
+            }
+            TimelineChange::RippleCaptions { at_ms, delta_ms } => {
+                captions::ripple(&mut timeline_data.captions, *at_ms, *delta_ms);
+            }
+        }
         Ok(())
     }
-    
-    pub fn set_seek_completion_callback(&mut self, callback: SeekCompletionCallback) -> Result<()> {
-        let mut guard = self.seek_completion_callback.lock().unwrap();
-        *guard = Some(callback);
-        Ok(())
+
+    fn track_mut(timeline_data: &mut TimelineData, track_id: i32) -> &mut TimelineTrack {
+        if let Some(position) = timeline_data.tracks.iter().position(|t| t.id == track_id) {
+            return &mut timeline_data.tracks[position];
+        }
+        timeline_data.tracks.push(TimelineTrack {
+            id: track_id,
+            name: format!("Track {}", track_id),
+            clips: Vec::new(),
+            audio_effects: Vec::new(),
+            volume_keyframes: Vec::new(),
+            track_type: TrackType::Both,
+            priority: 0,
+        });
+        timeline_data.tracks.last_mut().unwrap()
     }
-    
-    /// Update a specific clip's transform properties without reloading the entire timeline
-    pub fn update_clip_transform(
-        &mut self,
-        clip_id: i32,
-        preview_position_x: f64,
-        preview_position_y: f64,
-        preview_width: f64,
-        preview_height: f64,
-    ) -> Result<()> {
-        info!("Updating clip transform for clip_id {} to pos=({}, {}), size=({}, {})",
-              clip_id, preview_position_x, preview_position_y, preview_width, preview_height);
-        
-        // Find the clip source by matching the clip ID
-        let mut found_clip = None;
-        for (key, clip_source) in self.clip_sources.iter_mut() {
-            if clip_source.clip_data.id == Some(clip_id) {
-                found_clip = Some(key.clone());
-                break;
+
+    fn take_clip(timeline_data: &mut TimelineData, clip_id: i32) -> Option<TimelineClip> {
+        for track in timeline_data.tracks.iter_mut() {
+            if let Some(position) = track.clips.iter().position(|c| c.id == Some(clip_id)) {
+                return Some(track.clips.remove(position));
             }
         }
-        
-        let clip_key = found_clip.ok_or_else(|| anyhow!("Clip with ID {} not found", clip_id))?;
-        
-        // Get the clip source
-        let clip_source = self.clip_sources.get_mut(&clip_key)
-            .ok_or_else(|| anyhow!("Clip source not found for key {}", clip_key))?;
-        
-        // Update the clip data
-        clip_source.clip_data.preview_position_x = preview_position_x;
-        clip_source.clip_data.preview_position_y = preview_position_y;
-        clip_source.clip_data.preview_width = preview_width;
-        clip_source.clip_data.preview_height = preview_height;
-        
-        // Update the compositor pad properties
-        if let Some(ref compositor_pad) = clip_source.compositor_pad {
-            compositor_pad.set_property("xpos", preview_position_x as i32);
-            compositor_pad.set_property("ypos", preview_position_y as i32);
-            compositor_pad.set_property("width", preview_width as i32);
-            compositor_pad.set_property("height", preview_height as i32);
-            
-            info!("Updated compositor pad properties for clip {}", clip_id);
-        }
-        
-        // Update the caps filter to match the new dimensions
-        let caps = gst::Caps::builder("video/x-raw")
-            .field("width", preview_width as i32)
-            .field("height", preview_height as i32)
-            .field("pixel-aspect-ratio", gst::Fraction::new(1, 1))
-            .build();
-        clip_source.caps_filter.set_property("caps", &caps);
-        
-        // Trigger a frame update by forcing a seek and pulling preroll sample
-        // This forces the pipeline to re-render with the new transform properties
-        if let Some(pipeline) = &self.pipeline {
-            let current_position = *self.current_position_ms.lock().unwrap();
-            let current_state = pipeline.current_state();
-            
-            if current_state == gst::State::Playing {
-                // Already playing, the frame will update naturally
-                info!("Pipeline is playing, transform will be visible in next frame");
-            } else {
-                // Pipeline is paused/stopped, need to manually trigger frame update
-                info!("Pipeline is paused, manually triggering frame update for transform");
-                
-                // Ensure pipeline is in PAUSED state
-                if current_state != gst::State::Paused {
-                    if let Err(e) = pipeline.set_state(gst::State::Paused) {
-                        warn!("Failed to set pipeline to PAUSED: {}", e);
-                        return Ok(());
-                    }
-                    // Wait for state change to complete
-                    let timeout = gst::ClockTime::from_seconds(1);
-                    if let Err(e) = pipeline.state(Some(timeout)).0 {
-                        warn!("Failed to complete state change to PAUSED: {}", e);
-                        return Ok(());
-                    }
+        None
+    }
+
+    fn find_clip_key(&self, clip_id: i32) -> Option<String> {
+        self.clip_keys.get(&clip_id).cloned()
+    }
+
+    fn apply_change_to_pipeline(&mut self, change: TimelineChange) -> Result<()> {
+        match change {
+            TimelineChange::AddClip { clip } => {
+                let (Some(pipeline), Some(compositor), Some(audiomixer)) =
+                    (self.pipeline.clone(), self.compositor.clone(), self.audiomixer.clone()) else {
+                    return Err(FlipEditError::not_loaded().into());
+                };
+                if FlipEditError::check_file_exists(&clip.source_path).is_err() {
+                    warn!("Video file does not exist, skipping: {}", clip.source_path);
+                    return Ok(());
                 }
                 
-                // Force seek to current position to trigger frame render with new transform
-                let seek_result = pipeline.seek_simple(
-                    gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
-                    gst::ClockTime::from_mseconds(current_position),
-                );
+                let index = self.next_clip_index;
+                self.next_clip_index += 1;
+                self.add_clip_source(&pipeline, &compositor, &audiomixer, &clip, index)?;
                 
-                if seek_result.is_ok() {
-                    info!("Forced seek to {}ms to update frame with new transform", current_position);
-                    
-                    // Now pull the preroll sample to force the frame render
-                    if let Err(e) = self.pull_preroll_and_render() {
-                        warn!("Failed to pull preroll sample after transform update: {}", e);
+                // Bring the new branch up to the running pipeline's state
+                if let Some(source) = self.clip_sources.get(&format!("clip_{}", index)) {
+                    for element in [&source.caps_filter, &source.videoscale, &source.videoconvert, &source.uridecodebin] {
+                        element.sync_state_with_parent()?;
                     }
-                } else {
-                    warn!("Failed to seek for frame update after transform change");
                 }
-                
-                // Note: We DON'T restore to playing state here because the pipeline
-                // was already paused when we started, so it should stay paused
             }
+            TimelineChange::MoveClip { clip_id, track_id, start_time_on_track_ms } => {
+                if let Some(source) = self.find_clip_key(clip_id).and_then(|key| self.clip_sources.get_mut(&key)) {
+                    let length_ms = source.clip_data.end_time_on_track_ms - source.clip_data.start_time_on_track_ms;
+                    source.clip_data.track_id = track_id;
+                    source.clip_data.start_time_on_track_ms = start_time_on_track_ms;
+                    source.clip_data.end_time_on_track_ms = start_time_on_track_ms + length_ms;
+                    *source.timing.lock().unwrap() = ClipTiming::from_clip(&source.clip_data);
+                }
+            }
+            TimelineChange::ResizeClip { clip_id, start_time_on_track_ms, end_time_on_track_ms, start_time_in_source_ms, end_time_in_source_ms } => {
+                if let Some(source) = self.find_clip_key(clip_id).and_then(|key| self.clip_sources.get_mut(&key)) {
+                    source.clip_data.start_time_on_track_ms = start_time_on_track_ms;
+                    source.clip_data.end_time_on_track_ms = end_time_on_track_ms;
+                    source.clip_data.start_time_in_source_ms = start_time_in_source_ms;
+                    source.clip_data.end_time_in_source_ms = end_time_in_source_ms;
+                    *source.timing.lock().unwrap() = ClipTiming::from_clip(&source.clip_data);
+                }
+            }
+            TimelineChange::RemoveClip { clip_id } => {
+                if let Some(key) = self.find_clip_key(clip_id) {
+                    self.remove_clip_source(&key)?;
+                }
+            }
+            // The overlay picks the shifted cues up with the rest of the timeline
+            TimelineChange::RippleCaptions { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// Replace a clip's branch with a new one built from the current settings
+    fn rebuild_clip_source(&mut self, key: &str) -> Result<()> {
+        let Some(clip) = self.clip_sources.get(key).map(|source| source.clip_data.clone()) else {
+            return Ok(());
+        };
+        self.remove_clip_source(key)?;
+        self.apply_change_to_pipeline(TimelineChange::AddClip { clip })
+    }
+
+    fn remove_clip_source(&mut self, key: &str) -> Result<()> {
+        let Some(source) = self.clip_sources.remove(key) else {
+            return Ok(());
+        };
+        if let Some(id) = source.clip_data.id {
+            self.clip_keys.remove(&id);
+        }
+        let Some(pipeline) = self.pipeline.as_ref() else {
+            return Ok(());
+        };
+        
+        // Shut the branch down from the source so no buffers race into released pads
+        let audio_branch = source.audio_branch.lock().unwrap().take();
+        let mut elements = vec![source.uridecodebin.clone(), source.videoconvert.clone(), source.videoscale.clone(), source.caps_filter.clone()];
+        if let Some(ref branch) = audio_branch {
+            elements.push(branch.audioconvert.clone());
+            elements.extend(branch.effect_chain.clone());
+            elements.extend(branch.volume_envelope.clone());
+            elements.push(branch.audioresample.clone());
+        }
+        for element in &elements {
+            element.set_state(gst::State::Null)?;
+        }
+        pipeline.remove_many(&elements)?;
+        
+        // Audio-only clips already gave their pad back
+        if let (Some(compositor), Some(pad)) = (self.compositor.as_ref(), source.compositor_pad.as_ref()) {
+            if pad.parent_element().as_ref() == Some(compositor) {
+                compositor.release_request_pad(pad);
+            }
+        }
+        if let (Some(audiomixer), Some(branch)) = (self.audiomixer.as_ref(), audio_branch.as_ref()) {
+            audiomixer.release_request_pad(&branch.audiomixer_pad);
         }
         
-        info!("Successfully updated clip {} transform properties", clip_id);
+        info!("Removed clip source {} from live pipeline", key);
         Ok(())
     }
-    
+
+    /// Flushing seek to the current position so every clip re-reads its timing
+    fn resync_clips(&self) {
+        let Some(pipeline) = self.pipeline.as_ref() else {
+            return;
+        };
+        if pipeline.current_state() < gst::State::Paused {
+            // play() prerolls and positions the clips
+            return;
+        }
+        
+        self.update_position();
+        let position_ms = self.get_current_position_ms();
+        *self.deferred_seek_ms.lock().unwrap() = None;
+        if let Err(e) = pipeline.seek_simple(
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::ClockTime::from_mseconds(position_ms),
+        ) {
+            warn!("Failed to resync clips after timeline edit: {}", e);
+        }
+    }
+
     /// Pull preroll sample from appsink when pipeline is paused and update texture
     /// This is the correct way to get a frame when the pipeline is in PAUSED state
     fn pull_preroll_and_render(&self) -> Result<()> {
@@ -815,10 +4158,17 @@ impl DirectPipelinePlayer {
             
             // Pull the preroll sample from the appsink (for paused pipelines)
             match appsink.try_pull_preroll(gst::ClockTime::from_seconds(1)) {
+                #[cfg(target_os = "linux")]
+                Some(sample) if self.gl_output.is_some() => {
+                    if let Some(gl_output) = self.gl_output.as_ref() {
+                        gl_output.present(sample);
+                    }
+                    return Ok(());
+                }
                 Some(sample) => {
-                    if let Some(texture_id) = self.texture_id {
+                    if let (Some(texture_id), Some(update_fn)) = (self.texture_id, self.texture_update_fn.as_ref()) {
                         // Process the sample and update texture using the same method as normal playback
-                        match Self::handle_video_sample_from_buffer(&sample, texture_id) {
+                        match Self::handle_video_sample_from_buffer(&sample, texture_id, update_fn, &self.buffer_pool) {
                             Ok(_) => {
                                 info!("Successfully pulled preroll sample and updated texture {}", texture_id);
                                 return Ok(());
@@ -844,6 +4194,8 @@ impl DirectPipelinePlayer {
     fn handle_video_sample_from_buffer(
         sample: &gst::Sample,
         texture_id: i64,
+        update_fn: &TextureUpdateFn,
+        buffer_pool: &Arc<Mutex<FrameBufferPool>>,
     ) -> Result<()> {
         let buffer = sample.buffer().ok_or_else(|| anyhow!("No buffer in sample"))?;
         let map = buffer.map_readable().map_err(|_| anyhow!("Failed to map buffer"))?;
@@ -854,28 +4206,165 @@ impl DirectPipelinePlayer {
         let height = s.get::<i32>("height").unwrap_or(1080) as u32;
 
         let frame_data = FrameData {
-            data: map.as_slice().to_vec(),
+            data: buffer_pool.lock().unwrap().buffer_from_slice(map.as_slice()),
             width,
             height,
             texture_id: Some(texture_id as u64),
         };
 
-        // Update the texture with the new frame data
-        if crate::api::simple::update_video_frame(frame_data) {
-            info!("Successfully updated texture {} with preroll frame", texture_id);
-            Ok(())
-        } else {
-            Err(anyhow!("Failed to update texture with preroll frame data"))
+        update_fn(frame_data);
+        debug!("Updated texture {} with preroll frame", texture_id);
+        Ok(())
+    }
+
+    /// Last frame shown by the preview as PNG bytes, also written to `output_path` when given.
+    /// Reuses the composited frame, so it's much cheaper than a still-frame export.
+    pub fn capture_preview_frame(&self, output_path: Option<&str>) -> Result<Vec<u8>> {
+        let pipeline = self.pipeline.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        if self.deferred_seek_ms.lock().unwrap().is_some() {
+            // The sink's last sample predates the cached frame on screen
+            self.apply_deferred_seek(pipeline);
+            let _ = pipeline.state(Some(gst::ClockTime::from_seconds(1)));
         }
+        let video_sink = pipeline.by_name("texture_video_sink0")
+            .ok_or_else(|| anyhow!("Preview sink not found"))?;
+        let sample = video_sink.property::<Option<gst::Sample>>("last-sample")
+            .ok_or_else(|| anyhow!(FlipEditError::new(FlipEditErrorCode::NotLoaded, "No preview frame has been rendered yet")))?;
+        let png = Self::encode_png(&sample)?;
+        
+        if let Some(path) = output_path {
+            std::fs::write(path, &png).map_err(|e| anyhow!(FlipEditError::new(
+                FlipEditErrorCode::ResourceError,
+                format!("Failed to write {}: {}", path, e),
+            )))?;
+            info!("Saved preview frame to {}", path);
+        }
+        Ok(png)
+    }
+
+    /// Create the small texture `hover_preview` draws into, replacing any earlier one
+    pub fn create_hover_preview_texture(&mut self, engine_handle: i64, width: u32, height: u32) -> Result<i64> {
+        if width == 0 || height == 0 {
+            return Err(anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                format!("Invalid hover preview size {}x{}", width, height),
+            )));
+        }
+        self.hover_preview = None;
+        let hover_preview = HoverPreview::new(engine_handle, width, height)?;
+        let texture_id = hover_preview.texture_id();
+        self.hover_preview = Some(hover_preview);
+        Ok(texture_id)
+    }
+
+    /// Show the top visible clip's frame at timeline `position_ms` in the hover preview
+    /// texture, or blank it over a gap. Returns at once; the frame arrives shortly after.
+    pub fn hover_preview(&self, position_ms: i32) -> Result<()> {
+        let hover_preview = self.hover_preview.as_ref().ok_or_else(|| anyhow!(FlipEditError::new(
+            FlipEditErrorCode::NotLoaded,
+            "No hover preview texture; call create_hover_preview_texture first",
+        )))?;
+        let timeline_data = self.timeline_data.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        let position_ms = position_ms.max(0);
+        let layers = clip_index::track_layers(timeline_data);
+        let top_clip = timeline_data.tracks.iter()
+            .flat_map(|track| track.clips.iter().map(move |clip| (track, clip)))
+            .filter(|(track, clip)| {
+                clip.start_time_on_track_ms <= position_ms
+                    && position_ms < clip.end_time_on_track_ms
+                    && clip.track_type.on_track(track.track_type).is_some_and(TrackType::has_video)
+            })
+            .max_by_key(|(track, _)| layers.get(&track.id).copied())
+            .map(|(_, clip)| clip);
+
+        match top_clip {
+            Some(clip) => {
+                let (source_ms, _) = ClipTiming::from_clip(clip).map_timeline_position(position_ms as u64);
+                hover_preview.show(&clip.source_path, source_ms);
+            }
+            None => hover_preview.clear(),
+        }
+        Ok(())
+    }
+
+    pub fn dispose_hover_preview(&mut self) {
+        self.hover_preview = None;
+    }
+
+    /// Rolling decode, convert and upload timings since the timeline was loaded
+    pub fn get_performance_stats(&self) -> PerformanceStats {
+        self.perf_counters.stats()
+    }
+
+    /// Write the pipeline's topology, with caps and element states, as a Graphviz DOT file.
+    /// Same output as `GST_DEBUG_DUMP_DOT_DIR`, but written to `path` on demand.
+    pub fn dump_pipeline_graph(&self, path: &str) -> Result<()> {
+        let pipeline = self.pipeline.as_ref().ok_or_else(FlipEditError::not_loaded)?;
+        let dot = gst::debug_bin_to_dot_data(pipeline, gst::DebugGraphDetails::all());
+        std::fs::write(path, dot.as_bytes()).map_err(|e| anyhow!(FlipEditError::new(
+            FlipEditErrorCode::ResourceError,
+            format!("Failed to write {}: {}", path, e),
+        )))?;
+        info!("Wrote pipeline graph to {}", path);
+        Ok(())
+    }
+    
+    fn encode_png(sample: &gst::Sample) -> Result<Vec<u8>> {
+        let caps = sample.caps().ok_or_else(|| anyhow!("No caps in sample"))?;
+        let buffer = sample.buffer().ok_or_else(|| anyhow!("No buffer in sample"))?;
+        let info = gst_video::VideoInfo::from_caps(caps)?;
+        
+        // Reading maps GL frames into system memory; packing the rows drops any stride padding
+        let frame = gst_video::VideoFrameRef::from_buffer_ref_readable(buffer, &info)
+            .map_err(|_| anyhow!("Failed to map preview frame"))?;
+        let row_bytes = info.width() as usize * 4;
+        let stride = frame.plane_stride()[0] as usize;
+        let packed: Vec<u8> = frame.plane_data(0)?
+            .chunks(stride)
+            .take(info.height() as usize)
+            .flat_map(|row| &row[..row_bytes])
+            .copied()
+            .collect();
+        
+        let raw_caps = gst_video::VideoInfo::builder(gst_video::VideoFormat::Rgba, info.width(), info.height())
+            .build()?
+            .to_caps()?;
+        let raw_sample = gst::Sample::builder()
+            .buffer(&gst::Buffer::from_mut_slice(packed))
+            .caps(&raw_caps)
+            .build();
+        let png = gst_video::convert_sample(&raw_sample, &gst::Caps::new_empty_simple("image/png"), gst::ClockTime::from_seconds(5))?;
+        let png_buffer = png.buffer().ok_or_else(|| anyhow!("PNG encoder produced no data"))?;
+        let map = png_buffer.map_readable()?;
+        Ok(map.as_slice().to_vec())
     }
 
     pub fn dispose(&mut self) -> Result<()> {
-        if let Some(texture_id) = self.texture_id {
-            crate::video::irondash_texture::unregister_irondash_update_function(texture_id);
-            info!("Unregistered texture {}", texture_id);
+        self.render_cache.disable();
+        self.live_stream = None;
+        self.hover_preview = None;
+        if let Some(session) = self.voiceover.take() {
+            if let Err(e) = session.recorder.stop() {
+                warn!("Failed to finish voice-over recording: {}", e);
+            }
+        }
+        let result = self.stop_pipeline();
+        
+        // The pipeline held the last other clones, so this releases the texture
+        #[cfg(target_os = "linux")]
+        let had_gl_texture = self.gl_output.take().is_some();
+        #[cfg(not(target_os = "linux"))]
+        let had_gl_texture = false;
+        if self.texture_update_fn.take().is_some() || had_gl_texture {
+            info!("Released texture {:?}", self.texture_id);
+            resources::release(self.resource_owner, ResourceKind::Texture);
         }
         
-        self.stop_pipeline()
+        let leaked = resources::verify_released(self.resource_owner);
+        if !leaked.is_empty() {
+            warn!("Player disposed with {} unreleased resources", leaked.len());
+        }
+        result
     }
 }
 
@@ -883,4 +4372,100 @@ impl Default for DirectPipelinePlayer {
     fn default() -> Self {
         Self::new().expect("Failed to create default DirectPipelinePlayer")
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc;
+    use crate::utils::testing::fixtures::{self, MediaSpec};
+    use crate::utils::testing::golden::{self, RgbaFrame};
+    use crate::video::gst_worker::PlayerHandle;
+
+    fn timeline(clips: Vec<TimelineClip>) -> TimelineData {
+        TimelineData {
+            tracks: vec![TimelineTrack {
+                id: 1,
+                name: "Video 1".to_string(),
+                clips,
+                audio_effects: Vec::new(),
+                volume_keyframes: Vec::new(),
+                track_type: TrackType::default(),
+                priority: 0,
+            }],
+            captions: Vec::new(),
+            markers: Vec::new(),
+            auto_transition: false,
+        }
+    }
+
+    /// Load as the app does, failing the test unless the load completes
+    fn load(player: &PlayerHandle, timeline_data: TimelineData) {
+        let (sender, events) = mpsc::channel();
+        player.run(move |player| {
+            player.load_timeline_job(1, timeline_data, Box::new(move |event| {
+                let _ = sender.send(event);
+            }));
+        }).unwrap();
+        let last = events.try_iter().last();
+        assert!(matches!(last, Some(TimelineLoadEvent::Completed { .. })), "timeline didn't load: {:?}", last);
+    }
+
+    fn golden_path(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/golden").join(name)
+    }
+
+    #[test]
+    fn ripple_trim_moves_the_clips_after_it() {
+        let path = fixtures::fixture(&MediaSpec { tone_hz: None, ..MediaSpec::default() }).unwrap();
+        let player = PlayerHandle::spawn().unwrap();
+        load(&player, timeline(vec![
+            fixtures::clip(1, 1, &path, 0, 0, 1000),
+            fixtures::clip(2, 1, &path, 1000, 0, 1000),
+        ]));
+
+        player.call(|player| {
+            player.set_edit_mode(EditMode::Ripple);
+            player.apply_timeline_diff(vec![TimelineChange::ResizeClip {
+                clip_id: 1,
+                start_time_on_track_ms: 0,
+                end_time_on_track_ms: 600,
+                start_time_in_source_ms: 0,
+                end_time_in_source_ms: 600,
+            }])
+        }).unwrap();
+
+        let timeline_data = player.run(|player| player.get_timeline_data()).unwrap().unwrap();
+        let mut spans: Vec<_> = timeline_data.tracks[0].clips.iter()
+            .map(|c| (c.id, c.start_time_on_track_ms, c.end_time_on_track_ms, c.start_time_in_source_ms))
+            .collect();
+        spans.sort();
+        assert_eq!(spans, vec![(Some(1), 0, 600, 0), (Some(2), 600, 1600, 0)]);
+        assert_eq!(player.run(|player| player.get_duration_ms()).unwrap(), Some(1600));
+    }
+
+    #[test]
+    fn accurate_seek_shows_the_frame_at_the_clip_position() {
+        let path = fixtures::fixture(&MediaSpec { tone_hz: None, ..MediaSpec::default() }).unwrap();
+        let player = PlayerHandle::spawn().unwrap();
+        // 1000ms on the timeline is 750ms into the source
+        load(&player, timeline(vec![fixtures::clip(1, 1, &path, 500, 250, 1750)]));
+
+        let png = player.call(|player| {
+            player.seek_with_mode(1000, ScrubMode::Accurate)?;
+            player.capture_preview_frame(None)
+        }).unwrap();
+        let frame = RgbaFrame::from_png(png).unwrap();
+
+        // The bars scroll every frame, so the frame is closest to a decode of the right one
+        let (width, height) = (DEFAULT_OUTPUT_WIDTH, DEFAULT_OUTPUT_HEIGHT);
+        let expected = golden::frame_at(&path, 750, width, height).unwrap();
+        let later = golden::frame_at(&path, 950, width, height).unwrap();
+        let diff = golden::compare(&frame, &expected).unwrap();
+        assert!(
+            diff.mean_delta < golden::compare(&frame, &later).unwrap().mean_delta,
+            "frame at 1000ms isn't the source frame at 750ms: {:?}", diff,
+        );
+        golden::check_golden(&frame, &golden_path("accurate_seek_750ms.png"), 1.0).unwrap();
+    }
+}
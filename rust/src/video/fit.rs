@@ -0,0 +1,68 @@
+use crate::common::types::{ClipFitMode, PixelAspectRatio, TimelineClip};
+
+/// Where a clip goes in the mixer, in output pixels, and how much of its source is cropped
+/// away first, in source pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub crop_left: i32,
+    pub crop_right: i32,
+    pub crop_top: i32,
+    pub crop_bottom: i32,
+}
+
+/// Place a clip's source (stored size and pixel aspect ratio) in its preview box. Without a
+/// crop element (`can_crop` false) anything that would overflow the box is shrunk to fit
+/// instead, so `Fill` and oversized `Original` clips are letterboxed.
+pub fn place(
+    mode: ClipFitMode,
+    clip: &TimelineClip,
+    source: Option<((i32, i32), PixelAspectRatio)>,
+    output_par: PixelAspectRatio,
+    can_crop: bool,
+) -> Placement {
+    let (box_width, box_height) = (clip.preview_width, clip.preview_height);
+    let stretched = Placement {
+        x: clip.preview_position_x as i32,
+        y: clip.preview_position_y as i32,
+        width: box_width as i32,
+        height: box_height as i32,
+        crop_left: 0,
+        crop_right: 0,
+        crop_top: 0,
+        crop_bottom: 0,
+    };
+    let Some(((source_width, source_height), source_par)) = source else {
+        return stretched;
+    };
+    if mode == ClipFitMode::Stretch || source_width <= 0 || source_height <= 0 || box_width < 1.0 || box_height < 1.0 {
+        return stretched;
+    }
+
+    // The source at 100% in output pixels, which differ in shape from its own when the PARs do
+    let native_width = source_par.display_width(source_width) as f64 * output_par.denominator.max(1) as f64
+        / output_par.numerator.max(1) as f64;
+    let native_height = source_height as f64;
+    let fit_scale = (box_width / native_width).min(box_height / native_height);
+    let scale = match mode {
+        ClipFitMode::Fit | ClipFitMode::Stretch => fit_scale,
+        ClipFitMode::Fill => (box_width / native_width).max(box_height / native_height),
+        ClipFitMode::Original => 1.0,
+    };
+    let scale = if can_crop { scale } else { scale.min(fit_scale) };
+
+    // Centre each axis in the box, cropping what overflows it evenly from both sides
+    let axis = |box_start: f64, box_size: f64, native_size: f64, source_size: i32| {
+        let shown = native_size * scale;
+        let overflow = (shown - box_size).max(0.0);
+        let crop = (overflow / shown * source_size as f64).round() as i32;
+        let size = shown.min(box_size);
+        ((box_start + (box_size - size) / 2.0) as i32, size.round().max(1.0) as i32, crop / 2, crop - crop / 2)
+    };
+    let (x, width, crop_left, crop_right) = axis(clip.preview_position_x, box_width, native_width, source_width);
+    let (y, height, crop_top, crop_bottom) = axis(clip.preview_position_y, box_height, native_height, source_height);
+    Placement { x, y, width, height, crop_left, crop_right, crop_top, crop_bottom }
+}
@@ -0,0 +1,266 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use gstreamer as gst;
+
+use crate::captions;
+use crate::common::memory::{self, MemoryConsumer};
+use crate::common::types::{ColorSpace, ConversionSettings, MemoryCacheKind, PixelAspectRatio, RenderPath, TimelineClip, TimelineData};
+use crate::video::clip_index::{self, ClipIndex};
+
+/// A composited preview frame, RGBA
+pub struct CachedFrame {
+    pub data: Arc<Vec<u8>>,
+    pub width: u32,
+    pub height: u32,
+}
+
+struct Entry {
+    frame: CachedFrame,
+    end_ms: u64,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    // Start of each span between clip boundaries and a hash of what's visible in it, by start
+    spans: Vec<(u64, u64)>,
+    // Frames by (span hash, presentation time in ms)
+    entries: BTreeMap<(u64, u64), Entry>,
+    bytes: usize,
+    use_counter: u64,
+}
+
+/// Player settings every preview frame depends on, besides the timeline itself
+#[derive(Hash)]
+pub struct RenderSettings {
+    pub output_size: (i32, i32),
+    pub output_par: PixelAspectRatio,
+    pub render_path: RenderPath,
+    pub color_space: ColorSpace,
+    pub hdr_tone_mapping: bool,
+    pub conversion: ConversionSettings,
+}
+
+struct Store {
+    state: Mutex<CacheState>,
+    last_used: Mutex<Instant>,
+}
+
+/// LRU cache of composited preview frames, so scrubbing back over a range shows frames
+/// already rendered instead of seeking and decoding again. Frames are keyed by a hash of the
+/// clips visible where they were shown, so an edit only invalidates frames it can change.
+#[derive(Clone)]
+pub struct FrameCache {
+    store: Arc<Store>,
+}
+
+impl FrameCache {
+    pub fn new() -> Self {
+        let store = Arc::new(Store {
+            state: Mutex::new(CacheState::default()),
+            last_used: Mutex::new(Instant::now()),
+        });
+        memory::register(MemoryCacheKind::FrameCache, &store);
+        Self { store }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        *self.store.last_used.lock().unwrap() = Instant::now();
+        self.store.state.lock().unwrap()
+    }
+
+    /// Recompute span hashes for the timeline (clips and captions) being shown and the settings
+    /// it's rendered with, and drop frames of spans that changed
+    pub fn set_timeline(&self, timeline: &TimelineData, settings: &RenderSettings) {
+        let index = ClipIndex::new(timeline);
+        let layers = clip_index::track_layers(timeline);
+        let mut boundaries: Vec<u64> = index.clips().iter()
+            .flat_map(|c| [c.start_time_on_track_ms.max(0) as u64, c.end_time_on_track_ms.max(0) as u64])
+            .chain(timeline.captions.iter().flat_map(|cue| [cue.start_ms.max(0) as u64, cue.end_ms.max(0) as u64]))
+            .chain([0])
+            .collect();
+        boundaries.sort();
+        boundaries.dedup();
+
+        let spans: Vec<(u64, u64)> = boundaries.iter()
+            .map(|&start| {
+                let mut hasher = DefaultHasher::new();
+                settings.hash(&mut hasher);
+                for clip in index.overlapping(start as i64, start as i64 + 1) {
+                    hash_clip(clip, &mut hasher);
+                    layers.get(&clip.track_id).hash(&mut hasher);
+                }
+                // Burnt into the preview by the caption overlay
+                if let Some(cue) = captions::cue_at(&timeline.captions, start as i64) {
+                    cue.text.hash(&mut hasher);
+                    cue.style.hash(&mut hasher);
+                }
+                (start, hasher.finish())
+            })
+            .collect();
+
+        let live_hashes: HashSet<u64> = spans.iter().map(|&(_, hash)| hash).collect();
+        let mut state = self.state();
+        state.spans = spans;
+        let mut freed = 0;
+        state.entries.retain(|&(hash, _), entry| {
+            let keep = live_hashes.contains(&hash);
+            if !keep {
+                freed += entry.frame.data.len();
+            }
+            keep
+        });
+        state.bytes -= freed;
+    }
+
+    /// Drop frames shown between `start_ms` and `end_ms`, e.g. after a clip's transform changed
+    pub fn invalidate_range(&self, start_ms: u64, end_ms: u64) {
+        let mut state = self.state();
+        let mut freed = 0;
+        state.entries.retain(|&(_, time_ms), entry| {
+            let keep = entry.end_ms <= start_ms || time_ms >= end_ms;
+            if !keep {
+                freed += entry.frame.data.len();
+            }
+            keep
+        });
+        state.bytes -= freed;
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state();
+        state.entries.clear();
+        state.bytes = 0;
+    }
+
+    /// The cached frame shown at `time_ms`, if any
+    pub fn get(&self, time_ms: u64) -> Option<CachedFrame> {
+        let mut state = self.state();
+        let hash = span_hash(&state.spans, time_ms)?;
+        state.use_counter += 1;
+        let use_counter = state.use_counter;
+        let (_, entry) = state.entries.range_mut((hash, 0)..=(hash, time_ms)).next_back()?;
+        if time_ms >= entry.end_ms {
+            return None;
+        }
+        entry.last_used = use_counter;
+        Some(CachedFrame {
+            data: Arc::clone(&entry.frame.data),
+            width: entry.frame.width,
+            height: entry.frame.height,
+        })
+    }
+
+    /// Keep a rendered RGBA sample for later scrubs over its time
+    pub fn insert_sample(&self, sample: &gst::Sample) {
+        let (Some(buffer), Some(caps)) = (sample.buffer(), sample.caps()) else {
+            return;
+        };
+        let Some(structure) = caps.structure(0) else {
+            return;
+        };
+        let (Ok(width), Ok(height)) = (structure.get::<i32>("width"), structure.get::<i32>("height")) else {
+            return;
+        };
+        // Positions are in stream time, i.e. timeline time
+        let Some(time) = buffer.pts().and_then(|pts| {
+            sample.segment()
+                .and_then(|segment| segment.downcast_ref::<gst::ClockTime>())
+                .and_then(|segment| segment.to_stream_time(pts))
+        }) else {
+            return;
+        };
+        let Ok(map) = buffer.map_readable() else {
+            return;
+        };
+
+        let time_ms = time.mseconds();
+        let end_ms = buffer.duration().map_or(time_ms + 1, |duration| (time + duration).mseconds().max(time_ms + 1));
+        let data = Arc::new(map.as_slice().to_vec());
+        {
+            let mut state = self.state();
+            let Some(hash) = span_hash(&state.spans, time_ms) else {
+                return;
+            };
+            state.use_counter += 1;
+            let entry = Entry {
+                frame: CachedFrame { data, width: width as u32, height: height as u32 },
+                end_ms,
+                last_used: state.use_counter,
+            };
+            state.bytes += entry.frame.data.len();
+            if let Some(replaced) = state.entries.insert((hash, time_ms), entry) {
+                state.bytes -= replaced.frame.data.len();
+            }
+        }
+        memory::enforce_budget();
+    }
+}
+
+impl Default for FrameCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn span_hash(spans: &[(u64, u64)], time_ms: u64) -> Option<u64> {
+    let index = spans.partition_point(|&(start, _)| start <= time_ms);
+    index.checked_sub(1).map(|index| spans[index].1)
+}
+
+fn hash_clip(clip: &TimelineClip, hasher: &mut DefaultHasher) {
+    clip.source_path.hash(hasher);
+    (
+        clip.track_id,
+        clip.start_time_on_track_ms,
+        clip.end_time_on_track_ms,
+        clip.start_time_in_source_ms,
+        clip.end_time_in_source_ms,
+    ).hash(hasher);
+    for value in [clip.preview_position_x, clip.preview_position_y, clip.preview_width, clip.preview_height, clip.preview_opacity] {
+        value.to_bits().hash(hasher);
+    }
+    (clip.track_type, clip.fit_mode, clip.tone_mapping).hash(hasher);
+    let orientation = &clip.orientation;
+    (orientation.rotation_degrees.to_bits(), orientation.flip_horizontal, orientation.flip_vertical).hash(hasher);
+    for keyframe in &orientation.rotation_keyframes {
+        (keyframe.time_ms, keyframe.degrees.to_bits()).hash(hasher);
+    }
+    for keyframe in &clip.crop_keyframes {
+        let rect = keyframe.rect;
+        (keyframe.time_ms, [rect.x, rect.y, rect.width, rect.height].map(f64::to_bits)).hash(hasher);
+    }
+}
+
+impl MemoryConsumer for Store {
+    fn memory_bytes(&self) -> usize {
+        self.state.lock().unwrap().bytes
+    }
+
+    fn evict(&self, bytes: usize) -> usize {
+        let mut state = self.state.lock().unwrap();
+        let mut by_age: Vec<((u64, u64), u64)> = state.entries.iter()
+            .map(|(&key, entry)| (key, entry.last_used))
+            .collect();
+        by_age.sort_by_key(|&(_, last_used)| last_used);
+
+        let mut freed = 0;
+        for (key, _) in by_age {
+            if freed >= bytes {
+                break;
+            }
+            if let Some(entry) = state.entries.remove(&key) {
+                freed += entry.frame.data.len();
+            }
+        }
+        state.bytes -= freed;
+        freed
+    }
+
+    fn last_used(&self) -> Instant {
+        *self.last_used.lock().unwrap()
+    }
+}
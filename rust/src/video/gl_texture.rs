@@ -0,0 +1,366 @@
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::time::Duration;
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gstreamer_gl as gst_gl;
+use gstreamer_gl_egl as gst_gl_egl;
+use gstreamer_gl_x11 as gst_gl_x11;
+use gstreamer_video as gst_video;
+use gst::prelude::*;
+use gst_gl::prelude::*;
+use irondash_engine_context::EngineContext;
+use crate::video::timer_pacer::TimerPacer;
+use irondash_texture::{BoxedGLTexture, GLTexture, GLTextureProvider, PayloadProvider, Texture};
+use log::{debug, info, warn};
+
+const GL_TEXTURE_2D: u32 = 0x0DE1;
+const GL_VENDOR: u32 = 0x1F00;
+const GL_RENDERER: u32 = 0x1F01;
+const GL_VERSION: u32 = 0x1F02;
+
+// Caps feature of decoder output that lives in DMA-BUF file descriptors
+const CAPS_FEATURE_MEMORY_DMABUF: &str = "memory:DMABuf";
+
+// How long sink creation waits for Flutter to populate the texture once and expose its GL context
+const CONTEXT_CAPTURE_TIMEOUT: Duration = Duration::from_secs(1);
+
+lazy_static::lazy_static! {
+    /// Flutter's GL context wrapped for GStreamer, captured the first time Flutter populates a GL texture
+    static ref FLUTTER_GL_CONTEXT: Mutex<Option<(gst_gl::GLDisplay, gst_gl::GLContext)>> = Mutex::new(None);
+    /// Signalled once `FLUTTER_GL_CONTEXT` is set
+    static ref FLUTTER_GL_CONTEXT_CAPTURED: Condvar = Condvar::new();
+    /// Vendor, renderer and version strings of Flutter's GL context, read when it is captured
+    static ref FLUTTER_GL_DRIVER: Mutex<Option<GlDriverInfo>> = Mutex::new(None);
+}
+
+#[derive(Debug, Clone)]
+pub struct GlDriverInfo {
+    pub vendor: String,
+    pub renderer: String,
+    pub version: String,
+}
+
+/// Query the driver strings of the current context through `glGetString`
+fn query_gl_driver_info(context: &gst_gl::GLContext) -> Option<GlDriverInfo> {
+    let address = context.proc_address("glGetString");
+    if address == 0 {
+        return None;
+    }
+    // SAFETY: glGetString has this signature on every GL/GLES implementation, and the context is current
+    let gl_get_string: extern "system" fn(u32) -> *const std::os::raw::c_char = unsafe { std::mem::transmute(address) };
+    let read = |name: u32| {
+        let value = gl_get_string(name);
+        if value.is_null() {
+            String::new()
+        } else {
+            // SAFETY: glGetString returns a static NUL-terminated string
+            unsafe { std::ffi::CStr::from_ptr(value) }.to_string_lossy().into_owned()
+        }
+    };
+    Some(GlDriverInfo {
+        vendor: read(GL_VENDOR),
+        renderer: read(GL_RENDERER),
+        version: read(GL_VERSION),
+    })
+}
+
+pub fn flutter_gl_driver_info() -> Option<GlDriverInfo> {
+    FLUTTER_GL_DRIVER.lock().unwrap().clone()
+}
+
+/// Wrap the native display that owns the GL context current on this thread, so GStreamer's GL
+/// elements run on the same EGL or X11 display as Flutter and their textures are shareable
+fn current_gl_display(platform: gst_gl::GLPlatform, api: gst_gl::GLAPI) -> Option<gst_gl::GLDisplay> {
+    let name = if platform == gst_gl::GLPlatform::EGL { "eglGetCurrentDisplay" } else { "glXGetCurrentDisplay" };
+    let address = gst_gl::GLContext::proc_address_with_platform(platform, api, name);
+    if address == 0 {
+        return None;
+    }
+    // SAFETY: eglGetCurrentDisplay and glXGetCurrentDisplay take nothing and return a pointer-sized handle
+    let get_current_display: extern "system" fn() -> usize = unsafe { std::mem::transmute(address) };
+    let handle = get_current_display();
+    if handle == 0 {
+        return None;
+    }
+    // SAFETY: the handle is the display of the context current on this thread, which Flutter keeps
+    // open for the engine's lifetime
+    let display = if platform == gst_gl::GLPlatform::EGL {
+        unsafe { gst_gl_egl::GLDisplayEGL::with_egl_display(handle) }.ok()?.upcast()
+    } else {
+        unsafe { gst_gl_x11::GLDisplayX11::with_display(handle) }.ok()?.upcast()
+    };
+    Some(display)
+}
+
+/// Wrap the GL context current on this thread. Flutter calls `get_payload` with its context current.
+fn capture_flutter_gl_context() {
+    let mut guard = FLUTTER_GL_CONTEXT.lock().unwrap();
+    if guard.is_some() {
+        return;
+    }
+
+    for platform in [gst_gl::GLPlatform::EGL, gst_gl::GLPlatform::GLX] {
+        let handle = gst_gl::GLContext::current_gl_context(platform);
+        if handle == 0 {
+            continue;
+        }
+
+        let (api, _, _) = gst_gl::GLContext::current_gl_api(platform);
+        let display = current_gl_display(platform, api).unwrap_or_else(|| {
+            warn!("Couldn't wrap Flutter's {:?} display, sharing its GL context may fail", platform);
+            gst_gl::GLDisplay::new()
+        });
+        // SAFETY: the handle is the context current on this thread, which Flutter keeps alive for the engine's lifetime
+        let Some(context) = (unsafe { gst_gl::GLContext::new_wrapped(&display, handle, platform, api) }) else {
+            continue;
+        };
+
+        if let Err(e) = context.activate(true) {
+            warn!("Failed to activate wrapped Flutter GL context: {}", e);
+            continue;
+        }
+        if let Err(e) = context.fill_info() {
+            warn!("Failed to query wrapped Flutter GL context: {}", e);
+            continue;
+        }
+
+        info!("Captured Flutter GL context ({:?}, {:?}) for zero-copy textures", platform, api);
+        if let Some(driver) = query_gl_driver_info(&context) {
+            info!("Flutter GL driver: {} / {} / {}", driver.vendor, driver.renderer, driver.version);
+            *FLUTTER_GL_DRIVER.lock().unwrap() = Some(driver);
+        }
+        *guard = Some((display, context));
+        FLUTTER_GL_CONTEXT_CAPTURED.notify_all();
+        return;
+    }
+
+    debug!("No current GL context while populating texture");
+}
+
+pub fn flutter_gl_context() -> Option<(gst_gl::GLDisplay, gst_gl::GLContext)> {
+    FLUTTER_GL_CONTEXT.lock().unwrap().clone()
+}
+
+/// Restrict `capsfilter`, in front of a clip's `glupload`, to DMA-BUF when the decoder behind
+/// `src_pad` can output it, so `glupload` imports each frame through EGLImage instead of copying
+/// it from system memory. False when the decoder or Flutter's GL platform (GLX) can't do that;
+/// the clip is then uploaded like on `GlMemory`.
+pub fn request_dmabuf(src_pad: &gst::Pad, capsfilter: &gst::Element) -> bool {
+    let uses_egl = flutter_gl_context()
+        .is_some_and(|(_, context)| context.gl_platform().contains(gst_gl::GLPlatform::EGL));
+    if !uses_egl {
+        return false;
+    }
+    let dmabuf_caps = gst::Caps::builder("video/x-raw")
+        .features([CAPS_FEATURE_MEMORY_DMABUF])
+        .build();
+    if src_pad.query_caps(Some(&dmabuf_caps)).is_empty() {
+        return false;
+    }
+    // The decoder renegotiates to DMA-BUF once linked to the narrowed caps
+    capsfilter.set_property("caps", &dmabuf_caps);
+    true
+}
+
+/// A GL frame kept mapped until Flutter is done sampling it
+struct GLFrame {
+    _frame: Option<gst_video::VideoFrame<gst_video::video_frame::Readable>>,
+    texture_name: u32,
+    width: i32,
+    height: i32,
+}
+
+impl GLFrame {
+    /// No texture bound, which samples as black
+    fn empty() -> Self {
+        Self {
+            _frame: None,
+            texture_name: 0,
+            width: 1,
+            height: 1,
+        }
+    }
+
+    fn from_sample(sample: &gst::Sample) -> Option<Self> {
+        let buffer = sample.buffer_owned()?;
+        let info = gst_video::VideoInfo::from_caps(sample.caps()?).ok()?;
+
+        // Wait for GStreamer's GL commands on this buffer before Flutter samples it
+        if let (Some(sync_meta), Some((_, context))) = (buffer.meta::<gst_gl::GLSyncMeta>(), flutter_gl_context()) {
+            sync_meta.wait(&context);
+        }
+
+        let frame = gst_video::VideoFrame::from_buffer_readable_gl(buffer, &info).ok()?;
+        let texture_name = frame.texture_id(0).ok()?;
+        Some(Self {
+            width: info.width() as i32,
+            height: info.height() as i32,
+            texture_name,
+            _frame: Some(frame),
+        })
+    }
+}
+
+impl GLTextureProvider for GLFrame {
+    fn get(&self) -> GLTexture {
+        GLTexture {
+            target: GL_TEXTURE_2D,
+            name: &self.texture_name,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// Hands the latest GL sample to Flutter without copying pixels
+pub struct GLFrameProvider {
+    current: Mutex<Option<gst::Sample>>,
+}
+
+impl PayloadProvider<BoxedGLTexture> for GLFrameProvider {
+    fn get_payload(&self) -> BoxedGLTexture {
+        capture_flutter_gl_context();
+
+        let sample = self.current.lock().unwrap().clone();
+        match sample.as_ref().and_then(GLFrame::from_sample) {
+            Some(frame) => Box::new(frame),
+            None => Box::new(GLFrame::empty()),
+        }
+    }
+}
+
+/// GL-backed player texture fed directly from `memory:GLMemory` samples
+pub struct GlTextureOutput {
+    provider: Arc<GLFrameProvider>,
+    pacer: Arc<TimerPacer>,
+}
+
+impl GlTextureOutput {
+    /// Publish a sample; called from the streaming thread
+    pub fn present(&self, sample: gst::Sample) {
+        if let Some(buffer) = sample.buffer() {
+            let gst_context = buffer.peek_memory(0)
+                .downcast_memory_ref::<gst_gl::GLBaseMemory>()
+                .map(|memory| memory.context().clone());
+            if let (Some(sync_meta), Some(gst_context)) = (buffer.meta::<gst_gl::GLSyncMeta>(), gst_context) {
+                sync_meta.set_sync_point(&gst_context);
+            }
+        }
+
+        *self.provider.current.lock().unwrap() = Some(sample);
+        self.pacer.frame_ready();
+    }
+
+    /// Show black until the next sample is presented
+    pub fn clear(&self) {
+        *self.provider.current.lock().unwrap() = None;
+        self.pacer.frame_ready();
+    }
+}
+
+/// Create a GL texture on the main thread. Flutter populates it once right away so the
+/// GL context can be captured before the first pipeline is built.
+pub fn create_gl_player_texture(engine_handle: i64) -> Result<(i64, Arc<GlTextureOutput>)> {
+    let (tx, rx) = mpsc::channel();
+
+    EngineContext::perform_on_main_thread(move || {
+        let result: Result<(i64, Arc<GlTextureOutput>)> = (|| {
+            let provider = Arc::new(GLFrameProvider {
+                current: Mutex::new(None),
+            });
+            let texture = Texture::new_with_provider(engine_handle, provider.clone())
+                .map_err(|e| anyhow!("Failed to create GL texture: {}", e))?;
+            let texture_id = texture.id();
+            let texture = texture.into_sendable_texture();
+            texture.mark_frame_available();
+
+            let pacer = TimerPacer::start(move || texture.mark_frame_available());
+            info!("Created GL player texture with ID: {}", texture_id);
+            Ok((texture_id, Arc::new(GlTextureOutput { provider, pacer })))
+        })();
+        let _ = tx.send(result);
+    })?;
+
+    rx.recv().unwrap_or_else(|_| Err(anyhow!("Failed to receive GL texture creation result")))
+}
+
+/// `glupload ! glcolorconvert ! appsink` bin whose appsink (named `texture_video_sink0`) keeps frames in GL memory
+pub fn create_gl_video_sink(output: Arc<GlTextureOutput>) -> Result<gst::Element> {
+    let glupload = gst::ElementFactory::make("glupload")
+        .build()
+        .map_err(|e| anyhow!("Failed to create glupload: {}", e))?;
+    let glcolorconvert = gst::ElementFactory::make("glcolorconvert")
+        .build()
+        .map_err(|e| anyhow!("Failed to create glcolorconvert: {}", e))?;
+    let appsink = gst_app::AppSink::builder()
+        .name("texture_video_sink0")
+        .caps(&gst::Caps::builder("video/x-raw")
+            .features([gst_gl::CAPS_FEATURE_MEMORY_GL_MEMORY])
+            .field("format", "RGBA")
+            .field("texture-target", "2D")
+            .build())
+        .sync(true)
+        .drop(true)
+        .max_buffers(1)
+        .build();
+
+    let preroll_output = Arc::clone(&output);
+    appsink.set_callbacks(
+        gst_app::AppSinkCallbacks::builder()
+            .new_sample(move |sink| {
+                let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                output.present(sample);
+                Ok(gst::FlowSuccess::Ok)
+            })
+            .new_preroll(move |sink| {
+                if let Ok(sample) = sink.pull_preroll() {
+                    preroll_output.present(sample);
+                }
+                Ok(gst::FlowSuccess::Ok)
+            })
+            .build(),
+    );
+
+    let bin = gst::Bin::with_name("gl_texture_sink_bin");
+    bin.add_many([&glupload, &glcolorconvert, appsink.upcast_ref()])?;
+    gst::Element::link_many([&glupload, &glcolorconvert, appsink.upcast_ref()])?;
+
+    let sink_pad = glupload.static_pad("sink").ok_or_else(|| anyhow!("glupload has no sink pad"))?;
+    bin.add_pad(&gst::GhostPad::with_target(&sink_pad)?)?;
+
+    Ok(bin.upcast())
+}
+
+/// Answer GL `need-context` queries with Flutter's display and context so textures are shareable
+pub fn install_gl_context_handler(bus: &gst::Bus) -> Result<()> {
+    let captured = FLUTTER_GL_CONTEXT.lock().unwrap();
+    let (captured, _) = FLUTTER_GL_CONTEXT_CAPTURED
+        .wait_timeout_while(captured, CONTEXT_CAPTURE_TIMEOUT, |captured| captured.is_none())
+        .unwrap();
+    let (display, gl_context) = captured.clone()
+        .ok_or_else(|| anyhow!("Flutter GL context not captured yet; texture was never populated"))?;
+    drop(captured);
+
+    bus.set_sync_handler(move |_, message| {
+        if let gst::MessageView::NeedContext(need_context) = message.view() {
+            if let Some(element) = message.src().and_then(|src| src.downcast_ref::<gst::Element>()) {
+                match need_context.context_type() {
+                    "gst.gl.GLDisplay" => {
+                        let mut context = gst::Context::new("gst.gl.GLDisplay", true);
+                        context.get_mut().unwrap().set_gl_display(&display);
+                        element.set_context(&context);
+                    }
+                    "gst.gl.app_context" => {
+                        let mut context = gst::Context::new("gst.gl.app_context", true);
+                        context.get_mut().unwrap().structure_mut().set("context", &gl_context);
+                        element.set_context(&context);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        gst::BusSyncReply::Pass
+    });
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::glib;
+use log::{error, info, warn};
+use tokio::sync::mpsc as command_channel;
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::video::direct_pipeline_player::DirectPipelinePlayer;
+use crate::video::watchdog;
+
+type Command<P = DirectPipelinePlayer> = Box<dyn FnOnce(&mut P) + Send>;
+
+fn stopped_error() -> anyhow::Error {
+    anyhow!(FlipEditError::new(FlipEditErrorCode::PlayerStopped, "Player has stopped"))
+}
+
+/// Handle on a `DirectPipelinePlayer` owned by its own GStreamer worker thread. The worker runs
+/// a main loop on a context it owns, so the player's bus watches and position timer attach
+/// there rather than to the global default context, and a slow load or analysis on one player
+/// doesn't stall the others. Callers on any thread, such as the ones flutter_rust_bridge calls
+/// in on, send it commands, so the player, its pipeline and the GLib sources watching it are
+/// only ever used on the worker.
+pub struct PlayerHandle {
+    // The player only holds a weak reference, so dropping this still closes the channel
+    commands: Option<Arc<command_channel::UnboundedSender<Command>>>,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl PlayerHandle {
+    /// Start the worker and create its player, failing if the player can't be created
+    pub fn spawn() -> Result<Self> {
+        let (commands, receiver) = command_channel::unbounded_channel::<Command>();
+        let commands = Arc::new(commands);
+        let player_commands = Arc::downgrade(&commands);
+        let (ready_sender, ready) = mpsc::channel::<Result<()>>();
+        let worker = thread::Builder::new()
+            .name("gst-worker".to_string())
+            .spawn(move || {
+                let context = glib::MainContext::new();
+                let main_loop = glib::MainLoop::new(Some(&context), false);
+                let player_loop = main_loop.clone();
+                let owned = context.with_thread_default(|| {
+                    context.spawn_local(async move {
+                        run_player(receiver, player_commands, ready_sender).await;
+                        player_loop.quit();
+                    });
+                    main_loop.run();
+                });
+                if let Err(e) = owned {
+                    error!("GStreamer worker couldn't own its main context: {}", e);
+                }
+            })
+            .map_err(|e| anyhow!("Failed to start GStreamer worker thread: {}", e))?;
+        ready.recv().map_err(|_| anyhow!("GStreamer worker stopped during player startup"))??;
+        Ok(Self { commands: Some(commands), worker: Some(worker) })
+    }
+
+    fn send(&self, command: Command) -> Result<()> {
+        self.commands
+            .as_ref()
+            .and_then(|commands| commands.send(command).ok())
+            .ok_or_else(stopped_error)
+    }
+
+    /// Run `f` on the worker and wait for its result
+    pub fn run<R: Send + 'static>(&self, f: impl FnOnce(&mut DirectPipelinePlayer) -> R + Send + 'static) -> Result<R> {
+        let (reply_sender, reply) = mpsc::sync_channel(1);
+        self.send(Box::new(move |player| {
+            let _ = reply_sender.send(f(player));
+        }))?;
+        reply.recv().map_err(|_| anyhow!(FlipEditError::new(
+            FlipEditErrorCode::PlayerStopped,
+            "Player command failed without replying",
+        )))
+    }
+
+    /// `run` for commands that can fail themselves
+    pub fn call<R: Send + 'static>(&self, f: impl FnOnce(&mut DirectPipelinePlayer) -> Result<R> + Send + 'static) -> Result<R> {
+        self.run(f)?
+    }
+
+    /// Queue `f` without waiting, e.g. for setters; it still runs before any later command
+    pub fn post(&self, f: impl FnOnce(&mut DirectPipelinePlayer) + Send + 'static) {
+        if let Err(e) = self.send(Box::new(f)) {
+            warn!("Dropped player command: {}", e);
+        }
+    }
+}
+
+/// Create the worker's player and serve commands until the handle is dropped
+async fn run_player(
+    receiver: command_channel::UnboundedReceiver<Command>,
+    player_commands: Weak<command_channel::UnboundedSender<Command>>,
+    ready_sender: mpsc::Sender<Result<()>>,
+) {
+    let mut player = match DirectPipelinePlayer::new() {
+        Ok(mut player) => {
+            player.set_task_poster(Arc::new(move |command| {
+                if let Some(commands) = player_commands.upgrade() {
+                    let _ = commands.send(command);
+                }
+            }));
+            let _ = ready_sender.send(Ok(()));
+            player
+        }
+        Err(e) => {
+            let _ = ready_sender.send(Err(e));
+            return;
+        }
+    };
+    serve(&mut player, receiver, watchdog::CHECK_INTERVAL, DirectPipelinePlayer::check_health).await;
+    // The channel closes when the handle is dropped
+    if let Err(e) = player.dispose() {
+        warn!("Failed to dispose player: {}", e);
+    }
+    info!("Player stopped");
+}
+
+/// Run commands on `player` until the channel closes, calling `check` every `interval` however
+/// busy the channel is; playback sends position queries faster than the watchdog interval
+async fn serve<P>(
+    player: &mut P,
+    mut receiver: command_channel::UnboundedReceiver<Command<P>>,
+    interval: Duration,
+    mut check: impl FnMut(&mut P),
+) {
+    let mut last_check = Instant::now();
+    loop {
+        let wait = interval.saturating_sub(last_check.elapsed());
+        match glib::future_with_timeout(wait, receiver.recv()).await {
+            // A panicking command loses its reply, but the player keeps serving others
+            Ok(Some(command)) => {
+                if catch_unwind(AssertUnwindSafe(|| command(player))).is_err() {
+                    error!("Player command panicked");
+                }
+            }
+            Ok(None) => break,
+            Err(_) => {}
+        }
+        if last_check.elapsed() >= interval {
+            if catch_unwind(AssertUnwindSafe(|| check(player))).is_err() {
+                error!("Player health check panicked");
+            }
+            last_check = Instant::now();
+        }
+    }
+}
+
+impl Drop for PlayerHandle {
+    fn drop(&mut self) {
+        self.commands.take();
+        if let Some(worker) = self.worker.take() {
+            // Joining from the worker itself would never see the player stop
+            if worker.thread().id() != thread::current().id() && worker.join().is_err() {
+                warn!("GStreamer worker panicked while stopping");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_runs_while_commands_arrive_faster_than_the_interval() {
+        let interval = Duration::from_millis(50);
+        let (sender, receiver) = command_channel::unbounded_channel::<Command<u32>>();
+        let feeder = thread::spawn(move || {
+            let end = Instant::now() + interval * 10;
+            while Instant::now() < end {
+                if sender.send(Box::new(|_: &mut u32| {})).is_err() {
+                    break;
+                }
+                thread::sleep(interval / 10);
+            }
+        });
+
+        let mut checks = 0;
+        glib::MainContext::new().block_on(serve(&mut checks, receiver, interval, |checks| *checks += 1));
+        feeder.join().unwrap();
+        assert!(checks >= 5, "health check ran {} times in 10 intervals of commands", checks);
+    }
+}
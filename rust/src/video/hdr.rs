@@ -0,0 +1,112 @@
+use gstreamer as gst;
+
+/// Transfer function of a source's video stream, as far as the preview cares
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceTransfer {
+    Sdr,
+    /// SMPTE ST 2084 (HDR10, Dolby Vision base layer)
+    Pq,
+    /// ARIB STD-B67, which looks reasonable on SDR displays untouched but dull next to SDR clips
+    Hlg,
+}
+
+impl SourceTransfer {
+    pub fn is_hdr(self) -> bool {
+        self != SourceTransfer::Sdr
+    }
+
+    /// `glshader` fragment tone mapping this transfer to SDR BT.709, if it needs one
+    pub fn tone_map_fragment(self) -> Option<&'static str> {
+        match self {
+            SourceTransfer::Sdr => None,
+            SourceTransfer::Pq => Some(PQ_TONE_MAP_FRAGMENT),
+            SourceTransfer::Hlg => Some(HLG_TONE_MAP_FRAGMENT),
+        }
+    }
+
+    /// Read the transfer from a caps `colorimetry` field, either named (`bt2100-pq`)
+    /// or in `range:matrix:transfer:primaries` form
+    pub fn from_caps(caps: &gst::CapsRef) -> Self {
+        let Some(colorimetry) = caps.structure(0).and_then(|s| s.get::<&str>("colorimetry").ok()) else {
+            return SourceTransfer::Sdr;
+        };
+        match colorimetry {
+            "bt2100-pq" => return SourceTransfer::Pq,
+            "bt2100-hlg" => return SourceTransfer::Hlg,
+            _ => {}
+        }
+        // GstVideoTransferFunction: 14 = SMPTE2084, 15 = ARIB_STD_B67
+        match colorimetry.split(':').nth(2) {
+            Some("14") => SourceTransfer::Pq,
+            Some("15") => SourceTransfer::Hlg,
+            _ => SourceTransfer::Sdr,
+        }
+    }
+}
+
+/// `glshader` fragment mapping PQ-encoded BT.2020 RGB to SDR BT.709 for the preview:
+/// decode PQ, put reference white (203 nits) at 1.0, Reinhard tone map, convert
+/// primaries, then re-encode with a 2.2 gamma.
+pub const PQ_TONE_MAP_FRAGMENT: &str = r#"
+#ifdef GL_ES
+precision highp float;
+#endif
+varying vec2 v_texcoord;
+uniform sampler2D tex;
+
+vec3 pq_to_linear(vec3 e) {
+    const float m1 = 0.1593017578125;
+    const float m2 = 78.84375;
+    const float c1 = 0.8359375;
+    const float c2 = 18.8515625;
+    const float c3 = 18.6875;
+    vec3 p = pow(max(e, 0.0), vec3(1.0 / m2));
+    return pow(max(p - c1, 0.0) / (c2 - c3 * p), vec3(1.0 / m1));
+}
+
+void main() {
+    vec4 color = texture2D(tex, v_texcoord);
+    vec3 relative = pq_to_linear(color.rgb) * (10000.0 / 203.0);
+    vec3 mapped = relative / (1.0 + relative);
+    const mat3 bt2020_to_bt709 = mat3(
+        1.6605, -0.1246, -0.0182,
+        -0.5876, 1.1329, -0.1006,
+        -0.0728, -0.0083, 1.1187);
+    mapped = clamp(bt2020_to_bt709 * mapped, 0.0, 1.0);
+    gl_FragColor = vec4(pow(mapped, vec3(1.0 / 2.2)), color.a);
+}
+"#;
+
+/// `glshader` fragment mapping HLG-encoded BT.2020 RGB to SDR BT.709: invert the HLG OETF,
+/// apply the OOTF for a 1000 nit display, then tone map like `PQ_TONE_MAP_FRAGMENT`.
+pub const HLG_TONE_MAP_FRAGMENT: &str = r#"
+#ifdef GL_ES
+precision highp float;
+#endif
+varying vec2 v_texcoord;
+uniform sampler2D tex;
+
+vec3 hlg_to_scene_linear(vec3 e) {
+    const float a = 0.17883277;
+    const float b = 0.28466892;
+    const float c = 0.55991073;
+    vec3 low = e * e / 3.0;
+    vec3 high = (exp((e - c) / a) + b) / 12.0;
+    return mix(low, high, step(0.5, e));
+}
+
+void main() {
+    vec4 color = texture2D(tex, v_texcoord);
+    vec3 scene = hlg_to_scene_linear(max(color.rgb, 0.0));
+    float luma = dot(scene, vec3(0.2627, 0.6780, 0.0593));
+    vec3 display = 1000.0 * pow(max(luma, 1e-6), 0.2) * scene;
+    vec3 relative = display / 203.0;
+    vec3 mapped = relative / (1.0 + relative);
+    const mat3 bt2020_to_bt709 = mat3(
+        1.6605, -0.1246, -0.0182,
+        -0.5876, 1.1329, -0.1006,
+        -0.0728, -0.0083, 1.1187);
+    mapped = clamp(bt2020_to_bt709 * mapped, 0.0, 1.0);
+    gl_FragColor = vec4(pow(mapped, vec3(1.0 / 2.2)), color.a);
+}
+"#;
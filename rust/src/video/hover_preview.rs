@@ -0,0 +1,121 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use anyhow::Result;
+use log::{debug, info, warn};
+
+use crate::common::resources;
+use crate::common::types::{FrameBufferPool, FrameData, ResourceKind, ThumbnailFormat};
+use crate::video::irondash_texture::{create_player_texture, TextureUpdateFn};
+use crate::video::thumbnail::FrameExtractor;
+
+/// Source frame to show, `None` to blank the texture
+type HoverRequest = Option<(String, u64)>;
+
+/// A small texture of its own showing source frames for hover tooltips over the timeline.
+/// Frames are decoded on a worker thread with a separate pipeline, so hovering never touches
+/// the program texture or the playing pipeline, and stale hovers are skipped.
+pub struct HoverPreview {
+    texture_id: i64,
+    requests: Option<Sender<HoverRequest>>,
+    worker: Option<JoinHandle<()>>,
+    resource_owner: u64,
+}
+
+impl HoverPreview {
+    pub fn new(engine_handle: i64, width: u32, height: u32) -> Result<Self> {
+        let buffer_pool = Arc::new(Mutex::new(FrameBufferPool::new(0, 0)));
+        let (texture_id, update_fn) = create_player_texture(width, height, engine_handle, Arc::clone(&buffer_pool))?;
+        let resource_owner = resources::next_owner_id();
+        resources::track(resource_owner, ResourceKind::Texture, Some(engine_handle), format!("hover preview texture {}", texture_id));
+
+        let (requests, receiver) = mpsc::channel();
+        let worker = std::thread::Builder::new()
+            .name("hover-preview".into())
+            .spawn(move || run_worker(receiver, update_fn, buffer_pool, texture_id, width, height))?;
+        info!("Created {}x{} hover preview texture {}", width, height, texture_id);
+        Ok(Self { texture_id, requests: Some(requests), worker: Some(worker), resource_owner })
+    }
+
+    pub fn texture_id(&self) -> i64 {
+        self.texture_id
+    }
+
+    /// Show the frame of `source_path` at `source_ms`
+    pub fn show(&self, source_path: &str, source_ms: u64) {
+        self.send(Some((source_path.to_string(), source_ms)));
+    }
+
+    /// Blank the texture, e.g. when hovering over a gap
+    pub fn clear(&self) {
+        self.send(None);
+    }
+
+    fn send(&self, request: HoverRequest) {
+        if let Some(requests) = self.requests.as_ref() {
+            let _ = requests.send(request);
+        }
+    }
+}
+
+impl Drop for HoverPreview {
+    fn drop(&mut self) {
+        // Closing the channel ends the worker, which drops the last texture reference
+        self.requests.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+        resources::release(self.resource_owner, ResourceKind::Texture);
+    }
+}
+
+fn run_worker(
+    receiver: Receiver<HoverRequest>,
+    update_fn: TextureUpdateFn,
+    buffer_pool: Arc<Mutex<FrameBufferPool>>,
+    texture_id: i64,
+    width: u32,
+    height: u32,
+) {
+    let mut extractor: Option<FrameExtractor> = None;
+    while let Ok(mut request) = receiver.recv() {
+        // Only the latest hover matters
+        while let Ok(newer) = receiver.try_recv() {
+            request = newer;
+        }
+        let Some((source_path, source_ms)) = request else {
+            update_fn(FrameData {
+                data: buffer_pool.lock().unwrap().buffer_from_slice(&[0, 0, 0, 0]),
+                width: 1,
+                height: 1,
+                texture_id: Some(texture_id as u64),
+            });
+            continue;
+        };
+
+        if extractor.as_ref().map_or(true, |e| e.source_path() != source_path) {
+            extractor = match FrameExtractor::open(&source_path) {
+                Ok(opened) => Some(opened),
+                Err(e) => {
+                    warn!("Can't preview {}: {}", source_path, e);
+                    None
+                }
+            };
+        }
+        let Some(current) = extractor.as_ref() else {
+            continue;
+        };
+        match current.thumbnail_at(source_ms, width, height, ThumbnailFormat::Rgba) {
+            Ok(thumbnail) => {
+                debug!("Hover preview of {} at {}ms", source_path, source_ms);
+                update_fn(FrameData {
+                    data: buffer_pool.lock().unwrap().buffer_from_slice(&thumbnail.data),
+                    width: thumbnail.width,
+                    height: thumbnail.height,
+                    texture_id: Some(texture_id as u64),
+                });
+            }
+            Err(e) => warn!("Hover preview of {} at {}ms failed: {}", source_path, source_ms, e),
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{info, warn};
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::media_uri::srt_uri;
+use crate::common::types::LiveStreamSettings;
+use crate::video::render_cache::{make_element, make_first_available};
+
+/// Tee after the compositor output that the preview sink and live streams share
+pub const PROGRAM_VIDEO_TEE: &str = "program_video_tee";
+/// Tee after the audio mix, before the master volume so the monitor fader doesn't reach the stream
+pub const PROGRAM_AUDIO_TEE: &str = "program_audio_tee";
+const LIVE_AUDIO_MIX: &str = "live_audio_mix";
+const LIVE_AUDIO_QUEUE: &str = "live_audio_queue";
+// Time held back for a slow connection before the oldest buffers are dropped, so the preview
+// never waits on the network. The video queue sits before the encoder, so it drops raw frames
+// rather than breaking the encoded stream; the audio queue holds encoded AAC.
+const LIVE_QUEUE_TIME_NS: u64 = 2_000_000_000;
+
+/// Encoder and muxer branch fed from the program tees
+pub struct LiveOutput {
+    elements: Vec<gst::Element>,
+    tee_pads: Vec<gst::Pad>,
+}
+
+fn invalid(message: String) -> anyhow::Error {
+    anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, message))
+}
+
+/// Protocol of an ingest URL, which decides the container and sink
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LiveProtocol {
+    Rtmp,
+    Srt,
+}
+
+impl LiveProtocol {
+    fn from_url(url: &str) -> Option<Self> {
+        let url = url.to_ascii_lowercase();
+        if url.starts_with("rtmp://") || url.starts_with("rtmps://") {
+            Some(Self::Rtmp)
+        } else if url.starts_with("srt://") {
+            Some(Self::Srt)
+        } else {
+            None
+        }
+    }
+}
+
+fn rtmp_location(settings: &LiveStreamSettings) -> String {
+    let url = settings.url.trim_end_matches('/');
+    let location = if settings.stream_key.is_empty() {
+        url.to_string()
+    } else {
+        format!("{}/{}", url, settings.stream_key)
+    };
+    // librtmp needs to be told it's publishing a live stream
+    format!("{} live=1", location)
+}
+
+fn leaky_queue() -> Result<gst::Element> {
+    gst::ElementFactory::make("queue")
+        .property("max-size-time", LIVE_QUEUE_TIME_NS)
+        .property("max-size-buffers", 0u32)
+        .property("max-size-bytes", 0u32)
+        .property_from_str("leaky", "downstream")
+        .build()
+        .map_err(|e| anyhow!("Failed to create queue: {}", e))
+}
+
+impl LiveOutput {
+    /// Stream the program output of `pipeline` as `x264 ! flvmux ! rtmpsink`, or as MPEG-TS over
+    /// `srtsink` for srt:// URLs. `gl_frames` says
+    /// whether the program video is in GL memory and has to be downloaded first.
+    pub fn attach(pipeline: &gst::Pipeline, settings: &LiveStreamSettings, gl_frames: bool) -> Result<Self> {
+        let protocol = LiveProtocol::from_url(&settings.url)
+            .ok_or_else(|| invalid(format!("Not an RTMP or SRT ingest URL: {}", settings.url)))?;
+        if settings.video_bitrate_kbps == 0 || settings.audio_bitrate_kbps == 0 || settings.framerate <= 0 {
+            return Err(invalid("Live stream bitrates and framerate must be positive".to_string()));
+        }
+        let video_tee = pipeline.by_name(PROGRAM_VIDEO_TEE).ok_or_else(FlipEditError::not_loaded)?;
+
+        let mut video_chain = vec![leaky_queue()?];
+        if gl_frames {
+            video_chain.push(make_element("gldownload")?);
+        }
+        video_chain.push(make_element("videoconvert")?);
+        video_chain.push(make_element("videorate")?);
+        video_chain.push(gst::ElementFactory::make("capsfilter")
+            .property("caps", gst::Caps::builder("video/x-raw")
+                .field("format", "I420")
+                .field("framerate", gst::Fraction::new(settings.framerate, 1))
+                .build())
+            .build()
+            .map_err(|e| anyhow!("Failed to create capsfilter: {}", e))?);
+        let video_encoder = make_first_available(&["x264enc", "openh264enc"])?;
+        let keyframe_interval = settings.framerate as u32 * settings.keyframe_interval_secs.max(1);
+        if video_encoder.factory().is_some_and(|factory| factory.name() == "x264enc") {
+            video_encoder.set_property("bitrate", settings.video_bitrate_kbps);
+            video_encoder.set_property("key-int-max", keyframe_interval);
+            video_encoder.set_property_from_str("tune", "zerolatency");
+            video_encoder.set_property_from_str("speed-preset", "veryfast");
+        } else {
+            video_encoder.set_property("bitrate", settings.video_bitrate_kbps * 1000);
+            video_encoder.set_property("gop-size", keyframe_interval);
+        }
+        video_chain.push(video_encoder);
+        video_chain.push(make_element("h264parse")?);
+
+        // Silence keeps the stream's audio track flowing until program audio is linked in
+        let silence = gst::ElementFactory::make("audiotestsrc")
+            .property_from_str("wave", "silence")
+            .property("is-live", true)
+            .build()
+            .map_err(|e| anyhow!("Failed to create audiotestsrc: {}", e))?;
+        let audio_mix = gst::ElementFactory::make("audiomixer")
+            .name(LIVE_AUDIO_MIX)
+            .build()
+            .map_err(|e| anyhow!("Failed to create audiomixer: {}", e))?;
+        let audio_encoder = make_first_available(&["avenc_aac", "voaacenc", "fdkaacenc"])?;
+        audio_encoder.set_property_from_str("bitrate", &(settings.audio_bitrate_kbps * 1000).to_string());
+        let audio_chain = vec![
+            silence,
+            audio_mix,
+            make_element("audioconvert")?,
+            make_element("audioresample")?,
+            audio_encoder,
+            make_element("aacparse")?,
+            leaky_queue()?,
+        ];
+
+        let (muxer, sink) = match protocol {
+            LiveProtocol::Rtmp => {
+                let muxer = gst::ElementFactory::make("flvmux")
+                    .property("streamable", true)
+                    .build()
+                    .map_err(|e| anyhow!("Failed to create flvmux: {}", e))?;
+                let sink = gst::ElementFactory::make("rtmpsink")
+                    .property("location", rtmp_location(settings))
+                    .build()
+                    .map_err(|_| anyhow!(FlipEditError::new(FlipEditErrorCode::MissingPlugin, "rtmpsink isn't available")))?;
+                (muxer, sink)
+            }
+            LiveProtocol::Srt => {
+                let muxer = gst::ElementFactory::make("mpegtsmux")
+                    // Whole 7-packet groups, as SRT sends them one per datagram
+                    .property("alignment", 7i32)
+                    .build()
+                    .map_err(|e| anyhow!("Failed to create mpegtsmux: {}", e))?;
+                let uri = srt_uri(
+                    &settings.url,
+                    settings.srt_latency_ms,
+                    settings.srt_passphrase.as_deref(),
+                    Some(settings.stream_key.as_str()),
+                );
+                let sink = gst::ElementFactory::make("srtsink")
+                    .property("uri", uri)
+                    // Keep encoding while the receiver isn't listening yet
+                    .property("wait-for-connection", false)
+                    .build()
+                    .map_err(|_| anyhow!(FlipEditError::new(FlipEditErrorCode::MissingPlugin, "srtsink isn't available")))?;
+                (muxer, sink)
+            }
+        };
+        // A late or failing connection mustn't hold back the preview's state changes
+        sink.set_property("async", false);
+
+        let video_elements: Vec<&gst::Element> = video_chain.iter().chain([&muxer, &sink]).collect();
+        pipeline.add_many(&video_elements)?;
+        gst::Element::link_many(&video_elements)?;
+        pipeline.add_many(&audio_chain)?;
+        gst::Element::link_many(audio_chain.iter().chain([&muxer]))?;
+
+        let mut elements: Vec<gst::Element> = video_chain.into_iter().chain(audio_chain).collect();
+        elements.push(muxer);
+        elements.push(sink);
+        for element in &elements {
+            element.sync_state_with_parent()?;
+        }
+
+        let tee_pad = video_tee.request_pad_simple("src_%u")
+            .ok_or_else(|| anyhow!("Failed to request a program tee pad"))?;
+        let queue_pad = elements[0].static_pad("sink").ok_or_else(|| anyhow!("Live video queue has no sink pad"))?;
+        tee_pad.link(&queue_pad)?;
+
+        let output = Self { elements, tee_pads: vec![tee_pad] };
+        if let Some(audio_tee) = pipeline.by_name(PROGRAM_AUDIO_TEE) {
+            link_program_audio(pipeline, &audio_tee)?;
+        }
+        info!("Streaming program output to {} ({}kbps video, {}kbps audio)",
+              settings.url, settings.video_bitrate_kbps, settings.audio_bitrate_kbps);
+        Ok(output)
+    }
+
+    /// Stop streaming and take the branch out of `pipeline`, leaving the preview running
+    pub fn detach(self, pipeline: &gst::Pipeline) {
+        let audio_queue = pipeline.by_name(LIVE_AUDIO_QUEUE);
+        // The audio tee pad may have been linked after the stream started, so find it by its peer
+        let audio_tee_pad = audio_queue.as_ref()
+            .and_then(|queue| queue.static_pad("sink"))
+            .and_then(|pad| pad.peer());
+        let tee_pads = self.tee_pads.into_iter().chain(audio_tee_pad);
+        for pad in tee_pads {
+            if let (Some(tee), Some(peer)) = (pad.parent_element(), pad.peer()) {
+                let _ = pad.unlink(&peer);
+                tee.release_request_pad(&pad);
+            }
+        }
+        for element in self.elements.iter().chain(audio_queue.as_ref()) {
+            let _ = element.set_state(gst::State::Null);
+            if let Err(e) = pipeline.remove(element) {
+                warn!("Failed to remove live stream element {}: {}", element.name(), e);
+            }
+        }
+        info!("Stopped streaming program output");
+    }
+}
+
+/// Feed the program audio into a running live stream, e.g. once the first audio clip is linked;
+/// does nothing when no stream is running or its audio is already linked
+pub fn link_program_audio(pipeline: &gst::Pipeline, audio_tee: &gst::Element) -> Result<()> {
+    let Some(audio_mix) = pipeline.by_name(LIVE_AUDIO_MIX) else {
+        return Ok(());
+    };
+    if pipeline.by_name(LIVE_AUDIO_QUEUE).is_some() {
+        return Ok(());
+    }
+    let queue = gst::ElementFactory::make("queue")
+        .name(LIVE_AUDIO_QUEUE)
+        .build()
+        .map_err(|e| anyhow!("Failed to create queue: {}", e))?;
+    pipeline.add(&queue)?;
+    queue.link(&audio_mix)?;
+    queue.sync_state_with_parent()?;
+
+    let tee_pad = audio_tee.request_pad_simple("src_%u")
+        .ok_or_else(|| anyhow!("Failed to request a program audio tee pad"))?;
+    let queue_pad = queue.static_pad("sink").ok_or_else(|| anyhow!("Live audio queue has no sink pad"))?;
+    tee_pad.link(&queue_pad)?;
+    info!("Linked program audio into the live stream");
+    Ok(())
+}
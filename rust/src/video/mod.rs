@@ -3,4 +3,24 @@ pub mod pipeline;
 pub mod frame_handler;
 pub mod direct_pipeline_player;
 pub mod irondash_texture;
-pub mod texture_registry; 
\ No newline at end of file
+pub mod player_registry;
+pub mod gst_worker;
+pub mod render_cache;
+pub mod screen_recorder;
+pub mod live_output;
+pub mod clip_timing;
+pub mod clip_index;
+pub mod frame_cache;
+pub mod timer_pacer;
+pub mod perf_stats;
+pub mod watchdog;
+pub mod hdr;
+pub mod color;
+pub mod fit;
+pub mod orientation;
+pub mod pan_zoom;
+pub mod thumbnail;
+pub mod hover_preview;
+pub mod transitions;
+#[cfg(target_os = "linux")]
+pub mod gl_texture; 
\ No newline at end of file
@@ -0,0 +1,123 @@
+use std::sync::{Arc, Mutex};
+use gstreamer as gst;
+use gst::prelude::*;
+
+use crate::common::types::{ClipOrientation, PixelAspectRatio, RotationKeyframe};
+use crate::video::clip_timing::SharedClipTiming;
+
+/// Orientation shared between the player and a clip's tilt probe, so edits apply live
+pub type SharedOrientation = Arc<Mutex<ClipOrientation>>;
+
+// Tilts smaller than this are left to the flip element alone
+const TILT_EPSILON_DEGREES: f64 = 0.01;
+
+/// Rotation in degrees at `timeline_ms`, interpolated linearly between keyframes and held past the ends
+pub fn rotation_at(keyframes: &[RotationKeyframe], timeline_ms: f64) -> Option<f64> {
+    match keyframes.iter().position(|k| k.time_ms as f64 > timeline_ms) {
+        None => keyframes.last().map(|k| k.degrees),
+        Some(0) => Some(keyframes[0].degrees),
+        Some(i) => {
+            let (a, b) = (&keyframes[i - 1], &keyframes[i]);
+            let t = (timeline_ms - a.time_ms as f64) / (b.time_ms - a.time_ms) as f64;
+            Some(a.degrees + (b.degrees - a.degrees) * t)
+        }
+    }
+}
+
+/// Clockwise quarter turns of the frame itself, 0-3
+pub fn quarter_turns(orientation: &ClipOrientation) -> i32 {
+    ((orientation.rotation_degrees / 90.0).round() as i64).rem_euclid(4) as i32
+}
+
+/// Degrees the picture is tilted inside its turned frame at `timeline_ms`
+pub fn tilt_at(orientation: &ClipOrientation, timeline_ms: f64) -> f64 {
+    let rotation = rotation_at(&orientation.rotation_keyframes, timeline_ms).unwrap_or(orientation.rotation_degrees);
+    let turned = (orientation.rotation_degrees / 90.0).round() * 90.0;
+    rotation - turned
+}
+
+/// Whether the clip needs a tilt element, now or at any keyframe
+pub fn needs_tilt(orientation: &ClipOrientation) -> bool {
+    !orientation.rotation_keyframes.is_empty() || tilt_at(orientation, 0.0).abs() > TILT_EPSILON_DEGREES
+}
+
+/// `method` of `videoflip` and `glvideoflip` for the mirroring and quarter turns
+pub fn flip_method(orientation: &ClipOrientation) -> &'static str {
+    let mut turns = quarter_turns(orientation);
+    // Mirroring both ways is a half turn
+    let mirrored = match (orientation.flip_horizontal, orientation.flip_vertical) {
+        (true, true) => {
+            turns += 2;
+            false
+        }
+        (false, true) => {
+            turns += 2;
+            true
+        }
+        (horizontal, false) => horizontal,
+    };
+    match (mirrored, turns % 4) {
+        (false, 0) => "none",
+        (false, 1) => "clockwise",
+        (false, 2) => "rotate-180",
+        (false, _) => "counterclockwise",
+        (true, 0) => "horizontal-flip",
+        (true, 1) => "upper-right-diagonal",
+        (true, 2) => "vertical-flip",
+        (true, _) => "upper-left-diagonal",
+    }
+}
+
+/// Source size and pixel aspect ratio after the frame's quarter turns
+pub fn turned_geometry(
+    geometry: ((i32, i32), PixelAspectRatio),
+    orientation: &ClipOrientation,
+) -> ((i32, i32), PixelAspectRatio) {
+    let ((width, height), par) = geometry;
+    if quarter_turns(orientation) % 2 == 0 {
+        return geometry;
+    }
+    ((height, width), PixelAspectRatio { numerator: par.denominator, denominator: par.numerator })
+}
+
+#[derive(Default)]
+struct TiltState {
+    segment: Option<gst::FormattedSegment<gst::ClockTime>>,
+    angle: Option<f64>,
+}
+
+/// Keep a `rotate` element's angle on the clip's tilt, following rotation keyframes per frame
+pub fn follow_tilt(rotate: &gst::Element, orientation: SharedOrientation, timing: SharedClipTiming) {
+    let Some(sink_pad) = rotate.static_pad("sink") else {
+        return;
+    };
+    let rotate = rotate.downgrade();
+    let state = Mutex::new(TiltState::default());
+    sink_pad.add_probe(gst::PadProbeType::BUFFER | gst::PadProbeType::EVENT_DOWNSTREAM, move |_pad, info| {
+        let mut state = state.lock().unwrap();
+        let pts = match info.data {
+            Some(gst::PadProbeData::Event(ref event)) => {
+                if let gst::EventView::Segment(segment) = event.view() {
+                    state.segment = segment.segment().downcast_ref::<gst::ClockTime>().cloned();
+                }
+                return gst::PadProbeReturn::Ok;
+            }
+            Some(gst::PadProbeData::Buffer(ref buffer)) => buffer.pts(),
+            _ => return gst::PadProbeReturn::Ok,
+        };
+        let stream_time = state.segment.as_ref()
+            .zip(pts)
+            .and_then(|(segment, pts)| segment.to_stream_time(pts));
+        // Stream time is the source position; map it onto the timeline
+        let timing = *timing.lock().unwrap();
+        let timeline_ms = stream_time.map_or(timing.start_ms as f64, |time| timing.timeline_ms(time));
+        let angle = tilt_at(&orientation.lock().unwrap(), timeline_ms).to_radians();
+        if state.angle != Some(angle) {
+            state.angle = Some(angle);
+            if let Some(rotate) = rotate.upgrade() {
+                rotate.set_property("angle", angle);
+            }
+        }
+        gst::PadProbeReturn::Ok
+    });
+}
@@ -0,0 +1,101 @@
+use std::sync::{Arc, Mutex};
+use gstreamer as gst;
+use gst::prelude::*;
+
+use crate::common::types::{CropKeyframe, SourceRect};
+use crate::video::clip_timing::SharedClipTiming;
+
+/// Crop keyframes shared between the player and a clip's crop probe, so edits apply live
+pub type SharedCropKeyframes = Arc<Mutex<Vec<CropKeyframe>>>;
+
+/// Visible rect at `timeline_ms`, interpolated linearly between keyframes and held past the ends
+pub fn rect_at(keyframes: &[CropKeyframe], timeline_ms: f64) -> Option<SourceRect> {
+    match keyframes.iter().position(|k| k.time_ms as f64 > timeline_ms) {
+        None => keyframes.last().map(|k| k.rect),
+        Some(0) => Some(keyframes[0].rect),
+        Some(i) => {
+            let (a, b) = (&keyframes[i - 1], &keyframes[i]);
+            let t = (timeline_ms - a.time_ms as f64) / (b.time_ms - a.time_ms) as f64;
+            let lerp = |from: f64, to: f64| from + (to - from) * t;
+            Some(SourceRect {
+                x: lerp(a.rect.x, b.rect.x),
+                y: lerp(a.rect.y, b.rect.y),
+                width: lerp(a.rect.width, b.rect.width),
+                height: lerp(a.rect.height, b.rect.height),
+            })
+        }
+    }
+}
+
+/// `videocrop` left, right, top and bottom for showing `rect` of a `width`x`height` frame
+pub fn crop_pixels(rect: SourceRect, width: i32, height: i32) -> (i32, i32, i32, i32) {
+    let (width_f, height_f) = (width as f64, height as f64);
+    let left = ((rect.x * width_f).round() as i32).clamp(0, width - 1);
+    let top = ((rect.y * height_f).round() as i32).clamp(0, height - 1);
+    let right = ((width_f - (rect.x + rect.width) * width_f).round() as i32).clamp(0, width - 1 - left);
+    let bottom = ((height_f - (rect.y + rect.height) * height_f).round() as i32).clamp(0, height - 1 - top);
+    (left, right, top, bottom)
+}
+
+#[derive(Default)]
+struct CropState {
+    segment: Option<gst::FormattedSegment<gst::ClockTime>>,
+    size: Option<(i32, i32)>,
+    crop: Option<(i32, i32, i32, i32)>,
+}
+
+/// Move a `videocrop` along the clip's crop keyframes per frame. Without keyframes the crop
+/// is left to the fit placement.
+pub fn follow_crop_keyframes(videocrop: &gst::Element, keyframes: SharedCropKeyframes, timing: SharedClipTiming) {
+    let Some(sink_pad) = videocrop.static_pad("sink") else {
+        return;
+    };
+    let videocrop = videocrop.downgrade();
+    let state = Mutex::new(CropState::default());
+    sink_pad.add_probe(gst::PadProbeType::BUFFER | gst::PadProbeType::EVENT_DOWNSTREAM, move |_pad, info| {
+        let mut state = state.lock().unwrap();
+        let pts = match info.data {
+            Some(gst::PadProbeData::Event(ref event)) => {
+                match event.view() {
+                    gst::EventView::Segment(segment) => {
+                        state.segment = segment.segment().downcast_ref::<gst::ClockTime>().cloned();
+                    }
+                    gst::EventView::Caps(caps) => {
+                        state.size = caps.caps().structure(0)
+                            .and_then(|s| Some((s.get::<i32>("width").ok()?, s.get::<i32>("height").ok()?)));
+                    }
+                    _ => {}
+                }
+                return gst::PadProbeReturn::Ok;
+            }
+            Some(gst::PadProbeData::Buffer(ref buffer)) => buffer.pts(),
+            _ => return gst::PadProbeReturn::Ok,
+        };
+        let Some((width, height)) = state.size else {
+            return gst::PadProbeReturn::Ok;
+        };
+        let stream_time = state.segment.as_ref()
+            .zip(pts)
+            .and_then(|(segment, pts)| segment.to_stream_time(pts));
+        // Stream time is the source position; map it onto the timeline
+        let timing = *timing.lock().unwrap();
+        let timeline_ms = stream_time.map_or(timing.start_ms as f64, |time| timing.timeline_ms(time));
+        let Some(rect) = rect_at(&keyframes.lock().unwrap(), timeline_ms) else {
+            state.crop = None;
+            return gst::PadProbeReturn::Ok;
+        };
+
+        let crop = crop_pixels(rect, width, height);
+        if state.crop != Some(crop) {
+            state.crop = Some(crop);
+            if let Some(videocrop) = videocrop.upgrade() {
+                let (left, right, top, bottom) = crop;
+                videocrop.set_property("left", left);
+                videocrop.set_property("right", right);
+                videocrop.set_property("top", top);
+                videocrop.set_property("bottom", bottom);
+            }
+        }
+        gst::PadProbeReturn::Ok
+    });
+}
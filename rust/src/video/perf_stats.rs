@@ -0,0 +1,142 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use gstreamer as gst;
+use gst::prelude::*;
+
+use crate::common::types::PerformanceStats;
+
+// Frames the rolling averages cover, about two seconds at 60 FPS
+const WINDOW_FRAMES: usize = 120;
+// Buffers in flight per element before unmatched entries (e.g. dropped frames) are discarded
+const MAX_IN_FLIGHT: usize = 64;
+
+/// Stage of the decode -> convert -> texture path a timing belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Decode,
+    Convert,
+    Upload,
+}
+
+#[derive(Default)]
+struct Window {
+    samples: VecDeque<f64>,
+}
+
+impl Window {
+    fn push(&mut self, ms: f64) {
+        if self.samples.len() == WINDOW_FRAMES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(ms);
+    }
+
+    fn average(&self) -> f64 {
+        if self.samples.is_empty() {
+            0.0
+        } else {
+            self.samples.iter().sum::<f64>() / self.samples.len() as f64
+        }
+    }
+
+    fn max(&self) -> f64 {
+        self.samples.iter().copied().fold(0.0, f64::max)
+    }
+}
+
+/// Rolling per-frame timings of one player's video path
+#[derive(Default)]
+pub struct PerformanceCounters {
+    windows: Mutex<[Window; 3]>,
+    frames_rendered: AtomicU64,
+}
+
+impl PerformanceCounters {
+    pub fn record(&self, stage: Stage, elapsed: Duration) {
+        self.windows.lock().unwrap()[stage as usize].push(elapsed.as_secs_f64() * 1000.0);
+    }
+
+    pub fn frame_rendered(&self) {
+        self.frames_rendered.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn reset(&self) {
+        *self.windows.lock().unwrap() = Default::default();
+        self.frames_rendered.store(0, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> PerformanceStats {
+        let windows = self.windows.lock().unwrap();
+        let [decode, convert, upload] = &*windows;
+        PerformanceStats {
+            decode_ms: decode.average(),
+            convert_ms: convert.average(),
+            upload_ms: upload.average(),
+            decode_max_ms: decode.max(),
+            convert_max_ms: convert.max(),
+            upload_max_ms: upload.max(),
+            frames_rendered: self.frames_rendered.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Record how long buffers take to get through `element` as `stage`, matching each buffer
+/// leaving its source pad to the one with the same timestamp that entered its sink pad
+pub fn instrument_element(element: &gst::Element, stage: Stage, counters: &Arc<PerformanceCounters>) {
+    let (Some(sink_pad), Some(src_pad)) = (element.static_pad("sink"), element.static_pad("src")) else {
+        return;
+    };
+    let in_flight: Arc<Mutex<HashMap<gst::ClockTime, Instant>>> = Arc::new(Mutex::new(HashMap::new()));
+
+    let entered = Arc::clone(&in_flight);
+    sink_pad.add_probe(gst::PadProbeType::BUFFER, move |_, info| {
+        if let Some(pts) = info.buffer().and_then(|buffer| buffer.pts()) {
+            let mut entered = entered.lock().unwrap();
+            if entered.len() >= MAX_IN_FLIGHT {
+                entered.clear();
+            }
+            entered.insert(pts, Instant::now());
+        }
+        gst::PadProbeReturn::Ok
+    });
+
+    let counters = Arc::clone(counters);
+    src_pad.add_probe(gst::PadProbeType::BUFFER, move |_, info| {
+        if let Some(pts) = info.buffer().and_then(|buffer| buffer.pts()) {
+            if let Some(entered_at) = in_flight.lock().unwrap().remove(&pts) {
+                counters.record(stage, entered_at.elapsed());
+            }
+        }
+        gst::PadProbeReturn::Ok
+    });
+}
+
+/// Count the frames reaching `sink`, for sinks whose rendering can't be timed directly
+pub fn count_rendered_frames(sink: &gst::Element, counters: &Arc<PerformanceCounters>) {
+    let Some(pad) = sink.static_pad("sink") else {
+        return;
+    };
+    let counters = Arc::clone(counters);
+    pad.add_probe(gst::PadProbeType::BUFFER, move |_, _| {
+        counters.frame_rendered();
+        gst::PadProbeReturn::Ok
+    });
+}
+
+/// Time the video decoders `decodebin` plugs as they're added
+pub fn instrument_decoders(decodebin: &gst::Element, counters: &Arc<PerformanceCounters>) {
+    let Some(bin) = decodebin.downcast_ref::<gst::Bin>() else {
+        return;
+    };
+    let counters = Arc::clone(counters);
+    bin.connect_deep_element_added(move |_, _, element| {
+        let is_video_decoder = element
+            .factory()
+            .is_some_and(|factory| factory.klass().contains("Decoder") && factory.klass().contains("Video"));
+        if is_video_decoder {
+            instrument_element(element, Stage::Decode, &counters);
+        }
+    });
+}
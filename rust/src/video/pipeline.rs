@@ -4,14 +4,38 @@ use gstreamer_app::AppSink;
 use anyhow::{Result, Error};
 use log::{info, debug, error};
 use std::sync::{Arc, Mutex};
+use crate::audio_handler::{get_audio_engine_settings, AudioOutputTiming, AudioFormat, MediaData, MediaSender, PlayerGain};
+use crate::audio_downmix::mix_matrix;
 use crate::common::types::FrameData;
+use crate::video::direct_pipeline_player::follow_master_volume;
+use crate::video::irondash_texture::TextureUpdateFn;
+
+/// Where a pipeline's decoded audio is played
+#[derive(Clone)]
+pub enum AudioRoute {
+    /// Samples go to a cpal audio thread; video is delayed by its measured output latency
+    Cpal { sender: MediaSender, timing: Arc<AudioOutputTiming> },
+    /// Played by an autoaudiosink in the pipeline, so audio and video share the pipeline clock
+    GStreamer { gain: Arc<PlayerGain> },
+}
+
+/// Name of the volume element carrying a player's own gain on the GStreamer route
+pub const PLAYER_VOLUME: &str = "player_volume";
+
+// Smallest change of the video offset worth applying, to avoid re-syncing on every frame
+const VIDEO_OFFSET_STEP_NS: i64 = 5_000_000;
 
 pub struct VideoPipeline {
     pipeline: gstreamer::Pipeline,
 }
 
 impl VideoPipeline {
-    pub fn new(file_path: &str, frame_handler: Arc<Mutex<super::frame_handler::FrameHandler>>) -> Result<Self> {
+    pub fn new(
+        file_path: &str,
+        frame_handler: Arc<Mutex<super::frame_handler::FrameHandler>>,
+        texture_update_fn: Option<TextureUpdateFn>,
+        audio_route: Option<AudioRoute>,
+    ) -> Result<Self> {
         info!("Creating simplified GStreamer pipeline for: {}", file_path);
         gstreamer::init()?;
 
@@ -36,9 +60,30 @@ impl VideoPipeline {
         source.link(&decodebin)?;
         
         // Link videoconvert and appsink dynamically
+        let audio_timing = match audio_route {
+            Some(AudioRoute::Cpal { ref timing, .. }) => Some(Arc::clone(timing)),
+            _ => None,
+        };
         let videoconvert_weak = videoconvert.downgrade();
         let appsink_weak = appsink.downgrade();
+        let pipeline_weak = pipeline.downgrade();
         decodebin.connect_pad_added(move |_, src_pad| {
+            let is_audio = src_pad
+                .current_caps()
+                .and_then(|caps| caps.structure(0).map(|s| s.name().starts_with("audio/")))
+                .unwrap_or(false);
+            if is_audio {
+                if let (Some(pipeline), Some(route)) = (pipeline_weak.upgrade(), audio_route.as_ref()) {
+                    let result = match route {
+                        AudioRoute::Cpal { sender, .. } => Self::link_audio_output(&pipeline, src_pad, sender.clone()),
+                        AudioRoute::GStreamer { gain } => Self::link_audio_sink(&pipeline, src_pad, gain),
+                    };
+                    if let Err(e) = result {
+                        error!("Failed to link audio output: {}", e);
+                    }
+                }
+                return;
+            }
             if let (Some(videoconvert), Some(appsink)) = (videoconvert_weak.upgrade(), appsink_weak.upgrade()) {
                 let sink_pad = videoconvert.static_pad("sink").expect("Failed to get sink pad from videoconvert");
                 if sink_pad.is_linked() {
@@ -57,7 +102,10 @@ impl VideoPipeline {
         appsink.set_callbacks(
             gstreamer_app::AppSinkCallbacks::builder()
                 .new_sample(move |sink| {
-                    match Self::on_new_sample(sink, &frame_handler) {
+                    if let Some(ref timing) = audio_timing {
+                        Self::follow_audio_timing(sink, timing);
+                    }
+                    match Self::on_new_sample(sink, &frame_handler, texture_update_fn.as_ref()) {
                         Ok(_) => (),
                         Err(e) => error!("Error processing new sample: {}", e),
                     }
@@ -69,7 +117,120 @@ impl VideoPipeline {
         Ok(Self { pipeline })
     }
 
-    pub fn new_dual(file_path_left: &str, file_path_right: &str, frame_handler: Arc<Mutex<super::frame_handler::FrameHandler>>) -> Result<Self> {
+    /// Delay video by the cpal output latency, which with the audio clock as master includes
+    /// however far the output has drifted behind the pipeline clock
+    fn follow_audio_timing(sink: &AppSink, timing: &AudioOutputTiming) {
+        let offset_ns = timing.latency_ns();
+        let current_ns = sink.property::<i64>("ts-offset");
+        if (offset_ns - current_ns).abs() >= VIDEO_OFFSET_STEP_NS {
+            debug!("Video offset following audio output: {}ms (drift {}ms)", offset_ns / 1_000_000, timing.drift_ns() / 1_000_000);
+            sink.set_property("ts-offset", offset_ns);
+        }
+    }
+
+    /// Feed decoded audio to the audio thread as interleaved f32 in the engine's channel layout
+    /// at the source rate; the audio handler resamples it to the output device rate
+    fn link_audio_output(pipeline: &gst::Pipeline, src_pad: &gst::Pad, audio_sender: MediaSender) -> Result<()> {
+        let settings = get_audio_engine_settings();
+        let layout = settings.channel_layout;
+        let audioconvert = gst::ElementFactory::make("audioconvert").build()?;
+        if let Some(matrix) = src_pad.current_caps().and_then(|caps| mix_matrix(settings.downmix, &caps, layout)) {
+            audioconvert.set_property("mix-matrix", matrix);
+        }
+        let audio_appsink = gst::ElementFactory::make("appsink")
+            .property("sync", true)
+            .build()?
+            .downcast::<AppSink>()
+            .map_err(|_| Error::msg("Failed to downcast audio appsink"))?;
+        audio_appsink.set_caps(Some(
+            &gst::Caps::builder("audio/x-raw")
+                .field("format", "F32LE")
+                .field("layout", "interleaved")
+                .field("channels", layout.channels() as i32)
+                .field("channel-mask", gst::Bitmask::new(layout.channel_mask()))
+                .build(),
+        ));
+
+        pipeline.add_many([&audioconvert, audio_appsink.upcast_ref()])?;
+        audioconvert.link(&audio_appsink)?;
+        src_pad.link(&audioconvert.static_pad("sink").ok_or_else(|| Error::msg("audioconvert has no sink pad"))?)?;
+
+        let last_format = Mutex::new(None::<(u32, u32)>);
+        audio_appsink.set_callbacks(
+            gstreamer_app::AppSinkCallbacks::builder()
+                .new_sample(move |sink| {
+                    let sample = sink.pull_sample().map_err(|_| gst::FlowError::Eos)?;
+                    let (Some(buffer), Some(caps)) = (sample.buffer(), sample.caps()) else {
+                        return Ok(gst::FlowSuccess::Ok);
+                    };
+                    let Ok(info) = gstreamer_audio::AudioInfo::from_caps(caps) else {
+                        return Ok(gst::FlowSuccess::Ok);
+                    };
+
+                    // Announce the source format whenever it changes so the handler resamples from it
+                    let format = (info.rate(), info.channels());
+                    let mut last_format = last_format.lock().unwrap();
+                    if *last_format != Some(format) {
+                        *last_format = Some(format);
+                        let _ = audio_sender.send(MediaData::AudioFormat(AudioFormat {
+                            sample_rate: info.rate(),
+                            channels: info.channels(),
+                            bytes_per_sample: 4,
+                        }));
+                    }
+
+                    let map = buffer.map_readable().map_err(|_| gst::FlowError::Error)?;
+                    let samples = map
+                        .as_slice()
+                        .chunks_exact(4)
+                        .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                        .collect();
+                    if audio_sender.send(MediaData::AudioSamples(samples)).is_err() {
+                        return Err(gst::FlowError::Flushing);
+                    }
+                    Ok(gst::FlowSuccess::Ok)
+                })
+                .build(),
+        );
+
+        audioconvert.sync_state_with_parent()?;
+        audio_appsink.sync_state_with_parent()?;
+        debug!("Linked audio output to the audio thread");
+        Ok(())
+    }
+
+    /// Play decoded audio through an autoaudiosink in this pipeline, so the sink's clock and
+    /// latency are handled by GStreamer instead of the cpal bridge
+    fn link_audio_sink(pipeline: &gst::Pipeline, src_pad: &gst::Pad, gain: &PlayerGain) -> Result<()> {
+        let settings = get_audio_engine_settings();
+        let audioconvert = gst::ElementFactory::make("audioconvert").build()?;
+        if let Some(matrix) = src_pad.current_caps().and_then(|caps| mix_matrix(settings.downmix, &caps, settings.channel_layout)) {
+            audioconvert.set_property("mix-matrix", matrix);
+        }
+        let audioresample = gst::ElementFactory::make("audioresample").build()?;
+        let volume = gst::ElementFactory::make("volume").build()?;
+        follow_master_volume(&volume);
+        let player_volume = gst::ElementFactory::make("volume")
+            .name(PLAYER_VOLUME)
+            .property("volume", gain.volume())
+            .property("mute", gain.is_muted())
+            .build()?;
+        let sink = gst::ElementFactory::make("autoaudiosink")
+            .property("sync", true)
+            .build()?;
+
+        pipeline.add_many([&audioconvert, &audioresample, &player_volume, &volume, &sink])?;
+        gst::Element::link_many([&audioconvert, &audioresample, &player_volume, &volume, &sink])?;
+        src_pad.link(&audioconvert.static_pad("sink").ok_or_else(|| Error::msg("audioconvert has no sink pad"))?)?;
+
+        for element in [&audioconvert, &audioresample, &player_volume, &volume, &sink] {
+            element.sync_state_with_parent()?;
+        }
+        debug!("Linked audio output to autoaudiosink");
+        Ok(())
+    }
+
+    pub fn new_dual(file_path_left: &str, file_path_right: &str, frame_handler: Arc<Mutex<super::frame_handler::FrameHandler>>, texture_update_fn: Option<TextureUpdateFn>) -> Result<Self> {
         info!("Creating dual video pipeline: left={}, right={}", file_path_left, file_path_right);
         gstreamer::init()?;
 
@@ -99,7 +260,7 @@ impl VideoPipeline {
         appsink.set_callbacks(
             gstreamer_app::AppSinkCallbacks::builder()
                 .new_sample(move |sink| {
-                    match Self::on_new_sample(sink, &frame_handler) {
+                    match Self::on_new_sample(sink, &frame_handler, texture_update_fn.as_ref()) {
                         Ok(_) => (),
                         Err(e) => error!("Error processing new sample: {}", e),
                     }
@@ -114,6 +275,7 @@ impl VideoPipeline {
     fn on_new_sample(
         sink: &AppSink,
         frame_handler: &Arc<Mutex<super::frame_handler::FrameHandler>>,
+        texture_update_fn: Option<&TextureUpdateFn>,
     ) -> Result<()> {
         let sample = sink.pull_sample().map_err(|_| Error::msg("Failed to pull sample"))?;
         let buffer = sample.buffer().ok_or_else(|| Error::msg("Failed to get buffer"))?;
@@ -123,15 +285,15 @@ impl VideoPipeline {
         let map = buffer.map_readable().map_err(|_| Error::msg("Failed to map buffer"))?;
         
         let frame_data = FrameData {
-            data: map.as_slice().to_vec(),
+            data: frame_handler.lock().unwrap().buffer_pool.lock().unwrap().buffer_from_slice(map.as_slice()),
             width: info.width(),
             height: info.height(),
             texture_id: None, // Not used in this simplified path
         };
 
-        // Directly update the irondash texture
-        if let Err(e) = crate::video::irondash_texture::update_video_frame(frame_data) {
-             error!("Failed to update irondash video frame: {}", e);
+        // Directly update this pipeline's irondash texture
+        if let Some(update_fn) = texture_update_fn {
+            update_fn(frame_data);
         }
 
         // Also update the frame handler's dimensions so the UI can get the correct aspect ratio
@@ -171,6 +333,8 @@ pub struct PipelineManager {
     inner: Option<VideoPipeline>,
     // Keep original fields that caller passes but we no longer use
     _frame_handler: Arc<Mutex<super::frame_handler::FrameHandler>>,
+    texture_update_fn: Option<TextureUpdateFn>,
+    audio_route: Option<AudioRoute>,
 }
 
 impl PipelineManager {
@@ -178,17 +342,21 @@ impl PipelineManager {
     pub fn new(
         frame_handler: super::frame_handler::FrameHandler,
         _frame_callback: Arc<Mutex<Option<crate::video::player::FrameCallback>>>,
+        texture_update_fn: Option<TextureUpdateFn>,
+        audio_route: Option<AudioRoute>,
     ) -> Result<Self, String> {
         Ok(Self {
             pipeline: None,
             inner: None,
             _frame_handler: Arc::new(Mutex::new(frame_handler)),
+            texture_update_fn,
+            audio_route,
         })
     }
 
     pub fn create_pipeline(&mut self, file_path: &str) -> Result<(), String> {
         // Build VideoPipeline lazily
-        let vp = VideoPipeline::new(file_path, self._frame_handler.clone())
+        let vp = VideoPipeline::new(file_path, self._frame_handler.clone(), self.texture_update_fn.clone(), self.audio_route.clone())
             .map_err(|e| format!("Failed to create video pipeline: {}", e))?;
         self.pipeline = Some(vp.pipeline.clone());
         self.inner = Some(vp);
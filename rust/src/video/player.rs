@@ -1,7 +1,8 @@
-use crate::audio_handler::{MediaSender, MediaData, AudioFormat, start_audio_thread};
-use crate::common::types::FrameData;
+use crate::audio_handler::{AudioSession, MediaData, PlayerGain};
+use crate::common::types::{AudioOutputMode, FrameData};
 use crate::video::frame_handler::FrameHandler;
-use crate::video::pipeline::PipelineManager;
+use crate::video::irondash_texture::{create_player_texture, TextureUpdateFn};
+use crate::video::pipeline::{AudioRoute, PipelineManager, PLAYER_VOLUME};
 use gstreamer as gst;
 use gstreamer::prelude::*;
 use gstreamer_video as gst_video;
@@ -19,8 +20,10 @@ pub struct VideoPlayer {
     pub pipeline_manager: Option<PipelineManager>,
     pub frame_handler: FrameHandler,
     pub is_playing: Arc<Mutex<bool>>,
-    // Audio-related fields
-    pub audio_sender: Option<MediaSender>,
+    // This player's own cpal output, if it doesn't play audio through the pipeline
+    audio_session: Option<AudioSession>,
+    audio_route: AudioRoute,
+    audio_gain: Arc<PlayerGain>,
     // Seeking-related fields
     pub duration: Arc<Mutex<Option<u64>>>, // Duration in nanoseconds
     pub seekable: Arc<Mutex<bool>>,
@@ -35,18 +38,38 @@ pub struct VideoPlayer {
     timer_handle: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
     // Timer running flag
     timer_running: Arc<Mutex<bool>>,
+    // Interval between position updates while playing
+    position_update_interval_ms: Arc<Mutex<u64>>,
+    // Feeds this player's own texture, if one was created
+    texture_update_fn: Option<TextureUpdateFn>,
 }
 
 impl VideoPlayer {
     pub fn new() -> Self {
-        // Initialize audio system
-        let audio_sender = start_audio_thread();
+        Self::with_audio_output(AudioOutputMode::default())
+    }
+
+    /// Create a player whose audio is played by the cpal thread or by the pipeline itself
+    pub fn with_audio_output(mode: AudioOutputMode) -> Self {
+        // Initialize audio system; with GStreamer output the pipeline plays audio on its own clock
+        let audio_gain = Arc::new(PlayerGain::default());
+        let (audio_session, audio_route) = match mode {
+            AudioOutputMode::Cpal => {
+                let session = AudioSession::start(Arc::clone(&audio_gain));
+                let route = AudioRoute::Cpal { sender: session.sender(), timing: session.timing() };
+                (Some(session), route)
+            }
+            AudioOutputMode::GStreamer => (None, AudioRoute::GStreamer { gain: Arc::clone(&audio_gain) }),
+        };
+        info!("Video player audio output: {:?}", mode);
         
         Self {
             pipeline_manager: None,
             frame_handler: FrameHandler::new(),
             is_playing: Arc::new(Mutex::new(false)),
-            audio_sender: Some(audio_sender),
+            audio_session,
+            audio_route,
+            audio_gain,
             duration: Arc::new(Mutex::new(None)),
             seekable: Arc::new(Mutex::new(false)),
             file_path: None,
@@ -55,9 +78,18 @@ impl VideoPlayer {
             position_callback: Arc::new(Mutex::new(None)),
             timer_handle: Arc::new(Mutex::new(None)),
             timer_running: Arc::new(Mutex::new(false)),
+            position_update_interval_ms: Arc::new(Mutex::new(16)),
+            texture_update_fn: None,
         }
     }
 
+    /// Create the texture this player renders into; used by videos loaded afterwards
+    pub fn create_texture(&mut self, engine_handle: i64) -> Result<i64> {
+        let (texture_id, update_fn) = create_player_texture(1920, 1080, engine_handle, self.frame_handler.buffer_pool.clone())?;
+        self.texture_update_fn = Some(update_fn);
+        Ok(texture_id)
+    }
+
     pub fn set_texture_ptr(&mut self, ptr: i64) {
         self.frame_handler.set_texture_ptr(ptr);
     }
@@ -89,6 +121,8 @@ impl VideoPlayer {
         let mut pipeline_manager = PipelineManager::new(
             self.frame_handler.clone(),
             self.frame_callback.clone(),
+            self.texture_update_fn.clone(),
+            Some(self.audio_route.clone()),
         )?;
 
         // Load the video through pipeline manager
@@ -184,23 +218,9 @@ impl VideoPlayer {
             // Duration and seekability already queried during load_video()
             // No need to query again here
             
-            // Initialize audio system only if we're actually playing
+            // Resume audio output; the pipeline announces the source format with its first samples
             if self.is_playing() {
-                if let Some(ref audio_sender) = self.audio_sender {
-                    let audio_format = AudioFormat {
-                        sample_rate: 44100,
-                        channels: 2,
-                        bytes_per_sample: 2,
-                    };
-                    
-                    if let Err(e) = audio_sender.send(MediaData::AudioFormat(audio_format)) {
-                        warn!("Failed to send audio format: {}", e);
-                    }
-                    
-                    if let Err(e) = audio_sender.send(MediaData::Resume) {
-                        warn!("Failed to send resume command: {}", e);
-                    }
-                }
+                self.send_audio(MediaData::Resume);
             }
             
             // Start position update timer when playing
@@ -217,11 +237,7 @@ impl VideoPlayer {
         
         if let Some(pipeline_manager) = &mut self.pipeline_manager {
             // Send pause command to audio system first
-            if let Some(ref audio_sender) = self.audio_sender {
-                if let Err(e) = audio_sender.send(MediaData::Pause) {
-                    warn!("Failed to send pause command to audio system: {}", e);
-                }
-            }
+            self.send_audio(MediaData::Pause);
             
             info!("Calling pipeline_manager.pause()");
             let result = pipeline_manager.pause()?;
@@ -251,11 +267,7 @@ impl VideoPlayer {
     pub fn stop(&mut self) -> Result<(), String> {
         if let Some(pipeline_manager) = &mut self.pipeline_manager {
             // Stop audio playback
-            if let Some(ref audio_sender) = self.audio_sender {
-                if let Err(e) = audio_sender.send(MediaData::Stop) {
-                    warn!("Failed to send stop signal to audio system: {}", e);
-                }
-            }
+            self.send_audio(MediaData::Stop);
             
             let result = pipeline_manager.stop()?;
             *self.is_playing.lock().unwrap() = false;
@@ -300,6 +312,55 @@ impl VideoPlayer {
         false
     }
 
+    /// Delay between audio leaving the pipeline and reaching the speaker, which video is held back by;
+    /// zero with GStreamer output, where the sink's latency is part of the pipeline's own
+    pub fn get_audio_latency_ms(&self) -> f64 {
+        match self.audio_route {
+            AudioRoute::Cpal { ref timing, .. } => timing.latency_ns() as f64 / 1_000_000.0,
+            AudioRoute::GStreamer { .. } => 0.0,
+        }
+    }
+
+    fn send_audio(&self, data: MediaData) {
+        if let Some(ref session) = self.audio_session {
+            session.send(data);
+        }
+    }
+
+    /// This player's volume (1.0 = unity), applied on top of the master volume
+    pub fn set_volume(&self, gain: f64) {
+        self.audio_gain.set_volume(gain);
+        self.apply_pipeline_gain();
+    }
+
+    pub fn get_volume(&self) -> f64 {
+        self.audio_gain.volume()
+    }
+
+    /// Mute this player without affecting other players
+    pub fn set_muted(&self, muted: bool) {
+        self.audio_gain.set_muted(muted);
+        self.apply_pipeline_gain();
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.audio_gain.is_muted()
+    }
+
+    /// With GStreamer output the gain lives on the pipeline's volume element; the cpal
+    /// session reads it for every buffer
+    fn apply_pipeline_gain(&self) {
+        let volume = self.pipeline_manager
+            .as_ref()
+            .and_then(|manager| manager.pipeline.as_ref())
+            .and_then(|pipeline| pipeline.by_name(PLAYER_VOLUME));
+        if let Some(volume) = volume {
+            let gain = &self.audio_gain;
+            volume.set_property("volume", gain.volume());
+            volume.set_property("mute", gain.is_muted());
+        }
+    }
+
     pub fn dispose(&mut self) -> Result<(), String> {
         info!("Disposing VideoPlayer");
         
@@ -307,11 +368,7 @@ impl VideoPlayer {
         self.stop_position_timer();
         
         // Stop audio playback
-        if let Some(ref audio_sender) = self.audio_sender {
-            if let Err(e) = audio_sender.send(MediaData::Stop) {
-                warn!("Failed to send stop signal during dispose: {}", e);
-            }
-        }
+        self.send_audio(MediaData::Stop);
         
         if let Some(pipeline_manager) = &mut self.pipeline_manager {
             pipeline_manager.dispose()?;
@@ -348,6 +405,7 @@ impl VideoPlayer {
         let is_playing = Arc::clone(&self.is_playing);
         let timer_running = Arc::clone(&self.timer_running);
         let position_callback = Arc::clone(&self.position_callback);
+        let position_update_interval_ms = Arc::clone(&self.position_update_interval_ms);
         
         // Get pipeline reference for position queries
         let pipeline_ref = if let Some(pipeline_manager) = &self.pipeline_manager {
@@ -412,8 +470,9 @@ impl VideoPlayer {
                     start_time = std::time::Instant::now();
                 }
                 
-                // Update at ~60 FPS for smooth position updates
-                thread::sleep(Duration::from_millis(16));
+                // Defaults to ~60 FPS for smooth position updates
+                let interval_ms = *position_update_interval_ms.lock().unwrap();
+                thread::sleep(Duration::from_millis(interval_ms));
             }
             
             info!("Position timer thread stopped");
@@ -424,6 +483,12 @@ impl VideoPlayer {
         *timer_handle_guard = Some(handle);
     }
     
+    /// Change how often positions are pushed while playing
+    pub fn set_position_update_interval_ms(&self, interval_ms: u64) {
+        *self.position_update_interval_ms.lock().unwrap() = interval_ms.max(1);
+        info!("Position update interval set to {}ms", interval_ms.max(1));
+    }
+    
     fn stop_position_timer(&self) {
         debug!("Stopping position update timer");
         
@@ -537,9 +602,7 @@ impl VideoPlayer {
                     
                     // Update internal state and ensure audio is paused
                     *self.is_playing.lock().unwrap() = false;
-                    if let Some(ref audio_sender) = self.audio_sender {
-                        let _ = audio_sender.send(MediaData::Pause);
-                    }
+                    self.send_audio(MediaData::Pause);
                     
                     info!("Pipeline prepared for seek");
                     
@@ -609,11 +672,7 @@ impl VideoPlayer {
                                 
                                 // Resume audio if we're actually playing
                                 if self.is_playing() {
-                                    if let Some(ref audio_sender) = self.audio_sender {
-                                        if let Err(e) = audio_sender.send(MediaData::Resume) {
-                                            warn!("Failed to resume audio: {}", e);
-                                        }
-                                    }
+                                    self.send_audio(MediaData::Resume);
                                 }
                             }
                         } else {
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use anyhow::{anyhow, Result};
+use log::info;
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::types::{MonitorRole, PlayerTexture};
+use crate::video::direct_pipeline_player::DirectPipelinePlayer;
+use crate::video::gst_worker::PlayerHandle;
+
+/// Identifies a player: the Flutter engine (window) it renders into and its id within the registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PlayerKey {
+    pub engine_handle: i64,
+    pub player_id: u32,
+}
+
+type SharedPlayer = Arc<PlayerHandle>;
+
+static NEXT_PLAYER_ID: AtomicU32 = AtomicU32::new(1);
+
+lazy_static::lazy_static! {
+    /// Every live player, shared by all threads the bridge calls in on. Each runs on its own
+    /// worker thread so a slow load doesn't stall the others.
+    static ref PLAYERS: Mutex<HashMap<PlayerKey, SharedPlayer>> = Mutex::new(HashMap::new());
+    /// Which player backs each preview monitor
+    static ref MONITORS: Mutex<HashMap<MonitorRole, PlayerKey>> = Mutex::new(HashMap::new());
+}
+
+/// Create a player with its own texture on `engine_handle`.
+/// Must not be called from the platform main thread since texture creation waits on it.
+pub fn create_player(engine_handle: i64) -> Result<PlayerTexture> {
+    // Build outside the registry lock - texture creation blocks on the main thread
+    let player = PlayerHandle::spawn()?;
+    let texture_id = player.call(move |player| player.create_texture(engine_handle))?;
+
+    let player_id = NEXT_PLAYER_ID.fetch_add(1, Ordering::Relaxed);
+    let key = PlayerKey { engine_handle, player_id };
+    PLAYERS.lock().unwrap().insert(key, Arc::new(player));
+    info!("Registered player {:?} with texture {}", key, texture_id);
+    Ok(PlayerTexture { player_id, texture_id })
+}
+
+/// Run `f` against a registered player, on that player's worker
+pub fn with_player<R: Send + 'static>(
+    key: PlayerKey,
+    f: impl FnOnce(&mut DirectPipelinePlayer) -> Result<R> + Send + 'static,
+) -> Result<R> {
+    let player = PLAYERS
+        .lock()
+        .unwrap()
+        .get(&key)
+        .cloned()
+        .ok_or_else(|| anyhow!(FlipEditError::new(
+            FlipEditErrorCode::NotLoaded,
+            format!("Player {} on engine {} doesn't exist or was disposed", key.player_id, key.engine_handle),
+        )))?;
+    player.call(f)
+}
+
+/// Dispose and remove a player, if registered
+pub fn dispose_player(key: PlayerKey) -> Result<()> {
+    MONITORS.lock().unwrap().retain(|_, monitor_key| *monitor_key != key);
+    let player = PLAYERS.lock().unwrap().remove(&key);
+    if let Some(player) = player {
+        // Dropping the last handle stops the thread once any in-flight command finishes
+        player.call(|player| player.dispose())?;
+        info!("Disposed player {:?}", key);
+    }
+    Ok(())
+}
+
+/// Dispose every player rendering into `engine_handle`, e.g. when its window closes
+pub fn dispose_engine_players(engine_handle: i64) -> Result<()> {
+    let keys: Vec<PlayerKey> = PLAYERS
+        .lock()
+        .unwrap()
+        .keys()
+        .filter(|key| key.engine_handle == engine_handle)
+        .copied()
+        .collect();
+    for key in keys {
+        dispose_player(key)?;
+    }
+    Ok(())
+}
+
+/// Players currently registered on `engine_handle`
+pub fn engine_player_ids(engine_handle: i64) -> Vec<u32> {
+    PLAYERS
+        .lock()
+        .unwrap()
+        .keys()
+        .filter(|key| key.engine_handle == engine_handle)
+        .map(|key| key.player_id)
+        .collect()
+}
+
+/// Create the player for a preview monitor, replacing any existing one
+pub fn create_monitor(role: MonitorRole, engine_handle: i64) -> Result<i64> {
+    dispose_monitor(role)?;
+
+    let player = create_player(engine_handle)?;
+    let key = PlayerKey { engine_handle, player_id: player.player_id };
+    MONITORS.lock().unwrap().insert(role, key);
+    info!("{:?} monitor uses player {:?}", role, key);
+    Ok(player.texture_id)
+}
+
+/// Run `f` against the player backing `role`
+pub fn with_monitor<R: Send + 'static>(
+    role: MonitorRole,
+    f: impl FnOnce(&mut DirectPipelinePlayer) -> Result<R> + Send + 'static,
+) -> Result<R> {
+    let key = MONITORS
+        .lock()
+        .unwrap()
+        .get(&role)
+        .copied()
+        .ok_or_else(|| anyhow!(FlipEditError::new(
+            FlipEditErrorCode::NotLoaded,
+            format!("No {:?} monitor has been created", role),
+        )))?;
+    with_player(key, f)
+}
+
+/// Dispose the player backing `role`, if any
+pub fn dispose_monitor(role: MonitorRole) -> Result<()> {
+    let key = MONITORS.lock().unwrap().get(&role).copied();
+    match key {
+        Some(key) => dispose_player(key),
+        None => Ok(()),
+    }
+}
+
+/// Roles that currently have a player
+pub fn active_roles() -> Vec<MonitorRole> {
+    MONITORS.lock().unwrap().keys().copied().collect()
+}
@@ -0,0 +1,750 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::UNIX_EPOCH;
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{debug, info, warn};
+
+use crate::audio_automation;
+use crate::audio_effects;
+use crate::captions;
+use crate::common::error::FlipEditError;
+use crate::common::media_uri::source_uri;
+use crate::video::clip_index::{self, ClipIndex};
+use crate::video::clip_timing::{apply_clip_timing, ClipTiming};
+use crate::video::direct_pipeline_player::discard_pad;
+use crate::video::transitions::ClipFades;
+use crate::common::types::{CaptionAppearance, CaptionCue, ClipFitMode, ClipOrientation, RenderSegment, RenderStatus, TimelineClip, TimelineData, TimelineTrack, TrackAudioEffect, TrackType, VolumeKeyframe};
+
+/// Ranges with at least this many overlapping clips are rendered by default
+pub const DEFAULT_MIN_LAYERS: usize = 3;
+
+// Cached segments are rendered at half the 1920x1080 preview canvas
+const CANVAS_WIDTH: f64 = 1920.0;
+const CANVAS_HEIGHT: f64 = 1080.0;
+const RENDER_WIDTH: i32 = 960;
+const RENDER_HEIGHT: i32 = 540;
+
+/// Track id used for the substituted cached clips
+pub const RENDER_TRACK_ID: i32 = -1;
+
+pub type RenderStatusCallback = Box<dyn Fn(RenderSegment) -> Result<()> + Send + Sync>;
+
+/// Renders heavy timeline ranges to disk while playback is idle, and swaps the cached
+/// files in for the original clips when the timeline is (re)loaded.
+pub struct RenderCache {
+    cache_dir: Option<PathBuf>,
+    min_layers: usize,
+    segments: Arc<Mutex<Vec<RenderSegment>>>,
+    status_callback: Arc<Mutex<Option<RenderStatusCallback>>>,
+    cancel: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+    // Drawn into rendered segments when captions are burnt in
+    caption_appearance: Option<CaptionAppearance>,
+}
+
+struct RenderJob {
+    index: usize,
+    clips: Vec<TimelineClip>,
+    track_audio: HashMap<i32, TrackAudio>,
+    captions: Option<BurntCaptions>,
+}
+
+/// Cues over a segment's range and their look, for burning into its render
+#[derive(Debug, Clone)]
+struct BurntCaptions {
+    cues: Vec<CaptionCue>,
+    appearance: CaptionAppearance,
+}
+
+/// Per-track audio processing baked into rendered segments
+#[derive(Debug, Clone, Default)]
+struct TrackAudio {
+    effects: Vec<TrackAudioEffect>,
+    volume_keyframes: Vec<VolumeKeyframe>,
+}
+
+impl RenderCache {
+    pub fn new() -> Self {
+        Self {
+            cache_dir: None,
+            min_layers: DEFAULT_MIN_LAYERS,
+            segments: Arc::new(Mutex::new(Vec::new())),
+            status_callback: Arc::new(Mutex::new(None)),
+            cancel: Arc::new(AtomicBool::new(false)),
+            worker: None,
+            caption_appearance: None,
+        }
+    }
+
+    /// Start caching into `cache_dir`; takes effect on the next `update_timeline`
+    pub fn enable(&mut self, cache_dir: &str, min_layers: usize) -> Result<()> {
+        std::fs::create_dir_all(cache_dir)
+            .map_err(|e| anyhow!("Failed to create render cache directory {}: {}", cache_dir, e))?;
+        self.cache_dir = Some(PathBuf::from(cache_dir));
+        self.min_layers = min_layers.max(1);
+        info!("Background rendering enabled in {} (min {} layers)", cache_dir, self.min_layers);
+        Ok(())
+    }
+
+    /// Stop rendering and forget all segments. Rendered files stay on disk for reuse.
+    pub fn disable(&mut self) {
+        self.stop_worker();
+        self.cache_dir = None;
+        self.segments.lock().unwrap().clear();
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.cache_dir.is_some()
+    }
+
+    /// Burn the caption track into segments, drawn with `appearance`, or render them without
+    /// captions for `None`; takes effect on the next `update_timeline`
+    pub fn set_caption_burn_in(&mut self, appearance: Option<CaptionAppearance>) {
+        self.caption_appearance = appearance;
+    }
+
+    pub fn burns_captions(&self) -> bool {
+        self.caption_appearance.is_some()
+    }
+
+    pub fn set_status_callback(&self, callback: RenderStatusCallback) {
+        *self.status_callback.lock().unwrap() = Some(callback);
+    }
+
+    pub fn get_segments(&self) -> Vec<RenderSegment> {
+        self.segments.lock().unwrap().clone()
+    }
+
+    /// Recompute heavy segments for `timeline` and render the missing ones in the background.
+    /// Rendering yields whenever `is_playing` is set.
+    pub fn update_timeline(&mut self, timeline: &TimelineData, is_playing: Arc<Mutex<bool>>) {
+        self.stop_worker();
+
+        let Some(cache_dir) = self.cache_dir.clone() else {
+            return;
+        };
+
+        let mut segments = Vec::new();
+        let mut jobs = Vec::new();
+        let index = ClipIndex::new(timeline);
+        for (start_ms, end_ms) in find_heavy_segments(&index, self.min_layers) {
+            let clips = clips_in_range(timeline, &index, start_ms, end_ms);
+            let track_audio = track_audio_of(timeline, &clips);
+            let captions = self.caption_appearance.as_ref().map(|appearance| BurntCaptions {
+                cues: timeline.captions.iter()
+                    .filter(|cue| (cue.start_ms as i64) < end_ms as i64 && cue.end_ms as i64 > start_ms as i64)
+                    .cloned()
+                    .collect(),
+                appearance: appearance.clone(),
+            });
+            let key = segment_key(&clips, &track_audio, captions.as_ref(), start_ms, end_ms);
+            let cache_path = cache_dir.join(format!("segment_{:016x}.mp4", key));
+
+            // Identical content was rendered before, e.g. prior to an unrelated edit
+            let status = if cache_path.exists() {
+                RenderStatus::Rendered
+            } else {
+                jobs.push(RenderJob { index: segments.len(), clips, track_audio, captions });
+                RenderStatus::Pending
+            };
+
+            segments.push(RenderSegment {
+                start_ms,
+                end_ms,
+                status,
+                cache_path: cache_path.to_string_lossy().to_string(),
+            });
+        }
+
+        info!("Render cache: {} heavy segments, {} to render", segments.len(), jobs.len());
+        *self.segments.lock().unwrap() = segments.clone();
+        for segment in segments {
+            Self::emit_status(&self.status_callback, segment);
+        }
+
+        if jobs.is_empty() {
+            return;
+        }
+
+        let segments = Arc::clone(&self.segments);
+        let status_callback = Arc::clone(&self.status_callback);
+        let cancel = Arc::clone(&self.cancel);
+        self.worker = Some(std::thread::spawn(move || {
+            Self::run_jobs(jobs, segments, status_callback, cancel, is_playing);
+        }));
+    }
+
+    /// Replace clips inside rendered segments with the cached file on a top-level track. Caption
+    /// cues burnt into those files are cut from the returned caption track.
+    pub fn substitute(&self, timeline: &TimelineData) -> TimelineData {
+        let rendered: Vec<RenderSegment> = self.segments.lock().unwrap()
+            .iter()
+            .filter(|s| s.status == RenderStatus::Rendered)
+            .cloned()
+            .collect();
+        if rendered.is_empty() {
+            return timeline.clone();
+        }
+
+        let is_cached = |clip: &TimelineClip| rendered.iter().any(|s| {
+            clip.start_time_on_track_ms as u64 >= s.start_ms && clip.end_time_on_track_ms as u64 <= s.end_ms
+        });
+
+        let mut tracks: Vec<TimelineTrack> = timeline.tracks.iter()
+            .map(|track| TimelineTrack {
+                clips: track.clips.iter().filter(|c| !is_cached(c)).cloned().collect(),
+                ..track.clone()
+            })
+            .collect();
+
+        tracks.push(TimelineTrack {
+            id: RENDER_TRACK_ID,
+            name: "Rendered".to_string(),
+            // Effects and automation are already baked into the rendered audio
+            audio_effects: Vec::new(),
+            volume_keyframes: Vec::new(),
+            track_type: TrackType::Both,
+            // Drawn over the tracks it replaces, whatever their priorities
+            priority: i32::MAX,
+            clips: rendered.iter().map(|s| TimelineClip {
+                id: None,
+                track_id: RENDER_TRACK_ID,
+                source_path: s.cache_path.clone(),
+                start_time_on_track_ms: s.start_ms as i32,
+                end_time_on_track_ms: s.end_ms as i32,
+                start_time_in_source_ms: 0,
+                end_time_in_source_ms: (s.end_ms - s.start_ms) as i32,
+                preview_position_x: 0.0,
+                preview_position_y: 0.0,
+                preview_width: CANVAS_WIDTH,
+                preview_height: CANVAS_HEIGHT,
+                preview_opacity: 1.0,
+                track_type: TrackType::Both,
+                fit_mode: ClipFitMode::default(),
+                orientation: ClipOrientation::default(),
+                crop_keyframes: Vec::new(),
+                audio_effects: Vec::new(),
+                tone_mapping: None,
+                transition_in_ms: 0,
+            }).collect(),
+        });
+
+        let captions = if self.burns_captions() {
+            let ranges: Vec<(i32, i32)> = rendered.iter().map(|s| (s.start_ms as i32, s.end_ms as i32)).collect();
+            captions::outside_ranges(&timeline.captions, &ranges)
+        } else {
+            timeline.captions.clone()
+        };
+
+        debug!("Substituted {} rendered segments into timeline", rendered.len());
+        TimelineData { tracks, captions, markers: timeline.markers.clone(), auto_transition: timeline.auto_transition }
+    }
+
+    fn stop_worker(&mut self) {
+        if let Some(worker) = self.worker.take() {
+            self.cancel.store(true, Ordering::SeqCst);
+            if worker.join().is_err() {
+                warn!("Render worker panicked");
+            }
+        }
+        self.cancel = Arc::new(AtomicBool::new(false));
+    }
+
+    fn run_jobs(
+        jobs: Vec<RenderJob>,
+        segments: Arc<Mutex<Vec<RenderSegment>>>,
+        status_callback: Arc<Mutex<Option<RenderStatusCallback>>>,
+        cancel: Arc<AtomicBool>,
+        is_playing: Arc<Mutex<bool>>,
+    ) {
+        for job in jobs {
+            let Some(segment) = segments.lock().unwrap().get(job.index).cloned() else {
+                return;
+            };
+
+            let update = |status: RenderStatus| {
+                let updated = segments.lock().unwrap().get_mut(job.index).map(|segment| {
+                    segment.status = status;
+                    segment.clone()
+                });
+                if let Some(segment) = updated {
+                    Self::emit_status(&status_callback, segment);
+                }
+            };
+
+            update(RenderStatus::Rendering { percent: 0 });
+            let result = render_segment(&job.clips, &job.track_audio, job.captions.as_ref(), &segment, &cancel, &is_playing, |percent| {
+                update(RenderStatus::Rendering { percent });
+            });
+
+            match result {
+                Ok(()) => {
+                    info!("Rendered segment {}-{}ms to {}", segment.start_ms, segment.end_ms, segment.cache_path);
+                    update(RenderStatus::Rendered);
+                }
+                Err(_) if cancel.load(Ordering::SeqCst) => {
+                    update(RenderStatus::Pending);
+                    return;
+                }
+                Err(e) => {
+                    warn!("Failed to render segment {}-{}ms: {}", segment.start_ms, segment.end_ms, e);
+                    update(RenderStatus::Failed { message: e.to_string() });
+                }
+            }
+        }
+    }
+
+    fn emit_status(callback: &Arc<Mutex<Option<RenderStatusCallback>>>, segment: RenderSegment) {
+        if let Some(ref callback) = *callback.lock().unwrap() {
+            if let Err(e) = callback(segment) {
+                warn!("Render status callback error: {}", e);
+            }
+        }
+    }
+}
+
+impl Default for RenderCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for RenderCache {
+    fn drop(&mut self) {
+        self.stop_worker();
+    }
+}
+
+/// Find ranges where at least `min_layers` clips overlap, widened so every clip touching a
+/// range lies fully inside it (cached files can then replace whole clips).
+pub fn find_heavy_segments(index: &ClipIndex, min_layers: usize) -> Vec<(u64, u64)> {
+    // Sweep clip boundaries; ends sort before starts so touching clips don't count as overlapping
+    let mut edges: Vec<(u64, i32)> = index.clips().iter()
+        .flat_map(|c| [(c.start_time_on_track_ms as u64, 1), (c.end_time_on_track_ms as u64, -1)])
+        .collect();
+    edges.sort();
+
+    let mut ranges = Vec::new();
+    let mut depth = 0;
+    let mut range_start = None;
+    for (time, delta) in edges {
+        depth += delta;
+        match range_start {
+            None if depth as usize >= min_layers => range_start = Some(time),
+            Some(start) if (depth as usize) < min_layers => {
+                if time > start {
+                    ranges.push((start, time));
+                }
+                range_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    // Expand to whole clips until stable, then merge ranges that now overlap
+    for range in ranges.iter_mut() {
+        loop {
+            let widened = index.overlapping(range.0 as i64, range.1 as i64).iter().fold(*range, |(start, end), clip| {
+                (start.min(clip.start_time_on_track_ms as u64), end.max(clip.end_time_on_track_ms as u64))
+            });
+            if widened == *range {
+                break;
+            }
+            *range = widened;
+        }
+    }
+
+    ranges.sort();
+    let mut merged: Vec<(u64, u64)> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.0 < last.1 => last.1 = last.1.max(range.1),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Clips overlapping the range from the bottom layer up, with their track's type folded into
+/// their own and clips their track plays nothing of left out
+fn clips_in_range(timeline: &TimelineData, index: &ClipIndex, start_ms: u64, end_ms: u64) -> Vec<TimelineClip> {
+    let layers = clip_index::track_layers(timeline);
+    let mut clips = index.overlapping(start_ms as i64, end_ms as i64);
+    clips.sort_by_key(|clip| layers.get(&clip.track_id).copied().unwrap_or_default());
+    clips.into_iter()
+        .filter_map(|clip| {
+            let track_type = timeline.tracks.iter()
+                .find(|t| t.id == clip.track_id)
+                .map(|t| t.track_type)
+                .unwrap_or_default();
+            let track_type = clip.track_type.on_track(track_type)?;
+            Some(TimelineClip { track_type, ..clip.clone() })
+        })
+        .collect()
+}
+
+/// Effect chains and volume automation of the tracks `clips` sit on
+fn track_audio_of(timeline: &TimelineData, clips: &[TimelineClip]) -> HashMap<i32, TrackAudio> {
+    timeline.tracks.iter()
+        .filter(|t| !(t.audio_effects.is_empty() && t.volume_keyframes.is_empty()))
+        .filter(|t| clips.iter().any(|c| c.track_id == t.id))
+        .map(|t| (t.id, TrackAudio {
+            effects: t.audio_effects.clone(),
+            volume_keyframes: t.volume_keyframes.clone(),
+        }))
+        .collect()
+}
+
+/// Content hash of a segment; includes source modification times so replaced media re-renders
+fn segment_key(
+    clips: &[TimelineClip],
+    track_audio: &HashMap<i32, TrackAudio>,
+    captions: Option<&BurntCaptions>,
+    start_ms: u64,
+    end_ms: u64,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (start_ms, end_ms).hash(&mut hasher);
+    if let Some(captions) = captions {
+        let appearance = &captions.appearance;
+        (&appearance.font_family, appearance.font_size, appearance.outline, appearance.shadow).hash(&mut hasher);
+        for cue in &captions.cues {
+            (cue.start_ms, cue.end_ms, &cue.text, cue.style).hash(&mut hasher);
+        }
+    }
+    let mut track_ids: Vec<_> = track_audio.keys().copied().collect();
+    track_ids.sort();
+    for track_id in track_ids {
+        track_id.hash(&mut hasher);
+        let audio = &track_audio[&track_id];
+        for keyframe in &audio.volume_keyframes {
+            (keyframe.time_ms, keyframe.gain_db.to_bits()).hash(&mut hasher);
+        }
+        hash_effects(&audio.effects, &mut hasher);
+    }
+    for clip in clips {
+        clip.source_path.hash(&mut hasher);
+        hash_effects(&clip.audio_effects, &mut hasher);
+        clip.track_type.hash(&mut hasher);
+        (
+            clip.start_time_on_track_ms,
+            clip.end_time_on_track_ms,
+            clip.start_time_in_source_ms,
+            clip.end_time_in_source_ms,
+        ).hash(&mut hasher);
+        for value in [clip.preview_position_x, clip.preview_position_y, clip.preview_width, clip.preview_height, clip.preview_opacity] {
+            value.to_bits().hash(&mut hasher);
+        }
+        let modified = std::fs::metadata(&clip.source_path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs());
+        modified.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn hash_effects(effects: &[TrackAudioEffect], hasher: &mut DefaultHasher) {
+    effects.len().hash(hasher);
+    for effect in effects {
+        effect.factory_name.hash(hasher);
+        let mut parameters: Vec<_> = effect.parameters.iter().collect();
+        parameters.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, value) in parameters {
+            name.hash(hasher);
+            value.to_bits().hash(hasher);
+        }
+    }
+}
+
+pub(crate) fn make_element(factory: &str) -> Result<gst::Element> {
+    gst::ElementFactory::make(factory)
+        .build()
+        .map_err(|e| anyhow!("Failed to create {}: {}", factory, e))
+}
+
+/// First available element from `factories`, e.g. to pick an installed encoder
+pub(crate) fn make_first_available(factories: &[&str]) -> Result<gst::Element> {
+    factories.iter()
+        .find_map(|factory| gst::ElementFactory::make(factory).build().ok())
+        .ok_or_else(|| anyhow!("None of the elements {:?} are available", factories))
+}
+
+/// Render `clips` composited over `segment`'s range into its cache file at preview resolution,
+/// with `captions` drawn over them if given
+fn render_segment(
+    clips: &[TimelineClip],
+    track_audio: &HashMap<i32, TrackAudio>,
+    captions: Option<&BurntCaptions>,
+    segment: &RenderSegment,
+    cancel: &AtomicBool,
+    is_playing: &Mutex<bool>,
+    mut on_progress: impl FnMut(i32),
+) -> Result<()> {
+    let output_path = Path::new(&segment.cache_path);
+    // Render to a temporary name so an interrupted render is never mistaken for a cached one
+    let partial_path = output_path.with_extension("mp4.part");
+
+    let pipeline = gst::Pipeline::new();
+
+    let compositor = make_element("compositor")?;
+    compositor.set_property_from_str("background", "black");
+    let video_caps = gst::ElementFactory::make("capsfilter")
+        .property("caps", gst::Caps::builder("video/x-raw")
+            .field("width", RENDER_WIDTH)
+            .field("height", RENDER_HEIGHT)
+            .build())
+        .build()
+        .map_err(|e| anyhow!("Failed to create capsfilter: {}", e))?;
+    let video_convert = make_element("videoconvert")?;
+    let video_encoder = make_first_available(&["x264enc", "openh264enc", "avenc_h264"])?;
+    let video_queue = make_element("queue")?;
+
+    // A silent source keeps the audio branch flowing when no clip has audio
+    let silence = gst::ElementFactory::make("audiotestsrc")
+        .property_from_str("wave", "silence")
+        .build()
+        .map_err(|e| anyhow!("Failed to create audiotestsrc: {}", e))?;
+    let audiomixer = make_element("audiomixer")?;
+    let audio_convert = make_element("audioconvert")?;
+    let audio_resample = make_element("audioresample")?;
+    let audio_encoder = make_first_available(&["avenc_aac", "voaacenc", "fdkaacenc"])?;
+    let audio_queue = make_element("queue")?;
+
+    let muxer = make_element("mp4mux")?;
+    let filesink = gst::ElementFactory::make("filesink")
+        .property("location", partial_path.to_string_lossy().to_string())
+        .build()
+        .map_err(|e| anyhow!("Failed to create filesink: {}", e))?;
+
+    pipeline.add_many([
+        &compositor, &video_caps, &video_convert, &video_encoder, &video_queue,
+        &silence, &audiomixer, &audio_convert, &audio_resample, &audio_encoder, &audio_queue,
+        &muxer, &filesink,
+    ])?;
+    compositor.link(&video_caps)?;
+    match captions {
+        Some(captions) => {
+            // Segments render in timeline time, so the overlay finds cues by stream time as in the preview
+            let overlay = captions::create_overlay(&Arc::new(Mutex::new(captions.cues.clone())), &captions.appearance)
+                .ok_or_else(|| anyhow!("Can't burn in captions without textoverlay from gst-plugins-base"))?;
+            pipeline.add(&overlay)?;
+            video_caps.link_pads(Some("src"), &overlay, Some("video_sink"))?;
+            overlay.link(&video_convert)?;
+        }
+        None => video_caps.link(&video_convert)?,
+    }
+    gst::Element::link_many([&video_convert, &video_encoder, &video_queue, &muxer])?;
+    gst::Element::link_many([&silence, &audiomixer, &audio_convert, &audio_resample, &audio_encoder, &audio_queue, &muxer])?;
+    muxer.link(&filesink)?;
+
+    let scale_x = RENDER_WIDTH as f64 / CANVAS_WIDTH;
+    let scale_y = RENDER_HEIGHT as f64 / CANVAS_HEIGHT;
+    for (index, clip) in clips.iter().enumerate() {
+        let audio = track_audio.get(&clip.track_id).cloned().unwrap_or_default();
+        add_render_source(&pipeline, &compositor, &audiomixer, clip, audio, index, scale_x, scale_y)?;
+    }
+
+    pipeline.set_state(gst::State::Paused)?;
+    let (state_result, _, _) = pipeline.state(Some(gst::ClockTime::from_seconds(10)));
+    if let Err(e) = state_result {
+        let _ = pipeline.set_state(gst::State::Null);
+        return Err(anyhow!("Render pipeline failed to preroll: {}", e));
+    }
+
+    pipeline.seek(
+        1.0,
+        gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+        gst::SeekType::Set,
+        gst::ClockTime::from_mseconds(segment.start_ms),
+        gst::SeekType::Set,
+        gst::ClockTime::from_mseconds(segment.end_ms),
+    )?;
+
+    let bus = pipeline.bus().ok_or_else(|| anyhow!("Failed to get render pipeline bus"))?;
+    let length_ms = segment.end_ms.saturating_sub(segment.start_ms).max(1);
+    let mut last_percent = 0;
+
+    let result = loop {
+        if cancel.load(Ordering::SeqCst) {
+            break Err(anyhow!("Render cancelled"));
+        }
+
+        // Yield decoders to interactive playback
+        let target_state = if *is_playing.lock().unwrap() { gst::State::Paused } else { gst::State::Playing };
+        if pipeline.current_state() != target_state && pipeline.pending_state() == gst::State::VoidPending {
+            if let Err(e) = pipeline.set_state(target_state) {
+                break Err(anyhow!("Failed to change render pipeline state: {}", e));
+            }
+        }
+
+        if let Some(message) = bus.timed_pop_filtered(
+            gst::ClockTime::from_mseconds(100),
+            &[gst::MessageType::Eos, gst::MessageType::Error],
+        ) {
+            match message.view() {
+                gst::MessageView::Eos(..) => break Ok(()),
+                gst::MessageView::Error(err) => break Err(anyhow!(FlipEditError::from_error_message(err))),
+                _ => {}
+            }
+        }
+
+        if let Some(position) = pipeline.query_position::<gst::ClockTime>() {
+            let percent = (position.mseconds().saturating_sub(segment.start_ms) * 100 / length_ms).min(99) as i32;
+            if percent != last_percent {
+                last_percent = percent;
+                on_progress(percent);
+            }
+        }
+    };
+
+    let _ = pipeline.set_state(gst::State::Null);
+
+    match result {
+        Ok(()) => {
+            std::fs::rename(&partial_path, output_path)
+                .map_err(|e| anyhow!("Failed to finalize rendered segment: {}", e))?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&partial_path);
+            Err(e)
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn add_render_source(
+    pipeline: &gst::Pipeline,
+    compositor: &gst::Element,
+    audiomixer: &gst::Element,
+    clip: &TimelineClip,
+    track_audio: TrackAudio,
+    index: usize,
+    scale_x: f64,
+    scale_y: f64,
+) -> Result<()> {
+    let width = ((clip.preview_width * scale_x) as i32).max(1);
+    let height = ((clip.preview_height * scale_y) as i32).max(1);
+
+    let uridecodebin = gst::ElementFactory::make("uridecodebin")
+        .property("uri", source_uri(&clip.source_path))
+        .build()
+        .map_err(|e| anyhow!("Failed to create uridecodebin for {}: {}", clip.source_path, e))?;
+    let videoconvert = make_element("videoconvert")?;
+    let videoscale = make_element("videoscale")?;
+    let caps_filter = gst::ElementFactory::make("capsfilter")
+        .property("caps", gst::Caps::builder("video/x-raw")
+            .field("width", width)
+            .field("height", height)
+            .field("pixel-aspect-ratio", gst::Fraction::new(1, 1))
+            .build())
+        .build()
+        .map_err(|e| anyhow!("Failed to create capsfilter: {}", e))?;
+
+    pipeline.add_many([&uridecodebin, &videoconvert, &videoscale, &caps_filter])?;
+    gst::Element::link_many([&videoconvert, &videoscale, &caps_filter])?;
+
+    let compositor_pad = compositor.request_pad_simple("sink_%u")
+        .ok_or_else(|| anyhow!("Failed to request compositor pad"))?;
+    compositor_pad.set_property("zorder", index as u32 + 1);
+    compositor_pad.set_property("xpos", (clip.preview_position_x * scale_x) as i32);
+    compositor_pad.set_property("ypos", (clip.preview_position_y * scale_y) as i32);
+    compositor_pad.set_property("alpha", clip.preview_opacity.clamp(0.0, 1.0));
+    caps_filter.static_pad("src")
+        .ok_or_else(|| anyhow!("Failed to get capsfilter src pad"))?
+        .link(&compositor_pad)?;
+
+    // Audio-only sources (e.g. voice-overs) would otherwise stall the compositor
+    let compositor_weak = compositor.downgrade();
+    let unused_videoconvert = videoconvert.downgrade();
+    uridecodebin.connect_no_more_pads(move |_| {
+        let (Some(compositor), Some(videoconvert)) = (compositor_weak.upgrade(), unused_videoconvert.upgrade()) else {
+            return;
+        };
+        if !videoconvert.static_pad("sink").is_some_and(|pad| pad.is_linked()) {
+            compositor.release_request_pad(&compositor_pad);
+        }
+    });
+
+    let pipeline_weak = pipeline.downgrade();
+    let videoconvert_weak = videoconvert.downgrade();
+    let audiomixer_weak = audiomixer.downgrade();
+    let clip_timing = Arc::new(Mutex::new(ClipTiming::from_clip(clip)));
+    let (plays_video, plays_audio) = (clip.track_type.has_video(), clip.track_type.has_audio());
+    // The clip's own effects run before its track's, as in the player
+    let effects: Vec<TrackAudioEffect> = clip.audio_effects.iter().chain(&track_audio.effects).cloned().collect();
+    uridecodebin.connect_pad_added(move |_src, src_pad| {
+        let (Some(pipeline), Some(videoconvert), Some(audiomixer)) =
+            (pipeline_weak.upgrade(), videoconvert_weak.upgrade(), audiomixer_weak.upgrade()) else {
+            return;
+        };
+        let Some(caps) = src_pad.current_caps().or_else(|| Some(src_pad.query_caps(None))) else {
+            return;
+        };
+        let Some(media_type) = caps.structure(0).map(|s| s.name().to_string()) else {
+            return;
+        };
+
+        if media_type.starts_with("video/") || media_type.starts_with("audio/") {
+            apply_clip_timing(src_pad, Arc::clone(&clip_timing));
+        }
+
+        if (media_type.starts_with("video/") && !plays_video) || (media_type.starts_with("audio/") && !plays_audio) {
+            discard_pad(&pipeline, src_pad);
+        } else if media_type.starts_with("video/") {
+            if let Some(sink_pad) = videoconvert.static_pad("sink") {
+                if !sink_pad.is_linked() {
+                    if let Err(e) = src_pad.link(&sink_pad) {
+                        warn!("Failed to link render video pad: {:?}", e);
+                    }
+                }
+            }
+        } else if media_type.starts_with("audio/") {
+            let result: Result<()> = (|| {
+                let audioconvert = make_element("audioconvert")?;
+                let audioresample = make_element("audioresample")?;
+                pipeline.add_many([&audioconvert, &audioresample])?;
+                let effect_chain = audio_effects::build_effect_chain(&effects)?;
+                let volume_envelope = audio_automation::build_envelope_filter(
+                    &track_audio.volume_keyframes,
+                    ClipFades::default(),
+                    Arc::clone(&clip_timing),
+                )?;
+                let middle: Vec<gst::Element> = effect_chain.into_iter().chain(volume_envelope).collect();
+                for element in &middle {
+                    pipeline.add(element)?;
+                }
+                let mut chain = vec![audioconvert.clone()];
+                chain.extend(middle);
+                chain.push(audioresample.clone());
+                gst::Element::link_many(&chain)?;
+                for element in &chain[1..chain.len() - 1] {
+                    element.sync_state_with_parent()?;
+                }
+                let mixer_pad = audiomixer.request_pad_simple("sink_%u")
+                    .ok_or_else(|| anyhow!("Failed to request audiomixer pad"))?;
+                audioresample.static_pad("src")
+                    .ok_or_else(|| anyhow!("Failed to get audioresample src pad"))?
+                    .link(&mixer_pad)?;
+                audioconvert.sync_state_with_parent()?;
+                audioresample.sync_state_with_parent()?;
+                src_pad.link(&audioconvert.static_pad("sink").ok_or_else(|| anyhow!("Failed to get audioconvert sink pad"))?)?;
+                Ok(())
+            })();
+            if let Err(e) = result {
+                warn!("Failed to link render audio pad: {}", e);
+            }
+        }
+    });
+
+    Ok(())
+}
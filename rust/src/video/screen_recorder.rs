@@ -0,0 +1,277 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gst::prelude::*;
+use log::{info, warn};
+
+use crate::audio_input;
+use crate::audio_loudness;
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::export_metadata;
+use crate::common::types::{ScreenCaptureSettings, ScreenRecordingEvent};
+use crate::video::render_cache::{make_element, make_first_available};
+
+pub type ScreenRecordingCallback = Box<dyn Fn(ScreenRecordingEvent) + Send + Sync>;
+
+// How often progress is reported while recording
+const PROGRESS_INTERVAL_MS: u64 = 250;
+// Time allowed for the muxer to write its index after capture stops
+const FINALIZE_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct ScreenRecording {
+    stop: Arc<AtomicBool>,
+    monitor: thread::JoinHandle<Result<u64>>,
+}
+
+lazy_static::lazy_static! {
+    static ref SCREEN_RECORDING: Mutex<Option<ScreenRecording>> = Mutex::new(None);
+}
+
+fn missing_plugin(factory: &str) -> anyhow::Error {
+    anyhow!(FlipEditError::new(
+        FlipEditErrorCode::MissingPlugin,
+        format!("{} isn't available, install the GStreamer plugin that provides it", factory),
+    ))
+}
+
+/// Desktop or window capture for the current platform
+#[cfg(target_os = "linux")]
+fn create_screen_source(settings: &ScreenCaptureSettings) -> Result<gst::Element> {
+    // Wayland compositors only share the screen through a PipeWire stream granted by the portal
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        let (Some(node), Some(fd)) = (settings.window_id, settings.pipewire_fd) else {
+            return Err(anyhow!(FlipEditError::new(
+                FlipEditErrorCode::InvalidArgument,
+                "Screen capture on Wayland needs the PipeWire node and remote fd granted by the screencast portal",
+            )));
+        };
+        return gst::ElementFactory::make("pipewiresrc")
+            .property("fd", fd)
+            .property("path", node.to_string())
+            .property("do-timestamp", true)
+            .build()
+            .map_err(|_| missing_plugin("pipewiresrc"));
+    }
+    let source = gst::ElementFactory::make("ximagesrc")
+        .property("use-damage", false)
+        .property("show-pointer", settings.show_cursor)
+        .build()
+        .map_err(|_| missing_plugin("ximagesrc"))?;
+    if let Some(xid) = settings.window_id {
+        source.set_property("xid", xid);
+    }
+    Ok(source)
+}
+
+#[cfg(target_os = "macos")]
+fn create_screen_source(settings: &ScreenCaptureSettings) -> Result<gst::Element> {
+    if settings.window_id.is_some() {
+        warn!("Window capture isn't supported by avfvideosrc, recording the whole screen");
+    }
+    gst::ElementFactory::make("avfvideosrc")
+        .property("capture-screen", true)
+        .property("capture-screen-cursor", settings.show_cursor)
+        .build()
+        .map_err(|_| missing_plugin("avfvideosrc"))
+}
+
+#[cfg(target_os = "windows")]
+fn create_screen_source(settings: &ScreenCaptureSettings) -> Result<gst::Element> {
+    let source = gst::ElementFactory::make("d3d11screencapturesrc")
+        .property("show-cursor", settings.show_cursor)
+        .build()
+        .map_err(|_| missing_plugin("d3d11screencapturesrc"))?;
+    if let Some(hwnd) = settings.window_id {
+        source.set_property("window-handle", hwnd);
+    }
+    Ok(source)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn create_screen_source(_settings: &ScreenCaptureSettings) -> Result<gst::Element> {
+    Err(anyhow!(FlipEditError::new(
+        FlipEditErrorCode::MissingPlugin,
+        "Screen capture isn't supported on this platform",
+    )))
+}
+
+/// `<screen> ! videorate ! x264 ! h264parse ! mp4mux ! filesink`, with `<loopback> ! aac` muxed in
+/// when requested, loudness normalized before encoding if its settings ask for it
+fn build_pipeline(settings: &ScreenCaptureSettings) -> Result<gst::Pipeline> {
+    let pipeline = gst::Pipeline::with_name("screen_recorder");
+
+    let source = create_screen_source(settings)?;
+    let video_queue = make_element("queue")?;
+    let video_convert = make_element("videoconvert")?;
+    let video_rate = make_element("videorate")?;
+    let video_caps = gst::ElementFactory::make("capsfilter")
+        .property("caps", gst::Caps::builder("video/x-raw")
+            .field("format", "I420")
+            .field("framerate", gst::Fraction::new(settings.framerate, 1))
+            .build())
+        .build()
+        .map_err(|e| anyhow!("Failed to create capsfilter: {}", e))?;
+    let video_encoder = make_first_available(&["x264enc", "openh264enc", "avenc_h264"])?;
+    if video_encoder.factory().is_some_and(|factory| factory.name() == "x264enc") {
+        // Keep up with a live source rather than buffering for compression
+        video_encoder.set_property_from_str("tune", "zerolatency");
+        video_encoder.set_property_from_str("speed-preset", "veryfast");
+    }
+    // mp4mux takes AVC rather than the byte-stream some encoders output
+    let video_parse = make_element("h264parse")?;
+    let muxer = make_element("mp4mux")?;
+    export_metadata::apply_to_muxer(&muxer, &settings.metadata, &[], 0)?;
+    let filesink = gst::ElementFactory::make("filesink")
+        .property("location", &settings.output_path)
+        .build()
+        .map_err(|e| anyhow!("Failed to create filesink: {}", e))?;
+
+    let master_stage = if settings.capture_system_audio {
+        audio_loudness::build_master_stage(&settings.loudness)?
+    } else {
+        None
+    };
+    // Encoded frames wait here while the loudness stage looks ahead, rather than raw ones upstream
+    let lookahead_queue = match master_stage {
+        Some(_) => Some(gst::ElementFactory::make("queue")
+            .property("max-size-buffers", 0u32)
+            .property("max-size-bytes", 0u32)
+            .property("max-size-time", (audio_loudness::MASTER_STAGE_LATENCY * 2).as_nanos() as u64)
+            .build()
+            .map_err(|e| anyhow!("Failed to create queue: {}", e))?),
+        None => None,
+    };
+
+    let video_chain: Vec<&gst::Element> = [&source, &video_queue, &video_convert, &video_rate, &video_caps, &video_encoder, &video_parse]
+        .into_iter()
+        .chain(lookahead_queue.as_ref())
+        .chain([&muxer, &filesink])
+        .collect();
+    pipeline.add_many(&video_chain)?;
+    gst::Element::link_many(&video_chain)?;
+
+    if settings.capture_system_audio {
+        let audio_source = audio_input::create_system_audio_source()?;
+        let audio_queue = make_element("queue")?;
+        let audio_convert = make_element("audioconvert")?;
+        let audio_resample = make_element("audioresample")?;
+        let audio_encoder = make_first_available(&["avenc_aac", "voaacenc", "fdkaacenc"])?;
+        let audio_chain: Vec<&gst::Element> = [&audio_source, &audio_queue, &audio_convert]
+            .into_iter()
+            .chain(master_stage.as_ref())
+            .chain([&audio_resample, &audio_encoder])
+            .collect();
+        pipeline.add_many(&audio_chain)?;
+        gst::Element::link_many(audio_chain.iter().copied().chain([&muxer]))?;
+    }
+
+    Ok(pipeline)
+}
+
+/// Report progress until stopped or failed, then finalize the file and return its duration
+fn monitor_recording(
+    pipeline: gst::Pipeline,
+    output_path: String,
+    stop: Arc<AtomicBool>,
+    on_event: ScreenRecordingCallback,
+) -> Result<u64> {
+    let bus = pipeline.bus().ok_or_else(|| anyhow!("Screen recorder pipeline has no bus"))?;
+    let mut stopping_since: Option<Instant> = None;
+    let mut duration_ms = 0;
+
+    let result = loop {
+        if stop.load(Ordering::SeqCst) && stopping_since.is_none() {
+            // mp4mux only writes its index on EOS
+            pipeline.send_event(gst::event::Eos::new());
+            stopping_since = Some(Instant::now());
+        }
+        if stopping_since.is_some_and(|since| since.elapsed() > FINALIZE_TIMEOUT) {
+            warn!("Screen recording didn't finish within {:?}, the file may be unplayable", FINALIZE_TIMEOUT);
+            break Ok(());
+        }
+
+        if let Some(message) = bus.timed_pop_filtered(
+            gst::ClockTime::from_mseconds(PROGRESS_INTERVAL_MS),
+            &[gst::MessageType::Eos, gst::MessageType::Error],
+        ) {
+            match message.view() {
+                gst::MessageView::Eos(..) => break Ok(()),
+                gst::MessageView::Error(err) => break Err(anyhow!(FlipEditError::from_error_message(err))),
+                _ => {}
+            }
+        }
+
+        if stopping_since.is_none() {
+            if let Some(position) = pipeline.query_position::<gst::ClockTime>() {
+                duration_ms = position.mseconds();
+            }
+            let bytes_written = std::fs::metadata(&output_path).map_or(0, |metadata| metadata.len());
+            on_event(ScreenRecordingEvent::Progress { duration_ms, bytes_written });
+        }
+    };
+
+    let _ = pipeline.set_state(gst::State::Null);
+    match result {
+        Ok(()) => {
+            info!("Screen recording finished: {} ({}ms)", output_path, duration_ms);
+            on_event(ScreenRecordingEvent::Finished { output_path, duration_ms });
+            Ok(duration_ms)
+        }
+        Err(e) => {
+            warn!("Screen recording failed: {}", e);
+            let error = FlipEditError::from(e);
+            on_event(ScreenRecordingEvent::Error { error: error.clone() });
+            Err(anyhow!(error))
+        }
+    }
+}
+
+/// Start recording the screen to `settings.output_path`, reporting through `on_event`
+pub fn start_screen_recording(settings: ScreenCaptureSettings, on_event: ScreenRecordingCallback) -> Result<()> {
+    gst::init().map_err(|e| anyhow!("Failed to initialize GStreamer: {}", e))?;
+    if settings.framerate <= 0 {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            format!("Invalid screen recording framerate: {}", settings.framerate),
+        )));
+    }
+
+    let mut recording = SCREEN_RECORDING.lock().unwrap();
+    if recording.as_ref().is_some_and(|recording| !recording.monitor.is_finished()) {
+        return Err(anyhow!(FlipEditError::new(
+            FlipEditErrorCode::InvalidArgument,
+            "A screen recording is already running",
+        )));
+    }
+
+    let pipeline = build_pipeline(&settings)?;
+    if pipeline.set_state(gst::State::Playing).is_err() {
+        let _ = pipeline.set_state(gst::State::Null);
+        return Err(anyhow!(FlipEditError::new(FlipEditErrorCode::StateChangeFailed, "Failed to start screen capture")));
+    }
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let monitor = {
+        let stop = Arc::clone(&stop);
+        let output_path = settings.output_path.clone();
+        thread::spawn(move || monitor_recording(pipeline, output_path, stop, on_event))
+    };
+    info!("Recording the screen to {} at {}fps", settings.output_path, settings.framerate);
+    *recording = Some(ScreenRecording { stop, monitor });
+    Ok(())
+}
+
+/// Stop the running screen recording and wait for its file to be finalized, returning its duration
+pub fn stop_screen_recording() -> Result<u64> {
+    let recording = SCREEN_RECORDING.lock().unwrap().take()
+        .ok_or_else(|| anyhow!(FlipEditError::new(FlipEditErrorCode::InvalidArgument, "No screen recording is running")))?;
+    recording.stop.store(true, Ordering::SeqCst);
+    recording.monitor.join().map_err(|_| anyhow!("Screen recording thread panicked"))?
+}
+
+pub fn is_screen_recording() -> bool {
+    SCREEN_RECORDING.lock().unwrap().as_ref().is_some_and(|recording| !recording.monitor.is_finished())
+}
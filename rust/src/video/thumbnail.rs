@@ -0,0 +1,258 @@
+use anyhow::{anyhow, Result};
+use gstreamer as gst;
+use gstreamer_app as gst_app;
+use gstreamer_video as gst_video;
+use gst::prelude::*;
+use log::debug;
+
+use crate::common::error::{FlipEditError, FlipEditErrorCode};
+use crate::common::media_uri::source_uri;
+use crate::common::types::{Thumbnail, ThumbnailFormat};
+
+const PREROLL_TIMEOUT_SECS: u64 = 10;
+const CONVERT_TIMEOUT_SECS: u64 = 5;
+const JPEG_QUALITY: i32 = 85;
+
+/// Largest size within `max_width`x`max_height` with the frame's shape, never larger than
+/// the frame. A limit of 0 doesn't constrain that side.
+pub fn fit_size(width: u32, height: u32, max_width: u32, max_height: u32) -> (u32, u32) {
+    let limit = |max: u32, size: u32| if max == 0 { 1.0 } else { (max as f64 / size.max(1) as f64).min(1.0) };
+    let scale = limit(max_width, width).min(limit(max_height, height));
+    (
+        ((width as f64 * scale).round() as u32).max(1),
+        ((height as f64 * scale).round() as u32).max(1),
+    )
+}
+
+/// A paused decoding pipeline for pulling frames of one file at any position
+pub struct FrameExtractor {
+    pipeline: gst::Pipeline,
+    appsink: gst_app::AppSink,
+    source_path: String,
+}
+
+impl FrameExtractor {
+    pub fn open(source_path: &str) -> Result<Self> {
+        gst::init()?;
+        FlipEditError::check_file_exists(source_path)?;
+        // Square pixels, so anamorphic sources come out at their display shape
+        let pipeline = gst::parse::launch(
+            "uridecodebin name=decode ! videoconvert ! videoscale \
+             ! video/x-raw,format=RGBA,pixel-aspect-ratio=1/1 ! appsink name=sink sync=false max-buffers=1",
+        )?
+        .downcast::<gst::Pipeline>()
+        .map_err(|_| anyhow!("Frame extraction description didn't produce a pipeline"))?;
+        pipeline.by_name("decode")
+            .ok_or_else(|| anyhow!("Frame extraction pipeline has no decoder"))?
+            .set_property("uri", source_uri(source_path));
+        let appsink = pipeline.by_name("sink")
+            .and_then(|sink| sink.downcast::<gst_app::AppSink>().ok())
+            .ok_or_else(|| anyhow!("Frame extraction pipeline has no appsink"))?;
+
+        let extractor = Self { pipeline, appsink, source_path: source_path.to_string() };
+        extractor.pipeline.set_state(gst::State::Paused)?;
+        extractor.wait_for_preroll()?;
+        Ok(extractor)
+    }
+
+    pub fn source_path(&self) -> &str {
+        &self.source_path
+    }
+
+    fn wait_for_preroll(&self) -> Result<()> {
+        self.pipeline.state(Some(gst::ClockTime::from_seconds(PREROLL_TIMEOUT_SECS))).0
+            .map_err(|_| anyhow!(FlipEditError::new(
+                FlipEditErrorCode::DecodeError,
+                format!("Couldn't decode video from {}", self.source_path),
+            )))?;
+        Ok(())
+    }
+
+    /// Full-size RGBA frame shown at `position_ms`
+    pub fn frame_at(&self, position_ms: u64) -> Result<gst::Sample> {
+        self.pipeline.seek_simple(
+            gst::SeekFlags::FLUSH | gst::SeekFlags::ACCURATE,
+            gst::ClockTime::from_mseconds(position_ms),
+        )?;
+        self.wait_for_preroll()?;
+        self.appsink.try_pull_preroll(gst::ClockTime::from_seconds(PREROLL_TIMEOUT_SECS))
+            .ok_or_else(|| anyhow!(FlipEditError::new(
+                FlipEditErrorCode::DecodeError,
+                format!("No frame at {}ms in {}", position_ms, self.source_path),
+            )))
+    }
+
+    /// The frame at `position_ms`, scaled to fit `max_width`x`max_height` and encoded as `format`
+    pub fn thumbnail_at(&self, position_ms: u64, max_width: u32, max_height: u32, format: ThumbnailFormat) -> Result<Thumbnail> {
+        let mut thumbnail = encode_thumbnail(&self.frame_at(position_ms)?, max_width, max_height, format)?;
+        thumbnail.position_ms = position_ms;
+        Ok(thumbnail)
+    }
+}
+
+impl Drop for FrameExtractor {
+    fn drop(&mut self) {
+        let _ = self.pipeline.set_state(gst::State::Null);
+    }
+}
+
+/// Scale an RGBA sample down to fit `max_width`x`max_height` and encode it
+pub fn encode_thumbnail(sample: &gst::Sample, max_width: u32, max_height: u32, format: ThumbnailFormat) -> Result<Thumbnail> {
+    let caps = sample.caps().ok_or_else(|| anyhow!("No caps in sample"))?;
+    let info = gst_video::VideoInfo::from_caps(caps)?;
+    let (width, height) = fit_size(info.width(), info.height(), max_width, max_height);
+    debug!("Thumbnail of {}x{} frame at {}x{}", info.width(), info.height(), width, height);
+
+    let timeout = gst::ClockTime::from_seconds(CONVERT_TIMEOUT_SECS);
+    let data = match format {
+        ThumbnailFormat::Rgba => {
+            let scaled;
+            let sample = if (width, height) == (info.width(), info.height()) {
+                sample
+            } else {
+                let scaled_caps = gst_video::VideoInfo::builder(gst_video::VideoFormat::Rgba, width, height)
+                    .build()?
+                    .to_caps()?;
+                scaled = gst_video::convert_sample(sample, &scaled_caps, timeout)?;
+                &scaled
+            };
+            packed_rgba(sample)?
+        }
+        ThumbnailFormat::Jpeg => {
+            // convert_sample can't set encoder properties, so encode with our own quality
+            let jpeg = encode_jpeg(sample, width, height)?;
+            let buffer = jpeg.buffer().ok_or_else(|| anyhow!("JPEG encoder produced no data"))?;
+            buffer.map_readable()?.as_slice().to_vec()
+        }
+    };
+    Ok(Thumbnail { position_ms: 0, width, height, format, data })
+}
+
+fn encode_jpeg(sample: &gst::Sample, width: u32, height: u32) -> Result<gst::Sample> {
+    let pipeline = gst::parse::launch(&format!(
+        "appsrc name=src ! videoconvert ! videoscale ! jpegenc quality={} \
+         ! image/jpeg,width={},height={} ! appsink name=sink sync=false",
+        JPEG_QUALITY, width, height,
+    ))?
+    .downcast::<gst::Pipeline>()
+    .map_err(|_| anyhow!("JPEG description didn't produce a pipeline"))?;
+    let appsrc = pipeline.by_name("src")
+        .and_then(|src| src.downcast::<gst_app::AppSrc>().ok())
+        .ok_or_else(|| anyhow!("JPEG pipeline has no appsrc"))?;
+    let appsink = pipeline.by_name("sink")
+        .and_then(|sink| sink.downcast::<gst_app::AppSink>().ok())
+        .ok_or_else(|| anyhow!("JPEG pipeline has no appsink"))?;
+
+    let result = (|| {
+        appsrc.set_caps(sample.caps_owned().as_ref());
+        pipeline.set_state(gst::State::Playing)?;
+        let buffer = sample.buffer_owned().ok_or_else(|| anyhow!("No buffer in sample"))?;
+        appsrc.push_buffer(buffer)?;
+        appsrc.end_of_stream()?;
+        appsink.try_pull_sample(gst::ClockTime::from_seconds(CONVERT_TIMEOUT_SECS))
+            .ok_or_else(|| anyhow!("Timed out encoding JPEG"))
+    })();
+    let _ = pipeline.set_state(gst::State::Null);
+    result
+}
+
+/// RGBA rows without stride padding
+fn packed_rgba(sample: &gst::Sample) -> Result<Vec<u8>> {
+    let caps = sample.caps().ok_or_else(|| anyhow!("No caps in sample"))?;
+    let buffer = sample.buffer().ok_or_else(|| anyhow!("No buffer in sample"))?;
+    let info = gst_video::VideoInfo::from_caps(caps)?;
+    let frame = gst_video::VideoFrameRef::from_buffer_ref_readable(buffer, &info)
+        .map_err(|_| anyhow!("Failed to map frame"))?;
+    let row_bytes = info.width() as usize * 4;
+    let stride = frame.plane_stride()[0] as usize;
+    Ok(frame.plane_data(0)?
+        .chunks(stride)
+        .take(info.height() as usize)
+        .flat_map(|row| &row[..row_bytes])
+        .copied()
+        .collect())
+}
+
+/// One thumbnail of a file; use `FrameExtractor` directly for several
+pub fn extract_thumbnail(source_path: &str, position_ms: u64, max_width: u32, max_height: u32, format: ThumbnailFormat) -> Result<Thumbnail> {
+    FrameExtractor::open(source_path)?.thumbnail_at(position_ms, max_width, max_height, format)
+}
+
+/// Frames of one file at each of `times_ms`, decoded by a single pipeline seeking forward
+/// through them, e.g. for filmstrips. `on_frame` gets each frame as it's decoded, in time
+/// order; the result is in the order asked for.
+pub fn extract_frames(
+    source_path: &str,
+    times_ms: &[u64],
+    max_width: u32,
+    max_height: u32,
+    format: ThumbnailFormat,
+    mut on_frame: impl FnMut(&Thumbnail),
+) -> Result<Vec<Thumbnail>> {
+    if times_ms.is_empty() {
+        return Ok(Vec::new());
+    }
+    let extractor = FrameExtractor::open(source_path)?;
+    let mut order: Vec<usize> = (0..times_ms.len()).collect();
+    order.sort_by_key(|&i| times_ms[i]);
+
+    let mut frames: Vec<Option<Thumbnail>> = vec![None; times_ms.len()];
+    let mut last: Option<(u64, Thumbnail)> = None;
+    for i in order {
+        let time_ms = times_ms[i];
+        let thumbnail = match &last {
+            // Asked for the same time twice
+            Some((last_ms, thumbnail)) if *last_ms == time_ms => thumbnail.clone(),
+            _ => {
+                let thumbnail = extractor.thumbnail_at(time_ms, max_width, max_height, format)?;
+                on_frame(&thumbnail);
+                last = Some((time_ms, thumbnail.clone()));
+                thumbnail
+            }
+        };
+        frames[i] = Some(thumbnail);
+    }
+    Ok(frames.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::fixtures::{self, MediaSpec, VideoPattern};
+    use crate::utils::testing::golden::{self, RgbaFrame};
+
+    #[test]
+    fn frame_at_shows_the_frame_at_the_seek_position() {
+        let spec = MediaSpec { tone_hz: None, ..MediaSpec::default() };
+        let path = fixtures::fixture(&spec).unwrap();
+        let extractor = FrameExtractor::open(&path.to_string_lossy()).unwrap();
+
+        // Seek past the position and back, so a frame left over from the first seek would show
+        let later = RgbaFrame::from_sample(&extractor.frame_at(1500).unwrap()).unwrap();
+        let frame = RgbaFrame::from_sample(&extractor.frame_at(500).unwrap()).unwrap();
+
+        let expected = golden::frame_at(&path, 500, spec.width, spec.height).unwrap();
+        let diff = golden::compare(&frame, &expected).unwrap();
+        assert!(diff.mean_delta < 0.5, "frame at 500ms differs from a fresh decode: {:?}", diff);
+        // The bars scroll every frame, so a wrong frame can't pass for the right one
+        assert!(golden::compare(&later, &expected).unwrap().mean_delta > diff.mean_delta);
+    }
+
+    #[test]
+    fn frame_at_matches_a_solid_golden_frame() {
+        let spec = MediaSpec { pattern: Some(VideoPattern::Solid(0xff2080c0)), tone_hz: None, ..MediaSpec::default() };
+        let path = fixtures::fixture(&spec).unwrap();
+        let extractor = FrameExtractor::open(&path.to_string_lossy()).unwrap();
+        let frame = RgbaFrame::from_sample(&extractor.frame_at(1000).unwrap()).unwrap();
+
+        let (width, height) = (spec.width as u32, spec.height as u32);
+        let expected = RgbaFrame {
+            width,
+            height,
+            data: [0x20, 0x80, 0xc0, 0xff].repeat((width * height) as usize),
+        };
+        // The fixture's I420 loses a little of the color
+        let diff = golden::compare(&frame, &expected).unwrap();
+        assert!(diff.mean_delta < 4.0, "solid frame is off its color: {:?}", diff);
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+use log::{debug, warn};
+
+// Used until the app reports the display's actual rate
+const DEFAULT_REFRESH_RATE_MILLIHZ: u32 = 60_000;
+// How often an idle pacer thread checks whether its texture is gone
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_millis(250);
+
+// Display refresh rate in millihertz, shared by all textures
+static REFRESH_RATE_MILLIHZ: AtomicU32 = AtomicU32::new(DEFAULT_REFRESH_RATE_MILLIHZ);
+
+/// Set the rate frames are paced to: the refresh rate Flutter reports for the window's display,
+/// e.g. 120.0 on ProMotion or 144 Hz displays
+pub fn set_display_refresh_rate(hz: f64) {
+    let millihz = (hz.clamp(1.0, 1000.0) * 1000.0) as u32;
+    REFRESH_RATE_MILLIHZ.store(millihz, Ordering::Relaxed);
+    debug!("Pacing texture updates to {} Hz", hz);
+}
+
+pub fn get_display_refresh_rate() -> f64 {
+    REFRESH_RATE_MILLIHZ.load(Ordering::Relaxed) as f64 / 1000.0
+}
+
+fn refresh_interval() -> Duration {
+    Duration::from_secs_f64(1000.0 / REFRESH_RATE_MILLIHZ.load(Ordering::Relaxed) as f64)
+}
+
+#[derive(Default)]
+struct PacerState {
+    pending: bool,
+    last_notify: Option<Instant>,
+}
+
+/// Coalesces "new frame" notifications to at most one per refresh interval, so sources
+/// decoding faster than the display don't cause extra texture uploads. The latest frame
+/// always wins; frames replaced before the next interval are never uploaded.
+///
+/// The interval follows the display's refresh rate, but the pacer isn't phase-locked to
+/// vsync, so a notification can land anywhere within a display frame.
+pub struct TimerPacer {
+    state: Mutex<PacerState>,
+    wake: Condvar,
+}
+
+impl TimerPacer {
+    /// Start pacing `notify` (typically `mark_frame_available`) on a dedicated thread,
+    /// which exits once the returned pacer is dropped
+    pub fn start(notify: impl Fn() + Send + 'static) -> Arc<Self> {
+        let pacer = Arc::new(Self {
+            state: Mutex::new(PacerState::default()),
+            wake: Condvar::new(),
+        });
+
+        let weak = Arc::downgrade(&pacer);
+        if let Err(e) = thread::Builder::new()
+            .name("timer-pacer".to_string())
+            .spawn(move || Self::run(weak, notify))
+        {
+            warn!("Failed to start frame pacer thread: {}", e);
+        }
+        pacer
+    }
+
+    /// A new frame is ready; Flutter is told once the current interval has passed
+    pub fn frame_ready(&self) {
+        let mut state = self.state.lock().unwrap();
+        if !state.pending {
+            state.pending = true;
+            self.wake.notify_one();
+        }
+    }
+
+    fn run(weak: Weak<Self>, notify: impl Fn()) {
+        loop {
+            let Some(pacer) = weak.upgrade() else {
+                return;
+            };
+
+            let deadline = {
+                let state = pacer.state.lock().unwrap();
+                let (state, _) = pacer.wake
+                    .wait_timeout_while(state, IDLE_CHECK_INTERVAL, |state| !state.pending)
+                    .unwrap();
+                if !state.pending {
+                    continue;
+                }
+                state.last_notify.map(|last| last + refresh_interval())
+            };
+
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if deadline > now {
+                    thread::sleep(deadline - now);
+                }
+            }
+
+            {
+                let mut state = pacer.state.lock().unwrap();
+                state.pending = false;
+                state.last_notify = Some(Instant::now());
+            }
+            notify();
+        }
+    }
+}
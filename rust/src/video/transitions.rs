@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use gstreamer as gst;
+use gst::prelude::*;
+
+use crate::common::types::{TimelineClip, TimelineData, TimelineTrack};
+use crate::video::clip_timing::SharedClipTiming;
+
+/// Opacity shared between the player and a clip's fade probe, so opacity edits apply mid-fade
+pub type SharedOpacity = Arc<Mutex<f64>>;
+
+/// The crossfades a clip's branch plays, as timeline ranges in ms
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ClipFades {
+    /// The clip this one crossfades from; it's drawn above it
+    pub from_clip_id: Option<i32>,
+    /// Fading in from the clip's start, over the end of the clip it overlaps
+    pub fade_in: Option<(f64, f64)>,
+    /// Fading the audio out under the next clip's fade-in
+    pub fade_out: Option<(f64, f64)>,
+}
+
+impl ClipFades {
+    pub fn is_empty(&self) -> bool {
+        self.fade_in.is_none() && self.fade_out.is_none()
+    }
+
+    /// Gain of the clip's audio at `timeline_ms`
+    pub fn audio_gain(&self, timeline_ms: f64) -> f64 {
+        let fade_in = self.fade_in.map_or(1.0, |range| ramp(range, timeline_ms));
+        let fade_out = self.fade_out.map_or(1.0, |range| 1.0 - ramp(range, timeline_ms));
+        fade_in * fade_out
+    }
+}
+
+/// 0 before the range, 1 after it and linear in between
+fn ramp((from_ms, to_ms): (f64, f64), timeline_ms: f64) -> f64 {
+    ((timeline_ms - from_ms) / (to_ms - from_ms)).clamp(0.0, 1.0)
+}
+
+/// The clip on `track` that `clip` starts over, if any; the one ending last if several do
+fn overlapped_clip<'a>(track: &'a TimelineTrack, clip: &TimelineClip) -> Option<&'a TimelineClip> {
+    track.clips.iter()
+        .filter(|c| c.id != clip.id
+            && c.start_time_on_track_ms < clip.start_time_on_track_ms
+            && c.end_time_on_track_ms > clip.start_time_on_track_ms)
+        .max_by_key(|c| c.end_time_on_track_ms)
+}
+
+/// Crossfade every overlap on `track`: each clip's transition runs for as long as it overlaps
+/// the clip before it, and clips that no longer overlap one cut again
+pub fn crossfade_overlaps(track: &mut TimelineTrack) {
+    let lengths: Vec<i32> = track.clips.iter()
+        .map(|clip| overlapped_clip(track, clip).map_or(0, |from| {
+            from.end_time_on_track_ms.min(clip.end_time_on_track_ms) - clip.start_time_on_track_ms
+        }))
+        .collect();
+    for (clip, length_ms) in track.clips.iter_mut().zip(lengths) {
+        clip.transition_in_ms = length_ms.max(0);
+    }
+}
+
+/// Each clip's crossfades, by clip id. A transition is cut short where a trim has shortened
+/// the overlap it was made for, and dropped where the clips no longer overlap.
+pub fn clip_fades(timeline_data: &TimelineData) -> HashMap<i32, ClipFades> {
+    let mut fades: HashMap<i32, ClipFades> = HashMap::new();
+    for track in &timeline_data.tracks {
+        for clip in track.clips.iter().filter(|c| c.transition_in_ms > 0) {
+            let Some(from) = overlapped_clip(track, clip) else {
+                continue;
+            };
+            let (Some(clip_id), Some(from_id)) = (clip.id, from.id) else {
+                continue;
+            };
+            let start_ms = clip.start_time_on_track_ms;
+            let length_ms = clip.transition_in_ms
+                .min(from.end_time_on_track_ms.min(clip.end_time_on_track_ms) - start_ms);
+            if length_ms <= 0 {
+                continue;
+            }
+            let range = (start_ms as f64, (start_ms + length_ms) as f64);
+            let incoming = fades.entry(clip_id).or_default();
+            incoming.from_clip_id = Some(from_id);
+            incoming.fade_in = Some(range);
+            fades.entry(from_id).or_default().fade_out = Some(range);
+        }
+    }
+    fades
+}
+
+#[derive(Default)]
+struct FadeState {
+    segment: Option<gst::FormattedSegment<gst::ClockTime>>,
+    alpha: Option<f64>,
+}
+
+/// Ramp a clip's compositor pad alpha up to its opacity over `fade_in`, per frame. `src_pad`
+/// is the last pad of the clip's branch before the compositor.
+pub fn follow_fade_in(src_pad: &gst::Pad, compositor_pad: &gst::Pad, fade_in: (f64, f64), opacity: SharedOpacity, timing: SharedClipTiming) {
+    let compositor_pad = compositor_pad.downgrade();
+    let state = Mutex::new(FadeState::default());
+    src_pad.add_probe(gst::PadProbeType::BUFFER | gst::PadProbeType::EVENT_DOWNSTREAM, move |_pad, info| {
+        let mut state = state.lock().unwrap();
+        let pts = match info.data {
+            Some(gst::PadProbeData::Event(ref event)) => {
+                if let gst::EventView::Segment(segment) = event.view() {
+                    state.segment = segment.segment().downcast_ref::<gst::ClockTime>().cloned();
+                }
+                return gst::PadProbeReturn::Ok;
+            }
+            Some(gst::PadProbeData::Buffer(ref buffer)) => buffer.pts(),
+            _ => return gst::PadProbeReturn::Ok,
+        };
+        let stream_time = state.segment.as_ref()
+            .zip(pts)
+            .and_then(|(segment, pts)| segment.to_stream_time(pts));
+        let Some(stream_time) = stream_time else {
+            return gst::PadProbeReturn::Ok;
+        };
+
+        // Stream time is the source position; map it onto the timeline
+        let timeline_ms = timing.lock().unwrap().timeline_ms(stream_time);
+        let alpha = *opacity.lock().unwrap() * ramp(fade_in, timeline_ms);
+        if state.alpha != Some(alpha) {
+            state.alpha = Some(alpha);
+            if let Some(compositor_pad) = compositor_pad.upgrade() {
+                compositor_pad.set_property("alpha", alpha);
+            }
+        }
+        gst::PadProbeReturn::Ok
+    });
+}
@@ -0,0 +1,150 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::common::types::RecoveryStage;
+
+/// How often the worker asks the watchdog for a verdict
+pub const CHECK_INTERVAL: Duration = Duration::from_millis(500);
+// A playing pipeline whose position doesn't move for this long is stalled
+const STALL_TIMEOUT: Duration = Duration::from_secs(3);
+// Healthy this long after a recovery step, and longer than a stall takes to detect, counts as recovered
+const CONFIRM_AFTER: Duration = Duration::from_secs(5);
+
+/// A pipeline error reported by the bus watch
+pub struct Failure {
+    pub reason: String,
+    pub was_playing: bool,
+}
+
+/// What the player should do after a check
+#[derive(Debug)]
+pub enum Verdict {
+    Healthy,
+    /// The last recovery step held up
+    Recovered(RecoveryStage),
+    /// Try `stage`, restoring playback if the pipeline was playing
+    Recover { stage: RecoveryStage, reason: String, was_playing: bool },
+    /// Every stage was tried for this failure
+    GiveUp { reason: String },
+}
+
+struct Incident {
+    stage: RecoveryStage,
+    was_playing: bool,
+    attempted_at: Instant,
+    gave_up: bool,
+}
+
+impl RecoveryStage {
+    fn next(self) -> Option<Self> {
+        match self {
+            RecoveryStage::FlushSeek => Some(RecoveryStage::RebuildSink),
+            RecoveryStage::RebuildSink => Some(RecoveryStage::RebuildPipeline),
+            RecoveryStage::RebuildPipeline => None,
+        }
+    }
+}
+
+/// Detects pipeline errors and stalled playback, and escalates through the recovery stages
+/// (flush seek, new video sink, new pipeline) while the same failure keeps coming back
+pub struct Watchdog {
+    enabled: bool,
+    failure: Arc<Mutex<Option<Failure>>>,
+    last_progress: Option<(u64, Instant)>,
+    incident: Option<Incident>,
+}
+
+impl Watchdog {
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            failure: Arc::new(Mutex::new(None)),
+            last_progress: None,
+            incident: None,
+        }
+    }
+
+    /// Where the bus watch records errors for the next check
+    pub fn failure_slot(&self) -> Arc<Mutex<Option<Failure>>> {
+        Arc::clone(&self.failure)
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.reset();
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Forget the current failure, e.g. when the user loads a timeline
+    pub fn reset(&mut self) {
+        self.failure.lock().unwrap().take();
+        self.last_progress = None;
+        self.incident = None;
+    }
+
+    /// `position_ms` is the pipeline position when it should be playing, `None` otherwise
+    pub fn check(&mut self, position_ms: Option<u64>) -> Verdict {
+        let failure = self.failure.lock().unwrap().take();
+        if !self.enabled {
+            return Verdict::Healthy;
+        }
+
+        let stalled = self.is_stalled(position_ms);
+        let (reason, was_playing) = match failure {
+            Some(failure) => (failure.reason, failure.was_playing),
+            None if stalled => ("Playback stalled".to_string(), true),
+            None => {
+                return match self.incident.take() {
+                    Some(incident) if !incident.gave_up && incident.attempted_at.elapsed() < CONFIRM_AFTER => {
+                        self.incident = Some(incident);
+                        Verdict::Healthy
+                    }
+                    Some(incident) if !incident.gave_up => Verdict::Recovered(incident.stage),
+                    // Stay quiet until a reset rather than retrying a failure that can't be fixed
+                    incident => {
+                        self.incident = incident;
+                        Verdict::Healthy
+                    }
+                };
+            }
+        };
+
+        let (stage, was_playing) = match self.incident.as_mut() {
+            None => (RecoveryStage::FlushSeek, was_playing),
+            Some(incident) if incident.gave_up => return Verdict::Healthy,
+            Some(incident) => match incident.stage.next() {
+                Some(stage) => (stage, incident.was_playing),
+                None => {
+                    incident.gave_up = true;
+                    return Verdict::GiveUp { reason };
+                }
+            },
+        };
+        self.incident = Some(Incident { stage, was_playing, attempted_at: Instant::now(), gave_up: false });
+        self.last_progress = None;
+        Verdict::Recover { stage, reason, was_playing }
+    }
+
+    fn is_stalled(&mut self, position_ms: Option<u64>) -> bool {
+        let Some(position_ms) = position_ms else {
+            self.last_progress = None;
+            return false;
+        };
+        match self.last_progress {
+            Some((last_ms, since)) if last_ms == position_ms => since.elapsed() >= STALL_TIMEOUT,
+            _ => {
+                self.last_progress = Some((position_ms, Instant::now()));
+                false
+            }
+        }
+    }
+}
+
+impl Default for Watchdog {
+    fn default() -> Self {
+        Self::new()
+    }
+}